@@ -0,0 +1,30 @@
+/*!
+Passes a camera matrix to a mint based API and back.
+*/
+
+use cvmath::*;
+
+// Stand-in for a renderer which accepts any matrix convertible to mint
+fn upload<M: Into<mint::ColumnMatrix4<f32>>>(mat: M) -> [[f32; 4]; 4] {
+	mat.into().into()
+}
+
+fn main() {
+	let eye = Vec3(2.0, 3.0, -5.0);
+	let view = Mat4::look_at(eye, Vec3::ZERO, Vec3::Y, LH);
+	let proj = Mat4::perspective(Deg(60.0), 16.0 / 9.0, 0.1, 100.0, (LH, ZO));
+	let camera = proj * view;
+
+	// The columns of the mint matrix are the columns of the camera matrix
+	let columns = upload(camera);
+	assert_eq!(columns, camera.into_column_major());
+
+	// And back again
+	let back = Mat4::from(mint::ColumnMatrix4::from(columns));
+	assert_eq!(back, camera);
+
+	// Vectors convert the same way
+	let clip = camera * Vec4::from_point3(Vec3::ZERO);
+	let mint_clip: mint::Vector4<f32> = clip.into();
+	println!("origin in clip space: {:?}", mint_clip);
+}
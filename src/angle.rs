@@ -73,6 +73,10 @@ pub const fn Deg<T>(value: T) -> Deg<T> {
 
 #[cfg(feature = "dataview")]
 unsafe impl<T: dataview::Pod> dataview::Pod for Deg<T> {}
+#[cfg(feature = "bytemuck")]
+unsafe impl<T: bytemuck::Zeroable> bytemuck::Zeroable for Deg<T> {}
+#[cfg(feature = "bytemuck")]
+unsafe impl<T: bytemuck::Pod> bytemuck::Pod for Deg<T> {}
 
 /// Angle (radians).
 #[derive(Copy, Clone, Default, Eq, PartialEq, Ord, PartialOrd, Hash)]
@@ -90,6 +94,10 @@ pub const fn Rad<T>(value: T) -> Rad<T> {
 
 #[cfg(feature = "dataview")]
 unsafe impl<T: dataview::Pod> dataview::Pod for Rad<T> {}
+#[cfg(feature = "bytemuck")]
+unsafe impl<T: bytemuck::Zeroable> bytemuck::Zeroable for Rad<T> {}
+#[cfg(feature = "bytemuck")]
+unsafe impl<T: bytemuck::Pod> bytemuck::Pod for Rad<T> {}
 
 macro_rules! turn {
 	(Deg) => (360.0);
@@ -105,7 +113,7 @@ macro_rules! cvt {
 macro_rules! fmt {
 	(Deg $fmt:path) => {
 		impl<T: $fmt> $fmt for Deg<T> {
-			fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+			fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
 				self.value.fmt(f)?;
 				f.write_str("°")
 			}
@@ -113,7 +121,7 @@ macro_rules! fmt {
 	};
 	(Rad $fmt:path) => {
 		impl<T: $fmt> $fmt for Rad<T> {
-			fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+			fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
 				self.value.fmt(f)?;
 				f.write_str(" rad")
 			}
@@ -211,6 +219,9 @@ macro_rules! angle {
 			/// Returns a turn of `0°` or `0π rad`.
 			#[inline]
 			pub fn zero() -> $ty<T> { Angle::zero() }
+			/// Returns `true` if the angle is finite.
+			#[inline]
+			pub fn is_finite(self) -> bool { self.value.is_finite() }
 			/// Normalizes the angle to range `[-180°, 180°]` or `[-π rad, π rad]`.
 			#[inline]
 			pub fn normalize(self) -> $ty<T> { Angle::normalize(self) }
@@ -249,6 +260,15 @@ macro_rules! angle {
 			/// Converts to radians.
 			#[inline]
 			pub fn to_rad(self) -> Rad<T> { Angle::to_rad(self) }
+			/// Critically damped spring smoothing.
+			///
+			/// Takes the shortest path around the circle, see [`spring_damp`](crate::spring_damp).
+			/// The result is not normalized.
+			#[inline]
+			pub fn spring_damp(self, target: $ty<T>, velocity: &mut $ty<T>, smooth_time: T, dt: T, max_speed: Option<$ty<T>>) -> $ty<T> {
+				let target = self + (target - self).normalize();
+				$ty(crate::spring_damp(self.value, target.value, &mut velocity.value, smooth_time, dt, max_speed.map(|speed| speed.value)))
+			}
 		}
 
 		//----------------------------------------------------------------
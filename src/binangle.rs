@@ -0,0 +1,221 @@
+/*!
+Binary angles.
+*/
+
+use super::*;
+
+/// Binary angle measurement.
+///
+/// Stores the angle as a fraction of a full turn in a `u16`, `65536` is a full turn.
+/// Arithmetic wraps around exactly and the sine and cosine are computed with a lookup table using only basic float operations.
+/// The results are bit-exact across platforms, suitable for deterministic lockstep simulations.
+///
+/// ```
+/// use cvmath::{BinAngle, Deg};
+///
+/// let a = BinAngle::from_angle(Deg(270.0f64));
+/// let b = BinAngle::from_angle(Deg(135.0f64));
+/// assert_eq!(a + b, BinAngle::from_angle(Deg(45.0f64)));
+/// assert_eq!((a + b).to_deg::<f64>(), Deg(45.0));
+/// ```
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[repr(transparent)]
+pub struct BinAngle(pub u16);
+
+#[cfg(feature = "dataview")]
+unsafe impl dataview::Pod for BinAngle {}
+#[cfg(feature = "bytemuck")]
+unsafe impl bytemuck::Zeroable for BinAngle {}
+#[cfg(feature = "bytemuck")]
+unsafe impl bytemuck::Pod for BinAngle {}
+
+impl BinAngle {
+	/// Angle of `0°`.
+	pub const ZERO: BinAngle = BinAngle(0);
+	/// Quarter turn of `90°`.
+	pub const QUARTER: BinAngle = BinAngle(0x4000);
+	/// Half turn of `180°`.
+	pub const HALF: BinAngle = BinAngle(0x8000);
+
+	/// Maximum absolute error of [sin_cos](#method.sin_cos) compared to the exact sine and cosine.
+	pub const MAX_ERROR: f32 = 5e-6;
+
+	/// Converts from an angle, rounded to the nearest binary angle.
+	///
+	/// ```
+	/// use cvmath::{BinAngle, Deg, Rad};
+	///
+	/// assert_eq!(BinAngle::from_angle(Deg(90.0f32)), BinAngle::QUARTER);
+	/// assert_eq!(BinAngle::from_angle(Deg(-90.0f32)), BinAngle(0xc000));
+	/// assert_eq!(BinAngle::from_angle(Rad(core::f64::consts::TAU)), BinAngle::ZERO);
+	/// ```
+	#[inline]
+	pub fn from_angle<T: Float>(angle: impl Angle<T = T>) -> BinAngle {
+		let turns: f64 = (angle.to_rad().value / T::cast_from(core::f64::consts::TAU)).cast_to();
+		let turns = turns - turns.floor();
+		BinAngle(((turns * 65536.0).round() as u32) as u16)
+	}
+	/// Converts to radians.
+	#[inline]
+	pub fn to_rad<T: Float>(self) -> Rad<T> {
+		Rad(T::cast_from(self.0 as f64 * (core::f64::consts::TAU / 65536.0)))
+	}
+	/// Converts to degrees.
+	#[inline]
+	pub fn to_deg<T: Float>(self) -> Deg<T> {
+		Deg(T::cast_from(self.0 as f64 * (360.0 / 65536.0)))
+	}
+
+	/// Sine and cosine of the angle.
+	///
+	/// Linearly interpolates a table of the quarter sine wave, the error is at most [MAX_ERROR](#associatedconstant.MAX_ERROR).
+	/// Only uses float additions and multiplications making the result bit-exact across platforms.
+	///
+	/// ```
+	/// use cvmath::BinAngle;
+	///
+	/// assert_eq!(BinAngle::ZERO.sin_cos(), (0.0, 1.0));
+	/// assert_eq!(BinAngle::QUARTER.sin_cos(), (1.0, 0.0));
+	/// let (sin, cos) = BinAngle(0x2000).sin_cos();
+	/// assert!((sin - 0.5f32.sqrt()).abs() <= BinAngle::MAX_ERROR);
+	/// assert!((cos - 0.5f32.sqrt()).abs() <= BinAngle::MAX_ERROR);
+	/// ```
+	#[inline]
+	pub fn sin_cos(self) -> (f32, f32) {
+		let index = (self.0 >> 6 & 0xff) as usize;
+		let frac = (self.0 & 0x3f) as f32 * (1.0 / 64.0);
+		let sin = SIN_TABLE[index] + (SIN_TABLE[index + 1] - SIN_TABLE[index]) * frac;
+		let cos = SIN_TABLE[256 - index] + (SIN_TABLE[255 - index] - SIN_TABLE[256 - index]) * frac;
+		match self.0 >> 14 {
+			0 => (sin, cos),
+			1 => (cos, -sin),
+			2 => (-sin, -cos),
+			_ => (-cos, sin),
+		}
+	}
+	/// Sine of the angle, see [sin_cos](#method.sin_cos).
+	#[inline]
+	pub fn sin(self) -> f32 {
+		self.sin_cos().0
+	}
+	/// Cosine of the angle, see [sin_cos](#method.sin_cos).
+	#[inline]
+	pub fn cos(self) -> f32 {
+		self.sin_cos().1
+	}
+}
+
+impl<T: Float> From<Rad<T>> for BinAngle {
+	#[inline]
+	fn from(angle: Rad<T>) -> BinAngle {
+		BinAngle::from_angle(angle)
+	}
+}
+impl<T: Float> From<Deg<T>> for BinAngle {
+	#[inline]
+	fn from(angle: Deg<T>) -> BinAngle {
+		BinAngle::from_angle(angle)
+	}
+}
+impl<T: Float> From<BinAngle> for Rad<T> {
+	#[inline]
+	fn from(angle: BinAngle) -> Rad<T> {
+		angle.to_rad()
+	}
+}
+impl<T: Float> From<BinAngle> for Deg<T> {
+	#[inline]
+	fn from(angle: BinAngle) -> Deg<T> {
+		angle.to_deg()
+	}
+}
+
+//----------------------------------------------------------------
+// Operators
+
+impl ops::Add for BinAngle {
+	type Output = BinAngle;
+	#[inline]
+	fn add(self, rhs: BinAngle) -> BinAngle {
+		BinAngle(self.0.wrapping_add(rhs.0))
+	}
+}
+impl ops::Sub for BinAngle {
+	type Output = BinAngle;
+	#[inline]
+	fn sub(self, rhs: BinAngle) -> BinAngle {
+		BinAngle(self.0.wrapping_sub(rhs.0))
+	}
+}
+impl ops::Neg for BinAngle {
+	type Output = BinAngle;
+	#[inline]
+	fn neg(self) -> BinAngle {
+		BinAngle(self.0.wrapping_neg())
+	}
+}
+impl ops::Mul<i32> for BinAngle {
+	type Output = BinAngle;
+	#[inline]
+	fn mul(self, rhs: i32) -> BinAngle {
+		BinAngle((self.0 as i32).wrapping_mul(rhs) as u16)
+	}
+}
+impl ops::AddAssign for BinAngle {
+	#[inline]
+	fn add_assign(&mut self, rhs: BinAngle) {
+		*self = *self + rhs;
+	}
+}
+impl ops::SubAssign for BinAngle {
+	#[inline]
+	fn sub_assign(&mut self, rhs: BinAngle) {
+		*self = *self - rhs;
+	}
+}
+impl ops::MulAssign<i32> for BinAngle {
+	#[inline]
+	fn mul_assign(&mut self, rhs: i32) {
+		*self = *self * rhs;
+	}
+}
+
+//----------------------------------------------------------------
+
+// Quarter sine wave in 256 steps, sin(i * π / 512) rounded to f32
+static SIN_TABLE: [f32; 257] = [
+	0.0, 0.0061358847, 0.012271538, 0.01840673, 0.024541229, 0.030674804, 0.036807224, 0.04293826,
+	0.049067676, 0.055195246, 0.061320737, 0.06744392, 0.07356457, 0.07968244, 0.08579731, 0.091908954,
+	0.09801714, 0.10412163, 0.110222206, 0.11631863, 0.12241068, 0.1284981, 0.1345807, 0.14065824,
+	0.14673047, 0.15279719, 0.15885815, 0.16491312, 0.17096189, 0.17700422, 0.18303989, 0.18906866,
+	0.19509032, 0.20110464, 0.20711137, 0.21311031, 0.21910124, 0.22508392, 0.2310581, 0.2370236,
+	0.24298018, 0.24892761, 0.25486565, 0.2607941, 0.26671275, 0.27262136, 0.2785197, 0.28440753,
+	0.29028466, 0.2961509, 0.30200595, 0.30784965, 0.31368175, 0.31950203, 0.3253103, 0.3311063,
+	0.33688986, 0.34266073, 0.34841868, 0.35416353, 0.35989505, 0.36561298, 0.3713172, 0.37700742,
+	0.38268343, 0.38834503, 0.39399204, 0.3996242, 0.4052413, 0.41084316, 0.41642955, 0.42200026,
+	0.42755508, 0.43309382, 0.43861625, 0.44412214, 0.44961134, 0.45508358, 0.46053872, 0.4659765,
+	0.47139674, 0.47679922, 0.48218378, 0.48755017, 0.4928982, 0.49822766, 0.50353837, 0.50883013,
+	0.51410276, 0.519356, 0.52458966, 0.52980363, 0.53499764, 0.54017144, 0.545325, 0.55045795,
+	0.55557024, 0.56066155, 0.5657318, 0.57078075, 0.57580817, 0.58081394, 0.58579785, 0.5907597,
+	0.5956993, 0.60061646, 0.60551107, 0.6103828, 0.6152316, 0.6200572, 0.6248595, 0.62963825,
+	0.6343933, 0.63912445, 0.64383155, 0.6485144, 0.65317285, 0.6578067, 0.6624158, 0.66699994,
+	0.671559, 0.6760927, 0.680601, 0.6850837, 0.68954057, 0.69397146, 0.69837624, 0.70275474,
+	0.70710677, 0.7114322, 0.71573085, 0.72000253, 0.7242471, 0.72846437, 0.7326543, 0.7368166,
+	0.7409511, 0.74505776, 0.7491364, 0.7531868, 0.7572088, 0.7612024, 0.76516724, 0.76910335,
+	0.77301043, 0.7768885, 0.7807372, 0.78455657, 0.7883464, 0.79210657, 0.7958369, 0.79953724,
+	0.8032075, 0.8068476, 0.81045717, 0.8140363, 0.8175848, 0.8211025, 0.8245893, 0.82804507,
+	0.8314696, 0.8348629, 0.8382247, 0.841555, 0.8448536, 0.84812033, 0.8513552, 0.854558,
+	0.8577286, 0.86086696, 0.86397284, 0.86704624, 0.87008697, 0.873095, 0.8760701, 0.8790122,
+	0.8819213, 0.8847971, 0.88763964, 0.89044875, 0.8932243, 0.89596623, 0.8986745, 0.9013488,
+	0.9039893, 0.9065957, 0.909168, 0.91170603, 0.9142098, 0.9166791, 0.9191139, 0.92151403,
+	0.9238795, 0.9262102, 0.9285061, 0.93076694, 0.9329928, 0.9351835, 0.937339, 0.9394592,
+	0.94154406, 0.94359344, 0.9456073, 0.9475856, 0.94952816, 0.951435, 0.953306, 0.9551412,
+	0.95694035, 0.95870346, 0.9604305, 0.9621214, 0.96377605, 0.96539444, 0.96697646, 0.9685221,
+	0.97003126, 0.9715039, 0.97293997, 0.97433937, 0.9757021, 0.97702813, 0.9783174, 0.9795698,
+	0.98078525, 0.9819639, 0.9831055, 0.9842101, 0.98527765, 0.9863081, 0.9873014, 0.9882576,
+	0.9891765, 0.9900582, 0.99090266, 0.99170977, 0.99247956, 0.9932119, 0.993907, 0.9945646,
+	0.9951847, 0.9957674, 0.9963126, 0.9968203, 0.99729043, 0.99772304, 0.9981181, 0.99847555,
+	0.99879545, 0.99907774, 0.99932235, 0.9995294, 0.9996988, 0.9998306, 0.9999247, 0.99998116,
+	1.0,
+];
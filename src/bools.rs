@@ -9,6 +9,8 @@ Comparison masks are boolean vectors to be consumed by `select`.
 
 `is_infinite(self)`: Creates a mask for infinite components.
 
+`is_nan(self)`: Creates a mask for NaN components.
+
 `eq(self, rhs)`: Creates a mask for equal components.
 
 `ne(self, rhs)`: Creates a mask for unequal components.
@@ -25,12 +27,20 @@ Comparison masks are boolean vectors to be consumed by `select`.
 
 `all_close(self, rhs)`: Equivalent to `self.is_close(rhs).all()`.
 
+`all_finite(self)`: Equivalent to `self.is_finite().all()`.
+
+`any_nan(self)`: Equivalent to `self.is_nan().any()`.
+
 ### Examples
 
 ```
 use cvmath::{Vec2, Bool2};
 
 assert_eq!(Bool2 { x: true, y: false }, Vec2(1, 2).eq(Vec2(1, -2)));
+
+assert!(Vec2(1.0, 2.0).all_finite());
+assert!(!Vec2(1.0, f32::INFINITY).all_finite());
+assert!(Vec2(f64::NAN, 2.0).any_nan());
 ```
 
 ## Comparison operators
@@ -43,6 +53,12 @@ assert_eq!(Bool2 { x: true, y: false }, Vec2(1, 2).eq(Vec2(1, -2)));
 
 `select(self, lhs, rhs)`: Combines two vectors based on the bools, selecting components from the left-hand side if `true` and right-hand side if `false`.
 
+`select_scalar(self, lhs, rhs)`: Like `select` but with scalars for both sides.
+
+`bitmask(self)`, `from_bitmask(mask)`: Converts to and from a bitmask with the first component in the least significant bit.
+
+The bitwise operators `&`, `|`, `^` and `!` apply component-wise.
+
 ### Examples
 
 ```
@@ -51,6 +67,12 @@ use cvmath::{Bool2};
 assert!(Bool2 { x: true, y: false }.any());
 assert!(Bool2 { x: true, y: true }.all());
 assert!(Bool2 { x: false, y: false }.none());
+assert_eq!(Bool2(true, false) & Bool2(true, true), Bool2(true, false));
+assert_eq!(Bool2(true, false) | Bool2(false, false), Bool2(true, false));
+assert_eq!(Bool2(true, false) ^ Bool2(true, true), Bool2(false, true));
+assert_eq!(!Bool2(true, false), Bool2(false, true));
+assert_eq!(Bool2::from([true, false]), Bool2(true, false));
+assert_eq!(Bool2::from_bitmask(Bool2(false, true).bitmask()), Bool2(false, true));
 ```
 
 */
@@ -65,7 +87,7 @@ pub type Bool3 = Vec3<bool>;
 pub type Bool4 = Vec4<bool>;
 
 macro_rules! bools {
-	($bools:ident $vec:ident { $($field:ident),+ }) => {
+	($bools:ident $vec:ident { $($field:ident $I:tt),+ }) => {
 
 		#[doc = stringify!($bools)]
 		#[doc = " constructor."]
@@ -89,32 +111,73 @@ macro_rules! bools {
 			pub fn is_infinite(self) -> $bools where T: Float {
 				$vec { $($field: self.$field.is_infinite()),+ }
 			}
+			/// Creates a mask for NaN components.
+			#[inline]
+			pub fn is_nan(self) -> $bools where T: Float {
+				$vec { $($field: self.$field.is_nan()),+ }
+			}
 			/// Creates a mask for equal components.
+			///
+			/// ```
+			/// use cvmath::{Bool3, Vec3};
+			///
+			/// assert_eq!(Vec3(1, 2, 3).eq(Vec3(1, 0, 3)), Bool3(true, false, true));
+			/// ```
 			#[inline]
 			pub fn eq(self, rhs: $vec<T>) -> $bools where T: PartialEq {
 				$vec { $($field: self.$field == rhs.$field),+ }
 			}
 			/// Creates a mask for inequal components.
+			///
+			/// ```
+			/// use cvmath::{Bool3, Vec3};
+			///
+			/// assert_eq!(Vec3(1, 2, 3).ne(Vec3(1, 0, 3)), Bool3(false, true, false));
+			/// ```
 			#[inline]
 			pub fn ne(self, rhs: $vec<T>) -> $bools where T: PartialEq {
 				$vec { $($field: self.$field != rhs.$field),+ }
 			}
 			/// Creates a mask for left-hand side components are less than the right-hand side.
+			///
+			/// ```
+			/// use cvmath::{Bool3, Vec3};
+			///
+			/// assert_eq!(Vec3(1, 2, 3).lt(Vec3(2, 2, 2)), Bool3(true, false, false));
+			/// ```
 			#[inline]
 			pub fn lt(self, rhs: $vec<T>) -> $bools where T: PartialOrd {
 				$vec { $($field: self.$field < rhs.$field),+ }
 			}
 			/// Creates a mask for left-hand side components are less than or equal the right-hand side.
+			///
+			/// ```
+			/// use cvmath::{Bool3, Vec3};
+			///
+			/// assert_eq!(Vec3(1, 2, 3).le(Vec3(2, 2, 2)), Bool3(true, true, false));
+			/// ```
 			#[inline]
 			pub fn le(self, rhs: $vec<T>) -> $bools where T: PartialOrd {
 				$vec { $($field: self.$field <= rhs.$field),+ }
 			}
 			/// Creates a mask for left-hand side components are greater than the right-hand side.
+			///
+			/// ```
+			/// use cvmath::{Bool3, Vec3};
+			///
+			/// assert_eq!(Vec3(1, 2, 3).gt(Vec3(2, 2, 2)), Bool3(false, false, true));
+			/// ```
 			#[inline]
 			pub fn gt(self, rhs: $vec<T>) -> $bools where T: PartialOrd {
 				$vec { $($field: self.$field > rhs.$field),+ }
 			}
 			/// Creates a mask for left-hand side components are greater than or equal the right-hand side.
+			///
+			/// ```
+			/// use cvmath::{Bool3, Vec3};
+			///
+			/// assert_eq!(Vec3(1, 2, 3).ge(Vec3(2, 2, 2)), Bool3(false, true, true));
+			/// ```
 			#[inline]
 			pub fn ge(self, rhs: $vec<T>) -> $bools where T: PartialOrd {
 				$vec { $($field: self.$field >= rhs.$field),+ }
@@ -132,6 +195,16 @@ macro_rules! bools {
 			pub fn all_close(self, rhs: $vec<T>) -> bool where T: Float {
 				self.is_close(rhs).all()
 			}
+			/// Returns true if all the components are finite.
+			#[inline]
+			pub fn all_finite(self) -> bool where T: Float {
+				self.is_finite().all()
+			}
+			/// Returns true if any of the components are NaN.
+			#[inline]
+			pub fn any_nan(self) -> bool where T: Float {
+				self.is_nan().any()
+			}
 		}
 
 		//----------------------------------------------------------------
@@ -139,25 +212,92 @@ macro_rules! bools {
 
 		impl $bools {
 			/// Returns `true` if any of the components are `true`.
+			///
+			/// ```
+			/// use cvmath::Bool3;
+			///
+			/// assert!(Bool3(false, true, false).any());
+			/// assert!(!Bool3(false, false, false).any());
+			/// ```
 			#[inline]
 			pub const fn any(self) -> bool {
 				infix!(|| $(self.$field),+)
 			}
 			/// Returns `true` if all the components are `true`.
+			///
+			/// ```
+			/// use cvmath::Bool3;
+			///
+			/// assert!(Bool3(true, true, true).all());
+			/// assert!(!Bool3(true, false, true).all());
+			/// ```
 			#[inline]
 			pub const fn all(self) -> bool {
 				infix!(&& $(self.$field),+)
 			}
 			/// Returns `true` if none of the components are `true`.
+			///
+			/// ```
+			/// use cvmath::Bool3;
+			///
+			/// assert!(Bool3(false, false, false).none());
+			/// assert!(!Bool3(false, true, false).none());
+			/// ```
 			#[inline]
 			pub const fn none(self) -> bool {
 				!self.any()
 			}
 			/// Combines two vectors based on the bools, selecting components from the left-hand side if `true` and right-hand side if `false`.
+			///
+			/// ```
+			/// use cvmath::{Bool3, Vec3};
+			///
+			/// let mask = Bool3(true, false, true);
+			/// assert_eq!(mask.select(Vec3(1, 2, 3), Vec3(4, 5, 6)), Vec3(1, 5, 3));
+			/// ```
 			#[inline]
 			pub fn select<T>(self, lhs: $vec<T>, rhs: $vec<T>) -> $vec<T> {
 				$vec { $($field: if self.$field { lhs.$field } else { rhs.$field }),+ }
 			}
+			/// Combines two scalars based on the bools, selecting the left-hand side if `true` and right-hand side if `false`.
+			///
+			/// ```
+			/// use cvmath::{Bool3, Vec3};
+			///
+			/// let mask = Bool3(true, false, true);
+			/// assert_eq!(mask.select_scalar(1.0, -1.0), Vec3(1.0, -1.0, 1.0));
+			/// ```
+			#[inline]
+			pub fn select_scalar<T: Copy>(self, lhs: T, rhs: T) -> $vec<T> {
+				$vec { $($field: if self.$field { lhs } else { rhs }),+ }
+			}
+			/// Packs the bools in a bitmask, the first component in the least significant bit.
+			///
+			/// ```
+			/// use cvmath::Bool3;
+			///
+			/// assert_eq!(Bool3(true, false, true).bitmask(), 0b101);
+			/// ```
+			#[inline]
+			pub const fn bitmask(self) -> u8 {
+				let mut mask = 0;
+				$(mask |= (self.$field as u8) << $I;)+
+				mask
+			}
+			/// Unpacks the bools from a bitmask, the first component in the least significant bit.
+			///
+			/// Excess bits are ignored.
+			///
+			/// ```
+			/// use cvmath::Bool3;
+			///
+			/// assert_eq!(Bool3::from_bitmask(0b110), Bool3(false, true, true));
+			/// assert_eq!(Bool3::from_bitmask(0b1000), Bool3(false, false, false));
+			/// ```
+			#[inline]
+			pub const fn from_bitmask(mask: u8) -> $bools {
+				$vec { $($field: mask & (1 << $I) != 0),+ }
+			}
 		}
 
 		//----------------------------------------------------------------
@@ -194,6 +334,6 @@ macro_rules! bools {
 	};
 }
 
-bools!(Bool2 Vec2 { x, y });
-bools!(Bool3 Vec3 { x, y, z });
-bools!(Bool4 Vec4 { x, y, z, w });
+bools!(Bool2 Vec2 { x 0, y 1 });
+bools!(Bool3 Vec3 { x 0, y 1, z 2 });
+bools!(Bool4 Vec4 { x 0, y 1, z 2, w 3 });
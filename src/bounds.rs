@@ -24,6 +24,10 @@ pub const fn Bounds<T>(mins: T, maxs: T) -> Bounds<T> {
 
 #[cfg(feature = "dataview")]
 unsafe impl<T: dataview::Pod> dataview::Pod for Bounds<T> {}
+#[cfg(feature = "bytemuck")]
+unsafe impl<T: bytemuck::Zeroable> bytemuck::Zeroable for Bounds<T> {}
+#[cfg(feature = "bytemuck")]
+unsafe impl<T: bytemuck::Pod> bytemuck::Pod for Bounds<T> {}
 
 impl<T: Zero> Bounds<T> {
 	/// Zero bounds.
@@ -93,6 +97,21 @@ impl<T> Bounds<T> {
 }
 
 impl<T> Bounds<T> {
+	/// Returns whether `self.mins <= self.maxs` for every component.
+	///
+	/// Bounds with NaN components are not valid.
+	///
+	/// ```
+	/// use cvmath::{Bounds, Point2};
+	///
+	/// assert!(Bounds(Point2(1.0, 1.0), Point2(4.0, 1.0)).is_valid());
+	/// assert!(!Bounds(Point2(1.0, 1.0), Point2(0.0, 3.0)).is_valid());
+	/// assert!(!Bounds(Point2(1.0, f64::NAN), Point2(4.0, 3.0)).is_valid());
+	/// ```
+	#[inline]
+	pub fn is_valid(&self) -> bool where T: SpatialOrd {
+		self.mins.spatial_le(&self.maxs)
+	}
 	/// Returns whether the point `rhs` is contained within `self`.
 	///
 	/// <!--CONTAINS-->
@@ -152,6 +171,22 @@ impl<T> Bounds<T> {
 		let maxs = self.maxs.max(pt);
 		Bounds { mins, maxs }
 	}
+	/// Returns the bounds of the points.
+	///
+	/// Returns `None` if there are no points.
+	///
+	/// ```
+	/// use cvmath::{Bounds, Point2};
+	///
+	/// let points = [Point2(1, 4), Point2(-2, 3), Point2(0, -1)];
+	/// assert_eq!(Bounds::from_points(points), Some(Bounds(Point2(-2, -1), Point2(1, 4))));
+	/// assert_eq!(Bounds::<Point2<i32>>::from_points([]), None);
+	/// ```
+	pub fn from_points<I: IntoIterator<Item = T>>(points: I) -> Option<Bounds<T>> where T: Copy + Extrema {
+		let mut iter = points.into_iter();
+		let first = iter.next()?;
+		Some(iter.fold(Bounds { mins: first, maxs: first }, Bounds::include))
+	}
 	/// Returns the new bounds containing both `rhs` and `self`.
 	///
 	/// <!--UNION-->
@@ -207,6 +242,27 @@ impl<T> Bounds<T> {
 			None
 		}
 	}
+	/// Clips `self` to the area covered by `rhs`.
+	///
+	/// Unlike [`intersect`](Bounds::intersect) this always returns bounds.
+	/// When there is no overlap the result is collapsed to an empty bounds instead of being inverted.
+	///
+	/// ```
+	/// use cvmath::{Bounds, Point2};
+	///
+	/// let screen = Bounds(Point2(0, 0), Point2(640, 480));
+	/// let sprite = Bounds(Point2(600, -20), Point2(680, 60));
+	/// assert_eq!(Bounds(Point2(600, 0), Point2(640, 60)), sprite.clip_to(screen));
+	///
+	/// let offscreen = Bounds(Point2(700, 10), Point2(720, 30));
+	/// assert_eq!(Bounds(Point2(700, 10), Point2(700, 30)), offscreen.clip_to(screen));
+	/// ```
+	#[inline]
+	pub fn clip_to(self, rhs: Bounds<T>) -> Bounds<T> where T: Copy + Extrema {
+		let mins = self.mins.max(rhs.mins);
+		let maxs = self.maxs.min(rhs.maxs).max(mins);
+		Bounds { mins, maxs }
+	}
 }
 impl<T> Bounds<T> {
 	/// Returns whether `rhs` is strictly contained within `self`.
@@ -364,6 +420,58 @@ impl<T: Scalar> Rect<T> {
 	pub fn bottom_right(&self) -> Point2<T> {
 		self.maxs
 	}
+	/// Corners of the rectangle.
+	///
+	/// The corners are ordered top left, top right, bottom right and bottom left.
+	///
+	/// ```
+	/// use cvmath::{Line2, Rect, Point2};
+	///
+	/// let rect = Rect(Point2(1, 2), Point2(4, 3));
+	/// let corners = rect.corners();
+	/// assert_eq!(corners, [Point2(1, 2), Point2(4, 2), Point2(4, 3), Point2(1, 3)]);
+	///
+	/// let [a, b] = rect.edges()[1];
+	/// assert_eq!(rect.right_side(), Line2(corners[a], corners[b]));
+	/// ```
+	#[inline]
+	pub fn corners(&self) -> [Point2<T>; 4] {
+		[self.top_left(), self.top_right(), self.bottom_right(), self.bottom_left()]
+	}
+	/// Edges of the rectangle as index pairs into [`corners`](Rect::corners).
+	///
+	/// The edges are ordered top, right, bottom and left side.
+	#[inline]
+	pub fn edges(&self) -> [[usize; 2]; 4] {
+		[[0, 1], [1, 2], [2, 3], [3, 0]]
+	}
+	/// Splits the rectangle at its center into four quadrants.
+	///
+	/// The index of each quadrant encodes which half it covers: bit 0 is set for the upper half along `x`, bit 1 for the upper half along `y`.
+	/// This puts `mins` in the first quadrant and `maxs` in the last quadrant.
+	///
+	/// ```
+	/// use cvmath::{Point2, Rect};
+	///
+	/// let rect = Rect(Point2(0, 0), Point2(4, 2));
+	/// assert_eq!(rect.subdivide(), [
+	/// Rect(Point2(0, 0), Point2(2, 1)),
+	/// Rect(Point2(2, 0), Point2(4, 1)),
+	/// Rect(Point2(0, 1), Point2(2, 2)),
+	/// Rect(Point2(2, 1), Point2(4, 2)),
+	/// ]);
+	/// ```
+	#[inline]
+	pub fn subdivide(&self) -> [Rect<T>; 4] {
+		let Bounds { mins, maxs } = *self;
+		let c = self.center();
+		[
+			Bounds { mins: Point2(mins.x, mins.y), maxs: Point2(c.x, c.y) },
+			Bounds { mins: Point2(c.x, mins.y), maxs: Point2(maxs.x, c.y) },
+			Bounds { mins: Point2(mins.x, c.y), maxs: Point2(c.x, maxs.y) },
+			Bounds { mins: Point2(c.x, c.y), maxs: Point2(maxs.x, maxs.y) },
+		]
+	}
 	/// Top side of the rectangle.
 	#[inline]
 	pub fn top_side(&self) -> Line2<T> {
@@ -405,8 +513,328 @@ impl<T: Scalar> Rect<T> {
 			self.mins,
 		)
 	}
+	/// Transforms the rectangle and refits the bounds around the result.
+	///
+	/// The resulting bounds enclose all the transformed corners.
+	/// Unlike [`into_transform`](Rect::into_transform) this transforms the rectangle itself.
+	///
+	/// ```
+	/// use cvmath::{Deg, Point2, Rect, Transform2};
+	///
+	/// let rect = Rect(Point2(-1.0, -1.0), Point2(1.0, 1.0));
+	/// let result = rect.transform(&Transform2::rotate(Deg(45.0)));
+	/// let sqrt2 = 2.0f64.sqrt();
+	/// assert!(result.mins.distance(Point2(-sqrt2, -sqrt2)) < 1e-12);
+	/// assert!(result.maxs.distance(Point2(sqrt2, sqrt2)) < 1e-12);
+	/// ```
+	#[inline]
+	pub fn transform(self, m: &Transform2<T>) -> Rect<T> {
+		let Bounds { mins, maxs } = self;
+		let fit = |a1: T, a2: T, a3: T| {
+			let (x1, x2) = (mins.x * a1).min_max(maxs.x * a1);
+			let (y1, y2) = (mins.y * a2).min_max(maxs.y * a2);
+			(x1 + y1 + a3, x2 + y2 + a3)
+		};
+		let (x1, x2) = fit(m.a11, m.a12, m.a13);
+		let (y1, y2) = fit(m.a21, m.a22, m.a23);
+		Bounds { mins: Point2(x1, y1), maxs: Point2(x2, y2) }
+	}
+	/// Expands the rectangle outward by the margin on every side.
+	///
+	/// A negative margin shrinks the rectangle, see [`inflate_clamped`](Rect::inflate_clamped) to avoid inverting the bounds.
+	#[inline]
+	pub fn inflate(self, margin: T) -> Rect<T> {
+		self.inflate_vec(Vec2::dup(margin))
+	}
+	/// Expands the rectangle outward by a per-axis margin.
+	#[inline]
+	pub fn inflate_vec(self, margin: Vec2<T>) -> Rect<T> {
+		Bounds { mins: self.mins - margin, maxs: self.maxs + margin }
+	}
+	/// Expands the rectangle outward by the margin, clamping negative margins so the bounds never invert.
+	///
+	/// Shrinking by more than half the extent collapses that axis onto the center.
+	///
+	/// ```
+	/// use cvmath::{Point2, Rect};
+	///
+	/// let rect = Rect(Point2(0.0, 0.0), Point2(4.0, 2.0));
+	/// assert_eq!(rect.inflate_clamped(1.0), Rect(Point2(-1.0, -1.0), Point2(5.0, 3.0)));
+	/// assert_eq!(rect.inflate_clamped(-1.5), Rect(Point2(1.5, 1.0), Point2(2.5, 1.0)));
+	/// assert_eq!(rect.inflate_clamped(-5.0), Rect::point(Point2(2.0, 1.0)));
+	/// ```
+	#[inline]
+	pub fn inflate_clamped(self, margin: T) -> Rect<T> {
+		let center = self.center();
+		let margin = Vec2::dup(margin);
+		Bounds { mins: (self.mins - margin).min(center), maxs: (self.maxs + margin).max(center) }
+	}
+	/// Shrinks the rectangle inward by a per-axis margin on every side.
+	///
+	/// Insetting by more than half the extent collapses that axis onto the center, the bounds never invert.
+	///
+	/// ```
+	/// use cvmath::{Point2, Rect, Vec2};
+	///
+	/// let rect = Rect(Point2(0, 0), Point2(10, 4));
+	/// assert_eq!(rect.inset(Vec2(2, 1)), Rect(Point2(2, 1), Point2(8, 3)));
+	/// assert_eq!(rect.inset(Vec2(1, 3)), Rect(Point2(1, 2), Point2(9, 2)));
+	/// ```
+	#[inline]
+	pub fn inset(self, by: Vec2<T>) -> Rect<T> {
+		let center = self.center();
+		Bounds { mins: (self.mins + by).min(center), maxs: (self.maxs - by).max(center) }
+	}
+	/// Scales the rectangle by a per-axis factor keeping the anchor point fixed.
+	///
+	/// ```
+	/// use cvmath::{Point2, Rect, Vec2};
+	///
+	/// let rect = Rect(Point2(0.0, 0.0), Point2(4.0, 2.0));
+	/// let scaled = rect.scaled_about(Vec2(2.0, 0.5), rect.bottom_right());
+	/// assert_eq!(scaled, Rect(Point2(-4.0, 1.0), Point2(4.0, 2.0)));
+	/// ```
+	#[inline]
+	pub fn scaled_about(self, factor: Vec2<T>, anchor: Point2<T>) -> Rect<T> {
+		Bounds {
+			mins: anchor + (self.mins - anchor) * factor,
+			maxs: anchor + (self.maxs - anchor) * factor,
+		}
+	}
+	/// Positions a child rectangle of the given size inside the rectangle.
+	///
+	/// The child is aligned to the side or corner of the anchor, see [`Anchor`].
+	/// A child larger than the rectangle extends beyond its sides.
+	///
+	/// ```
+	/// use cvmath::{Anchor, Point2, Rect, Vec2};
+	///
+	/// let rect = Rect(Point2(0, 0), Point2(100, 50));
+	/// assert_eq!(rect.anchored(Vec2(20, 10), Anchor::TopLeft), Rect(Point2(0, 0), Point2(20, 10)));
+	/// assert_eq!(rect.anchored(Vec2(20, 10), Anchor::Center), Rect(Point2(40, 20), Point2(60, 30)));
+	/// assert_eq!(rect.anchored(Vec2(20, 10), Anchor::BottomRight), Rect(Point2(80, 40), Point2(100, 50)));
+	/// ```
+	#[inline]
+	pub fn anchored(self, size: Vec2<T>, anchor: Anchor) -> Rect<T> {
+		let two = T::ONE + T::ONE;
+		// Keep the aligned side exact
+		let align = |min: T, max: T, size: T, align: i8| match align {
+			-1 => (min, min + size),
+			0 => {
+				let start = min + (max - min - size) / two;
+				(start, start + size)
+			},
+			_ => (max - size, max),
+		};
+		let (ax, ay) = anchor.align();
+		let (x1, x2) = align(self.mins.x, self.maxs.x, size.x, ax);
+		let (y1, y2) = align(self.mins.y, self.maxs.y, size.y, ay);
+		Bounds { mins: Point2(x1, y1), maxs: Point2(x2, y2) }
+	}
+	/// Splits the rectangle into a left and right part.
+	///
+	/// The split lies at the distance from the left side, clamped to the rectangle.
+	///
+	/// ```
+	/// use cvmath::{Point2, Rect};
+	///
+	/// let rect = Rect(Point2(0, 0), Point2(10, 4));
+	/// assert_eq!(rect.split_h(3), (Rect(Point2(0, 0), Point2(3, 4)), Rect(Point2(3, 0), Point2(10, 4))));
+	/// assert_eq!(rect.split_h(20).1, Rect(Point2(10, 0), Point2(10, 4)));
+	/// ```
+	#[inline]
+	pub fn split_h(self, at: T) -> (Rect<T>, Rect<T>) {
+		let x = (self.mins.x + at).clamp(self.mins.x, self.maxs.x);
+		(
+			Bounds { mins: self.mins, maxs: Point2(x, self.maxs.y) },
+			Bounds { mins: Point2(x, self.mins.y), maxs: self.maxs },
+		)
+	}
+	/// Splits the rectangle into a top and bottom part.
+	///
+	/// The split lies at the distance from the top side, clamped to the rectangle.
+	///
+	/// ```
+	/// use cvmath::{Point2, Rect};
+	///
+	/// let rect = Rect(Point2(0, 0), Point2(10, 4));
+	/// assert_eq!(rect.split_v(1), (Rect(Point2(0, 0), Point2(10, 1)), Rect(Point2(0, 1), Point2(10, 4))));
+	/// ```
+	#[inline]
+	pub fn split_v(self, at: T) -> (Rect<T>, Rect<T>) {
+		let y = (self.mins.y + at).clamp(self.mins.y, self.maxs.y);
+		(
+			Bounds { mins: self.mins, maxs: Point2(self.maxs.x, y) },
+			Bounds { mins: Point2(self.mins.x, y), maxs: self.maxs },
+		)
+	}
+}
+
+/// Alignment of a child rectangle inside its parent.
+///
+/// The top side is at the minimum `y` coordinate, matching [`Rect::top`].
+/// See [`Rect::anchored`].
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq, Hash)]
+pub enum Anchor {
+	TopLeft,
+	Top,
+	TopRight,
+	Left,
+	#[default]
+	Center,
+	Right,
+	BottomLeft,
+	Bottom,
+	BottomRight,
+}
+
+impl Anchor {
+	// Alignment per axis, -1 to the minimum side, 0 centered and 1 to the maximum side
+	#[inline]
+	fn align(self) -> (i8, i8) {
+		match self {
+			Anchor::TopLeft => (-1, -1),
+			Anchor::Top => (0, -1),
+			Anchor::TopRight => (1, -1),
+			Anchor::Left => (-1, 0),
+			Anchor::Center => (0, 0),
+			Anchor::Right => (1, 0),
+			Anchor::BottomLeft => (-1, 1),
+			Anchor::Bottom => (0, 1),
+			Anchor::BottomRight => (1, 1),
+		}
+	}
+}
+
+impl<T: Float> Rect<T> {
+	/// Closest point in the rectangle.
+	///
+	/// Points inside the rectangle are returned unchanged.
+	///
+	/// ```
+	/// use cvmath::{Point2, Rect};
+	///
+	/// let rect = Rect(Point2(0.0, 0.0), Point2(4.0, 2.0));
+	/// assert_eq!(rect.closest_point(Point2(1.0, 1.0)), Point2(1.0, 1.0));
+	/// assert_eq!(rect.closest_point(Point2(-3.0, 1.0)), Point2(0.0, 1.0));
+	/// assert_eq!(rect.closest_point(Point2(6.0, 5.0)), Point2(4.0, 2.0));
+	/// ```
+	#[inline]
+	pub fn closest_point(&self, pt: Point2<T>) -> Point2<T> {
+		pt.max(self.mins).min(self.maxs)
+	}
+	/// Signed distance to the rectangle's boundary.
+	///
+	/// Negative inside and positive outside, the distance is exact including around the corners.
+	///
+	/// ```
+	/// use cvmath::{Point2, Rect};
+	///
+	/// let rect = Rect(Point2(0.0, 0.0), Point2(4.0, 2.0));
+	/// assert_eq!(rect.sdf(Point2(1.0, 1.0)), -1.0);
+	/// assert_eq!(rect.sdf(Point2(-3.0, 1.0)), 3.0);
+	/// assert_eq!(rect.sdf(Point2(7.0, 6.0)), 5.0);
+	/// ```
+	#[inline]
+	pub fn sdf(&self, pt: Point2<T>) -> T {
+		let half = self.size() * T::cast_from(0.5);
+		let q = (pt - self.center()).abs() - half;
+		let outside = q.max(Vec2::ZERO).len();
+		let inside = T::min(q.vmax(), T::ZERO);
+		outside + inside
+	}
+	/// Returns `true` if all the components are finite.
+	#[inline]
+	pub fn is_finite(&self) -> bool {
+		self.mins.all_finite() && self.maxs.all_finite()
+	}
+}
+
+impl<T: Scalar> Measure2<T> for Rect<T> {
+	#[inline]
+	fn area(&self) -> T {
+		Rect::area(self)
+	}
+	#[inline]
+	fn perimeter(&self) -> T {
+		(self.width() + self.height()) * (T::ONE + T::ONE)
+	}
+}
+
+impl<T: Int + CastTo<usize>> Rect<T> {
+	/// Number of integer points contained in the rectangle.
+	///
+	/// Follows the same convention as [`points`](Rect::points), empty and inverted rectangles contain no points.
+	///
+	/// ```
+	/// use cvmath::{Rect, Point2};
+	///
+	/// assert_eq!(6, Rect(Point2(1, 1), Point2(4, 3)).pixel_count());
+	/// assert_eq!(0, Rect(Point2(4, 3), Point2(1, 1)).pixel_count());
+	/// ```
+	#[inline]
+	pub fn pixel_count(&self) -> usize {
+		let size = self.size().max(Vec2::ZERO);
+		size.x.cast_to() * size.y.cast_to()
+	}
+	/// Iterates over the integer points contained in the rectangle in row-major order.
+	///
+	/// The points are taken from the half-open range `[mins, maxs)` matching [`strictly_contains`](Bounds::strictly_contains).
+	/// Empty and inverted rectangles yield no points.
+	///
+	/// ```
+	/// use cvmath::{Rect, Point2};
+	///
+	/// let rect = Rect(Point2(1, 1), Point2(4, 3));
+	/// let points: Vec<_> = rect.points().collect();
+	/// assert_eq!(points, [Point2(1, 1), Point2(2, 1), Point2(3, 1), Point2(1, 2), Point2(2, 2), Point2(3, 2)]);
+	/// assert_eq!(rect.pixel_count(), rect.points().len());
+	/// assert!(points.iter().all(|&pt| rect.strictly_contains(pt)));
+	///
+	/// assert_eq!(0, Rect(Point2(1, 1), Point2(1, 3)).points().count());
+	/// assert_eq!(0, Rect(Point2(4, 3), Point2(1, 1)).points().count());
+	/// ```
+	#[inline]
+	pub fn points(&self) -> impl ExactSizeIterator<Item = Point2<T>> {
+		RectPoints {
+			mins: self.mins,
+			maxs: self.maxs,
+			next: self.mins,
+			remaining: self.pixel_count(),
+		}
+	}
 }
 
+#[derive(Clone, Debug)]
+struct RectPoints<T> {
+	mins: Point2<T>,
+	maxs: Point2<T>,
+	next: Point2<T>,
+	remaining: usize,
+}
+impl<T: Int> Iterator for RectPoints<T> {
+	type Item = Point2<T>;
+	#[inline]
+	fn next(&mut self) -> Option<Point2<T>> {
+		if self.remaining == 0 {
+			return None;
+		}
+		self.remaining -= 1;
+		let pt = self.next;
+		self.next.x += T::ONE;
+		if self.next.x >= self.maxs.x {
+			self.next.x = self.mins.x;
+			self.next.y += T::ONE;
+		}
+		Some(pt)
+	}
+	#[inline]
+	fn size_hint(&self) -> (usize, Option<usize>) {
+		(self.remaining, Some(self.remaining))
+	}
+}
+impl<T: Int> ExactSizeIterator for RectPoints<T> {}
+
 //----------------------------------------------------------------
 
 /// Cuboid structure.
@@ -449,11 +877,104 @@ impl<T: Scalar> Cuboid<T> {
 	pub fn volume(&self) -> T {
 		(self.maxs.x - self.mins.x) * (self.maxs.y - self.mins.y) * (self.maxs.z - self.mins.z)
 	}
+	/// Surface area of the cuboid.
+	///
+	/// ```
+	/// use cvmath::{Cuboid, Point3};
+	///
+	/// let cuboid = Cuboid(Point3(0, 0, 0), Point3(1, 2, 3));
+	/// assert_eq!(cuboid.surface_area(), 22);
+	/// ```
+	#[inline]
+	pub fn surface_area(&self) -> T {
+		let Vec3 { x, y, z } = self.maxs - self.mins;
+		(x * y + y * z + z * x) * (T::ONE + T::ONE)
+	}
 	/// Center of the cuboid.
 	#[inline]
 	pub fn center(&self) -> Point3<T> {
 		(self.mins + self.maxs) / (T::ONE + T::ONE)
 	}
+	/// Corners of the cuboid.
+	///
+	/// The index of each corner encodes which component is taken from `maxs`: bit 0 for `x`, bit 1 for `y` and bit 2 for `z`.
+	/// The first corner is `mins` and the last corner is `maxs`.
+	///
+	/// ```
+	/// use cvmath::{Cuboid, Point3};
+	///
+	/// let cuboid = Cuboid(Point3(-1, -2, -3), Point3(1, 2, 3));
+	/// let corners = cuboid.corners();
+	/// assert_eq!(corners[0], cuboid.mins);
+	/// assert_eq!(corners[3], Point3(1, 2, -3));
+	/// assert_eq!(corners[5], Point3(1, -2, 3));
+	/// assert_eq!(corners[7], cuboid.maxs);
+	/// ```
+	#[inline]
+	pub fn corners(&self) -> [Point3<T>; 8] {
+		let Bounds { mins, maxs } = *self;
+		[
+			Point3(mins.x, mins.y, mins.z),
+			Point3(maxs.x, mins.y, mins.z),
+			Point3(mins.x, maxs.y, mins.z),
+			Point3(maxs.x, maxs.y, mins.z),
+			Point3(mins.x, mins.y, maxs.z),
+			Point3(maxs.x, mins.y, maxs.z),
+			Point3(mins.x, maxs.y, maxs.z),
+			Point3(maxs.x, maxs.y, maxs.z),
+		]
+	}
+	/// Edges of the cuboid as index pairs into [`corners`](Cuboid::corners).
+	///
+	/// The first four edges run along the `x` axis, the next four along `y` and the last four along `z`.
+	///
+	/// ```
+	/// use cvmath::{Cuboid, Point3, Vec3};
+	///
+	/// let cuboid = Cuboid(Point3(0.0, 0.0, 0.0), Point3(1.0, 2.0, 3.0));
+	/// let corners = cuboid.corners();
+	/// let edges = cuboid.edges();
+	/// assert_eq!(12, edges.len());
+	///
+	/// let total: f64 = edges.iter().map(|&[a, b]| corners[a].distance(corners[b])).sum();
+	/// assert_eq!(4.0 * (1.0 + 2.0 + 3.0), total);
+	/// ```
+	#[inline]
+	pub fn edges(&self) -> [[usize; 2]; 12] {
+		[
+			[0, 1], [2, 3], [4, 5], [6, 7],
+			[0, 2], [1, 3], [4, 6], [5, 7],
+			[0, 4], [1, 5], [2, 6], [3, 7],
+		]
+	}
+	/// Splits the cuboid at its center into eight octants.
+	///
+	/// The index of each octant encodes which half it covers: bit 0 is set for the upper half along `x`, bit 1 along `y` and bit 2 along `z`.
+	/// This matches the order of the [`corners`](Cuboid::corners), the octant at index `i` contains corner `i`.
+	///
+	/// ```
+	/// use cvmath::{Cuboid, Point3};
+	///
+	/// let cuboid = Cuboid(Point3(0.0, 0.0, 0.0), Point3(2.0, 4.0, 6.0));
+	/// let octants = cuboid.subdivide();
+	/// assert_eq!(octants[0], Cuboid(Point3(0.0, 0.0, 0.0), Point3(1.0, 2.0, 3.0)));
+	/// assert_eq!(octants[6], Cuboid(Point3(0.0, 2.0, 3.0), Point3(1.0, 4.0, 6.0)));
+	///
+	/// let volume: f64 = octants.iter().map(|octant| octant.volume()).sum();
+	/// assert_eq!(volume, cuboid.volume());
+	/// ```
+	#[inline]
+	pub fn subdivide(&self) -> [Cuboid<T>; 8] {
+		let Bounds { mins, maxs } = *self;
+		let c = self.center();
+		let octant = |i: usize| {
+			let (x1, x2) = if i & 1 == 0 { (mins.x, c.x) } else { (c.x, maxs.x) };
+			let (y1, y2) = if i & 2 == 0 { (mins.y, c.y) } else { (c.y, maxs.y) };
+			let (z1, z2) = if i & 4 == 0 { (mins.z, c.z) } else { (c.z, maxs.z) };
+			Bounds { mins: Point3(x1, y1, z1), maxs: Point3(x2, y2, z2) }
+		};
+		[octant(0), octant(1), octant(2), octant(3), octant(4), octant(5), octant(6), octant(7)]
+	}
 	/// Transform of the unit cube.
 	#[inline]
 	pub fn into_transform(self) -> Transform3<T> {
@@ -464,8 +985,263 @@ impl<T: Scalar> Cuboid<T> {
 			self.mins,
 		)
 	}
+	/// Transforms the cuboid and refits the bounds around the result.
+	///
+	/// The resulting bounds enclose all the transformed corners.
+	/// Unlike [`into_transform`](Cuboid::into_transform) this transforms the cuboid itself.
+	///
+	/// ```
+	/// use cvmath::{Cuboid, Point3, Transform3};
+	///
+	/// let cuboid = Cuboid(Point3(1.0, 2.0, 3.0), Point3(2.0, 4.0, 6.0));
+	/// let transform = Transform3::scale((-1.0, 2.0, 1.0)) * Transform3::translate((0.0, 0.0, -3.0));
+	/// assert_eq!(cuboid.transform(&transform), Cuboid(Point3(-2.0, 4.0, 0.0), Point3(-1.0, 8.0, 3.0)));
+	/// ```
+	#[inline]
+	pub fn transform(self, m: &Transform3<T>) -> Cuboid<T> {
+		let Bounds { mins, maxs } = self;
+		let fit = |a1: T, a2: T, a3: T, a4: T| {
+			let (x1, x2) = (mins.x * a1).min_max(maxs.x * a1);
+			let (y1, y2) = (mins.y * a2).min_max(maxs.y * a2);
+			let (z1, z2) = (mins.z * a3).min_max(maxs.z * a3);
+			(x1 + y1 + z1 + a4, x2 + y2 + z2 + a4)
+		};
+		let (x1, x2) = fit(m.a11, m.a12, m.a13, m.a14);
+		let (y1, y2) = fit(m.a21, m.a22, m.a23, m.a24);
+		let (z1, z2) = fit(m.a31, m.a32, m.a33, m.a34);
+		Bounds { mins: Point3(x1, y1, z1), maxs: Point3(x2, y2, z2) }
+	}
+	/// Expands the cuboid outward by the margin on every side.
+	///
+	/// A negative margin shrinks the cuboid, see [`inflate_clamped`](Cuboid::inflate_clamped) to avoid inverting the bounds.
+	#[inline]
+	pub fn inflate(self, margin: T) -> Cuboid<T> {
+		self.inflate_vec(Vec3::dup(margin))
+	}
+	/// Expands the cuboid outward by a per-axis margin.
+	#[inline]
+	pub fn inflate_vec(self, margin: Vec3<T>) -> Cuboid<T> {
+		Bounds { mins: self.mins - margin, maxs: self.maxs + margin }
+	}
+	/// Expands the cuboid outward by the margin, clamping negative margins so the bounds never invert.
+	///
+	/// Shrinking by more than half the extent collapses that axis onto the center.
+	///
+	/// ```
+	/// use cvmath::{Cuboid, Point3};
+	///
+	/// let cuboid = Cuboid(Point3(0, 0, 0), Point3(4, 6, 2));
+	/// assert_eq!(cuboid.inflate(1), Cuboid(Point3(-1, -1, -1), Point3(5, 7, 3)));
+	/// assert_eq!(cuboid.inflate_clamped(-2), Cuboid(Point3(2, 2, 1), Point3(2, 4, 1)));
+	/// assert!(cuboid.inflate(-2).mins.z > cuboid.inflate(-2).maxs.z);
+	/// ```
+	#[inline]
+	pub fn inflate_clamped(self, margin: T) -> Cuboid<T> {
+		let center = self.center();
+		let margin = Vec3::dup(margin);
+		Bounds { mins: (self.mins - margin).min(center), maxs: (self.maxs + margin).max(center) }
+	}
+	/// Scales the cuboid by a per-axis factor keeping the anchor point fixed.
+	///
+	/// ```
+	/// use cvmath::{Cuboid, Point3, Vec3};
+	///
+	/// let cuboid = Cuboid(Point3(0.0, 0.0, 0.0), Point3(2.0, 2.0, 2.0));
+	/// let scaled = cuboid.scaled_about(Vec3::dup(2.0), cuboid.center());
+	/// assert_eq!(scaled, Cuboid(Point3(-1.0, -1.0, -1.0), Point3(3.0, 3.0, 3.0)));
+	/// ```
+	#[inline]
+	pub fn scaled_about(self, factor: Vec3<T>, anchor: Point3<T>) -> Cuboid<T> {
+		Bounds {
+			mins: anchor + (self.mins - anchor) * factor,
+			maxs: anchor + (self.maxs - anchor) * factor,
+		}
+	}
+}
+
+impl<T: Float> Cuboid<T> {
+	/// Returns `true` if all the components are finite.
+	#[inline]
+	pub fn is_finite(&self) -> bool {
+		self.mins.all_finite() && self.maxs.all_finite()
+	}
+	/// Squared distance from the point to the cuboid.
+	///
+	/// Points inside the cuboid are at zero distance.
+	///
+	/// ```
+	/// use cvmath::{Cuboid, Point3};
+	///
+	/// let cuboid = Cuboid(Point3(0.0, 0.0, 0.0), Point3(1.0, 1.0, 1.0));
+	/// assert_eq!(cuboid.distance_sqr_to_point(Point3(0.5, 0.5, 0.5)), 0.0);
+	/// assert_eq!(cuboid.distance_sqr_to_point(Point3(3.0, 0.5, -1.0)), 5.0);
+	/// ```
+	#[inline]
+	pub fn distance_sqr_to_point(&self, pt: Point3<T>) -> T {
+		(pt - pt.max(self.mins).min(self.maxs)).len_sqr()
+	}
+	/// Distances along the ray where it enters and exits the cuboid.
+	///
+	/// Cheaper than [tracing](TraceRay::trace) as no normals are computed.
+	/// Returns `None` if the ray misses the cuboid or the cuboid is behind the ray.
+	/// The entry distance is negative if the ray starts inside the cuboid.
+	///
+	/// ```
+	/// use cvmath::{Cuboid, Point3, Ray, Vec3};
+	///
+	/// let cuboid = Cuboid(Point3(-1.0, -1.0, -1.0), Point3(1.0, 1.0, 1.0));
+	///
+	/// let ray = Ray(Point3(-5.0, 0.0, 0.0), Vec3(1.0, 0.0, 0.0));
+	/// assert_eq!(cuboid.ray_intersect_t(&ray), Some((4.0, 6.0)));
+	///
+	/// let ray = Ray(Point3(-5.0, 0.0, 0.0), Vec3(-1.0, 0.0, 0.0));
+	/// assert_eq!(cuboid.ray_intersect_t(&ray), None);
+	/// ```
+	#[inline]
+	pub fn ray_intersect_t(&self, ray: &Ray<T>) -> Option<(T, T)> {
+		let (tmin, tmax) = self.slabs(ray);
+		let t0 = tmin.vmax();
+		let t1 = tmax.vmin();
+		if t0 <= t1 && t1 >= T::ZERO { Some((t0, t1)) } else { None }
+	}
+	/// Distances along the ray where it enters and exits the slab of each axis.
+	///
+	/// Axes the ray is parallel to give infinite distances, or NaN if the ray lies in the plane of a face.
+	/// The NaN is ignored when reducing with `vmax` and `vmin`, treating the face as part of the cuboid.
+	#[inline]
+	fn slabs(&self, ray: &Ray<T>) -> (Vec3<T>, Vec3<T>) {
+		let inv_dir = Vec3::new(T::ONE / ray.direction.x, T::ONE / ray.direction.y, T::ONE / ray.direction.z);
+		let t_lo = (self.mins - ray.origin) * inv_dir;
+		let t_hi = (self.maxs - ray.origin) * inv_dir;
+		// Select instead of min and max, those would discard the NaN in favor of the infinity
+		let swap = t_lo.gt(t_hi);
+		(swap.select(t_hi, t_lo), swap.select(t_lo, t_hi))
+	}
+	/// Returns `true` if the plane passes through or touches the cuboid.
+	///
+	/// ```
+	/// use cvmath::{Cuboid, Plane, Point3, Vec3};
+	///
+	/// let cuboid = Cuboid(Point3(0.0, 0.0, 0.0), Point3(1.0, 1.0, 1.0));
+	/// assert!(cuboid.intersects_plane(&Plane(Vec3(0.0, 0.0, 1.0), -0.5)));
+	/// assert!(!cuboid.intersects_plane(&Plane(Vec3(0.0, 0.0, 1.0), -2.0)));
+	/// ```
+	#[inline]
+	pub fn intersects_plane(&self, plane: &Plane<T>) -> bool {
+		self.plane_side(plane) == PlaneSide::Straddle
+	}
+	/// Which side of the plane the cuboid lies on.
+	///
+	/// A cuboid touching the plane is straddling it.
+	///
+	/// ```
+	/// use cvmath::{Cuboid, Plane, PlaneSide, Point3, Vec3};
+	///
+	/// let cuboid = Cuboid(Point3(0.0, 0.0, 0.0), Point3(1.0, 1.0, 1.0));
+	/// let plane = Plane::from_point_normal(Point3(3.0, 0.0, 0.0), Vec3(1.0, 1.0, 0.0));
+	/// assert_eq!(cuboid.plane_side(&plane), PlaneSide::Back);
+	/// assert_eq!(cuboid.plane_side(&-plane), PlaneSide::Front);
+	/// assert_eq!(cuboid.plane_side(&Plane(Vec3(0.0, 1.0, 0.0), -1.0)), PlaneSide::Straddle);
+	/// ```
+	#[inline]
+	pub fn plane_side(&self, plane: &Plane<T>) -> PlaneSide {
+		let half = (self.maxs - self.mins) * T::cast_from(0.5);
+		// Projected radius of the cuboid onto the plane's normal
+		let radius = plane.normal.abs().dot(half);
+		let dist = plane.dist_pt(self.center());
+		if dist > radius {
+			PlaneSide::Front
+		}
+		else if dist < -radius {
+			PlaneSide::Back
+		}
+		else {
+			PlaneSide::Straddle
+		}
+	}
+}
+
+impl<T: Scalar> Measure3<T> for Cuboid<T> {
+	#[inline]
+	fn volume(&self) -> T {
+		Cuboid::volume(self)
+	}
+	#[inline]
+	fn surface_area(&self) -> T {
+		Cuboid::surface_area(self)
+	}
+}
+
+impl<T: Int + CastTo<usize>> Cuboid<T> {
+	/// Number of integer points contained in the cuboid.
+	///
+	/// Follows the same convention as [`points`](Cuboid::points), empty and inverted cuboids contain no points.
+	#[inline]
+	pub fn pixel_count(&self) -> usize {
+		let size = self.size().max(Vec3::ZERO);
+		size.x.cast_to() * size.y.cast_to() * size.z.cast_to()
+	}
+	/// Iterates over the integer points contained in the cuboid.
+	///
+	/// The points are taken from the half-open range `[mins, maxs)` with `x` varying fastest, then `y`, then `z`.
+	/// Empty and inverted cuboids yield no points.
+	///
+	/// ```
+	/// use cvmath::{Cuboid, Point3};
+	///
+	/// let cuboid = Cuboid(Point3(0, 0, 0), Point3(2, 2, 2));
+	/// let points: Vec<_> = cuboid.points().collect();
+	/// assert_eq!(points[..4], [Point3(0, 0, 0), Point3(1, 0, 0), Point3(0, 1, 0), Point3(1, 1, 0)]);
+	/// assert_eq!(points[4..], [Point3(0, 0, 1), Point3(1, 0, 1), Point3(0, 1, 1), Point3(1, 1, 1)]);
+	/// assert_eq!(cuboid.pixel_count(), cuboid.points().len());
+	///
+	/// assert_eq!(0, Cuboid(Point3(0, 0, 0), Point3(2, 2, 0)).points().count());
+	/// ```
+	#[inline]
+	pub fn points(&self) -> impl ExactSizeIterator<Item = Point3<T>> {
+		CuboidPoints {
+			mins: self.mins,
+			maxs: self.maxs,
+			next: self.mins,
+			remaining: self.pixel_count(),
+		}
+	}
 }
 
+#[derive(Clone, Debug)]
+struct CuboidPoints<T> {
+	mins: Point3<T>,
+	maxs: Point3<T>,
+	next: Point3<T>,
+	remaining: usize,
+}
+impl<T: Int> Iterator for CuboidPoints<T> {
+	type Item = Point3<T>;
+	#[inline]
+	fn next(&mut self) -> Option<Point3<T>> {
+		if self.remaining == 0 {
+			return None;
+		}
+		self.remaining -= 1;
+		let pt = self.next;
+		self.next.x += T::ONE;
+		if self.next.x >= self.maxs.x {
+			self.next.x = self.mins.x;
+			self.next.y += T::ONE;
+			if self.next.y >= self.maxs.y {
+				self.next.y = self.mins.y;
+				self.next.z += T::ONE;
+			}
+		}
+		Some(pt)
+	}
+	#[inline]
+	fn size_hint(&self) -> (usize, Option<usize>) {
+		(self.remaining, Some(self.remaining))
+	}
+}
+impl<T: Int> ExactSizeIterator for CuboidPoints<T> {}
+
 //----------------------------------------------------------------
 
 impl<T: Float> TraceRay<T> for Cuboid<T> {
@@ -474,36 +1250,28 @@ impl<T: Float> TraceRay<T> for Cuboid<T> {
 	}
 
 	fn trace(&self, ray: &Ray<T>, hits: &mut [TraceHit<T>]) -> usize {
-		let inv_dir = Vec3::new(T::ONE / ray.direction.x, T::ONE / ray.direction.y, T::ONE / ray.direction.z);
-		let mut tmin = (self.mins - ray.origin) * inv_dir;
-		let mut tmax = (self.maxs - ray.origin) * inv_dir;
-		if tmin.x > tmax.x {
-			tmin.x = tmax.x;
-			tmax.x = tmin.x;
-		}
-		if tmin.y > tmax.y {
-			tmin.y = tmax.y;
-			tmax.y = tmin.y;
-		}
-		if tmin.z > tmax.z {
-			tmin.z = tmax.z;
-			tmax.z = tmin.z;
-		}
+		let (tmin, tmax) = self.slabs(ray);
 		let t0 = tmin.vmax();
 		let t1 = tmax.vmin();
-		if t0 <= t1 {
-			hits[0] = TraceHit {
-				distance: t0,
-				normal: Vec3::new(
-					if t0 == tmin.x { -T::ONE } else { if t0 == tmax.x { T::ONE } else { T::ZERO } },
-					if t0 == tmin.y { -T::ONE } else { if t0 == tmax.y { T::ONE } else { T::ZERO } },
-					if t0 == tmin.z { -T::ONE } else { if t0 == tmax.z { T::ONE } else { T::ZERO } },
-				),
-			};
-			return 1;
-		}
-		else {
+		if t0 > t1 {
 			return 0;
 		}
+
+		// The ray enters through the faces of the slabs that were crossed last, facing against the ray
+		// The ray exits through the faces of the slabs that were crossed first, facing along the ray
+		let sign = ray.direction.lt(Vec3::ZERO).select_scalar(T::ONE, -T::ONE);
+		let entry = TraceHit { distance: t0, normal: Vec3::dup(t0).eq(tmin).select(sign, Vec3::ZERO) };
+		let exit = TraceHit { distance: t1, normal: Vec3::dup(t1).eq(tmax).select(-sign, Vec3::ZERO) };
+
+		let mut count = 0;
+		for hit in [entry, exit] {
+			if hit.distance >= T::ZERO {
+				if hits.len() > count {
+					hits[count] = hit;
+				}
+				count += 1;
+			}
+		}
+		return count;
 	}
 }
@@ -0,0 +1,113 @@
+/*!
+Camera view transforms.
+*/
+
+use super::*;
+
+/// Largest pitch in degrees, looking straight up or down makes the up vector degenerate.
+const PITCH_LIMIT: f64 = 89.9;
+
+impl<T: Float> Transform3<T> {
+	/// Clamps the pitch shy of ±90°.
+	///
+	/// Used by [orbit](Transform3::orbit) and [fps](Transform3::fps) to keep the view direction away from the up vector.
+	///
+	/// ```
+	/// use cvmath::{Deg, Transform3};
+	///
+	/// assert_eq!(Transform3::clamp_pitch(Deg(30.0)), Deg(30.0).to_rad());
+	/// assert!(Transform3::clamp_pitch(Deg(90.0f64)).to_deg() < Deg(90.0));
+	/// assert!(Transform3::clamp_pitch(Deg(-120.0f64)).to_deg() > Deg(-90.0));
+	/// ```
+	#[inline]
+	pub fn clamp_pitch(pitch: impl Angle<T = T>) -> Rad<T> {
+		let limit = T::cast_from(PITCH_LIMIT.to_radians());
+		Rad(pitch.to_rad().value.clamp(-limit, limit))
+	}
+
+	/// Rigid part of the [look-at matrix](Mat4::look_at) with +Y as the up vector.
+	#[inline]
+	fn look_at(eye: Point3<T>, target: Point3<T>, hand: Hand) -> Transform3<T> {
+		let Mat4 { a11, a12, a13, a14, a21, a22, a23, a24, a31, a32, a33, a34, .. } = Mat4::look_at(eye, target, Vec3::Y, hand);
+		Transform3 { a11, a12, a13, a14, a21, a22, a23, a24, a31, a32, a33, a34 }
+	}
+
+	/// View direction from yaw and pitch.
+	///
+	/// Yaw rotates around the Y axis starting from +Z towards +X, pitch tilts the direction up towards +Y.
+	#[inline]
+	fn view_direction(yaw: impl Angle<T = T>, pitch: impl Angle<T = T>) -> Vec3<T> {
+		let (sin_yaw, cos_yaw) = yaw.sin_cos();
+		let (sin_pitch, cos_pitch) = Transform3::clamp_pitch(pitch).sin_cos();
+		Vec3(sin_yaw * cos_pitch, sin_pitch, cos_yaw * cos_pitch)
+	}
+
+	/// Orbit camera view transform.
+	///
+	/// The camera looks at the target from the given distance.
+	/// Yaw rotates the view direction around the Y axis starting from +Z towards +X, pitch tilts it up towards +Y.
+	/// The pitch is [clamped](Transform3::clamp_pitch) shy of ±90°.
+	///
+	/// ```
+	/// use cvmath::{Deg, Mat4, Point3, Transform3, Vec3, LH};
+	///
+	/// let target = Point3(1.0, 2.0, 3.0);
+	/// let view = Transform3::orbit(target, 5.0, Deg(0.0), Deg(0.0), LH);
+	/// let expected = Mat4::look_at(Point3(1.0, 2.0, -2.0), target, Vec3(0.0, 1.0, 0.0), LH);
+	/// assert_eq!(view.mat4(), expected);
+	/// ```
+	#[inline]
+	pub fn orbit(target: Point3<T>, distance: T, yaw: impl Angle<T = T>, pitch: impl Angle<T = T>, hand: Hand) -> Transform3<T> {
+		let eye = target - Transform3::view_direction(yaw, pitch) * distance;
+		Transform3::look_at(eye, target, hand)
+	}
+
+	/// First person camera view transform.
+	///
+	/// The camera looks from the position in the direction given by yaw and pitch, see [orbit](Transform3::orbit).
+	///
+	/// ```
+	/// use cvmath::{Deg, Point3, Transform3, LH};
+	///
+	/// let view = Transform3::fps(Point3(1.0, 2.0, 3.0), Deg(90.0), Deg(0.0), LH);
+	/// assert!(view.forward(LH).distance(Point3(1.0, 0.0, 0.0)) < 1e-12);
+	/// ```
+	#[inline]
+	pub fn fps(position: Point3<T>, yaw: impl Angle<T = T>, pitch: impl Angle<T = T>, hand: Hand) -> Transform3<T> {
+		let target = position + Transform3::view_direction(yaw, pitch);
+		Transform3::look_at(position, target, hand)
+	}
+
+	/// Right vector of a view transform.
+	///
+	/// The view space X axis in world space, the first row of the rotation.
+	#[inline]
+	pub fn right(&self) -> Vec3<T> {
+		Vec3 { x: self.a11, y: self.a12, z: self.a13 }
+	}
+	/// Up vector of a view transform.
+	///
+	/// The view space Y axis in world space, the second row of the rotation.
+	#[inline]
+	pub fn up(&self) -> Vec3<T> {
+		Vec3 { x: self.a21, y: self.a22, z: self.a23 }
+	}
+	/// Forward vector of a view transform.
+	///
+	/// The direction the camera looks at in world space.
+	/// This is the view space +Z axis for [LH](Hand::LH) and the -Z axis for [RH](Hand::RH).
+	///
+	/// ```
+	/// use cvmath::{Deg, Point3, Transform3, Vec3, LH, RH};
+	///
+	/// for hand in [LH, RH] {
+	///     let view = Transform3::orbit(Point3(1.0, 2.0, 3.0), 5.0, Deg(90.0), Deg(0.0), hand);
+	///     assert!(view.forward(hand).distance(Vec3(1.0, 0.0, 0.0)) < 1e-12);
+	/// }
+	/// ```
+	#[inline]
+	pub fn forward(&self, hand: Hand) -> Vec3<T> {
+		let z = Vec3 { x: self.a31, y: self.a32, z: self.a33 };
+		match hand { Hand::LH => z, Hand::RH => -z }
+	}
+}
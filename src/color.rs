@@ -0,0 +1,221 @@
+/*!
+Color utilities.
+
+Colors are represented as `Vec3<T>` with red, green and blue components.
+Unless noted otherwise colors are in linear space with components in the `[0, 1]` range.
+*/
+
+use super::*;
+
+impl<T: Float> Vec3<T> {
+	/// Decodes an sRGB encoded color to linear space.
+	///
+	/// Applies the piecewise sRGB transfer function to each component.
+	///
+	/// ```
+	/// use cvmath::Vec3;
+	///
+	/// let gray = Vec3::dup(0.5f64).srgb_to_linear();
+	/// assert!((gray.x - 0.21404114).abs() < 1e-6);
+	/// assert_eq!(Vec3(1.0, 0.0, 0.0).srgb_to_linear(), Vec3(1.0, 0.0, 0.0));
+	/// ```
+	#[inline]
+	pub fn srgb_to_linear(self) -> Vec3<T> {
+		self.map(|c| {
+			if c <= T::cast_from(0.04045) {
+				c / T::cast_from(12.92)
+			}
+			else {
+				((c + T::cast_from(0.055)) / T::cast_from(1.055)).powf(T::cast_from(2.4))
+			}
+		})
+	}
+	/// Encodes a linear color to sRGB.
+	///
+	/// This is the inverse of [`srgb_to_linear`](Vec3::srgb_to_linear).
+	///
+	/// ```
+	/// use cvmath::Vec3;
+	///
+	/// // 18% gray encodes to about 46% in sRGB
+	/// let gray = Vec3::dup(0.18f64).linear_to_srgb();
+	/// assert!((gray.x - 0.46135613).abs() < 1e-6);
+	/// ```
+	#[inline]
+	pub fn linear_to_srgb(self) -> Vec3<T> {
+		self.map(|c| {
+			if c <= T::cast_from(0.0031308) {
+				c * T::cast_from(12.92)
+			}
+			else {
+				T::cast_from(1.055) * c.powf(T::cast_from(1.0 / 2.4)) - T::cast_from(0.055)
+			}
+		})
+	}
+	/// Relative luminance of a linear color.
+	///
+	/// Uses the Rec. 709 coefficients.
+	///
+	/// ```
+	/// use cvmath::Vec3;
+	///
+	/// assert_eq!(Vec3(1.0, 1.0, 1.0).luminance(), 1.0);
+	/// assert_eq!(Vec3(0.0, 1.0, 0.0).luminance(), 0.7152);
+	/// ```
+	#[inline]
+	pub fn luminance(self) -> T {
+		self.dot(Vec3(T::cast_from(0.2126), T::cast_from(0.7152), T::cast_from(0.0722)))
+	}
+	/// Converts a linear sRGB color to CIE XYZ.
+	///
+	/// See [`Mat3::RGB_TO_XYZ`](Mat3#associatedconstant.RGB_TO_XYZ).
+	///
+	/// ```
+	/// use cvmath::Vec3;
+	///
+	/// // The Y component is the luminance
+	/// let color = Vec3(0.2f64, 0.5, 0.8);
+	/// assert!((color.rgb_to_xyz().y - color.luminance()).abs() < 1e-15);
+	/// ```
+	#[inline]
+	pub fn rgb_to_xyz(self) -> Vec3<T> {
+		cast_mat3::<T>(Mat3::<f64>::RGB_TO_XYZ) * self
+	}
+	/// Converts a CIE XYZ color to linear sRGB.
+	///
+	/// This is the inverse of [`rgb_to_xyz`](Vec3::rgb_to_xyz).
+	/// Colors outside the sRGB gamut have components outside the `[0, 1]` range.
+	#[inline]
+	pub fn xyz_to_rgb(self) -> Vec3<T> {
+		cast_mat3::<T>(Mat3::<f64>::XYZ_TO_RGB) * self
+	}
+	/// Converts an RGB color to hue, saturation and value.
+	///
+	/// The hue is expressed as a fraction of a full turn in the `[0, 1)` range, red has hue `0`.
+	/// The hue of grays is `0`.
+	///
+	/// ```
+	/// use cvmath::Vec3;
+	///
+	/// assert_eq!(Vec3(1.0, 0.0, 0.0).rgb_to_hsv(), Vec3(0.0, 1.0, 1.0));
+	/// assert_eq!(Vec3(0.0, 0.5, 0.5).rgb_to_hsv(), Vec3(0.5, 1.0, 0.5));
+	/// assert_eq!(Vec3(0.25, 0.25, 0.25).rgb_to_hsv(), Vec3(0.0, 0.0, 0.25));
+	/// ```
+	#[inline]
+	pub fn rgb_to_hsv(self) -> Vec3<T> {
+		let Vec3 { x: r, y: g, z: b } = self;
+		let max = self.vmax();
+		let delta = max - self.vmin();
+
+		let saturation = if max > T::ZERO { delta / max } else { T::ZERO };
+
+		let sector = if delta == T::ZERO { T::ZERO }
+			else if max == r { (g - b) / delta }
+			else if max == g { (b - r) / delta + T::cast_from(2.0) }
+			else { (r - g) / delta + T::cast_from(4.0) };
+		let mut hue = sector / T::cast_from(6.0);
+		if hue < T::ZERO {
+			hue += T::ONE;
+		}
+		// Tiny negative hues round up to a full turn
+		if hue >= T::ONE {
+			hue -= T::ONE;
+		}
+
+		Vec3(hue, saturation, max)
+	}
+	/// Converts a color in hue, saturation and value to RGB.
+	///
+	/// This is the inverse of [`rgb_to_hsv`](Vec3::rgb_to_hsv), the hue wraps around outside the `[0, 1)` range.
+	///
+	/// ```
+	/// use cvmath::Vec3;
+	///
+	/// assert_eq!(Vec3(0.0, 1.0, 1.0).hsv_to_rgb(), Vec3(1.0, 0.0, 0.0));
+	/// assert_eq!(Vec3(1.0 / 3.0, 1.0, 1.0).hsv_to_rgb(), Vec3(0.0, 1.0, 0.0));
+	/// assert_eq!(Vec3(-0.5, 1.0, 0.5).hsv_to_rgb(), Vec3(0.0, 0.5, 0.5));
+	/// ```
+	#[inline]
+	pub fn hsv_to_rgb(self) -> Vec3<T> {
+		let Vec3 { x: hue, y: saturation, z: value } = self;
+		let h = (hue - hue.floor()) * T::cast_from(6.0);
+		let sector = h.floor();
+		let f = h - sector;
+
+		let p = value * (T::ONE - saturation);
+		let q = value * (T::ONE - saturation * f);
+		let t = value * (T::ONE - saturation * (T::ONE - f));
+
+		if sector < T::ONE { Vec3(value, t, p) }
+		else if sector < T::cast_from(2.0) { Vec3(q, value, p) }
+		else if sector < T::cast_from(3.0) { Vec3(p, value, t) }
+		else if sector < T::cast_from(4.0) { Vec3(p, q, value) }
+		else if sector < T::cast_from(5.0) { Vec3(t, p, value) }
+		else { Vec3(value, p, q) }
+	}
+	/// Reinhard tone mapping of a linear HDR color.
+	///
+	/// Maps each component from `[0, ∞)` to `[0, 1)` with `c / (1 + c)`.
+	///
+	/// ```
+	/// use cvmath::Vec3;
+	///
+	/// assert_eq!(Vec3(0.0, 1.0, 3.0).tonemap_reinhard(), Vec3(0.0, 0.5, 0.75));
+	/// ```
+	#[inline]
+	pub fn tonemap_reinhard(self) -> Vec3<T> {
+		self.map(|c| c / (T::ONE + c))
+	}
+	/// ACES filmic tone mapping of a linear HDR color.
+	///
+	/// Uses the curve fit by Krzysztof Narkowicz, the result is clamped to `[0, 1]`.
+	///
+	/// ```
+	/// use cvmath::Vec3;
+	///
+	/// let color = Vec3(0.0f64, 0.18, 100.0).tonemap_aces();
+	/// assert_eq!(color.x, 0.0);
+	/// assert!((color.y - 0.2669).abs() < 1e-4);
+	/// assert_eq!(color.z, 1.0);
+	/// ```
+	#[inline]
+	pub fn tonemap_aces(self) -> Vec3<T> {
+		let (a, b) = (T::cast_from(2.51), T::cast_from(0.03));
+		let (c, d, e) = (T::cast_from(2.43), T::cast_from(0.59), T::cast_from(0.14));
+		self.map(|x| (x * (a * x + b) / (x * (c * x + d) + e)).clamp(T::ZERO, T::ONE))
+	}
+}
+
+#[inline]
+fn cast_mat3<T: Float>(mat: Mat3<f64>) -> Mat3<T> {
+	Mat3::from_row_major(mat.into_row_major().map(|row| row.map(T::cast_from)))
+}
+
+macro_rules! color_matrices {
+	($ty:ty) => {
+		// The same literals are used for both precisions
+		#[allow(clippy::excessive_precision)]
+		impl Mat3<$ty> {
+			/// Converts linear sRGB to CIE XYZ.
+			///
+			/// Uses the sRGB primaries with a D65 white point as specified by IEC 61966-2-1.
+			/// The second row holds the Rec. 709 luminance coefficients.
+			pub const RGB_TO_XYZ: Mat3<$ty> = Mat3 {
+				a11: 0.4124, a12: 0.3576, a13: 0.1805,
+				a21: 0.2126, a22: 0.7152, a23: 0.0722,
+				a31: 0.0193, a32: 0.1192, a33: 0.9505,
+			};
+			/// Converts CIE XYZ to linear sRGB.
+			///
+			/// The exact inverse of [`RGB_TO_XYZ`](Mat3#associatedconstant.RGB_TO_XYZ) rounded to double precision.
+			pub const XYZ_TO_RGB: Mat3<$ty> = Mat3 {
+				a11: 3.2406254773200533, a12: -1.5372079722103187, a13: -0.4986285986982479,
+				a21: -0.9689307147293194, a22: 1.875756060885241, a23: 0.04151752384295394,
+				a31: 0.05571012044551061, a32: -0.2040210505984867, a33: 1.0569959422543882,
+			};
+		}
+	};
+}
+
+color_matrices!(f32);
+color_matrices!(f64);
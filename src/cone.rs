@@ -0,0 +1,153 @@
+use super::*;
+
+/// Cone structure.
+///
+/// A finite circular cone closed by a base disk.
+/// The apex is the tip of the cone and the base disk is centered at `apex + axis * height`.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[repr(C)]
+pub struct Cone3<T> {
+	/// The tip of the cone.
+	pub apex: Point3<T>,
+	/// Direction from the apex to the base, must be normalized.
+	pub axis: Vec3<T>,
+	/// Distance between the apex and the base.
+	pub height: T,
+	/// Radius of the base disk.
+	pub radius: T,
+}
+
+/// Cone3 constructor.
+#[allow(non_snake_case)]
+#[inline]
+pub fn Cone3<T>(apex: Point3<T>, axis: Vec3<T>, height: T, radius: T) -> Cone3<T> {
+	Cone3 { apex, axis, height, radius }
+}
+
+#[cfg(feature = "dataview")]
+unsafe impl<T: dataview::Pod> dataview::Pod for Cone3<T> {}
+#[cfg(feature = "bytemuck")]
+unsafe impl<T: bytemuck::Zeroable> bytemuck::Zeroable for Cone3<T> {}
+#[cfg(feature = "bytemuck")]
+unsafe impl<T: bytemuck::Pod> bytemuck::Pod for Cone3<T> {}
+
+impl<T> Cone3<T> {
+	/// Constructs a new cone.
+	#[inline]
+	pub const fn new(apex: Point3<T>, axis: Vec3<T>, height: T, radius: T) -> Cone3<T> {
+		Cone3 { apex, axis, height, radius }
+	}
+}
+
+impl<T: Float> Cone3<T> {
+	/// Cone from its apex, axis, height and half opening angle.
+	///
+	/// Useful for spotlight volumes where the angle is the spotlight's cutoff angle.
+	///
+	/// ```
+	/// use cvmath::{Cone3, Deg, Point3, Vec3};
+	///
+	/// let spot = Cone3::from_angle(Point3(0.0, 0.0, 0.0), Vec3(0.0, 0.0, -1.0), 10.0, Deg(45.0));
+	/// assert!((spot.radius - 10.0f64).abs() < 1e-12);
+	/// ```
+	#[inline]
+	pub fn from_angle(apex: Point3<T>, axis: Vec3<T>, height: T, angle: impl Angle<T = T>) -> Cone3<T> {
+		Cone3 { apex, axis, height, radius: height * angle.tan() }
+	}
+	/// Returns `true` if all the components are finite.
+	#[inline]
+	pub fn is_finite(&self) -> bool {
+		self.apex.all_finite() && self.axis.all_finite() && self.height.is_finite() && self.radius.is_finite()
+	}
+	/// Center of the base disk.
+	#[inline]
+	pub fn base(&self) -> Point3<T> {
+		self.apex + self.axis * self.height
+	}
+	/// Half opening angle of the cone.
+	#[inline]
+	pub fn angle(&self) -> Rad<T> {
+		Rad::atan2(self.radius, self.height)
+	}
+	/// Returns `true` if the point lies inside the cone or on its surface.
+	///
+	/// ```
+	/// use cvmath::{Cone3, Point3, Vec3};
+	///
+	/// let cone = Cone3(Point3(0.0, 0.0, 2.0), Vec3(0.0, 0.0, -1.0), 2.0, 1.0);
+	/// assert!(cone.contains(Point3(0.0, 0.0, 1.0)));
+	/// assert!(cone.contains(Point3(0.5, 0.0, 1.0)));
+	/// assert!(!cone.contains(Point3(0.75, 0.0, 1.0)));
+	/// ```
+	#[inline]
+	pub fn contains(&self, pt: Point3<T>) -> bool {
+		let v = pt - self.apex;
+		let h = v.dot(self.axis);
+		let r = self.radius * h / self.height;
+		h >= T::ZERO && h <= self.height && (v - self.axis * h).len_sqr() <= r * r
+	}
+	/// Axis aligned bounding box of the cone.
+	///
+	/// The bounds are tight, they enclose the apex and the base disk.
+	///
+	/// ```
+	/// use cvmath::{Cone3, Cuboid, Point3, Vec3};
+	///
+	/// let cone = Cone3(Point3(0.0, 0.0, 2.0), Vec3(0.0, 0.0, -1.0), 2.0, 1.0);
+	/// assert_eq!(cone.bounds(), Cuboid(Point3(-1.0, -1.0, 0.0), Point3(1.0, 1.0, 2.0)));
+	/// ```
+	#[inline]
+	pub fn bounds(&self) -> Cuboid<T> {
+		let extent = crate::cylinder::disk_extent(self.axis, self.radius);
+		let base = self.base();
+		Cuboid((base - extent).min(self.apex), (base + extent).max(self.apex))
+	}
+}
+
+impl<T: Float> TraceRay<T> for Cone3<T> {
+	fn inside(&self, ray: &Ray<T>) -> bool {
+		self.contains(ray.origin)
+	}
+
+	fn trace(&self, ray: &Ray<T>, hits: &mut [TraceHit<T>]) -> usize {
+		let w = ray.origin - self.apex;
+		let (wa, da) = (w.dot(self.axis), ray.direction.dot(self.axis));
+
+		let mut candidates = [TraceHit::default(); 3];
+		let mut n = 0;
+
+		// The lateral surface |v|² = m (v·axis)² of the double cone, keep the nappe between the apex and the base
+		let k = self.radius / self.height;
+		let m = T::ONE + k * k;
+		let two = T::ONE + T::ONE;
+		let a = ray.direction.dot(ray.direction) - m * da * da;
+		let b = two * (w.dot(ray.direction) - m * da * wa);
+		let c = w.dot(w) - m * wa * wa;
+		if let Some((t0, t1)) = solve_quadratic(a, b, c) {
+			for t in [t0, t1] {
+				let h = wa + da * t;
+				if h >= T::ZERO && h <= self.height {
+					let v = w + ray.direction * t;
+					// The normal is undefined at the apex, point it away from the base there
+					let normal = v - self.axis * (m * h);
+					let normal = if normal.len_sqr() > T::ZERO { normal.normalize() } else { -self.axis };
+					candidates[n] = TraceHit { distance: t, normal };
+					n += 1;
+				}
+			}
+		}
+
+		// The base disk
+		if da != T::ZERO {
+			let t = (self.height - wa) / da;
+			let v = w + ray.direction * t;
+			if (v - self.axis * self.height).len_sqr() <= self.radius * self.radius {
+				candidates[n] = TraceHit { distance: t, normal: self.axis };
+				n += 1;
+			}
+		}
+
+		crate::ray::convex_hits(&candidates[..n], hits)
+	}
+}
@@ -0,0 +1,170 @@
+/*!
+Constructive solid geometry.
+
+Combines shapes implementing [`TraceRay`] into new shapes.
+The hits of the operands are filtered by testing whether the hit point lies inside the other operand.
+*/
+
+use super::*;
+
+/// Maximum number of hits gathered from each operand.
+const MAX_HITS: usize = 16;
+
+#[inline]
+fn gather<T: Float, S: TraceRay<T> + ?Sized>(shape: &S, ray: &Ray<T>, hits: &mut [TraceHit<T>; MAX_HITS]) -> usize {
+	usize::min(shape.trace(ray, hits), MAX_HITS)
+}
+
+#[inline]
+fn inside_at<T: Float, S: TraceRay<T> + ?Sized>(shape: &S, ray: &Ray<T>, distance: T) -> bool {
+	shape.inside(&Ray { origin: ray.at(distance), direction: ray.direction })
+}
+
+#[inline]
+fn push<T>(hits: &mut [TraceHit<T>], count: &mut usize, hit: TraceHit<T>) {
+	if hits.len() > *count {
+		hits[*count] = hit;
+	}
+	*count += 1;
+}
+
+//----------------------------------------------------------------
+
+/// Intersection of two shapes.
+///
+/// The space inside both shapes.
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq, Hash)]
+pub struct Intersection3<A, B> {
+	/// The first shape.
+	pub a: A,
+	/// The second shape.
+	pub b: B,
+}
+
+/// Intersection3 constructor.
+#[allow(non_snake_case)]
+#[inline]
+pub fn Intersection3<A, B>(a: A, b: B) -> Intersection3<A, B> {
+	Intersection3 { a, b }
+}
+
+impl<T: Float, A: TraceRay<T>, B: TraceRay<T>> TraceRay<T> for Intersection3<A, B> {
+	fn inside(&self, ray: &Ray<T>) -> bool {
+		self.a.inside(ray) && self.b.inside(ray)
+	}
+
+	fn trace(&self, ray: &Ray<T>, hits: &mut [TraceHit<T>]) -> usize {
+		let mut scratch = [TraceHit::default(); MAX_HITS];
+		let mut count = 0;
+
+		// Keep the surface of each shape which lies inside the other shape
+		let n = gather(&self.a, ray, &mut scratch);
+		for &hit in &scratch[..n] {
+			if inside_at(&self.b, ray, hit.distance) {
+				push(hits, &mut count, hit);
+			}
+		}
+		let n = gather(&self.b, ray, &mut scratch);
+		for &hit in &scratch[..n] {
+			if inside_at(&self.a, ray, hit.distance) {
+				push(hits, &mut count, hit);
+			}
+		}
+
+		count
+	}
+}
+
+//----------------------------------------------------------------
+
+/// Difference of two shapes.
+///
+/// The space inside the first shape but outside the second shape.
+///
+/// ```
+/// use cvmath::*;
+///
+/// // A box with a spherical hole
+/// let shape = Difference3(Cuboid(Point3(-2.0, -2.0, -2.0), Point3(2.0, 2.0, 2.0)), Sphere(Point3(0.0, 0.0, 0.0), 1.0));
+///
+/// let ray = Ray(Point3(-5.0f64, 0.0, 0.0), Vec3(1.0, 0.0, 0.0));
+/// let mut hits = [TraceHit::default(); 4];
+/// assert_eq!(ray.trace(&shape, &mut hits), 4);
+///
+/// // Exits the box into the cavity, the normal points into the cavity
+/// assert!(hits.contains(&TraceHit { distance: 4.0, normal: Vec3(1.0, 0.0, 0.0) }));
+/// ```
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq, Hash)]
+pub struct Difference3<A, B> {
+	/// The shape to subtract from.
+	pub a: A,
+	/// The shape to subtract.
+	pub b: B,
+}
+
+/// Difference3 constructor.
+#[allow(non_snake_case)]
+#[inline]
+pub fn Difference3<A, B>(a: A, b: B) -> Difference3<A, B> {
+	Difference3 { a, b }
+}
+
+impl<T: Float, A: TraceRay<T>, B: TraceRay<T>> TraceRay<T> for Difference3<A, B> {
+	fn inside(&self, ray: &Ray<T>) -> bool {
+		self.a.inside(ray) && !self.b.inside(ray)
+	}
+
+	fn trace(&self, ray: &Ray<T>, hits: &mut [TraceHit<T>]) -> usize {
+		let mut scratch = [TraceHit::default(); MAX_HITS];
+		let mut count = 0;
+
+		// Keep the surface of the first shape which lies outside the second shape
+		let n = gather(&self.a, ray, &mut scratch);
+		for &hit in &scratch[..n] {
+			if !inside_at(&self.b, ray, hit.distance) {
+				push(hits, &mut count, hit);
+			}
+		}
+		// Keep the surface of the second shape which lies inside the first shape, turned inside out
+		let n = gather(&self.b, ray, &mut scratch);
+		for &hit in &scratch[..n] {
+			if inside_at(&self.a, ray, hit.distance) {
+				push(hits, &mut count, TraceHit { distance: hit.distance, normal: -hit.normal });
+			}
+		}
+
+		count
+	}
+}
+
+//----------------------------------------------------------------
+
+/// Complement of a shape.
+///
+/// The space outside the shape, the normals are flipped.
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq, Hash)]
+pub struct Complement3<A> {
+	/// The complemented shape.
+	pub a: A,
+}
+
+/// Complement3 constructor.
+#[allow(non_snake_case)]
+#[inline]
+pub fn Complement3<A>(a: A) -> Complement3<A> {
+	Complement3 { a }
+}
+
+impl<T: Float, A: TraceRay<T>> TraceRay<T> for Complement3<A> {
+	fn inside(&self, ray: &Ray<T>) -> bool {
+		!self.a.inside(ray)
+	}
+
+	fn trace(&self, ray: &Ray<T>, hits: &mut [TraceHit<T>]) -> usize {
+		let count = self.a.trace(ray, hits);
+		for hit in hits.iter_mut().take(count) {
+			hit.normal = -hit.normal;
+		}
+		count
+	}
+}
@@ -0,0 +1,134 @@
+use super::*;
+
+/// Cylinder structure.
+///
+/// A finite circular cylinder closed by two cap disks.
+/// The base disk is centered at `base` and the top disk at `base + axis * height`.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[repr(C)]
+pub struct Cylinder3<T> {
+	/// Center of the base disk.
+	pub base: Point3<T>,
+	/// Direction from the base to the top, must be normalized.
+	pub axis: Vec3<T>,
+	/// Distance between the base and the top.
+	pub height: T,
+	/// Radius of the cap disks.
+	pub radius: T,
+}
+
+/// Cylinder3 constructor.
+#[allow(non_snake_case)]
+#[inline]
+pub fn Cylinder3<T>(base: Point3<T>, axis: Vec3<T>, height: T, radius: T) -> Cylinder3<T> {
+	Cylinder3 { base, axis, height, radius }
+}
+
+#[cfg(feature = "dataview")]
+unsafe impl<T: dataview::Pod> dataview::Pod for Cylinder3<T> {}
+#[cfg(feature = "bytemuck")]
+unsafe impl<T: bytemuck::Zeroable> bytemuck::Zeroable for Cylinder3<T> {}
+#[cfg(feature = "bytemuck")]
+unsafe impl<T: bytemuck::Pod> bytemuck::Pod for Cylinder3<T> {}
+
+impl<T> Cylinder3<T> {
+	/// Constructs a new cylinder.
+	#[inline]
+	pub const fn new(base: Point3<T>, axis: Vec3<T>, height: T, radius: T) -> Cylinder3<T> {
+		Cylinder3 { base, axis, height, radius }
+	}
+}
+
+impl<T: Float> Cylinder3<T> {
+	/// Returns `true` if all the components are finite.
+	#[inline]
+	pub fn is_finite(&self) -> bool {
+		self.base.all_finite() && self.axis.all_finite() && self.height.is_finite() && self.radius.is_finite()
+	}
+	/// Center of the top disk.
+	#[inline]
+	pub fn top(&self) -> Point3<T> {
+		self.base + self.axis * self.height
+	}
+	/// Returns `true` if the point lies inside the cylinder or on its surface.
+	///
+	/// ```
+	/// use cvmath::{Cylinder3, Point3, Vec3};
+	///
+	/// let cylinder = Cylinder3(Point3(0.0, 0.0, 0.0), Vec3(0.0, 0.0, 1.0), 2.0, 1.0);
+	/// assert!(cylinder.contains(Point3(0.5, 0.5, 1.0)));
+	/// assert!(!cylinder.contains(Point3(0.0, 0.0, 3.0)));
+	/// assert!(!cylinder.contains(Point3(1.0, 1.0, 1.0)));
+	/// ```
+	#[inline]
+	pub fn contains(&self, pt: Point3<T>) -> bool {
+		let v = pt - self.base;
+		let h = v.dot(self.axis);
+		h >= T::ZERO && h <= self.height && (v - self.axis * h).len_sqr() <= self.radius * self.radius
+	}
+	/// Axis aligned bounding box of the cylinder.
+	///
+	/// The bounds are tight, each cap disk extends `radius * sqrt(1 - axis²)` along each coordinate axis.
+	///
+	/// ```
+	/// use cvmath::{Cuboid, Cylinder3, Point3, Vec3};
+	///
+	/// let cylinder = Cylinder3(Point3(1.0, 2.0, 3.0), Vec3(0.0, 0.0, 1.0), 2.0, 1.0);
+	/// assert_eq!(cylinder.bounds(), Cuboid(Point3(0.0, 1.0, 3.0), Point3(2.0, 3.0, 5.0)));
+	/// ```
+	#[inline]
+	pub fn bounds(&self) -> Cuboid<T> {
+		let extent = disk_extent(self.axis, self.radius);
+		let (base, top) = (self.base, self.top());
+		Cuboid(base.min(top) - extent, base.max(top) + extent)
+	}
+}
+
+// Extent of a disk with the given normal along the coordinate axes
+#[inline]
+pub(crate) fn disk_extent<T: Float>(normal: Vec3<T>, radius: T) -> Vec3<T> {
+	normal.map(|x| (T::ONE - x * x).max(T::ZERO).sqrt() * radius)
+}
+
+impl<T: Float> TraceRay<T> for Cylinder3<T> {
+	fn inside(&self, ray: &Ray<T>) -> bool {
+		self.contains(ray.origin)
+	}
+
+	fn trace(&self, ray: &Ray<T>, hits: &mut [TraceHit<T>]) -> usize {
+		let w = ray.origin - self.base;
+		let (wa, da) = (w.dot(self.axis), ray.direction.dot(self.axis));
+		let r2 = self.radius * self.radius;
+
+		let mut candidates = [TraceHit::default(); 4];
+		let mut n = 0;
+
+		// The lateral surface, the ray components perpendicular to the axis are at the radius
+		let w_perp = w - self.axis * wa;
+		let d_perp = ray.direction - self.axis * da;
+		let two = T::ONE + T::ONE;
+		if let Some((t0, t1)) = solve_quadratic(d_perp.dot(d_perp), two * w_perp.dot(d_perp), w_perp.dot(w_perp) - r2) {
+			for t in [t0, t1] {
+				let h = wa + da * t;
+				if h >= T::ZERO && h <= self.height {
+					candidates[n] = TraceHit { distance: t, normal: (w_perp + d_perp * t).normalize() };
+					n += 1;
+				}
+			}
+		}
+
+		// The cap disks, rays parallel to the axis only hit the caps
+		if da != T::ZERO {
+			for (h, normal) in [(T::ZERO, -self.axis), (self.height, self.axis)] {
+				let t = (h - wa) / da;
+				if (w_perp + d_perp * t).len_sqr() <= r2 {
+					candidates[n] = TraceHit { distance: t, normal };
+					n += 1;
+				}
+			}
+		}
+
+		crate::ray::convex_hits(&candidates[..n], hits)
+	}
+}
@@ -0,0 +1,236 @@
+use super::*;
+
+/// Dual quaternion structure.
+///
+/// Represents a rigid transform, a rotation followed by a translation, as `real + ε dual`.
+/// The real part is the unit rotation quaternion and the dual part is `0.5 * t * real` where `t` is the translation as a pure quaternion.
+///
+/// Blending dual quaternions avoids the candy-wrapper artifacts of linear blend skinning.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[repr(C)]
+pub struct DualQuaternion<T> {
+	/// The rotation part.
+	pub real: Quaternion<T>,
+	/// The translation part.
+	pub dual: Quaternion<T>,
+}
+
+/// DualQuaternion constructor.
+#[allow(non_snake_case)]
+#[inline]
+pub fn DualQuaternion<T>(real: Quaternion<T>, dual: Quaternion<T>) -> DualQuaternion<T> {
+	DualQuaternion { real, dual }
+}
+
+#[cfg(feature = "dataview")]
+unsafe impl<T: dataview::Pod> dataview::Pod for DualQuaternion<T> {}
+#[cfg(feature = "bytemuck")]
+unsafe impl<T: bytemuck::Zeroable> bytemuck::Zeroable for DualQuaternion<T> {}
+#[cfg(feature = "bytemuck")]
+unsafe impl<T: bytemuck::Pod> bytemuck::Pod for DualQuaternion<T> {}
+
+impl<T> DualQuaternion<T> {
+	/// Constructs a new dual quaternion from its parts.
+	#[inline]
+	pub const fn new(real: Quaternion<T>, dual: Quaternion<T>) -> DualQuaternion<T> {
+		DualQuaternion { real, dual }
+	}
+}
+impl<T: Zero> DualQuaternion<T> {
+	pub const ZERO: DualQuaternion<T> = DualQuaternion { real: Quaternion::ZERO, dual: Quaternion::ZERO };
+}
+impl<T: Zero + One> DualQuaternion<T> {
+	pub const IDENTITY: DualQuaternion<T> = DualQuaternion { real: Quaternion::IDENTITY, dual: Quaternion::ZERO };
+}
+
+impl<T: Float> DualQuaternion<T> {
+	/// Rigid transform rotating by the unit quaternion followed by the translation.
+	///
+	/// ```
+	/// use cvmath::{DualQuaternion, Quaternion, Vec3};
+	///
+	/// let dq = DualQuaternion::from_rotation_translation(Quaternion(0.5, 0.5, 0.5, 0.5), Vec3(1.0, 2.0, 3.0));
+	/// assert_eq!(dq * Vec3(1.0, 0.0, 0.0), Vec3(1.0, 3.0, 3.0));
+	/// ```
+	#[inline]
+	pub fn from_rotation_translation(rotation: Quaternion<T>, translation: Vec3<T>) -> DualQuaternion<T> {
+		let half = T::ONE / (T::ONE + T::ONE);
+		let t = Quaternion { a: T::ZERO, b: translation.x, c: translation.y, d: translation.z };
+		DualQuaternion { real: rotation, dual: t * rotation * half }
+	}
+	/// Rigid transform from a transform matrix.
+	///
+	/// The rotation part must be orthonormal with a positive determinant, any scale or shear is not representable.
+	///
+	/// ```
+	/// use cvmath::{Deg, DualQuaternion, Transform3, Vec3};
+	///
+	/// let transform = Transform3::translate(Vec3(1.0, 2.0, 3.0)) * Transform3::rotate(Deg(90.0), Vec3::Z);
+	/// let dq = DualQuaternion::from_transform3(transform);
+	/// assert!((dq * Vec3(1.0, 0.0, 0.0)).distance(transform * Vec3(1.0, 0.0, 0.0)) < 1e-12);
+	/// ```
+	#[inline]
+	pub fn from_transform3(transform: Transform3<T>) -> DualQuaternion<T> {
+		DualQuaternion::from_rotation_translation(Quaternion::from_rotation_matrix(transform.mat3()), transform.t())
+	}
+	/// Transform matrix of the unit dual quaternion.
+	///
+	/// ```
+	/// use cvmath::{Deg, DualQuaternion, Transform3, Vec3};
+	///
+	/// let transform = Transform3::translate(Vec3(1.0, 2.0, 3.0)) * Transform3::rotate(Deg(30.0), Vec3::Y);
+	/// let round_trip = DualQuaternion::from_transform3(transform).to_transform3();
+	/// assert!((round_trip - transform).into_row_major().iter().flatten().all(|&x: &f64| x.abs() < 1e-12));
+	/// ```
+	#[inline]
+	pub fn to_transform3(self) -> Transform3<T> {
+		Transform3::compose_trs(self.translation(), self.real, Vec3::ONE)
+	}
+	/// The rotation of the unit dual quaternion.
+	#[inline]
+	pub fn rotation(self) -> Quaternion<T> {
+		self.real
+	}
+	/// The translation of the unit dual quaternion.
+	#[inline]
+	pub fn translation(self) -> Vec3<T> {
+		let two = T::ONE + T::ONE;
+		let t = self.dual * self.real.conjugate() * two;
+		Vec3 { x: t.b, y: t.c, z: t.d }
+	}
+	/// Conjugate of the dual quaternion.
+	///
+	/// For unit dual quaternions this is the inverse rigid transform.
+	#[inline]
+	pub fn conjugate(self) -> DualQuaternion<T> {
+		DualQuaternion { real: self.real.conjugate(), dual: self.dual.conjugate() }
+	}
+	/// Normalizes the dual quaternion to represent a rigid transform.
+	///
+	/// Required after blending, the dual part is made orthogonal to the real part.
+	///
+	/// ```
+	/// use cvmath::{DualQuaternion, Quaternion, Vec3};
+	///
+	/// let dq = DualQuaternion::from_rotation_translation(Quaternion(0.5, 0.5, 0.5, 0.5), Vec3(1.0, 2.0, 3.0));
+	/// let scaled = DualQuaternion(dq.real * 2.0, dq.dual * 2.0);
+	/// assert_eq!(scaled.normalize(), dq);
+	/// ```
+	#[inline]
+	pub fn normalize(self) -> DualQuaternion<T> {
+		let len = self.real.len();
+		if len == T::ZERO {
+			return DualQuaternion::ZERO;
+		}
+		let inv = T::ONE / len;
+		let real = self.real * inv;
+		let dual = self.dual * inv;
+		DualQuaternion { real, dual: dual - real * real.dot(dual) }
+	}
+	/// Screw linear interpolation between unit dual quaternions.
+	///
+	/// Interpolates the rigid transform along the screw motion with constant angular and linear velocity, the dual quaternion equivalent of [slerp](Quaternion::slerp).
+	/// Takes the shortest path by flipping the sign of `rhs` if the rotations are more than 180° apart.
+	///
+	/// ```
+	/// use cvmath::{Deg, DualQuaternion, Quaternion, Vec3};
+	///
+	/// let a = DualQuaternion::IDENTITY;
+	/// let b = DualQuaternion::from_rotation_translation(Quaternion::rotation_z(Deg(90.0f64)), Vec3(0.0, 0.0, 2.0));
+	/// let mid = a.sclerp(b, 0.5);
+	/// assert!((mid.rotation() - Quaternion::rotation_z(Deg(45.0))).len() < 1e-12);
+	/// assert!(mid.translation().distance(Vec3(0.0, 0.0, 1.0)) < 1e-12);
+	/// ```
+	pub fn sclerp(self, rhs: DualQuaternion<T>, t: T) -> DualQuaternion<T> {
+		let rhs = if self.real.dot(rhs.real) < T::ZERO { -rhs } else { rhs };
+		let diff = self.conjugate() * rhs;
+		self * diff.pow(t)
+	}
+	// Raises the unit dual quaternion to a power by scaling its screw parameters
+	fn pow(self, t: T) -> DualQuaternion<T> {
+		let half = T::ONE / (T::ONE + T::ONE);
+		let w = self.real.a;
+		let v = Vec3 { x: self.real.b, y: self.real.c, z: self.real.d };
+		let sin_half = v.len();
+		// Pure translation has no screw axis, scale the translation only
+		if sin_half <= T::EPSILON {
+			return DualQuaternion::from_rotation_translation(Quaternion::IDENTITY, self.translation() * t);
+		}
+		let half_angle = sin_half.atan2(w);
+		// Screw axis direction, pitch and moment
+		let axis = v * (T::ONE / sin_half);
+		let pitch = -(self.dual.a + self.dual.a) / sin_half;
+		let ve = Vec3 { x: self.dual.b, y: self.dual.c, z: self.dual.d };
+		let moment = (ve - axis * (pitch * half * w)) * (T::ONE / sin_half);
+
+		let (sin, cos) = (half_angle * t).sin_cos();
+		let pitch = pitch * t;
+		let real_v = axis * sin;
+		let dual_v = moment * sin + axis * (pitch * half * cos);
+		DualQuaternion {
+			real: Quaternion { a: cos, b: real_v.x, c: real_v.y, d: real_v.z },
+			dual: Quaternion { a: -pitch * half * sin, b: dual_v.x, c: dual_v.y, d: dual_v.z },
+		}
+	}
+	/// Returns `true` if all the components are finite.
+	#[inline]
+	pub fn all_finite(self) -> bool {
+		self.real.all_finite() && self.dual.all_finite()
+	}
+	/// Returns `true` if any of the components are NaN.
+	#[inline]
+	pub fn any_nan(self) -> bool {
+		self.real.any_nan() || self.dual.any_nan()
+	}
+}
+
+impl<T: Float> From<Transform3<T>> for DualQuaternion<T> {
+	#[inline]
+	fn from(transform: Transform3<T>) -> DualQuaternion<T> {
+		DualQuaternion::from_transform3(transform)
+	}
+}
+impl<T: Float> From<DualQuaternion<T>> for Transform3<T> {
+	#[inline]
+	fn from(dq: DualQuaternion<T>) -> Transform3<T> {
+		dq.to_transform3()
+	}
+}
+
+//----------------------------------------------------------------
+// Operators
+
+impl<T: ops::Neg<Output = T>> ops::Neg for DualQuaternion<T> {
+	type Output = DualQuaternion<T>;
+	#[inline]
+	fn neg(self) -> DualQuaternion<T> {
+		DualQuaternion { real: -self.real, dual: -self.dual }
+	}
+}
+
+// Composes the rigid transforms, `rhs` is applied first
+impl<T: Copy + ops::Add<Output = T> + ops::Sub<Output = T> + ops::Mul<Output = T>> ops::Mul<DualQuaternion<T>> for DualQuaternion<T> {
+	type Output = DualQuaternion<T>;
+	#[inline]
+	fn mul(self, rhs: DualQuaternion<T>) -> DualQuaternion<T> {
+		DualQuaternion {
+			real: self.real * rhs.real,
+			dual: self.real * rhs.dual + self.dual * rhs.real,
+		}
+	}
+}
+impl<T: Copy + ops::Add<Output = T> + ops::Sub<Output = T> + ops::Mul<Output = T>> ops::MulAssign for DualQuaternion<T> {
+	#[inline]
+	fn mul_assign(&mut self, rhs: DualQuaternion<T>) {
+		*self = *self * rhs;
+	}
+}
+
+impl<T: Float> ops::Mul<Vec3<T>> for DualQuaternion<T> {
+	type Output = Vec3<T>;
+	#[inline]
+	fn mul(self, rhs: Vec3<T>) -> Vec3<T> {
+		self.real * rhs + self.translation()
+	}
+}
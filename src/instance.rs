@@ -0,0 +1,76 @@
+/*!
+Shape instancing.
+
+Places a shape in the world with a transform without duplicating or transforming its geometry.
+Rays are transformed into the local space of the shape instead, see [`Ray::transformed`].
+*/
+
+use super::*;
+
+/// Transformed instance of a shape.
+///
+/// Traces the shape in its local space and maps the hits back to world space.
+/// The inverse transform is cached to avoid recomputing it for every ray.
+///
+/// The transform may scale non-uniformly, the hit normals are transformed by the inverse transpose and renormalized.
+///
+/// ```
+/// use cvmath::*;
+///
+/// // The unit sphere scaled into an ellipsoid and moved away from the origin
+/// let unit = Sphere(Point3(0.0, 0.0, 0.0), 1.0);
+/// let ellipsoid = Instance3(unit, Transform3::translate(Vec3(0.0, 0.0, 10.0)) * Transform3::scale(Vec3(1.0, 1.0, 2.0)));
+///
+/// let ray = Ray(Point3(0.0f64, 0.0, 0.0), Vec3(0.0, 0.0, 1.0));
+/// let mut hits = [TraceHit::default(); 2];
+/// assert_eq!(ray.trace(&ellipsoid, &mut hits), 2);
+/// assert!(hits.contains(&TraceHit { distance: 8.0, normal: Vec3(0.0, 0.0, -1.0) }));
+/// assert!(hits.contains(&TraceHit { distance: 12.0, normal: Vec3(0.0, 0.0, 1.0) }));
+/// ```
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Instance3<T, S> {
+	/// The shape in local space.
+	pub shape: S,
+	/// Transform from local space to world space.
+	pub transform: Transform3<T>,
+	/// Inverse of the transform, from world space to local space.
+	pub inv: Transform3<T>,
+}
+
+/// Instance3 constructor.
+#[allow(non_snake_case)]
+#[inline]
+pub fn Instance3<T: Scalar, S>(shape: S, transform: Transform3<T>) -> Instance3<T, S> {
+	Instance3::new(shape, transform)
+}
+
+impl<T: Scalar, S> Instance3<T, S> {
+	/// Constructs a new instance and caches the inverse transform.
+	///
+	/// The transform must be invertible.
+	#[inline]
+	pub fn new(shape: S, transform: Transform3<T>) -> Instance3<T, S> {
+		Instance3 { shape, transform, inv: transform.inverse() }
+	}
+}
+
+impl<T: Float, S: TraceRay<T>> TraceRay<T> for Instance3<T, S> {
+	#[inline]
+	fn inside(&self, ray: &Ray<T>) -> bool {
+		self.shape.inside(&(self.inv * *ray))
+	}
+
+	fn trace(&self, ray: &Ray<T>, hits: &mut [TraceHit<T>]) -> usize {
+		let (local, scale) = ray.transformed(&self.inv);
+		let n = self.shape.trace(&local, hits);
+
+		// The inverse transpose of the transform is the transpose of the inverse
+		let normal_matrix = self.inv.mat3().transpose();
+		let inv_scale = T::ONE / scale;
+		for hit in hits.iter_mut().take(n) {
+			hit.distance *= inv_scale;
+			hit.normal = (normal_matrix * hit.normal).normalize();
+		}
+		n
+	}
+}
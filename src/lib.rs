@@ -1,21 +1,51 @@
 /*!
 Computer Vector Graphics Math Library
 =====================================
+
+Cargo features
+--------------
+
+`std` (default): Implements the `Error` trait for parse errors and enables the functions which allocate.
+Without it the crate is `no_std` and the `libm` feature provides the float math instead.
+
+`libm`: Float math through the [libm](https://crates.io/crates/libm) crate when `std` is disabled.
+
+`bytemuck`: Implements [bytemuck](https://crates.io/crates/bytemuck)'s `Pod` and `Zeroable` traits for the vector, matrix, quaternion and shape types.
+Vertex data can then be cast to bytes or scalars with `bytemuck::cast_slice`:
+
+```ignore
+let vertices = [cvmath::Vec3(1.0f32, 2.0, 3.0), cvmath::Vec3(4.0, 5.0, 6.0)];
+let bytes: &[u8] = bytemuck::cast_slice(&vertices);
+assert_eq!(bytes.len(), 24);
+```
+
+Without the feature [`Vec3::slice_as_scalars`] reinterprets a slice of vectors as a slice of its components.
 */
 
-use std::{fmt, mem, ops, slice};
-use std::str::FromStr;
+#![cfg_attr(not(feature = "std"), no_std)]
+
+#[cfg(not(any(feature = "std", feature = "libm")))]
+compile_error!("either the `std` or `libm` feature must be enabled");
+
+use core::{fmt, iter, mem, ops, slice};
+use core::str::FromStr;
 
 mod macros;
 
 mod num;
 mod angle;
+mod binangle;
 
 mod vec;
 mod bools;
 mod packed;
 mod mat;
 mod quaternion;
+mod dual_quaternion;
+mod spherical;
+mod color;
+mod projection;
+mod camera;
 
 #[cfg(feature = "qangle")]
 mod qangle;
@@ -23,29 +53,62 @@ mod qangle;
 mod point;
 mod bounds;
 mod line;
+mod triangle;
 mod plane;
 mod sphere;
+mod stadium;
+mod cylinder;
+mod cone;
 mod ray;
+mod csg;
+mod instance;
+mod polyline;
+mod measure;
+mod sample;
+mod screen_bounds;
+mod polygonize;
+mod predicates;
+mod solve;
+pub mod scalar;
+mod spring;
 
 use crate::num::*;
 use crate::angle::Angle;
 
 pub use crate::angle::{Rad, Deg};
+pub use crate::binangle::BinAngle;
 pub use crate::vec::{Vec2, Vec3, Vec4, X, Y, Z, W};
 pub use crate::bools::{Bool2, Bool3, Bool4};
-pub use crate::mat::{Mat2, Mat3, Mat4, Transform2, Transform3, Hand, Clip};
+pub use crate::packed::{Vec3h, Vec4h};
+pub use crate::mat::{Mat2, Mat3, Mat4, Transform2, Transform3, Hand, Clip, FitPolicy};
 pub use crate::mat::{Hand::*, Clip::*};
 pub use crate::quaternion::Quaternion;
+pub use crate::dual_quaternion::DualQuaternion;
+pub use crate::spherical::Spherical;
+pub use crate::projection::{Perspective, Orthographic, fov_y_from_fov_x, fov_x_from_fov_y, fov_y_from_diagonal, focal_length_from_fov, fov_from_focal_length};
 
 #[cfg(feature = "qangle")]
 pub use crate::qangle::QAngle;
 
 pub use crate::point::{Point2, Point3};
-pub use crate::bounds::{Bounds, Rect, Cuboid};
+pub use crate::bounds::{Bounds, Rect, Cuboid, Anchor};
 pub use crate::line::{Line, Line2, Line3};
-pub use crate::plane::Plane;
+pub use crate::triangle::{Triangle, Triangle2, Triangle3, CullMode};
+pub use crate::plane::{Plane, PlaneSide};
 pub use crate::sphere::Sphere;
-pub use crate::ray::{Ray, TraceHit, TraceRay};
+pub use crate::stadium::Stadium2;
+pub use crate::cylinder::Cylinder3;
+pub use crate::cone::Cone3;
+pub use crate::ray::{Ray, Bounce, TraceHit, TraceRay};
+pub use crate::csg::{Intersection3, Difference3, Complement3};
+pub use crate::instance::Instance3;
+pub use crate::measure::{Measure2, Measure3};
+pub use crate::polyline::polyline_length;
+#[cfg(feature = "std")]
+pub use crate::polyline::resample_uniform;
+pub use crate::spring::spring_damp;
+pub use crate::predicates::{orient2d, incircle};
+pub use crate::solve::{solve_quadratic, solve_cubic, smallest_root_in};
 
 #[doc(hidden)]
 pub use crate::vec::{Vec2f, Vec3f, Vec4f, Vec2d, Vec3d, Vec4d, Vec2i, Vec3i, Vec4i};
@@ -22,6 +22,10 @@ pub fn Line<T>(start: T, end: T) -> Line<T> {
 
 #[cfg(feature = "dataview")]
 unsafe impl<T: dataview::Pod> dataview::Pod for Line<T> {}
+#[cfg(feature = "bytemuck")]
+unsafe impl<T: bytemuck::Zeroable> bytemuck::Zeroable for Line<T> {}
+#[cfg(feature = "bytemuck")]
+unsafe impl<T: bytemuck::Pod> bytemuck::Pod for Line<T> {}
 
 impl<T> Line<T> {
 	/// Constructs a new line.
@@ -39,6 +43,111 @@ impl<T: ops::Sub<Output = T>> Line<T> {
 	}
 }
 
+impl<T> Line<T> {
+	/// Returns the line with start and end swapped.
+	///
+	/// ```
+	/// use cvmath::{Line2, Point2};
+	///
+	/// let line = Line2(Point2(1, 2), Point2(3, 4));
+	/// assert_eq!(line.reversed(), Line2(Point2(3, 4), Point2(1, 2)));
+	/// ```
+	#[inline]
+	pub fn reversed(self) -> Line<T> {
+		Line { start: self.end, end: self.start }
+	}
+}
+
+macro_rules! line {
+	($line:ident, $point:ident) => {
+		impl<T: Scalar> $line<T> {
+			/// Squared length of the line.
+			#[inline]
+			pub fn len_sqr(self) -> T {
+				self.direction().len_sqr()
+			}
+		}
+
+		impl<T: Float> $line<T> {
+			/// Length of the line.
+			///
+			/// ```
+			/// use cvmath::{Line3, Point3};
+			///
+			/// let line = Line3(Point3(1.0, 1.0, 1.0), Point3(3.0, 4.0, 7.0));
+			/// assert_eq!(line.len_sqr(), 49.0);
+			/// assert_eq!(line.len(), 7.0);
+			/// ```
+			#[inline]
+			pub fn len(self) -> T {
+				self.direction().len()
+			}
+			/// Normalized direction of the line.
+			///
+			/// The line with length zero has a zero direction.
+			#[inline]
+			pub fn unit_direction(self) -> $point<T> {
+				self.direction().normalize()
+			}
+			/// Point halfway between start and end.
+			#[inline]
+			pub fn midpoint(self) -> $point<T> {
+				self.at(T::cast_from(0.5))
+			}
+			/// Point at the parameter along the line.
+			///
+			/// Linearly interpolates from start at `t = 0` to end at `t = 1`, the end points are exact.
+			///
+			/// ```
+			/// use cvmath::{Line2, Point2};
+			///
+			/// let line = Line2(Point2(1.0, 2.0), Point2(5.0, -2.0));
+			/// assert_eq!(line.at(0.25), Point2(2.0, 1.0));
+			/// assert_eq!(line.midpoint(), Point2(3.0, 0.0));
+			/// ```
+			#[inline]
+			pub fn at(self, t: T) -> $point<T> {
+				self.start * (T::ONE - t) + self.end * t
+			}
+			/// Splits the line at the parameter.
+			///
+			/// ```
+			/// use cvmath::{Line2, Point2};
+			///
+			/// let line = Line2(Point2(0.0, 0.0), Point2(4.0, 0.0));
+			/// let (a, b) = line.split(0.75);
+			/// assert_eq!(a, Line2(Point2(0.0, 0.0), Point2(3.0, 0.0)));
+			/// assert_eq!(b, Line2(Point2(3.0, 0.0), Point2(4.0, 0.0)));
+			/// ```
+			#[inline]
+			pub fn split(self, t: T) -> ($line<T>, $line<T>) {
+				let mid = self.at(t);
+				(Line { start: self.start, end: mid }, Line { start: mid, end: self.end })
+			}
+			/// Evenly spaced points along the line.
+			///
+			/// Yields `n + 1` points starting at start and ending at end.
+			/// When `n` is zero only the start point is yielded.
+			///
+			/// ```
+			/// use cvmath::{Line2, Point2};
+			///
+			/// let line = Line2(Point2(0.0, 0.0), Point2(2.0, 4.0));
+			/// let points: Vec<_> = line.subdivide(4).collect();
+			/// assert_eq!(points, [Point2(0.0, 0.0), Point2(0.5, 1.0), Point2(1.0, 2.0), Point2(1.5, 3.0), Point2(2.0, 4.0)]);
+			/// ```
+			#[inline]
+			pub fn subdivide(self, n: usize) -> impl Iterator<Item = $point<T>> {
+				let div = usize::max(n, 1) as f64;
+				(0..=n).map(move |i| self.at(T::cast_from(i as f64 / div)))
+			}
+		}
+	};
+}
+
+line!(Line2, Point2);
+line!(Line3, Point3);
+
 impl<T: Float> Line2<T> {
 	/// Projects the point onto the line.
 	#[inline]
@@ -52,6 +161,48 @@ impl<T: Float> Line2<T> {
 		self.project(pt).distance(pt)
 	}
 
+	/// Closest point on the line segment.
+	///
+	/// Unlike [`project`](Line2::project) the result is clamped to the segment's end points.
+	///
+	/// ```
+	/// use cvmath::{Line2, Point2};
+	///
+	/// let line = Line2(Point2(0.0, 0.0), Point2(4.0, 0.0));
+	/// assert_eq!(line.closest_point(Point2(1.0, 3.0)), Point2(1.0, 0.0));
+	/// assert_eq!(line.closest_point(Point2(-2.0, 1.0)), Point2(0.0, 0.0));
+	/// assert_eq!(line.closest_point(Point2(7.0, -1.0)), Point2(4.0, 0.0));
+	/// ```
+	#[inline]
+	pub fn closest_point(&self, pt: Point2<T>) -> Point2<T> {
+		self.start + (pt - self.start).project_sat(self.direction())
+	}
+
+	/// Point to line segment distance.
+	///
+	/// Unlike [`dist_pt`](Line2::dist_pt) this measures the distance to the closest point on the segment.
+	///
+	/// ```
+	/// use cvmath::{Line2, Point2};
+	///
+	/// let line = Line2(Point2(0.0, 0.0), Point2(4.0, 0.0));
+	/// assert_eq!(line.distance_to(Point2(1.0, 3.0)), 3.0);
+	/// assert_eq!(line.distance_to(Point2(7.0, 4.0)), 5.0);
+	/// assert_eq!(line.dist_pt(Point2(7.0, 4.0)), 4.0);
+	/// ```
+	#[inline]
+	pub fn distance_to(&self, pt: Point2<T>) -> T {
+		self.closest_point(pt).distance(pt)
+	}
+
+	/// Distance field of the line segment.
+	///
+	/// An open segment has no inside, the distance is unsigned and equal to [`distance_to`](Line2::distance_to).
+	#[inline]
+	pub fn sdf(&self, pt: Point2<T>) -> T {
+		self.distance_to(pt)
+	}
+
 	/// Intersect a line and line segment.
 	///
 	/// The result is scalar with which to scale the segment to find the intersection point, none if the line and line segment are parallel.
@@ -112,6 +263,46 @@ impl<T: Float> Line2<T> {
 		Some(p / denom)
 	}
 
+	/// Intersect two line segments.
+	///
+	/// The result is the intersection point followed by the parameters along `self` and `rhs` at which it lies, both in the [0; 1] range.
+	/// Returns none if the segments do not cross.
+	///
+	/// Parallel segments never intersect, even when they are collinear and overlap.
+	///
+	/// ```
+	/// use cvmath::{Line2, Point2};
+	///
+	/// let line1 = Line2(Point2(0.0, 0.0), Point2(4.0, 4.0));
+	/// let line2 = Line2(Point2(0.0, 2.0), Point2(2.0, 0.0));
+	/// assert_eq!(line1.intersect(&line2), Some((Point2(1.0, 1.0), 0.25, 0.5)));
+	///
+	/// let short = Line2(Point2(0.0, 2.0), Point2(0.5, 1.5));
+	/// assert_eq!(line1.intersect(&short), None);
+	///
+	/// let collinear = Line2(Point2(2.0, 2.0), Point2(6.0, 6.0));
+	/// assert_eq!(line1.intersect(&collinear), None);
+	/// ```
+	#[inline]
+	pub fn intersect(&self, rhs: &Line2<T>) -> Option<(Point2<T>, T, T)> {
+		let r = self.direction();
+		let s = rhs.direction();
+
+		let denom = r.cross(s);
+		if denom == T::ZERO {
+			return None;
+		}
+
+		let qp = rhs.start - self.start;
+		let t = qp.cross(s) / denom;
+		let u = qp.cross(r) / denom;
+		if t < T::ZERO || t > T::ONE || u < T::ZERO || u > T::ONE {
+			return None;
+		}
+
+		Some((self.start + r * t, t, u))
+	}
+
 	/// Calculates the y coordinate where the line intercepts the Y axis.
 	///
 	/// Returns none if the line is parallel with the Y axis.
@@ -134,6 +325,42 @@ impl<T: Float> Line2<T> {
 		let x = self.start.x + self.direction().x * f;
 		Some(x)
 	}
+
+	/// Clips the polygon against the line as a half-plane.
+	///
+	/// Keeps the part of the polygon where `direction().cross(pt - start)` is non-negative, that is left of the line when the Y axis points up.
+	/// Points exactly on the line are kept.
+	/// The clipped polygon is written to `out` which is cleared first.
+	/// If less than three points remain the output is left empty.
+	///
+	/// ```
+	/// use cvmath::{Line2, Point2};
+	///
+	/// let line = Line2(Point2(0.0, 0.0), Point2(1.0, 1.0));
+	/// let triangle = [Point2(0.0, 0.0), Point2(2.0, 0.0), Point2(0.0, 2.0)];
+	///
+	/// let mut out = Vec::new();
+	/// line.clip_polygon(&triangle, &mut out);
+	/// assert_eq!(out, [Point2(0.0, 0.0), Point2(1.0, 1.0), Point2(0.0, 2.0)]);
+	/// ```
+	#[cfg(feature = "std")]
+	pub fn clip_polygon(&self, points: &[Point2<T>], out: &mut Vec<Point2<T>>) {
+		let direction = self.direction();
+		plane::clip_polygon(points, out, |pt| direction.cross(pt - self.start), Point2::lerp);
+	}
+}
+
+impl<T: Float> Measure2<T> for Line2<T> {
+	/// Lines have no area.
+	#[inline]
+	fn area(&self) -> T {
+		T::ZERO
+	}
+	/// The length of the line.
+	#[inline]
+	fn perimeter(&self) -> T {
+		self.start.distance(self.end)
+	}
 }
 
 impl<T: Float> Line3<T> {
@@ -147,6 +374,43 @@ impl<T: Float> Line3<T> {
 	}
 }
 
+//----------------------------------------------------------------
+// Operators
+
+/// Rotates both end points, see [`Mat2::rotate`].
+///
+/// ```
+/// use cvmath::{Deg, Line2, Mat2, Point2};
+///
+/// let line = Line2(Point2(1.0, 0.0), Point2(2.0, 0.0));
+/// let rotated = Mat2::rotate(Deg(90.0)) * line;
+/// assert!(rotated.start.distance(Point2(0.0, 1.0)) < 1e-12);
+/// assert!(rotated.end.distance(Point2(0.0, 2.0)) < 1e-12);
+/// ```
+impl<T: Copy + ops::Add<Output = T> + ops::Mul<Output = T>> ops::Mul<Line2<T>> for Mat2<T> {
+	type Output = Line2<T>;
+	#[inline]
+	fn mul(self, line: Line2<T>) -> Line2<T> {
+		Line2 { start: self * line.start, end: self * line.end }
+	}
+}
+/// Transforms both end points.
+impl<T: Copy + ops::Add<Output = T> + ops::Mul<Output = T>> ops::Mul<Line2<T>> for Transform2<T> {
+	type Output = Line2<T>;
+	#[inline]
+	fn mul(self, line: Line2<T>) -> Line2<T> {
+		Line2 { start: self * line.start, end: self * line.end }
+	}
+}
+/// Transforms both end points.
+impl<T: Copy + ops::Add<Output = T> + ops::Mul<Output = T>> ops::Mul<Line3<T>> for Transform3<T> {
+	type Output = Line3<T>;
+	#[inline]
+	fn mul(self, line: Line3<T>) -> Line3<T> {
+		Line3 { start: self * line.start, end: self * line.end }
+	}
+}
+
 /// Line2 structure.
 pub type Line2<T> = Line<Point2<T>>;
 
@@ -31,6 +31,10 @@ pub const fn Mat2<T>(
 
 #[cfg(feature = "dataview")]
 unsafe impl<T: dataview::Pod> dataview::Pod for Mat2<T> {}
+#[cfg(feature = "bytemuck")]
+unsafe impl<T: bytemuck::Zeroable> bytemuck::Zeroable for Mat2<T> {}
+#[cfg(feature = "bytemuck")]
+unsafe impl<T: bytemuck::Pod> bytemuck::Pod for Mat2<T> {}
 
 //----------------------------------------------------------------
 // Constructors
@@ -265,9 +269,32 @@ impl<T: Scalar> Mat2<T> {
 	}
 }
 
+impl<T: Float> Mat2<T> {
+	/// Returns `true` if all the components are finite.
+	///
+	/// ```
+	/// use cvmath::Mat2;
+	///
+	/// assert!(Mat2::<f32>::IDENTITY.all_finite());
+	/// assert!(!(Mat2::<f32>::IDENTITY * f32::INFINITY).all_finite());
+	/// assert!((Mat2::<f32>::ZERO * f32::NAN).any_nan());
+	/// ```
+	#[inline]
+	pub fn all_finite(self) -> bool {
+		self.x().all_finite() && self.y().all_finite()
+	}
+	/// Returns `true` if any of the components are NaN.
+	#[inline]
+	pub fn any_nan(self) -> bool {
+		self.x().any_nan() || self.y().any_nan()
+	}
+}
+
 //----------------------------------------------------------------
 // Operators
 
+impl_elementwise_ops!(Mat2 { a11, a12, a21, a22 });
+
 impl<T: Copy + ops::Mul<Output = T>> ops::Mul<T> for Mat2<T> {
 	type Output = Mat2<T>;
 	#[inline]
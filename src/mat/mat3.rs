@@ -34,6 +34,10 @@ pub const fn Mat3<T>(
 
 #[cfg(feature = "dataview")]
 unsafe impl<T: dataview::Pod> dataview::Pod for Mat3<T> {}
+#[cfg(feature = "bytemuck")]
+unsafe impl<T: bytemuck::Zeroable> bytemuck::Zeroable for Mat3<T> {}
+#[cfg(feature = "bytemuck")]
+unsafe impl<T: bytemuck::Pod> bytemuck::Pod for Mat3<T> {}
 
 //----------------------------------------------------------------
 // Constructors
@@ -93,6 +97,63 @@ impl<T: Scalar> Mat3<T> {
 		}
 	}
 }
+impl<T: Float> Mat3<T> {
+	/// Homography mapping the unit square to a quad.
+	///
+	/// The corners `(0, 0)`, `(1, 0)`, `(1, 1)` and `(0, 1)` map to the quad's points in order.
+	/// Returns none if the quad is degenerate.
+	///
+	/// Transform points with [transform_point2](#method.transform_point2).
+	///
+	/// ```
+	/// use cvmath::{Mat3, Point2};
+	///
+	/// let quad = [Point2(0.0, 0.0), Point2(4.0, 0.0), Point2(3.0, 2.0), Point2(1.0, 2.0)];
+	/// let homography = Mat3::from_unit_square_to_quad(quad).unwrap();
+	/// assert!(homography.transform_point2(Point2(1.0, 1.0)).distance(quad[2]) < 1e-12);
+	/// ```
+	pub fn from_unit_square_to_quad(quad: [Point2<T>; 4]) -> Option<Mat3<T>> {
+		let [p0, p1, p2, p3] = quad;
+		let s = p0 - p1 + p2 - p3;
+		let d1 = p1 - p2;
+		let d2 = p3 - p2;
+		let den = d1.cross(d2);
+		if den == T::ZERO {
+			return None;
+		}
+		let g = s.cross(d2) / den;
+		let h = d1.cross(s) / den;
+		let mat = Mat3 {
+			a11: p1.x - p0.x + g * p1.x, a12: p3.x - p0.x + h * p3.x, a13: p0.x,
+			a21: p1.y - p0.y + g * p1.y, a22: p3.y - p0.y + h * p3.y, a23: p0.y,
+			a31: g, a32: h, a33: T::ONE,
+		};
+		if mat.determinant() == T::ZERO {
+			return None;
+		}
+		Some(mat)
+	}
+	/// Homography mapping a quad to another quad.
+	///
+	/// The points of the source quad map to the points of the destination quad in order.
+	/// Returns none if either quad is degenerate.
+	///
+	/// ```
+	/// use cvmath::{Mat3, Point2};
+	///
+	/// let src = [Point2(0.0, 0.0), Point2(2.0, 0.0), Point2(2.0, 2.0), Point2(0.0, 2.0)];
+	/// let dst = [Point2(1.0, 1.0), Point2(5.0, 0.0), Point2(4.0, 3.0), Point2(2.0, 4.0)];
+	/// let homography = Mat3::from_quad_to_quad(src, dst).unwrap();
+	/// for i in 0..4 {
+	///     assert!(homography.transform_point2(src[i]).distance(dst[i]) < 1e-12);
+	/// }
+	/// ```
+	pub fn from_quad_to_quad(src: [Point2<T>; 4], dst: [Point2<T>; 4]) -> Option<Mat3<T>> {
+		let src = Mat3::from_unit_square_to_quad(src)?;
+		let dst = Mat3::from_unit_square_to_quad(dst)?;
+		Some(dst * src.inverse())
+	}
+}
 impl<T: Zero + One> From<Transform2<T>> for Mat3<T> {
 	#[inline]
 	fn from(mat: Transform2<T>) -> Mat3<T> {
@@ -168,6 +229,69 @@ impl<T> Mat3<T> {
 			[self.a13, self.a23, self.a33],
 		]
 	}
+	/// Exports the matrix as a flat column-major array.
+	///
+	/// ```
+	/// use cvmath::Mat3;
+	///
+	/// let mat = Mat3::from_row_major([[1, 2, 3], [4, 5, 6], [7, 8, 9]]);
+	/// assert_eq!(mat.to_cols_array_3x3(), [1, 4, 7, 2, 5, 8, 3, 6, 9]);
+	/// assert_eq!(mat.to_cols_array_3x3()[..], mat.into_column_major().concat()[..]);
+	/// ```
+	#[inline]
+	pub fn to_cols_array_3x3(self) -> [T; 9] {
+		[
+			self.a11, self.a21, self.a31,
+			self.a12, self.a22, self.a32,
+			self.a13, self.a23, self.a33,
+		]
+	}
+	/// Exports the matrix as a flat column-major array with each column padded to 4 components.
+	///
+	/// This matches the std140 layout of a `mat3` uniform where every column is aligned like a `vec4`.
+	///
+	/// ```
+	/// use cvmath::Mat3;
+	///
+	/// let mat = Mat3::from_row_major([[1, 2, 3], [4, 5, 6], [7, 8, 9]]);
+	/// assert_eq!(mat.to_cols_array_std140(), [1, 4, 7, 0, 2, 5, 8, 0, 3, 6, 9, 0]);
+	/// ```
+	#[inline]
+	pub fn to_cols_array_std140(self) -> [T; 12] where T: Zero {
+		[
+			self.a11, self.a21, self.a31, T::ZERO,
+			self.a12, self.a22, self.a32, T::ZERO,
+			self.a13, self.a23, self.a33, T::ZERO,
+		]
+	}
+}
+
+// mint stores the matrix as column vectors
+#[cfg(feature = "mint")]
+impl<T> From<mint::ColumnMatrix3<T>> for Mat3<T> {
+	#[inline]
+	fn from(mat: mint::ColumnMatrix3<T>) -> Mat3<T> {
+		Mat3 {
+			a11: mat.x.x, a12: mat.y.x, a13: mat.z.x,
+			a21: mat.x.y, a22: mat.y.y, a23: mat.z.y,
+			a31: mat.x.z, a32: mat.y.z, a33: mat.z.z,
+		}
+	}
+}
+#[cfg(feature = "mint")]
+impl<T> From<Mat3<T>> for mint::ColumnMatrix3<T> {
+	#[inline]
+	fn from(mat: Mat3<T>) -> mint::ColumnMatrix3<T> {
+		mint::ColumnMatrix3 {
+			x: mint::Vector3 { x: mat.a11, y: mat.a21, z: mat.a31 },
+			y: mint::Vector3 { x: mat.a12, y: mat.a22, z: mat.a32 },
+			z: mint::Vector3 { x: mat.a13, y: mat.a23, z: mat.a33 },
+		}
+	}
+}
+#[cfg(feature = "mint")]
+impl<T> mint::IntoMint for Mat3<T> {
+	type MintType = mint::ColumnMatrix3<T>;
 }
 
 //----------------------------------------------------------------
@@ -253,11 +377,111 @@ impl<T: Scalar> Mat3<T> {
 			a33: self.a11 * self.a22 - self.a12 * self.a21,
 		}
 	}
+	/// Computes the matrix to transform normals with.
+	///
+	/// This is the inverse-transpose of the matrix, it keeps normals perpendicular to the surface under non-uniform scaling.
+	/// The transformed normals are not normalized.
+	///
+	/// Returns none if the matrix is singular.
+	///
+	/// ```
+	/// use cvmath::{Mat3, Vec3};
+	///
+	/// let mat = Mat3::scale(Vec3(2.0, 4.0, -1.0));
+	/// assert_eq!(mat.normal_matrix(), Some(Mat3::scale(Vec3(0.5, 0.25, -1.0))));
+	/// assert_eq!(Mat3::scale(Vec3(1.0, 0.0, 1.0)).normal_matrix(), None);
+	/// ```
+	#[inline]
+	pub fn normal_matrix(self) -> Option<Mat3<T>> {
+		let det = self.determinant();
+		if det != T::ZERO {
+			Some(self.adjugate().transpose() * (T::ONE / det))
+		}
+		else {
+			None
+		}
+	}
+}
+
+impl<T: Float> Mat3<T> {
+	/// Returns `true` if all the components are finite.
+	///
+	/// ```
+	/// use cvmath::Mat3;
+	///
+	/// assert!(Mat3::<f32>::IDENTITY.all_finite());
+	/// assert!(!(Mat3::<f32>::IDENTITY * f32::INFINITY).all_finite());
+	/// assert!((Mat3::<f32>::IDENTITY * f32::NAN).any_nan());
+	/// ```
+	#[inline]
+	pub fn all_finite(self) -> bool {
+		self.x().all_finite() && self.y().all_finite() && self.z().all_finite()
+	}
+	/// Returns `true` if any of the components are NaN.
+	#[inline]
+	pub fn any_nan(self) -> bool {
+		self.x().any_nan() || self.y().any_nan() || self.z().any_nan()
+	}
+	/// Transforms a 2D point as a homography.
+	///
+	/// Multiplies the point in homogeneous coordinates followed by the homogeneous divide.
+	///
+	/// ```
+	/// use cvmath::{Mat3, Point2};
+	///
+	/// let mat = Mat3::from_row_major([[2.0, 0.0, 1.0], [0.0, 2.0, 0.0], [0.0, 1.0, 1.0]]);
+	/// assert_eq!(mat.transform_point2(Point2(1.0, 1.0)), Point2(1.5, 1.0));
+	/// ```
+	#[inline]
+	pub fn transform_point2(self, pt: Point2<T>) -> Point2<T> {
+		(self * pt.vec3(T::ONE)).hdiv()
+	}
+	/// Re-orthogonalizes a rotation matrix that drifted due to accumulated rounding errors.
+	///
+	/// Keeps the direction of the X axis, the Z axis is recomputed as the cross product of the X and Y axes and the Y axis follows from the Z and X axes.
+	/// This is cheaper than full Gram-Schmidt orthogonalization and the result is a right-handed rotation matrix.
+	///
+	/// ```
+	/// use cvmath::{Deg, Mat3, Vec3};
+	///
+	/// let rot = Mat3::rotate(Deg(30.0), Vec3(0.0, 0.0, 1.0));
+	/// let drifted = rot * 1.01 + Mat3::from_row_major([[0.0, 0.01, 0.0], [0.0; 3], [0.0; 3]]);
+	/// assert!(drifted.orthogonality_error() > 0.01);
+	/// assert!(drifted.renormalize().orthogonality_error() < 1e-12);
+	/// ```
+	#[inline]
+	pub fn renormalize(self) -> Mat3<T> {
+		let x = self.x().normalize();
+		let z = x.cross(self.y()).normalize();
+		let y = z.cross(x);
+		Mat3::compose(x, y, z)
+	}
+	/// Measures how far the matrix is from being orthonormal.
+	///
+	/// Returns the largest absolute element of `transpose(self) * self - I`, zero for rotation matrices.
+	///
+	/// ```
+	/// use cvmath::{Deg, Mat3, Vec3};
+	///
+	/// assert!(Mat3::rotate(Deg(30.0), Vec3(1.0, 0.0, 0.0)).orthogonality_error() < 1e-15);
+	/// assert_eq!(Mat3::scale(Vec3(1.0, 2.0, 1.0)).orthogonality_error(), 3.0);
+	/// ```
+	#[inline]
+	pub fn orthogonality_error(self) -> T {
+		let (x, y, z) = (self.x(), self.y(), self.z());
+		let errors = [
+			x.len_sqr() - T::ONE, y.len_sqr() - T::ONE, z.len_sqr() - T::ONE,
+			x.dot(y), y.dot(z), z.dot(x),
+		];
+		errors.iter().fold(T::ZERO, |max, e| max.max(e.abs()))
+	}
 }
 
 //----------------------------------------------------------------
 // Operators
 
+impl_elementwise_ops!(Mat3 { a11, a12, a13, a21, a22, a23, a31, a32, a33 });
+
 impl<T: Copy + ops::Mul<Output = T>> ops::Mul<T> for Mat3<T> {
 	type Output = Mat3<T>;
 	#[inline]
@@ -37,6 +37,10 @@ pub const fn Mat4<T>(
 
 #[cfg(feature = "dataview")]
 unsafe impl<T: dataview::Pod> dataview::Pod for Mat4<T> {}
+#[cfg(feature = "bytemuck")]
+unsafe impl<T: bytemuck::Zeroable> bytemuck::Zeroable for Mat4<T> {}
+#[cfg(feature = "bytemuck")]
+unsafe impl<T: bytemuck::Pod> bytemuck::Pod for Mat4<T> {}
 
 //----------------------------------------------------------------
 // Constructors
@@ -112,6 +116,68 @@ impl<T: Scalar> Mat4<T> {
 			a41: T::ZERO, a42: T::ZERO, a43: T::ZERO, a44: T::ONE,
 		}
 	}
+	/// Reflection matrix across a plane.
+	///
+	/// Mirrors the scene for planar reflections, eg. render the reflection in a mirror or water surface with `view * Mat4::reflect_plane(plane)`.
+	/// The plane normal must be normalized.
+	///
+	/// The matrix flips the winding order of triangles, swap the front face culling when rendering the reflected scene.
+	///
+	/// ```
+	/// use cvmath::{Mat4, Plane, Point3, Vec3};
+	///
+	/// let plane = Plane(Vec3(0.0, 0.0, 1.0), -2.0);
+	/// let mirror = Mat4::reflect_plane(plane);
+	/// assert_eq!(mirror.transform_point3(Point3(1.0, 2.0, 5.0)), Point3(1.0, 2.0, -1.0));
+	/// assert_eq!(mirror.transform_point3(Point3(1.0, 2.0, 5.0)), plane.reflect_pt(Point3(1.0, 2.0, 5.0)));
+	/// ```
+	#[inline]
+	pub fn reflect_plane(plane: Plane<T>) -> Mat4<T> {
+		let Vec3 { x, y, z } = plane.normal;
+		let d = plane.distance;
+		let two = T::ONE + T::ONE;
+		let (x2, y2, z2) = (x * two, y * two, z * two);
+		Mat4 {
+			a11: T::ONE - x2 * x, a12: -x2 * y, a13: -x2 * z, a14: -x2 * d,
+			a21: -y2 * x, a22: T::ONE - y2 * y, a23: -y2 * z, a24: -y2 * d,
+			a31: -z2 * x, a32: -z2 * y, a33: T::ONE - z2 * z, a34: -z2 * d,
+			a41: T::ZERO, a42: T::ZERO, a43: T::ZERO, a44: T::ONE,
+		}
+	}
+	/// Projected shadow matrix.
+	///
+	/// Flattens geometry onto a plane as seen from a light, the classic planar shadow.
+	/// The light is a homogeneous position, `w = 1` for a point light at that position or `w = 0` for a directional light where `xyz` is the direction towards the light.
+	/// The plane does not need to be normalized.
+	///
+	/// The result is a projective matrix, the transformed points have `w ≠ 1` and must be [homogeneous divided](Vec4::hdiv) which the GPU does after the vertex shader.
+	/// The light should be on the positive side of the plane and the geometry between the light and the plane, otherwise the transformed `w` becomes negative and the points are clipped.
+	///
+	/// ```
+	/// use cvmath::{Mat4, Plane, Point3, Vec3, Vec4};
+	///
+	/// // Ground plane and a point light above it
+	/// let ground = Plane(Vec3(0.0, 1.0, 0.0), 0.0);
+	/// let shadow = Mat4::shadow(ground, Vec4(0.0, 10.0, 0.0, 1.0));
+	/// assert_eq!(shadow.transform_point3(Point3(1.0, 5.0, 2.0)), Point3(2.0, 0.0, 4.0));
+	///
+	/// // Sunlight shining straight down
+	/// let shadow = Mat4::shadow(ground, Vec4(0.0, 1.0, 0.0, 0.0));
+	/// assert_eq!(shadow.transform_point3(Point3(1.0, 5.0, 2.0)), Point3(1.0, 0.0, 2.0));
+	/// ```
+	#[inline]
+	pub fn shadow(plane: Plane<T>, light: Vec4<T>) -> Mat4<T> {
+		let Vec3 { x: a, y: b, z: c } = plane.normal;
+		let d = plane.distance;
+		let Vec4 { x, y, z, w } = light;
+		let dot = a * x + b * y + c * z + d * w;
+		Mat4 {
+			a11: dot - x * a, a12: -x * b, a13: -x * c, a14: -x * d,
+			a21: -y * a, a22: dot - y * b, a23: -y * c, a24: -y * d,
+			a31: -z * a, a32: -z * b, a33: dot - z * c, a34: -z * d,
+			a41: -w * a, a42: -w * b, a43: -w * c, a44: dot - w * d,
+		}
+	}
 }
 impl<T: Zero + One> From<Transform3<T>> for Mat4<T> {
 	#[inline]
@@ -241,6 +307,49 @@ impl<T: Float> Mat4<T> {
 		Mat4::frustum(left, right, bottom, top, near, far, flags)
 	}
 
+	/// Off-center perspective matrix.
+	///
+	/// Shifts the view window of [`perspective`](Self::perspective) sideways without rotating the camera, as used by stereo and portal rendering.
+	/// The lens shift is a fraction of the view window size, a shift of `(0.5, 0.0)` moves the window right by half its width so the view direction aligns with its left edge.
+	/// A zero lens shift equals [`perspective`](Self::perspective).
+	///
+	/// ```
+	/// use cvmath::{Deg, Mat4, Vec2, Vec3, Vec4, RH, NO};
+	///
+	/// let centered = Mat4::perspective(Deg(90.0), 1.0, 0.1, 100.0, (RH, NO));
+	/// let shifted = Mat4::perspective_offcenter(Deg(90.0), 1.0, 0.1, 100.0, Vec2(0.5, 0.0), (RH, NO));
+	/// assert_eq!(Mat4::perspective_offcenter(Deg(90.0), 1.0, 0.1, 100.0, Vec2(0.0, 0.0), (RH, NO)), centered);
+	///
+	/// // Points straight ahead project onto the left edge of the screen
+	/// let ndc = (shifted * Vec4::from_point3(Vec3(0.0, 0.0, -10.0))).hdiv();
+	/// assert!((ndc.x + 1.0f64).abs() < 1e-12);
+	/// ```
+	#[inline]
+	pub fn perspective_offcenter(fovy: impl Angle<T = T>, aspect: T, near: T, far: T, lens_shift: Vec2<T>, flags: (Hand, Clip)) -> Mat4<T> {
+		debug_assert!(aspect > T::ZERO);
+		debug_assert!(T::ZERO < near && near < far);
+
+		let two = T::ONE + T::ONE;
+		let h = two * near * (fovy / (T::ONE + T::ONE)).tan();
+		let w = aspect * h;
+
+		let dx = lens_shift.x * w;
+		let dy = lens_shift.y * h;
+		let left = -w / two + dx;
+		let right = w / two + dx;
+		let bottom = -h / two + dy;
+		let top = h / two + dy;
+		Mat4::frustum(left, right, bottom, top, near, far, flags)
+	}
+
+	/// Perspective matrix from the horizontal field of view.
+	///
+	/// See [`fov_y_from_fov_x`](crate::fov_y_from_fov_x).
+	#[inline]
+	pub fn perspective_fov_x(fovx: impl Angle<T = T>, aspect: T, near: T, far: T, flags: (Hand, Clip)) -> Mat4<T> {
+		Mat4::perspective(fov_y_from_fov_x(fovx.to_rad(), aspect), aspect, near, far, flags)
+	}
+
 	/// Perspective FOV matrix.
 	#[inline]
 	pub fn perspective_fov(fovy: impl Angle<T = T>, width: T, height: T, near: T, far: T, flags: (Hand, Clip)) -> Mat4<T> {
@@ -302,6 +411,104 @@ impl<T> Mat4<T> {
 			[self.a14, self.a24, self.a34, self.a44],
 		]
 	}
+	/// Imports the matrix from a flat column-major array.
+	///
+	/// ```
+	/// use cvmath::Mat4;
+	///
+	/// let mat = Mat4::from_cols_array([1, 5, 9, 13, 2, 6, 10, 14, 3, 7, 11, 15, 4, 8, 12, 16]);
+	/// assert_eq!(mat, Mat4::from_row_major([[1, 2, 3, 4], [5, 6, 7, 8], [9, 10, 11, 12], [13, 14, 15, 16]]));
+	/// ```
+	#[inline]
+	pub fn from_cols_array(cols: [T; 16]) -> Mat4<T> {
+		let [a11, a21, a31, a41, a12, a22, a32, a42, a13, a23, a33, a43, a14, a24, a34, a44] = cols;
+		Mat4 {
+			a11, a12, a13, a14,
+			a21, a22, a23, a24,
+			a31, a32, a33, a34,
+			a41, a42, a43, a44,
+		}
+	}
+	/// Exports the matrix as a flat column-major array.
+	///
+	/// This is the layout expected by OpenGL, Vulkan and wgpu when uploading a `mat4` uniform.
+	///
+	/// ```
+	/// use cvmath::Mat4;
+	///
+	/// let mat = Mat4::from_row_major([[1, 2, 3, 4], [5, 6, 7, 8], [9, 10, 11, 12], [13, 14, 15, 16]]);
+	/// assert_eq!(mat.to_cols_array(), [1, 5, 9, 13, 2, 6, 10, 14, 3, 7, 11, 15, 4, 8, 12, 16]);
+	///
+	/// let cols = mat.into_column_major();
+	/// assert_eq!(mat.to_cols_array()[..], cols.concat()[..]);
+	/// ```
+	#[inline]
+	pub fn to_cols_array(self) -> [T; 16] {
+		[
+			self.a11, self.a21, self.a31, self.a41,
+			self.a12, self.a22, self.a32, self.a42,
+			self.a13, self.a23, self.a33, self.a43,
+			self.a14, self.a24, self.a34, self.a44,
+		]
+	}
+}
+
+/// The array is in memory order, which is row-major.
+///
+/// Use [`to_cols_array`](Mat4::to_cols_array) when a column-major layout is required.
+impl<T> AsRef<[T; 16]> for Mat4<T> {
+	#[inline]
+	fn as_ref(&self) -> &[T; 16] {
+		unsafe { mem::transmute(self) }
+	}
+}
+impl<T> AsRef<[T]> for Mat4<T> {
+	#[inline]
+	fn as_ref(&self) -> &[T] {
+		<Self as AsRef<[T; 16]>>::as_ref(self)
+	}
+}
+impl<T> AsMut<[T; 16]> for Mat4<T> {
+	#[inline]
+	fn as_mut(&mut self) -> &mut [T; 16] {
+		unsafe { mem::transmute(self) }
+	}
+}
+impl<T> AsMut<[T]> for Mat4<T> {
+	#[inline]
+	fn as_mut(&mut self) -> &mut [T] {
+		<Self as AsMut<[T; 16]>>::as_mut(self)
+	}
+}
+
+// The mint matrices store the columns, transpose between the row-major fields and the column vectors
+#[cfg(feature = "mint")]
+impl<T> From<mint::ColumnMatrix4<T>> for Mat4<T> {
+	#[inline]
+	fn from(mat: mint::ColumnMatrix4<T>) -> Mat4<T> {
+		Mat4 {
+			a11: mat.x.x, a12: mat.y.x, a13: mat.z.x, a14: mat.w.x,
+			a21: mat.x.y, a22: mat.y.y, a23: mat.z.y, a24: mat.w.y,
+			a31: mat.x.z, a32: mat.y.z, a33: mat.z.z, a34: mat.w.z,
+			a41: mat.x.w, a42: mat.y.w, a43: mat.z.w, a44: mat.w.w,
+		}
+	}
+}
+#[cfg(feature = "mint")]
+impl<T> From<Mat4<T>> for mint::ColumnMatrix4<T> {
+	#[inline]
+	fn from(mat: Mat4<T>) -> mint::ColumnMatrix4<T> {
+		mint::ColumnMatrix4 {
+			x: mint::Vector4 { x: mat.a11, y: mat.a21, z: mat.a31, w: mat.a41 },
+			y: mint::Vector4 { x: mat.a12, y: mat.a22, z: mat.a32, w: mat.a42 },
+			z: mint::Vector4 { x: mat.a13, y: mat.a23, z: mat.a33, w: mat.a43 },
+			w: mint::Vector4 { x: mat.a14, y: mat.a24, z: mat.a34, w: mat.a44 },
+		}
+	}
+}
+#[cfg(feature = "mint")]
+impl<T> mint::IntoMint for Mat4<T> {
+	type MintType = mint::ColumnMatrix4<T>;
 }
 
 //----------------------------------------------------------------
@@ -340,6 +547,54 @@ impl<T> Mat4<T> {
 	}
 }
 
+impl<T: Float> Mat4<T> {
+	/// Composes the affine matrix from translation, rotation and scale.
+	///
+	/// Points are scaled first, then rotated and finally translated.
+	///
+	/// ```
+	/// use cvmath::{Mat4, Quaternion, Vec3, Vec4};
+	///
+	/// let rotation = Quaternion(0.5, 0.5, 0.5, 0.5);
+	/// let mat = Mat4::compose_trs(Vec3(1.0, 2.0, 3.0), rotation, Vec3(2.0, 2.0, 2.0));
+	/// assert_eq!(mat * Vec4(1.0, 0.0, 0.0, 1.0), Vec4(1.0, 4.0, 3.0, 1.0));
+	/// ```
+	#[inline]
+	pub fn compose_trs(translation: Vec3<T>, rotation: Quaternion<T>, scale: Vec3<T>) -> Mat4<T> {
+		Transform3::compose_trs(translation, rotation, scale).into()
+	}
+	/// Decomposes the affine matrix into translation, rotation and scale.
+	///
+	/// The inverse of [compose_trs](#method.compose_trs).
+	/// A mirroring matrix (negative determinant) is represented by negating the X scale.
+	/// The rotation is undefined if any scale is zero.
+	///
+	/// ```
+	/// use cvmath::{Mat4, Quaternion, Vec3};
+	///
+	/// let mat = Mat4::compose_trs(Vec3(1.0f64, 2.0, 3.0), Quaternion(0.5, 0.5, 0.5, 0.5), Vec3(2.0, -1.0, 4.0));
+	/// let (t, r, s) = mat.decompose();
+	/// assert_eq!(t, Vec3(1.0, 2.0, 3.0));
+	/// assert!(s.distance(Vec3(-2.0, 1.0, 4.0)) < 1e-12);
+	///
+	/// let diff = Mat4::compose_trs(t, r, s) - mat;
+	/// assert!(diff.into_row_major().iter().flatten().all(|x| x.abs() < 1e-12));
+	/// ```
+	#[inline]
+	pub fn decompose(self) -> (Vec3<T>, Quaternion<T>, Vec3<T>) {
+		let x = self.x().xyz();
+		let y = self.y().xyz();
+		let z = self.z().xyz();
+		let translation = self.w().xyz();
+		let mut scale = Vec3(x.len(), y.len(), z.len());
+		if x.dot(y.cross(z)) < T::ZERO {
+			scale.x = -scale.x;
+		}
+		let rotation = Mat3::compose(x / scale.x, y / scale.y, z / scale.z);
+		(translation, Quaternion::from_rotation_matrix(rotation), scale)
+	}
+}
+
 //----------------------------------------------------------------
 // Operations
 
@@ -358,6 +613,8 @@ impl<T: Scalar> Mat4<T> {
 		self.a11 + self.a22 + self.a33 + self.a44
 	}
 	/// Computes the inverse matrix.
+	///
+	/// For affine matrices such as view and model matrices prefer [inverse_affine](#method.inverse_affine) or [inverse_rigid](#method.inverse_rigid).
 	#[inline]
 	pub fn inverse(self) -> Mat4<T> {
 		let det = self.determinant();
@@ -368,6 +625,49 @@ impl<T: Scalar> Mat4<T> {
 			self
 		}
 	}
+	/// Computes the inverse of an affine matrix.
+	///
+	/// Assumes the last row is `(0, 0, 0, 1)` which is checked with a debug assertion.
+	/// Cheaper than the general [inverse](#method.inverse), the result matches it up to rounding errors.
+	///
+	/// ```
+	/// use cvmath::{Mat4, Vec3};
+	///
+	/// let mat = Mat4::translate(Vec3(1.0, 2.0, 3.0)) * Mat4::scale(Vec3(2.0, 4.0, 8.0));
+	/// assert_eq!(mat.inverse_affine(), mat.inverse());
+	/// ```
+	#[inline]
+	pub fn inverse_affine(self) -> Mat4<T> {
+		debug_assert!(self.a41 == T::ZERO && self.a42 == T::ZERO && self.a43 == T::ZERO && self.a44 == T::ONE, "matrix is not affine");
+		let transform = Transform3 {
+			a11: self.a11, a12: self.a12, a13: self.a13, a14: self.a14,
+			a21: self.a21, a22: self.a22, a23: self.a23, a24: self.a24,
+			a31: self.a31, a32: self.a32, a33: self.a33, a34: self.a34,
+		};
+		transform.inverse().into()
+	}
+	/// Computes the inverse of a rigid matrix.
+	///
+	/// Assumes the matrix is affine and its rotation part is orthonormal, which is checked with debug assertions.
+	/// The rotation part is transposed, this is the cheapest way to invert view matrices.
+	///
+	/// ```
+	/// use cvmath::{Mat4, Vec3, LH};
+	///
+	/// let view = Mat4::look_at(Vec3(1.0, 2.0, 3.0), Vec3(0.0, 0.0, 0.0), Vec3(0.0, 1.0, 0.0), LH);
+	/// let diff = view.inverse_rigid() * view - Mat4::IDENTITY;
+	/// assert!(diff.into_row_major().iter().flatten().all(|x: &f64| x.abs() < 1e-12));
+	/// ```
+	#[inline]
+	pub fn inverse_rigid(self) -> Mat4<T> where T: Float {
+		debug_assert!(self.a41 == T::ZERO && self.a42 == T::ZERO && self.a43 == T::ZERO && self.a44 == T::ONE, "matrix is not affine");
+		let transform = Transform3 {
+			a11: self.a11, a12: self.a12, a13: self.a13, a14: self.a14,
+			a21: self.a21, a22: self.a22, a23: self.a23, a24: self.a24,
+			a31: self.a31, a32: self.a32, a33: self.a33, a34: self.a34,
+		};
+		transform.inverse_rigid().into()
+	}
 	/// Returns the transposed matrix.
 	#[inline]
 	pub fn transpose(self) -> Mat4<T> {
@@ -383,28 +683,236 @@ impl<T: Scalar> Mat4<T> {
 	pub fn adjugate(self) -> Mat4<T> {
 		Mat4 {
 			a11: self.a22 * (self.a33 * self.a44 - self.a34 * self.a43) - self.a23 * (self.a32 * self.a44 - self.a34 * self.a42) + self.a24 * (self.a32 * self.a43 - self.a33 * self.a42),
-			a12: -(self.a21 * (self.a33 * self.a44 - self.a34 * self.a43) - self.a23 * (self.a31 * self.a44 - self.a34 * self.a41) + self.a24 * (self.a31 * self.a43 - self.a33 * self.a41)),
-			a13: self.a21 * (self.a32 * self.a44 - self.a34 * self.a42) - self.a22 * (self.a31 * self.a44 - self.a34 * self.a41) + self.a24 * (self.a31 * self.a42 - self.a32 * self.a41),
-			a14: -(self.a21 * (self.a32 * self.a43 - self.a33 * self.a42) - self.a22 * (self.a31 * self.a43 - self.a33 * self.a41) + self.a23 * (self.a31 * self.a42 - self.a32 * self.a41)),
-			a21: -(self.a12 * (self.a33 * self.a44 - self.a34 * self.a43) - self.a13 * (self.a32 * self.a44 - self.a34 * self.a42) + self.a14 * (self.a32 * self.a43 - self.a33 * self.a42)),
+			a12: -(self.a12 * (self.a33 * self.a44 - self.a34 * self.a43) - self.a13 * (self.a32 * self.a44 - self.a34 * self.a42) + self.a14 * (self.a32 * self.a43 - self.a33 * self.a42)),
+			a13: self.a12 * (self.a23 * self.a44 - self.a24 * self.a43) - self.a13 * (self.a22 * self.a44 - self.a24 * self.a42) + self.a14 * (self.a22 * self.a43 - self.a23 * self.a42),
+			a14: -(self.a12 * (self.a23 * self.a34 - self.a24 * self.a33) - self.a13 * (self.a22 * self.a34 - self.a24 * self.a32) + self.a14 * (self.a22 * self.a33 - self.a23 * self.a32)),
+			a21: -(self.a21 * (self.a33 * self.a44 - self.a34 * self.a43) - self.a23 * (self.a31 * self.a44 - self.a34 * self.a41) + self.a24 * (self.a31 * self.a43 - self.a33 * self.a41)),
 			a22: self.a11 * (self.a33 * self.a44 - self.a34 * self.a43) - self.a13 * (self.a31 * self.a44 - self.a34 * self.a41) + self.a14 * (self.a31 * self.a43 - self.a33 * self.a41),
-			a23: -(self.a11 * (self.a32 * self.a44 - self.a34 * self.a42) - self.a12 * (self.a31 * self.a44 - self.a34 * self.a41) + self.a14 * (self.a31 * self.a42 - self.a32 * self.a41)),
-			a24: self.a11 * (self.a32 * self.a43 - self.a33 * self.a42) - self.a12 * (self.a31 * self.a43 - self.a33 * self.a41) + self.a13 * (self.a31 * self.a42 - self.a32 * self.a41),
-			a31: self.a12 * (self.a23 * self.a44 - self.a24 * self.a43) - self.a13 * (self.a22 * self.a44 - self.a24 * self.a42) + self.a14 * (self.a22 * self.a43 - self.a23 * self.a42),
-			a32: -(self.a11 * (self.a23 * self.a44 - self.a24 * self.a43) - self.a13 * (self.a21 * self.a44 - self.a24 * self.a41) + self.a14 * (self.a21 * self.a43 - self.a23 * self.a41)),
+			a23: -(self.a11 * (self.a23 * self.a44 - self.a24 * self.a43) - self.a13 * (self.a21 * self.a44 - self.a24 * self.a41) + self.a14 * (self.a21 * self.a43 - self.a23 * self.a41)),
+			a24: self.a11 * (self.a23 * self.a34 - self.a24 * self.a33) - self.a13 * (self.a21 * self.a34 - self.a24 * self.a31) + self.a14 * (self.a21 * self.a33 - self.a23 * self.a31),
+			a31: self.a21 * (self.a32 * self.a44 - self.a34 * self.a42) - self.a22 * (self.a31 * self.a44 - self.a34 * self.a41) + self.a24 * (self.a31 * self.a42 - self.a32 * self.a41),
+			a32: -(self.a11 * (self.a32 * self.a44 - self.a34 * self.a42) - self.a12 * (self.a31 * self.a44 - self.a34 * self.a41) + self.a14 * (self.a31 * self.a42 - self.a32 * self.a41)),
 			a33: self.a11 * (self.a22 * self.a44 - self.a24 * self.a42) - self.a12 * (self.a21 * self.a44 - self.a24 * self.a41) + self.a14 * (self.a21 * self.a42 - self.a22 * self.a41),
-			a34: -(self.a11 * (self.a22 * self.a43 - self.a23 * self.a42) - self.a12 * (self.a21 * self.a43 - self.a23 * self.a41) + self.a13 * (self.a21 * self.a42 - self.a22 * self.a41)),
-			a41: -(self.a12 * (self.a23 * self.a34 - self.a24 * self.a33) - self.a13 * (self.a22 * self.a34 - self.a24 * self.a32) + self.a14 * (self.a22 * self.a33 - self.a23 * self.a32)),
-			a42: self.a11 * (self.a23 * self.a34 - self.a24 * self.a33) - self.a13 * (self.a21 * self.a34 - self.a24 * self.a31) + self.a14 * (self.a21 * self.a33 - self.a23 * self.a31),
-			a43: -(self.a11 * (self.a22 * self.a34 - self.a24 * self.a32) - self.a12 * (self.a21 * self.a34 - self.a24 * self.a31) + self.a14 * (self.a21 * self.a32 - self.a22 * self.a31)),
+			a34: -(self.a11 * (self.a22 * self.a34 - self.a24 * self.a32) - self.a12 * (self.a21 * self.a34 - self.a24 * self.a31) + self.a14 * (self.a21 * self.a32 - self.a22 * self.a31)),
+			a41: -(self.a21 * (self.a32 * self.a43 - self.a33 * self.a42) - self.a22 * (self.a31 * self.a43 - self.a33 * self.a41) + self.a23 * (self.a31 * self.a42 - self.a32 * self.a41)),
+			a42: self.a11 * (self.a32 * self.a43 - self.a33 * self.a42) - self.a12 * (self.a31 * self.a43 - self.a33 * self.a41) + self.a13 * (self.a31 * self.a42 - self.a32 * self.a41),
+			a43: -(self.a11 * (self.a22 * self.a43 - self.a23 * self.a42) - self.a12 * (self.a21 * self.a43 - self.a23 * self.a41) + self.a13 * (self.a21 * self.a42 - self.a22 * self.a41)),
 			a44: self.a11 * (self.a22 * self.a33 - self.a23 * self.a32) - self.a12 * (self.a21 * self.a33 - self.a23 * self.a31) + self.a13 * (self.a21 * self.a32 - self.a22 * self.a31),
 		}
 	}
+	/// Transforms a slice of vectors.
+	///
+	/// Writes `self * points[i]` to `out[i]`, panics if the slices differ in length.
+	/// To transform the vectors in place see [transform_slice_in_place](#method.transform_slice_in_place).
+	///
+	/// ```
+	/// use cvmath::{Mat4, Vec3, Vec4};
+	///
+	/// let mat = Mat4::translate(Vec3(1.0, 2.0, 3.0));
+	/// let points = [Vec4(0.0, 0.0, 0.0, 1.0), Vec4(1.0, 1.0, 1.0, 0.0)];
+	/// let mut out = [Vec4::ZERO; 2];
+	/// mat.transform_slice(&points, &mut out);
+	/// assert_eq!(out, [Vec4(1.0, 2.0, 3.0, 1.0), Vec4(1.0, 1.0, 1.0, 0.0)]);
+	/// ```
+	#[inline]
+	pub fn transform_slice(&self, points: &[Vec4<T>], out: &mut [Vec4<T>]) {
+		assert_eq!(points.len(), out.len());
+		for (out, &pt) in out.iter_mut().zip(points) {
+			*out = *self * pt;
+		}
+	}
+	/// Transforms a slice of vectors in place.
+	#[inline]
+	pub fn transform_slice_in_place(&self, points: &mut [Vec4<T>]) {
+		for pt in points {
+			*pt = *self * *pt;
+		}
+	}
+}
+
+impl<T: Float> Mat4<T> {
+	/// Transforms a point.
+	///
+	/// The point is extended with `w = 1` and the result is [homogeneous divided](Vec4::hdiv), the translation and projection are applied.
+	///
+	/// ```
+	/// use cvmath::{Deg, Mat4, Vec3, Vec4, LH, ZO};
+	///
+	/// let proj = Mat4::perspective(Deg(90.0), 1.0, 1.0, 100.0, (LH, ZO));
+	/// let pt = Vec3(1.0, 2.0, 4.0);
+	/// assert_eq!(proj.transform_point3(pt), (proj * Vec4::from_point3(pt)).hdiv());
+	/// ```
+	#[inline]
+	pub fn transform_point3(&self, pt: Point3<T>) -> Point3<T> {
+		(*self * Vec4::from_point3(pt)).hdiv()
+	}
+	/// Transforms a direction.
+	///
+	/// The direction is extended with `w = 0`, the translation is not applied and the result is not divided.
+	///
+	/// ```
+	/// use cvmath::{Mat4, Vec3};
+	///
+	/// let mat = Mat4::translate(Vec3(5.0, 0.0, 0.0)) * Mat4::scale(Vec3(2.0, 2.0, 2.0));
+	/// assert_eq!(mat.transform_vector3(Vec3(1.0, 2.0, 3.0)), Vec3(2.0, 4.0, 6.0));
+	/// ```
+	#[inline]
+	pub fn transform_vector3(&self, v: Vec3<T>) -> Vec3<T> {
+		(*self * Vec4::from_vec3(v)).xyz()
+	}
+	/// Unprojects a screen coordinate back to world space.
+	///
+	/// The matrix is the inverse of the view-projection matrix.
+	/// The screen x and y coordinates are pixels in the viewport with y pointing down, matching [screen](#method.screen).
+	/// The screen z coordinate is the normalized device depth, from `-1.0` (`Clip::NO`) or `0.0` (`Clip::ZO`) at the near plane to `1.0` at the far plane.
+	///
+	/// ```
+	/// use cvmath::{Deg, Mat4, Point2, Rect, Vec3, LH, ZO};
+	///
+	/// let viewport = Rect(Point2(0.0, 0.0), Point2(800.0, 600.0));
+	/// let proj = Mat4::perspective(Deg(90.0), 800.0 / 600.0, 1.0, 100.0, (LH, ZO));
+	/// let inv = proj.inverse();
+	/// // The center of the screen at the near plane
+	/// assert!(inv.unproject(Vec3(400.0, 300.0, 0.0), viewport).distance(Vec3(0.0, 0.0, 1.0)) < 1e-12);
+	/// ```
+	#[inline]
+	pub fn unproject(self, screen: Vec3<T>, viewport: Rect<T>) -> Vec3<T> {
+		let two = T::ONE + T::ONE;
+		let x = (screen.x - viewport.left()) / viewport.width() * two - T::ONE;
+		let y = T::ONE - (screen.y - viewport.top()) / viewport.height() * two;
+		self.transform_point3(Vec3(x, y, screen.z))
+	}
+	/// Ray through a pixel of the screen.
+	///
+	/// The matrix is the inverse of the view-projection matrix, see [unproject](#method.unproject).
+	/// The ray starts on the near plane and its direction is normalized.
+	///
+	/// ```
+	/// use cvmath::{Deg, Mat4, Point2, Rect, Vec3, LH, ZO};
+	///
+	/// let viewport = Rect(Point2(0.0, 0.0), Point2(800.0, 600.0));
+	/// let proj = Mat4::perspective(Deg(90.0), 800.0 / 600.0, 1.0, 100.0, (LH, ZO));
+	/// let ray = proj.inverse().screen_ray(Point2(400.0, 300.0), viewport, ZO);
+	/// assert!(ray.origin.distance(Vec3(0.0, 0.0, 1.0)) < 1e-12);
+	/// assert!(ray.direction.distance(Vec3(0.0, 0.0, 1.0)) < 1e-12);
+	/// ```
+	#[inline]
+	pub fn screen_ray(self, pixel: Point2<T>, viewport: Rect<T>, clip: Clip) -> Ray<T> {
+		let near_z = match clip { Clip::ZO => T::ZERO, Clip::NO => -T::ONE };
+		let near = self.unproject(pixel.vec3(near_z), viewport);
+		let far = self.unproject(pixel.vec3(T::ONE), viewport);
+		Ray { origin: near, direction: (far - near).normalize() }
+	}
+	/// Interpolates between projection matrices.
+	///
+	/// This is an element-wise linear interpolation.
+	/// For perspective matrices with the same `Hand` and `Clip` flags the result is a valid projection matrix, useful to animate the field of view or the clipping planes.
+	/// Do not use this to interpolate rotations, see [`Transform3::interpolate_rigid`](crate::Transform3::interpolate_rigid).
+	///
+	/// ```
+	/// use cvmath::{Deg, Mat4, RH, NO};
+	///
+	/// let a = Mat4::perspective(Deg(60.0), 1.5, 0.1, 100.0, (RH, NO));
+	/// let b = Mat4::perspective(Deg(90.0), 1.5, 0.1, 100.0, (RH, NO));
+	/// assert_eq!(a.interpolate_projection(b, 0.0), a);
+	/// assert_eq!(a.interpolate_projection(b, 1.0), b);
+	/// ```
+	#[inline]
+	pub fn interpolate_projection(self, rhs: Mat4<T>, t: T) -> Mat4<T> {
+		self * (T::ONE - t) + rhs * t
+	}
+	/// Projection matrix with the near plane replaced by a clip plane.
+	///
+	/// Implements Eric Lengyel's oblique near-plane clipping, used by planar reflections and portals to clip the geometry behind the mirror without user clip planes.
+	/// The third row of the projection matrix is modified such that the near plane coincides with the clip plane while the far plane is skewed as little as possible.
+	///
+	/// The clip plane `(a, b, c, d)` is expected in view space with the points satisfying `a x + b y + c z + d >= 0` visible.
+	/// The camera must lie on the invisible side of the plane (`d < 0`).
+	/// The `clip` flag must match the one used to create the projection matrix.
+	///
+	/// ```
+	/// use cvmath::{Deg, Mat4, Vec4, RH, NO};
+	///
+	/// let proj = Mat4::perspective(Deg(90.0), 1.0, 0.1, 100.0, (RH, NO));
+	/// // The regular near plane reproduces the projection matrix
+	/// let near = Vec4(0.0, 0.0, -1.0, -0.1);
+	/// let same = proj.with_oblique_near(near, NO);
+	/// assert!((same - proj).into_row_major().iter().flatten().all(|&x: &f64| x.abs() < 1e-9));
+	///
+	/// // Points on the tilted clip plane map to the near depth
+	/// let plane = Vec4(0.0, 1.0, -1.0, -2.0);
+	/// let oblique = proj.with_oblique_near(plane, NO);
+	/// let ndc = (oblique * Vec4(1.0, -1.0, -3.0, 1.0)).hdiv();
+	/// assert!((ndc.z + 1.0).abs() < 1e-9);
+	/// ```
+	#[inline]
+	pub fn with_oblique_near(self, clip_plane: Vec4<T>, clip: Clip) -> Mat4<T> {
+		let sign = |x: T| if x > T::ZERO { T::ONE } else if x < T::ZERO { -T::ONE } else { T::ZERO };
+		// The far corner of the view frustum opposite to the clip plane
+		let corner = self.inverse() * Vec4(sign(clip_plane.x), sign(clip_plane.y), T::ONE, T::ONE);
+		let scale = T::ONE / clip_plane.dot(corner);
+		let row = match clip {
+			Clip::ZO => clip_plane * scale,
+			Clip::NO => clip_plane * (scale + scale) - Vec4(self.a41, self.a42, self.a43, self.a44),
+		};
+		Mat4 { a31: row.x, a32: row.y, a33: row.z, a34: row.w, ..self }
+	}
+	/// Projection matrix jittered by a subpixel offset.
+	///
+	/// Shifts the projected image by the offset in pixels, eg. to cycle through a sample pattern for temporal anti-aliasing.
+	/// The offset follows the viewport convention of [screen](#method.screen) with the y axis pointing down, the viewport is the size in pixels.
+	/// The depth is not affected and a zero offset returns the matrix unchanged.
+	///
+	/// ```
+	/// use cvmath::{Deg, Mat4, Vec2, Vec3, RH, NO};
+	///
+	/// let proj = Mat4::perspective(Deg(60.0), 1.5, 0.1, 100.0, (RH, NO));
+	/// let viewport = Vec2(1200.0, 800.0);
+	/// assert_eq!(proj.with_jitter(Vec2(0.0, 0.0), viewport), proj);
+	///
+	/// // Half a pixel right and down in normalized device coordinates
+	/// let jittered = proj.with_jitter(Vec2(0.5, 0.5), viewport);
+	/// let pt = Vec3(1.0, 2.0, -10.0);
+	/// let offset = jittered.transform_point3(pt) - proj.transform_point3(pt);
+	/// assert!(offset.distance(Vec3(1.0 / 1200.0, -1.0 / 800.0, 0.0)) < 1e-12);
+	/// ```
+	#[inline]
+	pub fn with_jitter(self, offset: Vec2<T>, viewport: Vec2<T>) -> Mat4<T> {
+		let two = T::ONE + T::ONE;
+		let dx = two * offset.x / viewport.x;
+		let dy = -two * offset.y / viewport.y;
+		// Adds the offset to the clip coordinates scaled by w so it survives the perspective divide
+		Mat4 {
+			a11: self.a11 + dx * self.a41, a12: self.a12 + dx * self.a42, a13: self.a13 + dx * self.a43, a14: self.a14 + dx * self.a44,
+			a21: self.a21 + dy * self.a41, a22: self.a22 + dy * self.a42, a23: self.a23 + dy * self.a43, a24: self.a24 + dy * self.a44,
+			..self
+		}
+	}
+	/// Returns `true` if all the components are finite.
+	///
+	/// ```
+	/// use cvmath::Mat4;
+	///
+	/// assert!(Mat4::<f64>::IDENTITY.all_finite());
+	/// assert!(!(Mat4::<f64>::IDENTITY * f64::INFINITY).all_finite());
+	/// assert!((Mat4::<f64>::IDENTITY * f64::NAN).any_nan());
+	/// ```
+	#[inline]
+	pub fn all_finite(self) -> bool {
+		self.x().all_finite() && self.y().all_finite() && self.z().all_finite() && self.w().all_finite()
+	}
+	/// Returns `true` if any of the components are NaN.
+	#[inline]
+	pub fn any_nan(self) -> bool {
+		self.x().any_nan() || self.y().any_nan() || self.z().any_nan() || self.w().any_nan()
+	}
 }
 
 //----------------------------------------------------------------
 // Operators
 
+impl_elementwise_ops!(Mat4 { a11, a12, a13, a14, a21, a22, a23, a24, a31, a32, a33, a34, a41, a42, a43, a44 });
+
 impl<T: Copy + ops::Mul<Output = T>> ops::Mul<T> for Mat4<T> {
 	type Output = Mat4<T>;
 	#[inline]
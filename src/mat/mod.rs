@@ -4,6 +4,186 @@ Transformation matrices.
 
 use super::*;
 
+/// Implements the component-wise operators shared by all matrices.
+macro_rules! impl_elementwise_ops {
+	($mat:ident { $($field:ident),+ }) => {
+		impl<T: ops::Add<Output = T>> ops::Add<$mat<T>> for $mat<T> {
+			type Output = $mat<T>;
+			#[inline]
+			fn add(self, rhs: $mat<T>) -> $mat<T> {
+				$mat { $($field: self.$field + rhs.$field),+ }
+			}
+		}
+		impl<T: ops::Sub<Output = T>> ops::Sub<$mat<T>> for $mat<T> {
+			type Output = $mat<T>;
+			#[inline]
+			fn sub(self, rhs: $mat<T>) -> $mat<T> {
+				$mat { $($field: self.$field - rhs.$field),+ }
+			}
+		}
+		impl<T: ops::Neg<Output = T>> ops::Neg for $mat<T> {
+			type Output = $mat<T>;
+			#[inline]
+			fn neg(self) -> $mat<T> {
+				$mat { $($field: -self.$field),+ }
+			}
+		}
+		impl<T: Copy + ops::Div<Output = T>> ops::Div<T> for $mat<T> {
+			type Output = $mat<T>;
+			#[inline]
+			fn div(self, rhs: T) -> $mat<T> {
+				$mat { $($field: self.$field / rhs),+ }
+			}
+		}
+		impl<T: ops::AddAssign> ops::AddAssign<$mat<T>> for $mat<T> {
+			#[inline]
+			fn add_assign(&mut self, rhs: $mat<T>) {
+				$(self.$field += rhs.$field;)+
+			}
+		}
+		impl<T: ops::SubAssign> ops::SubAssign<$mat<T>> for $mat<T> {
+			#[inline]
+			fn sub_assign(&mut self, rhs: $mat<T>) {
+				$(self.$field -= rhs.$field;)+
+			}
+		}
+		impl<T: Copy + ops::DivAssign> ops::DivAssign<T> for $mat<T> {
+			#[inline]
+			fn div_assign(&mut self, rhs: T) {
+				$(self.$field /= rhs;)+
+			}
+		}
+		impl<T: Zero + ops::Add<Output = T>> iter::Sum<$mat<T>> for $mat<T> {
+			#[inline]
+			fn sum<I: Iterator<Item = $mat<T>>>(iter: I) -> $mat<T> {
+				iter.fold($mat::ZERO, |acc, mat| acc + mat)
+			}
+		}
+		impl<T: Copy + Zero + One + ops::Add<Output = T> + ops::Mul<Output = T>> iter::Product<$mat<T>> for $mat<T> {
+			#[inline]
+			fn product<I: Iterator<Item = $mat<T>>>(iter: I) -> $mat<T> {
+				iter.fold($mat::IDENTITY, |acc, mat| acc * mat)
+			}
+		}
+	};
+}
+
+/// Implements formatting and parsing of the matrix rows.
+///
+/// The Display output `[(a11,a12),(a21,a22)]` lists the rows like vectors and can be parsed back.
+/// The alternate flag `{:#}` prints one row per line with the columns aligned.
+macro_rules! impl_fmt_rows {
+	($mat:ident $row:ident $N:tt { $([$($field:ident),+]),+ }) => {
+		impl<T: fmt::Display> fmt::Display for $mat<T> {
+			fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+				crate::mat::fmt_rows(f, &[$(&[$(&self.$field),+][..]),+])
+			}
+		}
+		impl<T: FromStr> FromStr for $mat<T> {
+			type Err = crate::vec::ParseVecError<T::Err>;
+			fn from_str(s: &str) -> Result<$mat<T>, Self::Err> {
+				let mut rows = crate::mat::Rows::new(s)?;
+				$(let [$($field),+]: [T; $N] = rows.next()?.parse::<$row<T>>()?.into();)+
+				rows.finish()?;
+				Ok($mat { $($($field),+),+ })
+			}
+		}
+	};
+}
+
+struct FmtLen(usize);
+impl fmt::Write for FmtLen {
+	fn write_str(&mut self, s: &str) -> fmt::Result {
+		self.0 += s.chars().count();
+		Ok(())
+	}
+}
+
+fn fmt_elem<W: fmt::Write, T: fmt::Display>(w: &mut W, value: &T, precision: Option<usize>) -> fmt::Result {
+	match precision {
+		Some(precision) => write!(w, "{:.*}", precision, value),
+		None => write!(w, "{}", value),
+	}
+}
+
+fn fmt_rows<T: fmt::Display>(f: &mut fmt::Formatter, rows: &[&[&T]]) -> fmt::Result {
+	if !f.alternate() {
+		f.write_str("[")?;
+		for (i, row) in rows.iter().enumerate() {
+			if i != 0 {
+				f.write_str(",")?;
+			}
+			f.write_str("(")?;
+			for (j, value) in row.iter().enumerate() {
+				if j != 0 {
+					f.write_str(",")?;
+				}
+				value.fmt(f)?;
+			}
+			f.write_str(")")?;
+		}
+		return f.write_str("]");
+	}
+
+	// Right align the columns to the widest element or the requested width
+	let precision = f.precision();
+	let mut widths = [0usize; 4];
+	for row in rows {
+		for (width, value) in widths.iter_mut().zip(row.iter()) {
+			let mut len = FmtLen(0);
+			fmt_elem(&mut len, *value, precision)?;
+			*width = usize::max(*width, len.0);
+		}
+	}
+	let min_width = f.width().unwrap_or(0);
+	for (i, row) in rows.iter().enumerate() {
+		f.write_str(if i == 0 { "[(" } else { ",\n (" })?;
+		for (j, value) in row.iter().enumerate() {
+			if j != 0 {
+				f.write_str(", ")?;
+			}
+			let mut len = FmtLen(0);
+			fmt_elem(&mut len, *value, precision)?;
+			for _ in len.0..usize::max(widths[j], min_width) {
+				f.write_str(" ")?;
+			}
+			fmt_elem(f, *value, precision)?;
+		}
+		f.write_str(")")?;
+	}
+	f.write_str("]")
+}
+
+/// Splits the rows of a formatted matrix.
+struct Rows<'a> {
+	rest: &'a str,
+	first: bool,
+}
+impl<'a> Rows<'a> {
+	fn new<E>(s: &'a str) -> Result<Rows<'a>, crate::vec::ParseVecError<E>> {
+		let s = s.trim();
+		// Must be surrounded by brackets
+		let rest = s.strip_prefix('[').and_then(|s| s.strip_suffix(']')).ok_or(crate::vec::ParseVecError::SyntaxError)?;
+		Ok(Rows { rest, first: true })
+	}
+	fn next<E>(&mut self) -> Result<&'a str, crate::vec::ParseVecError<E>> {
+		let mut s = self.rest.trim_start();
+		if !self.first {
+			s = s.strip_prefix(',').ok_or(crate::vec::ParseVecError::DimMismatch)?.trim_start();
+		}
+		self.first = false;
+		if !s.starts_with('(') {
+			return Err(crate::vec::ParseVecError::SyntaxError);
+		}
+		let end = s.find(')').ok_or(crate::vec::ParseVecError::SyntaxError)?;
+		self.rest = &s[end + 1..];
+		Ok(&s[..end + 1])
+	}
+	fn finish<E>(self) -> Result<(), crate::vec::ParseVecError<E>> {
+		if self.rest.trim().is_empty() { Ok(()) } else { Err(crate::vec::ParseVecError::DimMismatch) }
+	}
+}
+
 mod mat2;
 mod mat3;
 mod mat4;
@@ -18,6 +198,12 @@ pub use self::mat4::Mat4;
 pub use self::transform2::Transform2;
 pub use self::transform3::Transform3;
 
+impl_fmt_rows!(Mat2 Vec2 2 { [a11, a12], [a21, a22] });
+impl_fmt_rows!(Mat3 Vec3 3 { [a11, a12, a13], [a21, a22, a23], [a31, a32, a33] });
+impl_fmt_rows!(Mat4 Vec4 4 { [a11, a12, a13, a14], [a21, a22, a23, a24], [a31, a32, a33, a34], [a41, a42, a43, a44] });
+impl_fmt_rows!(Transform2 Vec3 3 { [a11, a12, a13], [a21, a22, a23] });
+impl_fmt_rows!(Transform3 Vec4 4 { [a11, a12, a13, a14], [a21, a22, a23, a24], [a31, a32, a33, a34] });
+
 /// Handness of the coordinate system.
 #[derive(Copy, Clone, Debug, Eq, PartialEq)]
 pub enum Hand {
@@ -40,6 +226,19 @@ pub enum Clip {
 	NO,
 }
 
+/// How to fit a rectangle into another rectangle.
+///
+/// See [`Transform2::fit`].
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum FitPolicy {
+	/// Scales each axis independently to fill the destination, the aspect ratio is not preserved.
+	Stretch,
+	/// Uniform scale so the source fits inside the destination, centered with letterboxing.
+	Contain,
+	/// Uniform scale so the source covers the destination, centered and cropped.
+	Cover,
+}
+
 specialized_type!(Mat2, Mat2f, f32, a11, a12, a21, a22);
 specialized_type!(Mat3, Mat3f, f32, a11, a12, a13, a21, a22, a23, a31, a32, a33);
 specialized_type!(Mat4, Mat4f, f32, a11, a12, a13, a14, a21, a22, a23, a24, a31, a32, a33, a34, a41, a42, a43, a44);
@@ -34,6 +34,10 @@ pub const fn Transform2<T>(
 
 #[cfg(feature = "dataview")]
 unsafe impl<T: dataview::Pod> dataview::Pod for Transform2<T> {}
+#[cfg(feature = "bytemuck")]
+unsafe impl<T: bytemuck::Zeroable> bytemuck::Zeroable for Transform2<T> {}
+#[cfg(feature = "bytemuck")]
+unsafe impl<T: bytemuck::Pod> bytemuck::Pod for Transform2<T> {}
 
 //----------------------------------------------------------------
 // Constructors
@@ -128,6 +132,79 @@ impl<T: Scalar> Transform2<T> {
 			a21: T::ZERO, a22: scale.y, a23: to.mins.y - from.mins.y * scale.y,
 		}
 	}
+	/// Orthographic projection matrix.
+	///
+	/// Maps the bounds to normalized device coordinates in the range `[-1, 1]`.
+	/// The y axis is not flipped, the bottom edge (`mins.y`) maps to `-1`.
+	///
+	/// ```
+	/// use cvmath::{Point2, Rect, Transform2};
+	///
+	/// let ortho = Transform2::ortho(Rect(Point2(0.0, 0.0), Point2(800.0, 600.0)));
+	/// assert_eq!(ortho * Point2(0.0, 0.0), Point2(-1.0, -1.0));
+	/// assert_eq!(ortho * Point2(800.0, 600.0), Point2(1.0, 1.0));
+	/// assert_eq!(ortho * Point2(400.0, 300.0), Point2(0.0, 0.0));
+	/// ```
+	#[inline]
+	pub fn ortho(bounds: Rect<T>) -> Transform2<T> {
+		Transform2::remap(bounds, Rect(Point2(-T::ONE, -T::ONE), Point2(T::ONE, T::ONE)))
+	}
+	/// Fit matrix.
+	///
+	/// Maps the source rectangle into the destination rectangle according to the fit policy.
+	/// With [`FitPolicy::Contain`] and [`FitPolicy::Cover`] the aspect ratio is preserved and the source is centered in the destination.
+	///
+	/// ```
+	/// use cvmath::{FitPolicy, Point2, Rect, Transform2};
+	///
+	/// let src = Rect(Point2(0.0, 0.0), Point2(100.0, 50.0));
+	/// let dst = Rect(Point2(0.0, 0.0), Point2(200.0, 200.0));
+	///
+	/// let contain = Transform2::fit(src, dst, FitPolicy::Contain);
+	/// assert_eq!(contain * Point2(0.0, 0.0), Point2(0.0, 50.0));
+	/// assert_eq!(contain * Point2(100.0, 50.0), Point2(200.0, 150.0));
+	///
+	/// let cover = Transform2::fit(src, dst, FitPolicy::Cover);
+	/// assert_eq!(cover * Point2(0.0, 0.0), Point2(-100.0, 0.0));
+	/// assert_eq!(cover * Point2(100.0, 50.0), Point2(300.0, 200.0));
+	/// ```
+	#[inline]
+	pub fn fit(src: Rect<T>, dst: Rect<T>, policy: FitPolicy) -> Transform2<T> {
+		let scale = dst.size() / src.size();
+		let scale = match policy {
+			FitPolicy::Stretch => return Transform2::remap(src, dst),
+			FitPolicy::Contain => scale.x.min(scale.y),
+			FitPolicy::Cover => scale.x.max(scale.y),
+		};
+		let src_center = src.center();
+		let dst_center = dst.center();
+		Transform2 {
+			a11: scale, a12: T::ZERO, a13: dst_center.x - src_center.x * scale,
+			a21: T::ZERO, a22: scale, a23: dst_center.y - src_center.y * scale,
+		}
+	}
+	/// Screen coordinate matrix.
+	///
+	/// Maps normalized device coordinates in the range `[-1, 1]` with y pointing up to pixel coordinates in the screen with y pointing down, matching [`Mat4::screen`](crate::Mat4::screen).
+	/// The corners of the NDC square map to the outer edges of the screen, not to the centers of the corner pixels.
+	///
+	/// ```
+	/// use cvmath::{Point2, Rect, Transform2};
+	///
+	/// let screen = Transform2::screen(Rect(Point2(0.0, 0.0), Point2(800.0, 600.0)));
+	/// assert_eq!(screen * Point2(-1.0, 1.0), Point2(0.0, 0.0));
+	/// assert_eq!(screen * Point2(1.0, -1.0), Point2(800.0, 600.0));
+	/// assert_eq!(screen * Point2(0.0, 0.0), Point2(400.0, 300.0));
+	/// ```
+	#[inline]
+	pub fn screen(screen: Rect<T>) -> Transform2<T> {
+		let two = T::ONE + T::ONE;
+		let half = screen.size() / two;
+		Transform2 {
+			a11: half.x, a12: T::ZERO, a13: screen.mins.x + half.x,
+			a21: T::ZERO, a22: -half.y, a23: screen.mins.y + half.y,
+		}
+	}
 }
 
 //----------------------------------------------------------------
@@ -251,9 +328,102 @@ impl<T: Scalar> Transform2<T> {
 	}
 }
 
+impl<T: Float> Transform2<T> {
+	/// Returns `true` if all the components are finite.
+	///
+	/// ```
+	/// use cvmath::{Transform2, Vec2};
+	///
+	/// assert!(Transform2::translate(Vec2(1.0, 2.0)).all_finite());
+	/// assert!(!Transform2::translate(Vec2(f32::INFINITY, 2.0)).all_finite());
+	/// assert!(Transform2::translate(Vec2(f32::NAN, 2.0)).any_nan());
+	/// ```
+	#[inline]
+	pub fn all_finite(self) -> bool {
+		self.x().all_finite() && self.y().all_finite() && self.t().all_finite()
+	}
+	/// Returns `true` if any of the components are NaN.
+	#[inline]
+	pub fn any_nan(self) -> bool {
+		self.x().any_nan() || self.y().any_nan() || self.t().any_nan()
+	}
+	/// Similarity transform mapping a pair of points onto another pair.
+	///
+	/// The transform consists of a rotation, uniform scale and translation, the first source point maps to the first destination point and likewise for the second.
+	/// Returns none if the source points coincide.
+	///
+	/// ```
+	/// use cvmath::{Point2, Transform2};
+	///
+	/// let src = (Point2(0.0, 0.0), Point2(1.0, 0.0));
+	/// let dst = (Point2(1.0, 1.0), Point2(1.0, 3.0));
+	/// let transform = Transform2::from_point_pairs(src, dst).unwrap();
+	/// assert_eq!(transform * src.0, dst.0);
+	/// assert_eq!(transform * src.1, dst.1);
+	/// assert_eq!(transform * Point2(0.0, 1.0), Point2(-1.0, 1.0));
+	/// ```
+	#[inline]
+	pub fn from_point_pairs(src: (Point2<T>, Point2<T>), dst: (Point2<T>, Point2<T>)) -> Option<Transform2<T>> {
+		let s = src.1 - src.0;
+		let d = dst.1 - dst.0;
+		let len_sqr = s.len_sqr();
+		if len_sqr == T::ZERO {
+			return None;
+		}
+		Some(Transform2::similarity(s.dot(d) / len_sqr, s.cross(d) / len_sqr, src.0, dst.0))
+	}
+	/// Least squares similarity transform mapping the source points onto the destination points.
+	///
+	/// Finds the rotation, uniform scale and translation minimizing the sum of squared distances between the transformed source points and the destination points, reflections are not considered.
+	/// The translation maps the centroids onto each other, the scaled rotation is the closed form least squares solution treating the points relative to their centroids as complex numbers.
+	/// Returns none if there are no points or all the source points coincide.
+	///
+	/// Panics if the slices differ in length.
+	///
+	/// ```
+	/// use cvmath::{Deg, Point2, Transform2, Vec2};
+	///
+	/// let expected = Transform2::translate(Vec2(3.0f64, -1.0)) * Transform2::rotate(Deg(30.0)) * Transform2::scale(Vec2(2.0, 2.0));
+	/// let src = [Point2(0.0, 0.0), Point2(1.0, 0.0), Point2(0.0, 2.0), Point2(-1.0, 1.0)];
+	/// let dst = src.map(|pt| expected * pt);
+	///
+	/// let transform = Transform2::fit_similarity(&src, &dst).unwrap();
+	/// for (a, b) in transform.into_row_major().iter().flatten().zip(expected.into_row_major().iter().flatten()) {
+	///     assert!((a - b).abs() < 1e-12);
+	/// }
+	/// ```
+	pub fn fit_similarity(src: &[Point2<T>], dst: &[Point2<T>]) -> Option<Transform2<T>> {
+		assert_eq!(src.len(), dst.len());
+		let src_center = Point2::centroid(src)?;
+		let dst_center = Point2::centroid(dst)?;
+		let (mut dot, mut cross, mut len_sqr) = (T::ZERO, T::ZERO, T::ZERO);
+		for (&s, &d) in src.iter().zip(dst) {
+			let s = s - src_center;
+			let d = d - dst_center;
+			dot += s.dot(d);
+			cross += s.cross(d);
+			len_sqr += s.len_sqr();
+		}
+		if len_sqr == T::ZERO {
+			return None;
+		}
+		Some(Transform2::similarity(dot / len_sqr, cross / len_sqr, src_center, dst_center))
+	}
+	// Scaled rotation by the complex number `a + bi` mapping the source point onto the destination point
+	#[inline]
+	fn similarity(a: T, b: T, src: Point2<T>, dst: Point2<T>) -> Transform2<T> {
+		Transform2 {
+			a11: a, a12: -b, a13: dst.x - (a * src.x - b * src.y),
+			a21: b, a22: a, a23: dst.y - (b * src.x + a * src.y),
+		}
+	}
+}
+
 //----------------------------------------------------------------
 // Operators
 
+impl_elementwise_ops!(Transform2 { a11, a12, a13, a21, a22, a23 });
+
 impl<T: Copy + ops::Add<Output = T> + ops::Mul<Output = T>> ops::Mul<Vec2<T>> for Transform2<T> {
 	type Output = Vec2<T>;
 	#[inline]
@@ -37,6 +37,10 @@ pub const fn Transform3<T>(
 
 #[cfg(feature = "dataview")]
 unsafe impl<T: dataview::Pod> dataview::Pod for Transform3<T> {}
+#[cfg(feature = "bytemuck")]
+unsafe impl<T: bytemuck::Zeroable> bytemuck::Zeroable for Transform3<T> {}
+#[cfg(feature = "bytemuck")]
+unsafe impl<T: bytemuck::Pod> bytemuck::Pod for Transform3<T> {}
 
 //----------------------------------------------------------------
 // Constructors
@@ -100,6 +104,23 @@ impl<T: Scalar> Transform3<T> {
 	pub fn rotate(angle: impl Angle<T = T>, axis: Vec3<T>) -> Transform3<T> where T: Float {
 		Mat3::rotate(angle, axis).affine()
 	}
+	/// Composes the transform from translation, rotation and scale.
+	///
+	/// Points are scaled first, then rotated and finally translated.
+	/// Equivalent to `Transform3::translate(translation) * rotation * Transform3::scale(scale)`.
+	///
+	/// ```
+	/// use cvmath::{Quaternion, Transform3, Vec3};
+	///
+	/// let rotation = Quaternion(0.5, 0.5, 0.5, 0.5);
+	/// let transform = Transform3::compose_trs(Vec3(1.0, 2.0, 3.0), rotation, Vec3(2.0, 2.0, 2.0));
+	/// assert_eq!(transform * Vec3(1.0, 0.0, 0.0), Vec3(1.0, 4.0, 3.0));
+	/// ```
+	#[inline]
+	pub fn compose_trs(translation: Vec3<T>, rotation: Quaternion<T>, scale: Vec3<T>) -> Transform3<T> where T: Float {
+		let r = rotation.rotation_matrix();
+		Transform3::compose(r.x() * scale.x, r.y() * scale.y, r.z() * scale.z, translation)
+	}
 }
 
 //----------------------------------------------------------------
@@ -153,6 +174,53 @@ impl<T> Transform3<T> {
 			[self.a14, self.a24, self.a34],
 		]
 	}
+	/// Exports the matrix extended to 4x4 as a flat column-major array.
+	///
+	/// ```
+	/// use cvmath::Transform3;
+	///
+	/// let mat = Transform3::from_row_major([[1, 2, 3, 4], [5, 6, 7, 8], [9, 10, 11, 12]]);
+	/// assert_eq!(mat.to_mat4_cols_array(), [1, 5, 9, 0, 2, 6, 10, 0, 3, 7, 11, 0, 4, 8, 12, 1]);
+	/// assert_eq!(mat.to_mat4_cols_array(), mat.mat4().to_cols_array());
+	/// ```
+	#[inline]
+	pub fn to_mat4_cols_array(self) -> [T; 16] where T: Zero + One {
+		[
+			self.a11, self.a21, self.a31, T::ZERO,
+			self.a12, self.a22, self.a32, T::ZERO,
+			self.a13, self.a23, self.a33, T::ZERO,
+			self.a14, self.a24, self.a34, T::ONE,
+		]
+	}
+}
+
+// The mint columns are the basis vectors followed by the translation
+#[cfg(feature = "mint")]
+impl<T> From<mint::ColumnMatrix3x4<T>> for Transform3<T> {
+	#[inline]
+	fn from(mat: mint::ColumnMatrix3x4<T>) -> Transform3<T> {
+		Transform3 {
+			a11: mat.x.x, a12: mat.y.x, a13: mat.z.x, a14: mat.w.x,
+			a21: mat.x.y, a22: mat.y.y, a23: mat.z.y, a24: mat.w.y,
+			a31: mat.x.z, a32: mat.y.z, a33: mat.z.z, a34: mat.w.z,
+		}
+	}
+}
+#[cfg(feature = "mint")]
+impl<T> From<Transform3<T>> for mint::ColumnMatrix3x4<T> {
+	#[inline]
+	fn from(mat: Transform3<T>) -> mint::ColumnMatrix3x4<T> {
+		mint::ColumnMatrix3x4 {
+			x: mint::Vector3 { x: mat.a11, y: mat.a21, z: mat.a31 },
+			y: mint::Vector3 { x: mat.a12, y: mat.a22, z: mat.a32 },
+			z: mint::Vector3 { x: mat.a13, y: mat.a23, z: mat.a33 },
+			w: mint::Vector3 { x: mat.a14, y: mat.a24, z: mat.a34 },
+		}
+	}
+}
+#[cfg(feature = "mint")]
+impl<T> mint::IntoMint for Transform3<T> {
+	type MintType = mint::ColumnMatrix3x4<T>;
 }
 
 //----------------------------------------------------------------
@@ -246,11 +314,192 @@ impl<T: Scalar> Transform3<T> {
 			self
 		}
 	}
+	/// Computes the matrix to transform normals with.
+	///
+	/// This is the inverse-transpose of the rotation matrix, it keeps normals perpendicular to the surface under non-uniform scaling.
+	/// The transformed normals are not normalized.
+	///
+	/// Returns none if the matrix is singular.
+	///
+	/// ```
+	/// use cvmath::{Mat3, Transform3, Vec3};
+	///
+	/// let transform = Transform3::scale(Vec3(2.0, 4.0, -1.0)) * Transform3::translate(Vec3(1.0, 2.0, 3.0));
+	/// assert_eq!(transform.normal_matrix(), Some(Mat3::scale(Vec3(0.5, 0.25, -1.0))));
+	///
+	/// assert_eq!(Transform3::scale(Vec3(1.0, 0.0, 1.0)).normal_matrix(), None);
+	/// ```
+	#[inline]
+	pub fn normal_matrix(self) -> Option<Mat3<T>> {
+		self.mat3().normal_matrix()
+	}
+	/// Transforms a normal.
+	///
+	/// The normal is transformed by the [normal matrix](#method.normal_matrix) and normalized.
+	/// Returns the zero vector if the matrix is singular.
+	///
+	/// Computes the normal matrix on every call, when transforming many normals compute it once with [normal_matrix](#method.normal_matrix).
+	///
+	/// ```
+	/// use cvmath::{Transform3, Vec3};
+	///
+	/// // The surface x = y scaled along x, the normal tilts towards y
+	/// let transform = Transform3::scale(Vec3(2.0, 1.0, 1.0));
+	/// let normal = transform.transform_normal(Vec3(1.0, -1.0, 0.0));
+	/// let tangent = transform.mat3() * Vec3(1.0, 1.0, 0.0);
+	/// assert_eq!(normal.dot(tangent), 0.0);
+	/// assert_eq!(normal, Vec3(1.0, -2.0, 0.0).normalize());
+	/// ```
+	#[inline]
+	pub fn transform_normal(&self, n: Vec3<T>) -> Vec3<T> where T: Float {
+		match self.normal_matrix() {
+			Some(normal_matrix) => (normal_matrix * n).normalize(),
+			None => Vec3::ZERO,
+		}
+	}
+	/// Returns `true` if the transform mirrors space, flipping the winding order of triangles.
+	///
+	/// ```
+	/// use cvmath::{Transform3, Vec3};
+	///
+	/// assert!(!Transform3::scale(Vec3(1.0, 2.0, 3.0)).flips_winding());
+	/// assert!(Transform3::scale(Vec3(1.0, -2.0, 3.0)).flips_winding());
+	/// ```
+	#[inline]
+	pub fn flips_winding(self) -> bool {
+		self.determinant() < T::ZERO
+	}
+	/// Transforms a slice of points.
+	///
+	/// Writes `self * points[i]` to `out[i]`, panics if the slices differ in length.
+	/// The points are translated, to transform directions use the [rotation part](#method.mat3).
+	/// To transform the points in place see [transform_points_in_place](#method.transform_points_in_place).
+	///
+	/// ```
+	/// use cvmath::{Transform3, Vec3};
+	///
+	/// let transform = Transform3::translate(Vec3(1.0, 2.0, 3.0));
+	/// let points = [Vec3(0.0, 0.0, 0.0), Vec3(1.0, 1.0, 1.0)];
+	/// let mut out = [Vec3::ZERO; 2];
+	/// transform.transform_points(&points, &mut out);
+	/// assert_eq!(out, [Vec3(1.0, 2.0, 3.0), Vec3(2.0, 3.0, 4.0)]);
+	/// ```
+	#[inline]
+	pub fn transform_points(&self, points: &[Vec3<T>], out: &mut [Vec3<T>]) {
+		assert_eq!(points.len(), out.len());
+		for (out, &pt) in out.iter_mut().zip(points) {
+			*out = *self * pt;
+		}
+	}
+	/// Transforms a slice of points in place.
+	#[inline]
+	pub fn transform_points_in_place(&self, points: &mut [Vec3<T>]) {
+		for pt in points {
+			*pt = *self * *pt;
+		}
+	}
+}
+
+impl<T: Float> Transform3<T> {
+	/// Computes the inverse of a rigid transform.
+	///
+	/// Assumes the rotation part is orthonormal which is checked with a debug assertion.
+	/// The rotation is transposed and the translation is rotated back and negated.
+	/// For orthonormal rotations the result matches [inverse](#method.inverse) up to rounding errors.
+	///
+	/// ```
+	/// use cvmath::{Deg, Transform3, Vec3};
+	///
+	/// let transform = Transform3::translate(Vec3(1.0, 2.0, 3.0)) * Transform3::rotate(Deg(30.0), Vec3::Y);
+	/// let inverse = transform.inverse_rigid();
+	/// assert!((transform * inverse).x().distance(Vec3::X) < 1e-12);
+	/// assert!((transform * inverse).t().len() < 1e-12);
+	/// ```
+	#[inline]
+	pub fn inverse_rigid(self) -> Transform3<T> {
+		debug_assert!({
+			let (x, y, z) = (self.x(), self.y(), self.z());
+			let tolerance = T::cast_from(1e-3);
+			(x.dot(x) - T::ONE).abs() < tolerance && (y.dot(y) - T::ONE).abs() < tolerance && (z.dot(z) - T::ONE).abs() < tolerance &&
+			x.dot(y).abs() < tolerance && x.dot(z).abs() < tolerance && y.dot(z).abs() < tolerance
+		}, "rotation is not orthonormal");
+		Transform3 {
+			a11: self.a11, a12: self.a21, a13: self.a31,
+			a14: -(self.a11 * self.a14 + self.a21 * self.a24 + self.a31 * self.a34),
+			a21: self.a12, a22: self.a22, a23: self.a32,
+			a24: -(self.a12 * self.a14 + self.a22 * self.a24 + self.a32 * self.a34),
+			a31: self.a13, a32: self.a23, a33: self.a33,
+			a34: -(self.a13 * self.a14 + self.a23 * self.a24 + self.a33 * self.a34),
+		}
+	}
+	/// Transform of `self` relative to `other`.
+	///
+	/// Computes `other.inverse() * self` assuming `other` is a rigid transform, see [inverse_rigid](#method.inverse_rigid).
+	///
+	/// ```
+	/// use cvmath::{Deg, Transform3, Vec3};
+	///
+	/// let parent = Transform3::translate(Vec3(1.0, 2.0, 3.0)) * Transform3::rotate(Deg(90.0), Vec3::Z);
+	/// let child = parent * Transform3::translate(Vec3(4.0, 0.0, 0.0));
+	/// assert!((child.relative_to(parent).t() - Vec3(4.0, 0.0, 0.0)).len() < 1e-12);
+	/// ```
+	#[inline]
+	pub fn relative_to(self, other: Transform3<T>) -> Transform3<T> {
+		other.inverse_rigid() * self
+	}
+	/// Interpolates between rigid transforms.
+	///
+	/// The rotations are interpolated with [slerp](Quaternion::slerp) and the translations linearly.
+	/// Unlike element-wise interpolation the result remains a rigid transform without shearing.
+	/// Any scale or shear in the inputs is discarded, the rotations are extracted by orthonormalizing the basis vectors.
+	/// Inputs with reflections, ie. a negative determinant, are not supported.
+	///
+	/// ```
+	/// use cvmath::{Deg, Transform3, Vec3};
+	///
+	/// let a = Transform3::translate(Vec3(0.0, 0.0, 0.0));
+	/// let b = Transform3::translate(Vec3(2.0, 0.0, 0.0)) * Transform3::rotate(Deg(90.0), Vec3::Z);
+	/// let mid = a.interpolate_rigid(b, 0.5);
+	/// let expected = Transform3::translate(Vec3(1.0, 0.0, 0.0)) * Transform3::rotate(Deg(45.0), Vec3::Z);
+	/// assert!((mid - expected).into_row_major().iter().flatten().all(|&x: &f64| x.abs() < 1e-12));
+	/// ```
+	#[inline]
+	pub fn interpolate_rigid(self, rhs: Transform3<T>, t: T) -> Transform3<T> {
+		// Gram-Schmidt orthonormalization removes the scale and shear
+		let rotation = |m: Mat3<T>| {
+			let x = m.x().normalize();
+			let y = (m.y() - x * x.dot(m.y())).normalize();
+			Quaternion::from_rotation_matrix(Mat3::compose(x, y, x.cross(y)))
+		};
+		let rotation = rotation(self.mat3()).slerp(rotation(rhs.mat3()), t);
+		let translation = self.t().lerp(rhs.t(), t);
+		Transform3::compose_trs(translation, rotation, Vec3::ONE)
+	}
+	/// Returns `true` if all the components are finite.
+	///
+	/// ```
+	/// use cvmath::{Transform3, Vec3};
+	///
+	/// assert!(Transform3::translate(Vec3(1.0, 2.0, 3.0)).all_finite());
+	/// assert!(!Transform3::translate(Vec3(1.0, f32::INFINITY, 3.0)).all_finite());
+	/// assert!(Transform3::translate(Vec3(1.0, 2.0, f32::NAN)).any_nan());
+	/// ```
+	#[inline]
+	pub fn all_finite(self) -> bool {
+		self.x().all_finite() && self.y().all_finite() && self.z().all_finite() && self.t().all_finite()
+	}
+	/// Returns `true` if any of the components are NaN.
+	#[inline]
+	pub fn any_nan(self) -> bool {
+		self.x().any_nan() || self.y().any_nan() || self.z().any_nan() || self.t().any_nan()
+	}
 }
 
 //----------------------------------------------------------------
 // Operators
 
+impl_elementwise_ops!(Transform3 { a11, a12, a13, a14, a21, a22, a23, a24, a31, a32, a33, a34 });
+
 impl<T: Copy + ops::Add<Output = T> + ops::Mul<Output = T>> ops::Mul<Vec3<T>> for Transform3<T> {
 	type Output = Vec3<T>;
 	#[inline]
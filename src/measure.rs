@@ -0,0 +1,46 @@
+/*!
+Measuring the size of shapes.
+*/
+
+/// Shapes in 2D space with an area and perimeter.
+///
+/// ```
+/// use cvmath::{Line2, Measure2, Point2, Rect};
+///
+/// let rect = Rect(Point2(0.0, 0.0), Point2(3.0, 2.0));
+/// assert_eq!(rect.area(), 6.0);
+/// assert_eq!(rect.perimeter(), 10.0);
+///
+/// // Lines have no area, their perimeter is their length
+/// let line = Line2(Point2(0.0, 0.0), Point2(3.0, 4.0));
+/// assert_eq!(line.area(), 0.0);
+/// assert_eq!(line.perimeter(), 5.0);
+/// ```
+pub trait Measure2<T> {
+	/// Returns the area enclosed by the shape.
+	fn area(&self) -> T;
+
+	/// Returns the length of the boundary of the shape.
+	fn perimeter(&self) -> T;
+}
+
+/// Shapes in 3D space with a volume and surface area.
+///
+/// Unbounded shapes such as [`Plane`](crate::Plane) report infinite measures.
+///
+/// ```
+/// use cvmath::{Measure3, Plane, Point3, Sphere, Vec3};
+///
+/// let sphere = Sphere(Point3(1.0, 2.0, 3.0), 2.0);
+/// assert_eq!(sphere.surface_area(), 16.0 * std::f64::consts::PI);
+///
+/// let plane = Plane(Vec3(0.0, 0.0, 1.0), 0.0);
+/// assert_eq!(plane.volume(), f64::INFINITY);
+/// ```
+pub trait Measure3<T> {
+	/// Returns the volume enclosed by the shape.
+	fn volume(&self) -> T;
+
+	/// Returns the area of the surface of the shape.
+	fn surface_area(&self) -> T;
+}
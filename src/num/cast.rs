@@ -26,6 +26,7 @@ macro_rules! impl_as_cast {
 		impl CastFrom<u16> for $ty { #[inline] fn cast_from(from: u16) -> $ty { from as $ty } }
 		impl CastFrom<u32> for $ty { #[inline] fn cast_from(from: u32) -> $ty { from as $ty } }
 		impl CastFrom<u64> for $ty { #[inline] fn cast_from(from: u64) -> $ty { from as $ty } }
+		impl CastFrom<usize> for $ty { #[inline] fn cast_from(from: usize) -> $ty { from as $ty } }
 
 		impl CastFrom<i8> for $ty { #[inline] fn cast_from(from: i8) -> $ty { from as $ty } }
 		impl CastFrom<i16> for $ty { #[inline] fn cast_from(from: i16) -> $ty { from as $ty } }
@@ -41,6 +42,7 @@ impl_as_cast!(u8);
 impl_as_cast!(u16);
 impl_as_cast!(u32);
 impl_as_cast!(u64);
+impl_as_cast!(usize);
 impl_as_cast!(i8);
 impl_as_cast!(i16);
 impl_as_cast!(i32);
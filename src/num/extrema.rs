@@ -1,4 +1,4 @@
-use std::cmp;
+use core::cmp;
 
 /// Calculate mins and maxs.
 pub trait Extrema<Rhs = Self>: Sized {
@@ -8,7 +8,7 @@ pub trait Extrema<Rhs = Self>: Sized {
 
 	#[inline]
 	fn clamp(self, min: Rhs, max: Rhs) -> Self {
-		self.min(min).max(max)
+		self.max(min).min(max)
 	}
 }
 
@@ -2,8 +2,12 @@
 pub trait FloatOps: Copy {
 	fn is_finite(self) -> bool;
 	fn is_infinite(self) -> bool;
+	fn is_nan(self) -> bool;
 	fn sqrt(self) -> Self;
+	/// Approximate reciprocal square root with a relative error below 0.2%.
+	fn rsqrt_fast(self) -> Self;
 	fn exp(self) -> Self;
+	fn powf(self, n: Self) -> Self;
 	fn floor(self) -> Self;
 	fn ceil(self) -> Self;
 	fn round(self) -> Self;
@@ -26,8 +30,19 @@ pub trait FloatOps: Copy {
 //----------------------------------------------------------------
 // Implementation
 
+// Uses the inherent float methods with std, the libm functions otherwise
+macro_rules! math {
+	($std:expr, $libm:expr) => {{
+		#[cfg(feature = "std")]
+		let value = $std;
+		#[cfg(not(feature = "std"))]
+		let value = $libm;
+		value
+	}};
+}
+
 macro_rules! impl_float_ops {
-	($ty:ty) => {
+	($ty:ty, $magic:expr, libm { $sqrt:ident $exp:ident $pow:ident $floor:ident $ceil:ident $round:ident $trunc:ident $sin:ident $cos:ident $tan:ident $sincos:ident $asin:ident $acos:ident $atan:ident $atan2:ident }) => {
 		impl FloatOps for $ty {
 			#[inline]
 			fn is_finite(self) -> bool {
@@ -38,70 +53,84 @@ macro_rules! impl_float_ops {
 				self.is_infinite()
 			}
 			#[inline]
+			fn is_nan(self) -> bool {
+				self.is_nan()
+			}
+			#[inline]
 			fn sqrt(self) -> $ty {
-				self.sqrt()
+				math!(self.sqrt(), libm::$sqrt(self))
+			}
+			#[inline]
+			fn rsqrt_fast(self) -> $ty {
+				// Initial guess from the bit pattern refined by one Newton-Raphson iteration
+				let y = <$ty>::from_bits($magic - (self.to_bits() >> 1));
+				y * (1.5 - 0.5 * self * y * y)
 			}
 			#[inline]
 			fn exp(self) -> $ty {
-				self.exp()
+				math!(self.exp(), libm::$exp(self))
+			}
+			#[inline]
+			fn powf(self, n: $ty) -> $ty {
+				math!(self.powf(n), libm::$pow(self, n))
 			}
 			#[inline]
 			fn floor(self) -> $ty {
-				self.floor()
+				math!(self.floor(), libm::$floor(self))
 			}
 			#[inline]
 			fn ceil(self) -> $ty {
-				self.ceil()
+				math!(self.ceil(), libm::$ceil(self))
 			}
 			#[inline]
 			fn round(self) -> $ty {
-				self.round()
+				math!(self.round(), libm::$round(self))
 			}
 			#[inline]
 			fn fract(self) -> $ty {
-				self.fract()
+				math!(self.fract(), self - libm::$trunc(self))
 			}
 			#[inline]
 			fn sin(self) -> $ty {
-				self.sin()
+				math!(self.sin(), libm::$sin(self))
 			}
 			#[inline]
 			fn cos(self) -> $ty {
-				self.cos()
+				math!(self.cos(), libm::$cos(self))
 			}
 			#[inline]
 			fn tan(self) -> $ty {
-				self.tan()
+				math!(self.tan(), libm::$tan(self))
 			}
 			#[inline]
 			fn sin_cos(self) -> ($ty, $ty) {
-				self.sin_cos()
+				math!(self.sin_cos(), libm::$sincos(self))
 			}
 			#[inline]
 			fn asin(self) -> $ty {
-				self.asin()
+				math!(self.asin(), libm::$asin(self))
 			}
 			#[inline]
 			fn acos(self) -> $ty {
-				self.acos()
+				math!(self.acos(), libm::$acos(self))
 			}
 			#[inline]
 			fn atan(self) -> $ty {
-				self.atan()
+				math!(self.atan(), libm::$atan(self))
 			}
 			#[inline]
 			fn atan2(self, x: $ty) -> $ty {
-				self.atan2(x)
+				math!(self.atan2(x), libm::$atan2(self, x))
 			}
 			#[inline]
 			fn is_close(self, other: Self) -> bool {
 				const ATOL: $ty = <$ty>::EPSILON; // Comparisons below 1.0
-				let rtol = 1.0 / <$ty>::powi(10.0, <$ty>::DIGITS as i32); // Comparisons above 1.0
+				let rtol = 1.0 / math!(<$ty>::powi(10.0, <$ty>::DIGITS as i32), libm::$pow(10.0, <$ty>::DIGITS as $ty)); // Comparisons above 1.0
 				(self - other).abs() <= <$ty>::max(ATOL, rtol * self.abs().max(other.abs()))
 			}
 		}
 	}
 }
 
-impl_float_ops!(f32);
-impl_float_ops!(f64);
+impl_float_ops!(f32, 0x5f375a86, libm { sqrtf expf powf floorf ceilf roundf truncf sinf cosf tanf sincosf asinf acosf atanf atan2f });
+impl_float_ops!(f64, 0x5fe6eb50c7b537a9, libm { sqrt exp pow floor ceil round trunc sin cos tan sincos asin acos atan atan2 });
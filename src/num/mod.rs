@@ -2,7 +2,7 @@
 Numeric traits.
 */
 
-use std::{cmp, fmt, ops};
+use core::{cmp, fmt, ops};
 
 mod zero;
 mod one;
@@ -36,7 +36,8 @@ pub trait Scalar where Self
 #[allow(dead_code)]
 pub trait Int where Self : Scalar + cmp::Eq + cmp::Ord {}
 
-pub trait Float where Self : Scalar + FloatOps + CastFrom<f64> {
+// Converting to f64 lets precision sensitive code fall back to double precision, eg. watertight triangle tracing
+pub trait Float where Self : Scalar + FloatOps + CastFrom<f64> + CastTo<f64> {
 	const INFINITY: Self;
 	const NEG_INFINITY: Self;
 	const EPSILON: Self;
@@ -73,13 +74,19 @@ impl Scalar for i64 {
 impl Scalar for f32 {
 	#[inline]
 	fn mul_add(self, a: f32, b: f32) -> f32 {
-		self.mul_add(a, b)
+		#[cfg(feature = "std")]
+		return self.mul_add(a, b);
+		#[cfg(not(feature = "std"))]
+		return libm::fmaf(self, a, b);
 	}
 }
 impl Scalar for f64 {
 	#[inline]
 	fn mul_add(self, a: f64, b: f64) -> f64 {
-		self.mul_add(a, b)
+		#[cfg(feature = "std")]
+		return self.mul_add(a, b);
+		#[cfg(not(feature = "std"))]
+		return libm::fma(self, a, b);
 	}
 }
 
@@ -1,4 +1,4 @@
-use std::ops;
+use core::ops;
 
 pub trait One where Self: Sized + ops::Mul<Output = Self> {
 	const ONE: Self;
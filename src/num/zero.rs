@@ -1,4 +1,4 @@
-use std::ops;
+use core::ops;
 
 pub trait Zero where Self: Sized + ops::Add<Output = Self> + ops::Mul<Output = Self> {
 	const ZERO: Self;
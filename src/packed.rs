@@ -1,5 +1,5 @@
 /*!
-Packs and unpacks unsigned integers.
+Packs and unpacks unsigned integers and half floats.
 */
 
 use super::*;
@@ -187,3 +187,247 @@ impl Vec4<u8> {
 		(self.w as u32) << 24 | (self.z as u32) << 16 | (self.y as u32) << 8 | (self.x as u32)
 	}
 }
+
+//----------------------------------------------------------------
+// Half floats
+
+/// Vec3 with IEEE 754 half float components.
+///
+/// The components are stored as their `u16` bit patterns, convert with [from_f32](Vec3h::from_f32) and [to_f32](Vec3h::to_f32).
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[repr(transparent)]
+pub struct Vec3h(pub Vec3<u16>);
+
+/// Vec4 with IEEE 754 half float components.
+///
+/// The components are stored as their `u16` bit patterns, convert with [from_f32](Vec4h::from_f32) and [to_f32](Vec4h::to_f32).
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[repr(transparent)]
+pub struct Vec4h(pub Vec4<u16>);
+
+#[cfg(feature = "dataview")]
+unsafe impl dataview::Pod for Vec3h {}
+#[cfg(feature = "dataview")]
+unsafe impl dataview::Pod for Vec4h {}
+#[cfg(feature = "bytemuck")]
+unsafe impl bytemuck::Zeroable for Vec3h {}
+#[cfg(feature = "bytemuck")]
+unsafe impl bytemuck::Pod for Vec3h {}
+#[cfg(feature = "bytemuck")]
+unsafe impl bytemuck::Zeroable for Vec4h {}
+#[cfg(feature = "bytemuck")]
+unsafe impl bytemuck::Pod for Vec4h {}
+
+/// Converts `f32` to half float bits, rounding to nearest even.
+///
+/// Values too large for a half float become infinity, NaN stays NaN.
+fn f32_to_f16(v: f32) -> u16 {
+	let bits = v.to_bits();
+	let sign = ((bits >> 16) & 0x8000) as u16;
+	let exp = ((bits >> 23) & 0xFF) as i32;
+	let man = bits & 0x007FFFFF;
+
+	// Infinity and NaN, keep NaN quiet
+	if exp == 0xFF {
+		return sign | 0x7C00 | if man != 0 { 0x0200 | (man >> 13) as u16 } else { 0 };
+	}
+
+	let exp = exp - 127 + 15;
+	if exp >= 0x1F {
+		return sign | 0x7C00;
+	}
+
+	// Subnormals, everything below half the smallest subnormal rounds to zero
+	if exp <= 0 {
+		if exp < -10 {
+			return sign;
+		}
+		let man = man | 0x00800000;
+		let shift = (14 - exp) as u32;
+		let halfway = 1 << (shift - 1);
+		let rem = man & ((1 << shift) - 1);
+		let mut half = man >> shift;
+		if rem > halfway || rem == halfway && half & 1 != 0 {
+			half += 1;
+		}
+		return sign | half as u16;
+	}
+
+	// Rounding up may carry into the exponent, which is the correct result
+	let mut half = (exp as u32) << 10 | man >> 13;
+	let rem = man & 0x1FFF;
+	if rem > 0x1000 || rem == 0x1000 && half & 1 != 0 {
+		half += 1;
+	}
+	sign | half as u16
+}
+
+/// Converts half float bits to `f32`, this conversion is exact.
+fn f16_to_f32(v: u16) -> f32 {
+	let sign = ((v & 0x8000) as u32) << 16;
+	let exp = ((v >> 10) & 0x1F) as u32;
+	let man = (v & 0x03FF) as u32;
+
+	let bits = match exp {
+		0x1F => sign | 0x7F800000 | man << 13,
+		0 if man == 0 => sign,
+		// Subnormals are normal in f32
+		0 => {
+			let value = man as f32 * (1.0 / 16777216.0);
+			sign | value.to_bits()
+		},
+		_ => sign | (exp + 127 - 15) << 23 | man << 13,
+	};
+	f32::from_bits(bits)
+}
+
+impl Vec3h {
+	/// Converts to half floats.
+	///
+	/// ```
+	/// use cvmath::{Vec3, Vec3h};
+	///
+	/// let v = Vec3h::from_f32(Vec3(1.0, -2.0, 0.5));
+	/// assert_eq!(v, Vec3h(Vec3(0x3C00, 0xC000, 0x3800)));
+	/// assert_eq!(v.to_f32(), Vec3(1.0, -2.0, 0.5));
+	/// ```
+	#[inline]
+	pub fn from_f32(v: Vec3<f32>) -> Vec3h {
+		Vec3h(v.map(f32_to_f16))
+	}
+	/// Converts from half floats.
+	#[inline]
+	pub fn to_f32(self) -> Vec3<f32> {
+		self.0.map(f16_to_f32)
+	}
+}
+impl Vec4h {
+	/// Converts to half floats.
+	///
+	/// ```
+	/// use cvmath::{Vec4, Vec4h};
+	///
+	/// let v = Vec4h::from_f32(Vec4(1.0, -2.0, 0.5, f32::INFINITY));
+	/// assert_eq!(v, Vec4h(Vec4(0x3C00, 0xC000, 0x3800, 0x7C00)));
+	/// assert_eq!(v.to_f32(), Vec4(1.0, -2.0, 0.5, f32::INFINITY));
+	/// ```
+	#[inline]
+	pub fn from_f32(v: Vec4<f32>) -> Vec4h {
+		Vec4h(v.map(f32_to_f16))
+	}
+	/// Converts from half floats.
+	#[inline]
+	pub fn to_f32(self) -> Vec4<f32> {
+		self.0.map(f16_to_f32)
+	}
+}
+
+//----------------------------------------------------------------
+// Normalized integers
+
+impl<T: Float> Vec3<T> {
+	/// Packs components in the `[0, 1]` range into unsigned normalized bytes.
+	///
+	/// Components are clamped and rounded to the nearest byte, no gamma is applied.
+	///
+	/// ```
+	/// use cvmath::Vec3;
+	///
+	/// assert_eq!(Vec3(0.0, 0.5, 1.5).pack_unorm8(), [0, 128, 255]);
+	/// ```
+	#[inline]
+	pub fn pack_unorm8(self) -> [u8; 3] {
+		let scale = T::cast_from(255.0);
+		let v = self.map(|c| (c.clamp(T::ZERO, T::ONE) * scale).round().cast_to() as u8);
+		[v.x, v.y, v.z]
+	}
+	/// Unpacks unsigned normalized bytes into the `[0, 1]` range.
+	///
+	/// ```
+	/// use cvmath::Vec3;
+	///
+	/// assert_eq!(Vec3::unpack_unorm8([0, 51, 255]), Vec3(0.0, 0.2, 1.0));
+	/// ```
+	#[inline]
+	pub fn unpack_unorm8(v: [u8; 3]) -> Vec3<T> {
+		let scale = T::cast_from(255.0);
+		Vec3::from(v).map(|c| T::cast_from(c as f64) / scale)
+	}
+	/// Packs components in the `[-1, 1]` range into signed normalized bytes.
+	///
+	/// Components are clamped and rounded to the nearest byte, `-128` is never produced.
+	///
+	/// ```
+	/// use cvmath::Vec3;
+	///
+	/// assert_eq!(Vec3(-1.0, 0.0, 0.5).pack_snorm8(), [-127, 0, 64]);
+	/// ```
+	#[inline]
+	pub fn pack_snorm8(self) -> [i8; 3] {
+		let scale = T::cast_from(127.0);
+		let v = self.map(|c| (c.clamp(-T::ONE, T::ONE) * scale).round().cast_to() as i8);
+		[v.x, v.y, v.z]
+	}
+	/// Unpacks signed normalized bytes into the `[-1, 1]` range.
+	///
+	/// Both `-128` and `-127` unpack to `-1`.
+	///
+	/// ```
+	/// use cvmath::Vec3;
+	///
+	/// assert_eq!(Vec3::unpack_snorm8([-128, -127, 127]), Vec3(-1.0, -1.0, 1.0));
+	/// ```
+	#[inline]
+	pub fn unpack_snorm8(v: [i8; 3]) -> Vec3<T> {
+		let scale = T::cast_from(127.0);
+		Vec3::from(v).map(|c| T::max(T::cast_from(c as f64) / scale, -T::ONE))
+	}
+}
+
+//----------------------------------------------------------------
+// Octahedral encoding
+
+impl<T: Float> Vec3<T> {
+	/// Encodes a unit vector with octahedral mapping.
+	///
+	/// The unit sphere is projected onto an octahedron which is unfolded into the `[-1, 1]` square.
+	/// The lower hemisphere is folded over the diagonals, the -Z pole maps to the corners of the square.
+	///
+	/// ```
+	/// use cvmath::{Vec2, Vec3};
+	///
+	/// assert_eq!(Vec3(0.0, 0.0, 1.0).to_oct(), Vec2(0.0, 0.0));
+	/// assert_eq!(Vec3(1.0, 0.0, 0.0).to_oct(), Vec2(1.0, 0.0));
+	/// assert_eq!(Vec3(0.0, 0.0, -1.0).to_oct(), Vec2(1.0, 1.0));
+	/// ```
+	#[inline]
+	pub fn to_oct(self) -> Vec2<T> {
+		let p = Vec2(self.x, self.y) / (self.x.abs() + self.y.abs() + self.z.abs());
+		if self.z >= T::ZERO {
+			return p;
+		}
+		let sign = p.ge(Vec2::ZERO).select_scalar(T::ONE, -T::ONE);
+		(Vec2::dup(T::ONE) - Vec2(p.y, p.x).abs()) * sign
+	}
+	/// Decodes a unit vector from octahedral mapping.
+	///
+	/// See [to_oct](Vec3::to_oct) for the inverse operation.
+	///
+	/// ```
+	/// use cvmath::Vec3;
+	///
+	/// let v = Vec3(1.0, -2.0, -3.0).normalize();
+	/// assert!(Vec3::from_oct(v.to_oct()).distance(v) < 1e-12);
+	/// ```
+	#[inline]
+	pub fn from_oct(v: Vec2<T>) -> Vec3<T> {
+		let z = T::ONE - v.x.abs() - v.y.abs();
+		let xy = if z >= T::ZERO { v } else {
+			let sign = v.ge(Vec2::ZERO).select_scalar(T::ONE, -T::ONE);
+			(Vec2::dup(T::ONE) - Vec2(v.y, v.x).abs()) * sign
+		};
+		Vec3(xy.x, xy.y, z).normalize()
+	}
+}
@@ -20,6 +20,10 @@ pub fn Plane<T>(normal: Vec3<T>, distance: T) -> Plane<T> {
 
 #[cfg(feature = "dataview")]
 unsafe impl<T: dataview::Pod> dataview::Pod for Plane<T> {}
+#[cfg(feature = "bytemuck")]
+unsafe impl<T: bytemuck::Zeroable> bytemuck::Zeroable for Plane<T> {}
+#[cfg(feature = "bytemuck")]
+unsafe impl<T: bytemuck::Pod> bytemuck::Pod for Plane<T> {}
 
 impl<T> Plane<T> {
 	/// Constructs a new plane.
@@ -45,8 +49,76 @@ impl<T> Plane<T> {
 }
 
 impl<T: Float> Plane<T> {
+	/// Constructs a new plane through a point with the given normal.
+	///
+	/// The normal is normalized.
+	///
+	/// ```
+	/// use cvmath::{Plane, Point3, Vec3};
+	///
+	/// let plane = Plane::from_point_normal(Point3(0.0, 0.0, 2.0), Vec3(0.0, 0.0, 4.0));
+	/// assert_eq!(plane, Plane(Vec3(0.0, 0.0, 1.0), -2.0));
+	/// ```
+	#[inline]
+	pub fn from_point_normal(pt: Point3<T>, normal: Vec3<T>) -> Plane<T> {
+		let normal = normal.normalize();
+		let distance = -normal.dot(pt);
+		Plane { normal, distance }
+	}
+
+	/// Constructs a new plane through three points.
+	///
+	/// The normal points towards the side from which the points appear in counter-clockwise order, matching [`Triangle3::normal`].
+	/// If the points are collinear the plane is not finite, see [try_from_points](#method.try_from_points).
+	///
+	/// ```
+	/// use cvmath::{Plane, Point3, Vec3};
+	///
+	/// let plane = Plane::from_points(Point3(0.0, 0.0, 1.0), Point3(1.0, 0.0, 1.0), Point3(0.0, 1.0, 1.0));
+	/// assert_eq!(plane, Plane(Vec3(0.0, 0.0, 1.0), -1.0));
+	/// ```
+	#[inline]
+	pub fn from_points(a: Point3<T>, b: Point3<T>, c: Point3<T>) -> Plane<T> {
+		Plane::from_point_normal(a, (b - a).cross(c - a))
+	}
+
+	/// Constructs a new plane through three points.
+	///
+	/// Returns none if the points are coincident or collinear.
+	///
+	/// ```
+	/// use cvmath::{Plane, Point3};
+	///
+	/// let plane = Plane::try_from_points(Point3(0.0, 0.0, 0.0), Point3(1.0, 1.0, 1.0), Point3(2.0, 2.0, 2.0));
+	/// assert_eq!(plane, None);
+	/// ```
+	#[inline]
+	pub fn try_from_points(a: Point3<T>, b: Point3<T>, c: Point3<T>) -> Option<Plane<T>> {
+		let ab = b - a;
+		let ac = c - a;
+		let normal = ab.cross(ac);
+		// Reject when the sine of the angle between the edges vanishes
+		if normal.len() > T::EPSILON * ab.len() * ac.len() {
+			Some(Plane::from_point_normal(a, normal))
+		}
+		else {
+			None
+		}
+	}
+}
+
+impl<T: Float> Plane<T> {
+	/// Returns `true` if all the components are finite.
+	#[inline]
+	pub fn is_finite(&self) -> bool {
+		self.normal.all_finite() && self.distance.is_finite()
+	}
+
 	/// Returns the projection of a point onto the plane.
 	///
+	/// The normal must be normalized.
+	/// Points on the plane, with a signed distance of zero, are returned unchanged.
+	///
 	/// ```
 	/// use cvmath::{Plane, Point3, Vec3};
 	///
@@ -56,10 +128,13 @@ impl<T: Float> Plane<T> {
 	/// ```
 	#[inline]
 	pub fn project_pt(&self, pt: Point3<T>) -> Point3<T> {
-		pt - self.normal * (self.normal.dot(pt) + self.distance)
+		pt - self.normal * self.dist_pt(pt)
 	}
 
-	/// Returns the distance from the plane to a point.
+	/// Returns the signed distance from the plane to a point.
+	///
+	/// The distance is positive for points in front of the plane, on the side the normal points towards, and negative for points behind it.
+	/// The distance is in units of the normal's length, normalize the normal for euclidean distances.
 	///
 	/// ```
 	/// use cvmath::{Plane, Point3, Vec3};
@@ -72,29 +147,249 @@ impl<T: Float> Plane<T> {
 	pub fn dist_pt(&self, pt: Point3<T>) -> T {
 		self.normal.dot(pt) + self.distance
 	}
-}
 
-//----------------------------------------------------------------
+	/// Returns the reflection of a point across the plane.
+	///
+	/// The normal must be normalized.
+	///
+	/// ```
+	/// use cvmath::{Plane, Point3, Vec3};
+	///
+	/// let plane = Plane(Vec3(0.0, 0.0, 1.0), -1.0);
+	/// let pt = Point3(20.0, 10.0, 4.0);
+	/// assert_eq!(plane.reflect_pt(pt), Point3(20.0, 10.0, -2.0));
+	/// ```
+	#[inline]
+	pub fn reflect_pt(&self, pt: Point3<T>) -> Point3<T> {
+		let d = self.dist_pt(pt);
+		pt - self.normal * (d + d)
+	}
 
-impl<T: Float> TraceRay<T> for Plane<T> {
-	fn inside(&self, ray: &Ray<T>) -> bool {
-		self.normal.dot(ray.origin) + self.distance < T::ZERO
+	/// Clips the line segment against the plane.
+	///
+	/// Keeps the part of the line in front of the plane, points exactly on the plane are kept.
+	/// Returns none if the line is entirely behind the plane.
+	///
+	/// ```
+	/// use cvmath::{Line3, Plane, Point3, Vec3};
+	///
+	/// let plane = Plane(Vec3(0.0, 0.0, 1.0), 0.0);
+	/// let line = Line3(Point3(1.0, 2.0, -1.0), Point3(1.0, 2.0, 3.0));
+	/// assert_eq!(plane.clip_line(line), Some(Line3(Point3(1.0, 2.0, 0.0), Point3(1.0, 2.0, 3.0))));
+	///
+	/// let behind = Line3(Point3(1.0, 2.0, -1.0), Point3(5.0, 2.0, -3.0));
+	/// assert_eq!(plane.clip_line(behind), None);
+	/// ```
+	#[inline]
+	pub fn clip_line(&self, line: Line3<T>) -> Option<Line3<T>> {
+		let d1 = self.dist_pt(line.start);
+		let d2 = self.dist_pt(line.end);
+		if d1 < T::ZERO && d2 < T::ZERO {
+			return None;
+		}
+		if d1 < T::ZERO {
+			let start = line.start.lerp(line.end, d1 / (d1 - d2));
+			return Some(Line3 { start, end: line.end });
+		}
+		if d2 < T::ZERO {
+			let end = line.start.lerp(line.end, d1 / (d1 - d2));
+			return Some(Line3 { start: line.start, end });
+		}
+		Some(line)
 	}
 
-	fn trace(&self, ray: &Ray<T>, hits: &mut [TraceHit<T>]) -> usize {
+	/// Clips the polygon against the plane.
+	///
+	/// Keeps the part of the polygon in front of the plane, points exactly on the plane are kept.
+	/// The clipped polygon is written to `out` which is cleared first.
+	/// If less than three points remain the output is left empty.
+	///
+	/// ```
+	/// use cvmath::{Plane, Point3, Vec3};
+	///
+	/// let plane = Plane(Vec3(1.0, 0.0, 0.0), 0.0);
+	/// let square = [Point3(-1.0, -1.0, 0.0), Point3(1.0, -1.0, 0.0), Point3(1.0, 1.0, 0.0), Point3(-1.0, 1.0, 0.0)];
+	///
+	/// let mut out = Vec::new();
+	/// plane.clip_polygon(&square, &mut out);
+	/// assert_eq!(out, [Point3(0.0, -1.0, 0.0), Point3(1.0, -1.0, 0.0), Point3(1.0, 1.0, 0.0), Point3(0.0, 1.0, 0.0)]);
+	///
+	/// (-plane).clip_polygon(&out.clone(), &mut out);
+	/// assert!(out.is_empty());
+	/// ```
+	#[cfg(feature = "std")]
+	pub fn clip_polygon(&self, points: &[Point3<T>], out: &mut Vec<Point3<T>>) {
+		clip_polygon(points, out, |pt| self.dist_pt(pt), Point3::lerp);
+	}
+
+	/// Trace the ray against the plane.
+	///
+	/// Hits are reported from both sides of the plane.
+	/// If `two_sided` is set the hit normal is flipped to oppose the ray direction, otherwise it is the plane normal.
+	///
+	/// Returns none if the ray is parallel to the plane or points away from it.
+	///
+	/// ```
+	/// use cvmath::{Plane, Point3, Ray, Vec3};
+	///
+	/// let ground = Plane(Vec3(0.0, 1.0, 0.0), 1.0);
+	/// let ray = Ray(Point3(0.0, -3.0, 0.0), Vec3(0.0, 1.0, 0.0));
+	///
+	/// let hit = ground.trace_ray(&ray, false).unwrap();
+	/// assert_eq!(hit.distance, 2.0);
+	/// assert_eq!(hit.normal, Vec3(0.0, 1.0, 0.0));
+	///
+	/// let hit = ground.trace_ray(&ray, true).unwrap();
+	/// assert_eq!(hit.normal, Vec3(0.0, -1.0, 0.0));
+	///
+	/// let parallel = Ray(Point3(0.0, 2.0, 0.0), Vec3(1.0, 0.0, 0.0));
+	/// assert_eq!(ground.trace_ray(&parallel, true), None);
+	/// ```
+	#[inline]
+	pub fn trace_ray(&self, ray: &Ray<T>, two_sided: bool) -> Option<TraceHit<T>> {
 		let denom = self.normal.dot(ray.direction);
 		if denom.abs() < T::EPSILON {
-			return 0;
+			return None;
 		}
 
-		let distance = -self.normal.dot(ray.origin) / denom;
+		let distance = -self.dist_pt(ray.origin) / denom;
 		if distance < T::ZERO {
-			return 0;
+			return None;
+		}
+
+		let normal = if two_sided && denom > T::ZERO { -self.normal } else { self.normal };
+		Some(TraceHit { distance, normal })
+	}
+
+	/// Returns `true` if the planes are not parallel.
+	///
+	/// ```
+	/// use cvmath::{Plane, Vec3};
+	///
+	/// let a = Plane(Vec3(0.0, 0.0, 1.0), 0.0);
+	/// assert!(a.intersects_plane(&Plane(Vec3(1.0, 0.0, 0.0), 0.0)));
+	/// assert!(!a.intersects_plane(&Plane(Vec3(0.0, 0.0, -1.0), 2.0)));
+	/// ```
+	#[inline]
+	pub fn intersects_plane(&self, other: &Plane<T>) -> bool {
+		self.normal.cross(other.normal).len_sqr() > T::EPSILON * self.normal.len_sqr() * other.normal.len_sqr()
+	}
+
+	/// Intersection line of the planes.
+	///
+	/// The line starts at the point of the intersection closest to the origin and its end lies one unit along the direction `self.normal × other.normal`.
+	/// Returns none if the planes are parallel.
+	///
+	/// ```
+	/// use cvmath::{Line3, Plane, Point3, Vec3};
+	///
+	/// let a = Plane(Vec3(0.0, 0.0, 1.0), -2.0);
+	/// let b = Plane(Vec3(1.0, 0.0, 0.0), -3.0);
+	/// assert_eq!(a.intersect_plane(&b), Some(Line3(Point3(3.0, 0.0, 2.0), Point3(3.0, 1.0, 2.0))));
+	/// ```
+	#[inline]
+	pub fn intersect_plane(&self, other: &Plane<T>) -> Option<Line3<T>> {
+		if !self.intersects_plane(other) {
+			return None;
+		}
+		let dir = self.normal.cross(other.normal);
+		let start = (dir.cross(other.normal) * self.distance + self.normal.cross(dir) * other.distance) / dir.len_sqr();
+		Some(Line3 { start, end: start + dir.normalize() })
+	}
+}
+
+/// Which side of a plane a shape lies on.
+///
+/// See [`Cuboid::plane_side`](Bounds::plane_side).
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+pub enum PlaneSide {
+	/// In front of the plane, on the side the normal points towards.
+	Front,
+	/// Behind the plane.
+	Back,
+	/// Crossing or touching the plane.
+	Straddle,
+}
+
+impl<T: ops::Neg<Output = T>> ops::Neg for Plane<T> {
+	type Output = Plane<T>;
+	#[inline]
+	fn neg(self) -> Plane<T> {
+		Plane { normal: -self.normal, distance: -self.distance }
+	}
+}
+
+impl<T: Float> ops::Mul<Plane<T>> for Transform3<T> {
+	type Output = Plane<T>;
+	/// Transforms the plane.
+	///
+	/// The normal is transformed by the [normal matrix](Transform3::normal_matrix) and renormalized.
+	/// Points in front of the plane remain in front of the transformed plane.
+	/// If the transform is singular the plane is returned unchanged.
+	#[inline]
+	fn mul(self, plane: Plane<T>) -> Plane<T> {
+		let Some(normal_matrix) = self.normal_matrix() else { return plane };
+		let pt = self * (plane.normal * (-plane.distance / plane.normal.len_sqr()));
+		let normal = (normal_matrix * plane.normal).normalize();
+		Plane::new_alt(normal, pt)
+	}
+}
+
+/// Planes are unbounded, the volume of the half-space behind the plane and the area of the plane are infinite.
+impl<T: Float> Measure3<T> for Plane<T> {
+	#[inline]
+	fn volume(&self) -> T {
+		T::INFINITY
+	}
+	#[inline]
+	fn surface_area(&self) -> T {
+		T::INFINITY
+	}
+}
+
+/// Sutherland–Hodgman clipping against a single boundary.
+///
+/// Points with a non-negative signed distance are kept.
+#[cfg(feature = "std")]
+pub(crate) fn clip_polygon<T: Float, P: Copy>(points: &[P], out: &mut Vec<P>, dist: impl Fn(P) -> T, lerp: impl Fn(P, P, T) -> P) {
+	out.clear();
+	let Some(&last) = points.last() else { return };
+
+	let mut prev = last;
+	let mut prev_dist = dist(prev);
+	for &pt in points {
+		let pt_dist = dist(pt);
+		if pt_dist >= T::ZERO {
+			if prev_dist < T::ZERO && pt_dist > T::ZERO {
+				out.push(lerp(prev, pt, prev_dist / (prev_dist - pt_dist)));
+			}
+			out.push(pt);
 		}
+		else if prev_dist > T::ZERO {
+			out.push(lerp(prev, pt, prev_dist / (prev_dist - pt_dist)));
+		}
+		prev = pt;
+		prev_dist = pt_dist;
+	}
+
+	if out.len() < 3 {
+		out.clear();
+	}
+}
+
+//----------------------------------------------------------------
+
+impl<T: Float> TraceRay<T> for Plane<T> {
+	fn inside(&self, ray: &Ray<T>) -> bool {
+		self.normal.dot(ray.origin) + self.distance < T::ZERO
+	}
+
+	fn trace(&self, ray: &Ray<T>, hits: &mut [TraceHit<T>]) -> usize {
+		let Some(hit) = self.trace_ray(ray, false) else { return 0 };
 
 		let mut count = 0;
 		if hits.len() > count {
-			hits[count] = TraceHit { distance, normal: self.normal };
+			hits[count] = hit;
 			count += 1;
 		}
 
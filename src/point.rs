@@ -32,3 +32,35 @@ specialized_type!(Point3, Point3d, f64, x, y, z);
 
 specialized_type!(Point2, Point2i, i32, x, y);
 specialized_type!(Point3, Point3i, i32, x, y, z);
+
+//----------------------------------------------------------------
+
+#[cfg(feature = "mint")]
+impl<T> From<mint::Point2<T>> for Point2<T> {
+	#[inline]
+	fn from(pt: mint::Point2<T>) -> Point2<T> {
+		Point2 { x: pt.x, y: pt.y }
+	}
+}
+#[cfg(feature = "mint")]
+impl<T> From<Point2<T>> for mint::Point2<T> {
+	#[inline]
+	fn from(pt: Point2<T>) -> mint::Point2<T> {
+		mint::Point2 { x: pt.x, y: pt.y }
+	}
+}
+
+#[cfg(feature = "mint")]
+impl<T> From<mint::Point3<T>> for Point3<T> {
+	#[inline]
+	fn from(pt: mint::Point3<T>) -> Point3<T> {
+		Point3 { x: pt.x, y: pt.y, z: pt.z }
+	}
+}
+#[cfg(feature = "mint")]
+impl<T> From<Point3<T>> for mint::Point3<T> {
+	#[inline]
+	fn from(pt: Point3<T>) -> mint::Point3<T> {
+		mint::Point3 { x: pt.x, y: pt.y, z: pt.z }
+	}
+}
@@ -0,0 +1,153 @@
+/*!
+Approximate polygonization of shapes.
+
+Converts shapes into points and line segments for rendering outlines and wireframes, eg. with the SVG writer or a debug line renderer.
+
+The 2D outlines are closed polygons where the last point connects back to the first.
+They wind counter-clockwise when the Y axis points up, which means the signed area is positive (see [`orient2d`]).
+When the Y axis points down, as in screen space, they appear clockwise.
+*/
+
+use super::*;
+
+impl<T: Scalar> Rect<T> {
+	/// Outline of the rectangle.
+	///
+	/// Returns the [corners](Rect::corners) starting at `mins`.
+	///
+	/// ```
+	/// use cvmath::{Point2, Rect};
+	///
+	/// let rect = Rect(Point2(0, 0), Point2(2, 1));
+	/// let points: Vec<_> = rect.polygonize().collect();
+	/// assert_eq!(points, [Point2(0, 0), Point2(2, 0), Point2(2, 1), Point2(0, 1)]);
+	/// ```
+	#[inline]
+	pub fn polygonize(&self) -> impl Iterator<Item = Point2<T>> {
+		self.corners().into_iter()
+	}
+}
+
+impl<T: Float> Triangle2<T> {
+	/// Outline of the triangle.
+	///
+	/// Starts at `p0`, the other two points are swapped if the triangle winds clockwise.
+	///
+	/// ```
+	/// use cvmath::{Point2, Triangle2};
+	///
+	/// let triangle = Triangle2(Point2(0.0, 0.0), Point2(0.0, 1.0), Point2(1.0, 0.0));
+	/// let points: Vec<_> = triangle.polygonize().collect();
+	/// assert_eq!(points, [Point2(0.0, 0.0), Point2(1.0, 0.0), Point2(0.0, 1.0)]);
+	/// ```
+	#[inline]
+	pub fn polygonize(&self) -> impl Iterator<Item = Point2<T>> {
+		let Triangle { p0, p1, p2 } = *self;
+		let points = if (p1 - p0).cross(p2 - p0) < T::ZERO { [p0, p2, p1] } else { [p0, p1, p2] };
+		points.into_iter()
+	}
+}
+
+impl<T: Float> Stadium2<T> {
+	/// Outline of the stadium.
+	///
+	/// Each half circle cap is approximated by `n` segments, giving `2 * (n + 1)` points.
+	/// The points lie on the boundary, starting at the end point cap.
+	/// The number of segments is clamped to at least one, which cuts the caps off straight giving the rectangle around the line segment.
+	///
+	/// A stadium with a zero length line segment is a circle, giving a regular polygon with duplicated points where the caps meet.
+	///
+	/// ```
+	/// use cvmath::{Line2, Point2, Stadium2};
+	///
+	/// let stadium = Stadium2(Line2(Point2(0.0, 0.0), Point2(4.0, 0.0)), 1.0);
+	/// let points: Vec<_> = stadium.polygonize(2).collect();
+	/// assert_eq!(points.len(), 6);
+	/// assert!(points[0].distance(Point2(4.0, -1.0)) < 1e-12);
+	/// assert!(points[1].distance(Point2(5.0, 0.0)) < 1e-12);
+	/// assert!(points[4].distance(Point2(-1.0, 0.0)) < 1e-12);
+	/// ```
+	pub fn polygonize(&self, n: usize) -> impl Iterator<Item = Point2<T>> {
+		let Stadium2 { line, radius } = *self;
+		let dir = line.end - line.start;
+		let dir = if dir == Vec2::ZERO { Vec2::X } else { dir.normalize() };
+		// Rotated counter-clockwise with the Y axis up
+		let perp = Vec2 { x: -dir.y, y: dir.x };
+
+		let n = usize::max(n, 1);
+		let pi = Rad::<T>::half().value;
+		let step = pi / T::cast_from(n as f64);
+		let half_pi = pi / (T::ONE + T::ONE);
+		(0..2).flat_map(move |cap| {
+			let center = if cap == 0 { line.end } else { line.start };
+			(0..=n).map(move |i| {
+				let angle = step * T::cast_from(i as f64) - half_pi + pi * T::cast_from(cap as f64);
+				let (sin, cos) = angle.sin_cos();
+				center + (dir * cos + perp * sin) * radius
+			})
+		})
+	}
+}
+
+impl<T: Scalar> Cuboid<T> {
+	/// Edges of the cuboid as line segments.
+	///
+	/// The segments connect the [corners](Cuboid::corners) in the order of the [edges](Cuboid::edges).
+	///
+	/// ```
+	/// use cvmath::{Cuboid, Line3, Point3};
+	///
+	/// let cuboid = Cuboid(Point3(0, 0, 0), Point3(1, 2, 3));
+	/// let lines = cuboid.edge_lines();
+	/// assert_eq!(lines[0], Line3(Point3(0, 0, 0), Point3(1, 0, 0)));
+	/// assert_eq!(lines[4], Line3(Point3(0, 0, 0), Point3(0, 2, 0)));
+	/// assert_eq!(lines[11], Line3(Point3(1, 2, 0), Point3(1, 2, 3)));
+	/// ```
+	#[inline]
+	pub fn edge_lines(&self) -> [Line3<T>; 12] {
+		let corners = self.corners();
+		self.edges().map(|[a, b]| Line3 { start: corners[a], end: corners[b] })
+	}
+}
+
+impl<T: Float> Sphere<T> {
+	/// Wireframe of the sphere.
+	///
+	/// The wireframe consists of `rings` circles of latitude and `segments` meridians between the poles along the Y axis.
+	/// Every circle of latitude is split into `segments` lines winding counter-clockwise around the Y axis, starting at +Z towards +X.
+	/// This matches the angles of [`Spherical`](struct@Spherical).
+	/// Every meridian is split into `rings + 1` lines from the north pole to the south pole.
+	///
+	/// ```
+	/// use cvmath::{Point3, Sphere};
+	///
+	/// let sphere = Sphere(Point3(0.0f64, 0.0, 0.0), 2.0);
+	/// let lines = sphere.polygonize_wire(3, 8);
+	/// assert_eq!(lines.len(), 3 * 8 + 8 * 4);
+	/// assert!(lines.iter().all(|line| (line.start.len() - 2.0).abs() < 1e-12));
+	/// ```
+	#[cfg(feature = "std")]
+	pub fn polygonize_wire(&self, rings: usize, segments: usize) -> Vec<Line3<T>> {
+		let pi = Rad::<T>::half().value;
+		let lat_step = pi / T::cast_from((rings + 1) as f64);
+		let lon_step = (pi + pi) / T::cast_from(segments as f64);
+		let point = |i: usize, j: usize| {
+			let (sin_lat, cos_lat) = (lat_step * T::cast_from(i as f64)).sin_cos();
+			let (sin_lon, cos_lon) = (lon_step * T::cast_from(j as f64)).sin_cos();
+			self.center + Vec3(sin_lat * sin_lon, cos_lat, sin_lat * cos_lon) * self.radius
+		};
+
+		let mut lines = Vec::with_capacity(rings * segments + segments * (rings + 1));
+		for i in 1..=rings {
+			for j in 0..segments {
+				lines.push(Line3 { start: point(i, j), end: point(i, j + 1) });
+			}
+		}
+		for j in 0..segments {
+			for i in 0..=rings {
+				lines.push(Line3 { start: point(i, j), end: point(i + 1, j) });
+			}
+		}
+		lines
+	}
+}
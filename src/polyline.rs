@@ -0,0 +1,61 @@
+/*!
+Polylines stored as slices of points.
+*/
+
+use super::*;
+
+/// Total length of the polyline.
+///
+/// ```
+/// use cvmath::{polyline_length, Point2};
+///
+/// let path = [Point2(0.0, 0.0), Point2(3.0, 4.0), Point2(3.0, 6.0)];
+/// assert_eq!(polyline_length(&path), 7.0);
+/// ```
+#[inline]
+pub fn polyline_length<T: Float>(points: &[Point2<T>]) -> T {
+	points.windows(2).fold(T::ZERO, |acc, w| acc + w[0].distance(w[1]))
+}
+
+/// Resamples the polyline into `n` points equally spaced by arc length.
+///
+/// The first and last points of the polyline are kept.
+/// Zero length segments are skipped.
+/// Returns an empty vector if the polyline has no points.
+///
+/// ```
+/// use cvmath::{resample_uniform, Point2};
+///
+/// let path = [Point2(0.0, 0.0), Point2(2.0, 0.0), Point2(2.0, 0.0), Point2(2.0, 4.0)];
+/// let points = resample_uniform(&path, 4);
+/// assert_eq!(points, [Point2(0.0, 0.0), Point2(2.0, 0.0), Point2(2.0, 2.0), Point2(2.0, 4.0)]);
+/// ```
+#[cfg(feature = "std")]
+pub fn resample_uniform<T: Float>(points: &[Point2<T>], n: usize) -> Vec<Point2<T>> {
+	let mut result = Vec::with_capacity(n);
+	let (Some(&first), Some(&last)) = (points.first(), points.last()) else { return result };
+	if n < 2 || points.len() < 2 {
+		result.resize(n, first);
+		return result;
+	}
+
+	let step = polyline_length(points) / T::cast_from((n - 1) as f64);
+
+	// Arc length at the start of the current segment
+	let mut acc = T::ZERO;
+	let mut i = 0;
+	result.push(first);
+	for k in 1..n - 1 {
+		let target = step * T::cast_from(k as f64);
+		let mut len = points[i].distance(points[i + 1]);
+		while i + 2 < points.len() && acc + len < target {
+			acc += len;
+			i += 1;
+			len = points[i].distance(points[i + 1]);
+		}
+		let t = if len > T::ZERO { ((target - acc) / len).min(T::ONE) } else { T::ZERO };
+		result.push(points[i].lerp(points[i + 1], t));
+	}
+	result.push(last);
+	result
+}
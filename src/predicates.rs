@@ -0,0 +1,292 @@
+/*!
+Robust geometric predicates.
+
+The naive determinants misclassify nearly degenerate inputs due to rounding, which corrupts algorithms such as Delaunay triangulation and convex hulls.
+These predicates first evaluate the determinant in floating point together with an error bound.
+Only when the result is too close to zero to be trusted the determinant is evaluated exactly using floating point expansions.
+
+See Jonathan Richard Shewchuk, _Adaptive Precision Floating-Point Arithmetic and Fast Robust Geometric Predicates_.
+
+The coordinates are converted to `f64` which is exact for `f32` inputs.
+The results are exact unless the intermediate products overflow or underflow.
+*/
+
+use core::cmp::Ordering;
+use super::*;
+
+/// Orientation of three points.
+///
+/// Returns `Greater` if the points `a`, `b` and `c` are in counter-clockwise order, `Less` if they are in clockwise order and `Equal` if they are collinear.
+/// Counter-clockwise means `c` lies to the left of the directed line from `a` to `b` when the Y axis points up.
+///
+/// ```
+/// use core::cmp::Ordering;
+/// use cvmath::{orient2d, Point2};
+///
+/// let a = Point2(0.0, 0.0);
+/// let b = Point2(1.0, 0.0);
+/// assert_eq!(orient2d(a, b, Point2(0.5, 1.0)), Ordering::Greater);
+/// assert_eq!(orient2d(a, b, Point2(0.5, -1.0)), Ordering::Less);
+/// assert_eq!(orient2d(a, b, Point2(3.0, 0.0)), Ordering::Equal);
+///
+/// // The naive determinant gets this nearly collinear case wrong
+/// let a = Point2(0.5f64, 0.5);
+/// let b = Point2(12.0, 12.0);
+/// let c = Point2(24.0, 24.0);
+/// let c = Point2(c.x + f64::EPSILON * 16.0, c.y);
+/// assert_eq!(orient2d(a, b, c), Ordering::Less);
+/// ```
+pub fn orient2d<T: Float>(a: Point2<T>, b: Point2<T>, c: Point2<T>) -> Ordering {
+	let [ax, ay, bx, by, cx, cy] = [a.x.cast_to(), a.y.cast_to(), b.x.cast_to(), b.y.cast_to(), c.x.cast_to(), c.y.cast_to()];
+
+	let detleft = (ax - cx) * (by - cy);
+	let detright = (ay - cy) * (bx - cx);
+	let det = detleft - detright;
+	let detsum = detleft.abs() + detright.abs();
+	if det.abs() > CCW_ERRBOUND * detsum {
+		return sign(det);
+	}
+
+	orient2d_exact(ax, ay, bx, by, cx, cy)
+}
+
+/// Position of a point relative to the circle through three points.
+///
+/// With `a`, `b` and `c` in counter-clockwise order (see [`orient2d`]) returns `Greater` if `d` lies inside the circle, `Less` if it lies outside and `Equal` if it lies on the circle.
+/// The result is reversed if `a`, `b` and `c` are in clockwise order.
+///
+/// ```
+/// use core::cmp::Ordering;
+/// use cvmath::{incircle, Point2};
+///
+/// let a = Point2(1.0, 0.0);
+/// let b = Point2(0.0, 1.0);
+/// let c = Point2(-1.0, 0.0);
+/// assert_eq!(incircle(a, b, c, Point2(0.0, 0.0)), Ordering::Greater);
+/// assert_eq!(incircle(a, b, c, Point2(2.0, 0.0)), Ordering::Less);
+/// assert_eq!(incircle(a, b, c, Point2(0.0, -1.0)), Ordering::Equal);
+/// ```
+pub fn incircle<T: Float>(a: Point2<T>, b: Point2<T>, c: Point2<T>, d: Point2<T>) -> Ordering {
+	let [ax, ay, bx, by]: [f64; 4] = [a.x.cast_to(), a.y.cast_to(), b.x.cast_to(), b.y.cast_to()];
+	let [cx, cy, dx, dy]: [f64; 4] = [c.x.cast_to(), c.y.cast_to(), d.x.cast_to(), d.y.cast_to()];
+
+	let (adx, ady) = (ax - dx, ay - dy);
+	let (bdx, bdy) = (bx - dx, by - dy);
+	let (cdx, cdy) = (cx - dx, cy - dy);
+
+	let (bdxcdy, cdxbdy) = (bdx * cdy, cdx * bdy);
+	let alift = adx * adx + ady * ady;
+	let (cdxady, adxcdy) = (cdx * ady, adx * cdy);
+	let blift = bdx * bdx + bdy * bdy;
+	let (adxbdy, bdxady) = (adx * bdy, bdx * ady);
+	let clift = cdx * cdx + cdy * cdy;
+
+	let det = alift * (bdxcdy - cdxbdy) + blift * (cdxady - adxcdy) + clift * (adxbdy - bdxady);
+	let permanent =
+		(bdxcdy.abs() + cdxbdy.abs()) * alift +
+		(cdxady.abs() + adxcdy.abs()) * blift +
+		(adxbdy.abs() + bdxady.abs()) * clift;
+	if det.abs() > ICC_ERRBOUND * permanent {
+		return sign(det);
+	}
+
+	incircle_exact(ax, ay, bx, by, cx, cy, dx, dy)
+}
+
+//----------------------------------------------------------------
+
+const EPSILON: f64 = f64::EPSILON * 0.5;
+const CCW_ERRBOUND: f64 = (3.0 + 16.0 * EPSILON) * EPSILON;
+const ICC_ERRBOUND: f64 = (10.0 + 96.0 * EPSILON) * EPSILON;
+const SPLITTER: f64 = 134217729.0; // 2^27 + 1
+
+#[inline]
+fn sign(x: f64) -> Ordering {
+	x.partial_cmp(&0.0).unwrap_or(Ordering::Equal)
+}
+
+#[inline]
+fn two_sum(a: f64, b: f64) -> (f64, f64) {
+	let x = a + b;
+	let bv = x - a;
+	let av = x - bv;
+	(x, (a - av) + (b - bv))
+}
+
+#[inline]
+fn two_diff(a: f64, b: f64) -> (f64, f64) {
+	let x = a - b;
+	let bv = a - x;
+	let av = x + bv;
+	(x, (a - av) + (bv - b))
+}
+
+#[inline]
+fn split(a: f64) -> (f64, f64) {
+	let c = SPLITTER * a;
+	let hi = c - (c - a);
+	(hi, a - hi)
+}
+
+#[inline]
+fn two_product(a: f64, b: f64) -> (f64, f64) {
+	let x = a * b;
+	let (ahi, alo) = split(a);
+	let (bhi, blo) = split(b);
+	let err = x - ahi * bhi - alo * bhi - ahi * blo;
+	(x, alo * blo - err)
+}
+
+/// Adds a scalar to the expansion, dropping zero components.
+fn grow_expansion(e: &[f64], b: f64, h: &mut [f64]) -> usize {
+	let mut q = b;
+	let mut n = 0;
+	for &e in e {
+		let (sum, err) = two_sum(q, e);
+		q = sum;
+		if err != 0.0 {
+			h[n] = err;
+			n += 1;
+		}
+	}
+	if q != 0.0 || n == 0 {
+		h[n] = q;
+		n += 1;
+	}
+	n
+}
+
+/// Adds the expansion `f` to the expansion `h` in place, `scratch` must be as long as `h`.
+fn add_expansion(h: &mut [f64], mut len: usize, f: &[f64], scratch: &mut [f64]) -> usize {
+	for &f in f {
+		let n = grow_expansion(&h[..len], f, scratch);
+		h[..n].copy_from_slice(&scratch[..n]);
+		len = n;
+	}
+	len
+}
+
+/// Multiplies the expansion by a scalar, dropping zero components.
+fn scale_expansion(e: &[f64], b: f64, h: &mut [f64]) -> usize {
+	let mut n = 0;
+	let (mut q, lo) = two_product(e[0], b);
+	if lo != 0.0 {
+		h[n] = lo;
+		n += 1;
+	}
+	for &e in &e[1..] {
+		let (p1, p0) = two_product(e, b);
+		let (sum, err) = two_sum(q, p0);
+		if err != 0.0 {
+			h[n] = err;
+			n += 1;
+		}
+		let (sum, err) = two_sum(p1, sum);
+		q = sum;
+		if err != 0.0 {
+			h[n] = err;
+			n += 1;
+		}
+	}
+	if q != 0.0 || n == 0 {
+		h[n] = q;
+		n += 1;
+	}
+	n
+}
+
+/// Multiplies two expansions, `h` must hold `2 * e.len() * f.len()` components.
+fn mul_expansion(e: &[f64], f: &[f64], h: &mut [f64]) -> usize {
+	let mut term = [0.0; 64];
+	let mut scratch = [0.0; 512];
+	let mut len = 0;
+	for &f in f {
+		let n = scale_expansion(e, f, &mut term);
+		len = add_expansion(h, len, &term[..n], &mut scratch[..h.len()]);
+	}
+	len
+}
+
+/// Difference of two scalars as an expansion.
+#[inline]
+fn diff(a: f64, b: f64) -> ([f64; 2], usize) {
+	let (x, y) = two_diff(a, b);
+	if y != 0.0 { ([y, x], 2) } else { ([x, 0.0], 1) }
+}
+
+/// Sign of an expansion, the most significant component is the last one.
+#[inline]
+fn expansion_sign(e: &[f64]) -> Ordering {
+	sign(e[e.len() - 1])
+}
+
+fn orient2d_exact(ax: f64, ay: f64, bx: f64, by: f64, cx: f64, cy: f64) -> Ordering {
+	// ax by - ax cy - cx by - ay bx + ay cx + cy bx
+	let terms = [(ax, by), (-ax, cy), (-cx, by), (-ay, bx), (ay, cx), (cy, bx)];
+	let mut h = [0.0; 12];
+	let mut scratch = [0.0; 12];
+	let mut len = 0;
+	for (a, b) in terms {
+		let (x, y) = two_product(a, b);
+		len = add_expansion(&mut h, len, &[y, x], &mut scratch);
+	}
+	expansion_sign(&h[..len])
+}
+
+/// The exact `a * b + c * d` where each value is an expansion of at most two components.
+fn sum_of_products(a: &[f64], b: &[f64], c: &[f64], d: &[f64], h: &mut [f64; 16]) -> usize {
+	let mut ab = [0.0; 8];
+	let mut cd = [0.0; 8];
+	let n = mul_expansion(a, b, &mut ab);
+	let m = mul_expansion(c, d, &mut cd);
+	h[..n].copy_from_slice(&ab[..n]);
+	let mut scratch = [0.0; 16];
+	add_expansion(h, n, &cd[..m], &mut scratch)
+}
+
+/// Negated expansion of at most two components.
+#[inline]
+fn neg(e: &[f64]) -> ([f64; 2], usize) {
+	let mut h = [0.0; 2];
+	for (h, &e) in h.iter_mut().zip(e) {
+		*h = -e;
+	}
+	(h, e.len())
+}
+
+#[allow(clippy::too_many_arguments)]
+fn incircle_exact(ax: f64, ay: f64, bx: f64, by: f64, cx: f64, cy: f64, dx: f64, dy: f64) -> Ordering {
+	let (adx, adx_n) = diff(ax, dx);
+	let (ady, ady_n) = diff(ay, dy);
+	let (bdx, bdx_n) = diff(bx, dx);
+	let (bdy, bdy_n) = diff(by, dy);
+	let (cdx, cdx_n) = diff(cx, dx);
+	let (cdy, cdy_n) = diff(cy, dy);
+	let (adx, ady) = (&adx[..adx_n], &ady[..ady_n]);
+	let (bdx, bdy) = (&bdx[..bdx_n], &bdy[..bdy_n]);
+	let (cdx, cdy) = (&cdx[..cdx_n], &cdy[..cdy_n]);
+
+	// Each row contributes lift * minor where the minor is `a * d - b * c`
+	let rows = [
+		(adx, ady, [bdx, cdy, bdy, cdx]),
+		(bdx, bdy, [cdx, ady, cdy, adx]),
+		(cdx, cdy, [adx, bdy, ady, bdx]),
+	];
+
+	let mut det = [0.0; 1536];
+	let mut scratch = [0.0; 1536];
+	let mut det_len = 0;
+	for (x, y, [a, d, b, c]) in rows {
+		let (neg_b, neg_b_len) = neg(b);
+		let mut minor = [0.0; 16];
+		let minor_len = sum_of_products(a, d, &neg_b[..neg_b_len], c, &mut minor);
+
+		let mut lift = [0.0; 16];
+		let lift_len = sum_of_products(x, x, y, y, &mut lift);
+
+		let mut term = [0.0; 512];
+		let term_len = mul_expansion(&lift[..lift_len], &minor[..minor_len], &mut term);
+		det_len = add_expansion(&mut det, det_len, &term[..term_len], &mut scratch);
+	}
+	expansion_sign(&det[..det_len])
+}
@@ -0,0 +1,216 @@
+/*!
+Projection parameters.
+*/
+
+use super::*;
+
+/// Vertical field of view from the horizontal field of view.
+///
+/// The aspect ratio is the width divided by the height.
+/// Uses the tangent relation `tan(fov_y / 2) = tan(fov_x / 2) / aspect`.
+///
+/// ```
+/// use cvmath::{fov_y_from_fov_x, Deg};
+///
+/// let fov_y = fov_y_from_fov_x(Deg(90.0), 16.0 / 9.0);
+/// assert!((fov_y.value - 58.7155f64).abs() < 1e-4);
+/// ```
+#[inline]
+pub fn fov_y_from_fov_x<A: Angle>(fov_x: A, aspect: A::T) -> A {
+	let two = A::T::ONE + A::T::ONE;
+	A::atan((fov_x / two).tan() / aspect) * two
+}
+
+/// Horizontal field of view from the vertical field of view.
+///
+/// The aspect ratio is the width divided by the height.
+/// Uses the tangent relation `tan(fov_x / 2) = tan(fov_y / 2) * aspect`.
+///
+/// ```
+/// use cvmath::{fov_x_from_fov_y, Deg};
+///
+/// let fov_x = fov_x_from_fov_y(Deg(58.7155f64), 16.0 / 9.0);
+/// assert!((fov_x.value - 90.0).abs() < 1e-3);
+/// ```
+#[inline]
+pub fn fov_x_from_fov_y<A: Angle>(fov_y: A, aspect: A::T) -> A {
+	let two = A::T::ONE + A::T::ONE;
+	A::atan((fov_y / two).tan() * aspect) * two
+}
+
+/// Vertical field of view from the diagonal field of view.
+///
+/// The aspect ratio is the width divided by the height.
+/// Uses the tangent relation `tan(fov_d / 2) = tan(fov_y / 2) * sqrt(1 + aspect²)`.
+///
+/// ```
+/// use cvmath::{fov_y_from_diagonal, Deg};
+///
+/// // A square viewport with a 90° diagonal
+/// let fov_y = fov_y_from_diagonal(Deg(90.0), 1.0);
+/// assert!((fov_y.value - 70.5288f64).abs() < 1e-4);
+/// ```
+#[inline]
+pub fn fov_y_from_diagonal<A: Angle>(fov_d: A, aspect: A::T) -> A {
+	let two = A::T::ONE + A::T::ONE;
+	A::atan((fov_d / two).tan() / (A::T::ONE + aspect * aspect).sqrt()) * two
+}
+
+/// Focal length from the field of view.
+///
+/// The sensor size is measured along the same axis as the field of view, the result is in the same units.
+/// Uses the pinhole relation `focal_length = sensor_size / (2 tan(fov / 2))`.
+///
+/// ```
+/// use cvmath::{focal_length_from_fov, Deg};
+///
+/// // A full frame sensor is 24mm tall
+/// let focal_length = focal_length_from_fov(Deg(90.0f64), 24.0);
+/// assert!((focal_length - 12.0).abs() < 1e-12);
+/// ```
+#[inline]
+pub fn focal_length_from_fov<A: Angle>(fov: A, sensor_size: A::T) -> A::T {
+	let two = A::T::ONE + A::T::ONE;
+	sensor_size / (two * (fov / two).tan())
+}
+
+/// Field of view from the focal length.
+///
+/// Inverse of [`focal_length_from_fov`].
+///
+/// ```
+/// use cvmath::{fov_from_focal_length, Deg};
+///
+/// let fov: Deg<f64> = fov_from_focal_length(50.0, 24.0);
+/// assert!((fov.value - 26.9915).abs() < 1e-4);
+/// ```
+#[inline]
+pub fn fov_from_focal_length<A: Angle>(focal_length: A::T, sensor_size: A::T) -> A {
+	let two = A::T::ONE + A::T::ONE;
+	A::atan(sensor_size / (two * focal_length)) * two
+}
+
+/// Perspective projection parameters.
+///
+/// Bundles the arguments of [`Mat4::perspective`].
+///
+/// ```
+/// use cvmath::{Deg, Perspective, Point2, Rect, Vec3, LH, ZO};
+///
+/// let viewport = Rect(Point2(0.0, 0.0), Point2(800.0, 600.0));
+/// let proj = Perspective::new(Deg(90.0).to_rad(), 1.0, 1.0, 100.0, (LH, ZO))
+///     .with_aspect(viewport.width(), viewport.height());
+///
+/// let ray = proj.ray(Point2(400.0, 300.0), viewport);
+/// assert!(ray.direction.distance(Vec3(0.0, 0.0, 1.0)) < 1e-12);
+/// ```
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Perspective<T> {
+	/// The vertical field of view.
+	pub fov_y: Rad<T>,
+	/// The width divided by the height.
+	pub aspect_ratio: T,
+	/// Distance to the near clipping plane.
+	pub near: T,
+	/// Distance to the far clipping plane.
+	pub far: T,
+	/// The handedness and clip space depth range.
+	pub flags: (Hand, Clip),
+}
+
+impl<T> Perspective<T> {
+	/// Constructs new perspective projection parameters.
+	#[inline]
+	pub const fn new(fov_y: Rad<T>, aspect_ratio: T, near: T, far: T, flags: (Hand, Clip)) -> Perspective<T> {
+		Perspective { fov_y, aspect_ratio, near, far, flags }
+	}
+}
+
+impl<T: Float> Perspective<T> {
+	/// Sets the aspect ratio from the width and height of the viewport.
+	#[inline]
+	pub fn with_aspect(self, width: T, height: T) -> Perspective<T> {
+		debug_assert!(width > T::ZERO && height > T::ZERO);
+		Perspective { aspect_ratio: width / height, ..self }
+	}
+	/// Returns the projection matrix.
+	#[inline]
+	pub fn matrix(&self) -> Mat4<T> {
+		Mat4::perspective(self.fov_y, self.aspect_ratio, self.near, self.far, self.flags)
+	}
+	/// Ray through a pixel of the viewport in view space.
+	///
+	/// See [`Mat4::screen_ray`].
+	#[inline]
+	pub fn ray(&self, pixel: Point2<T>, viewport: Rect<T>) -> Ray<T> {
+		self.matrix().inverse().screen_ray(pixel, viewport, self.flags.1)
+	}
+}
+
+/// Orthographic projection parameters.
+///
+/// Bundles the arguments of [`Mat4::ortho_3d`].
+///
+/// ```
+/// use cvmath::{Orthographic, Point2, Rect, Vec3, LH, ZO};
+///
+/// let viewport = Rect(Point2(0.0, 0.0), Point2(800.0, 600.0));
+/// let proj = Orthographic::new(-1.0, 1.0, -1.0, 1.0, 1.0, 100.0, (LH, ZO))
+///     .with_aspect(viewport.width(), viewport.height());
+/// assert!((proj.right - 4.0 / 3.0f64).abs() < 1e-12);
+///
+/// let ray = proj.ray(Point2(800.0, 0.0), viewport);
+/// assert!(ray.origin.distance(Vec3(4.0 / 3.0, 1.0, 1.0)) < 1e-12);
+/// assert!(ray.direction.distance(Vec3(0.0, 0.0, 1.0)) < 1e-12);
+/// ```
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Orthographic<T> {
+	/// The left edge of the view volume.
+	pub left: T,
+	/// The right edge of the view volume.
+	pub right: T,
+	/// The bottom edge of the view volume.
+	pub bottom: T,
+	/// The top edge of the view volume.
+	pub top: T,
+	/// Distance to the near clipping plane.
+	pub near: T,
+	/// Distance to the far clipping plane.
+	pub far: T,
+	/// The handedness and clip space depth range.
+	pub flags: (Hand, Clip),
+}
+
+impl<T> Orthographic<T> {
+	/// Constructs new orthographic projection parameters.
+	#[inline]
+	pub const fn new(left: T, right: T, bottom: T, top: T, near: T, far: T, flags: (Hand, Clip)) -> Orthographic<T> {
+		Orthographic { left, right, bottom, top, near, far, flags }
+	}
+}
+
+impl<T: Float> Orthographic<T> {
+	/// Sets the aspect ratio from the width and height of the viewport.
+	///
+	/// Keeps the vertical extent and the horizontal center, the left and right edges are adjusted.
+	#[inline]
+	pub fn with_aspect(self, width: T, height: T) -> Orthographic<T> {
+		debug_assert!(width > T::ZERO && height > T::ZERO);
+		let two = T::ONE + T::ONE;
+		let center = (self.left + self.right) / two;
+		let half_width = (self.top - self.bottom) / two * (width / height);
+		Orthographic { left: center - half_width, right: center + half_width, ..self }
+	}
+	/// Returns the projection matrix.
+	#[inline]
+	pub fn matrix(&self) -> Mat4<T> {
+		Mat4::ortho_3d(self.left, self.right, self.bottom, self.top, self.near, self.far, self.flags)
+	}
+	/// Ray through a pixel of the viewport in view space.
+	///
+	/// See [`Mat4::screen_ray`].
+	#[inline]
+	pub fn ray(&self, pixel: Point2<T>, viewport: Rect<T>) -> Ray<T> {
+		self.matrix().inverse().screen_ray(pixel, viewport, self.flags.1)
+	}
+}
@@ -19,6 +19,10 @@ pub const fn Quaternion<T>(a: T, b: T, c: T, d: T) -> Quaternion<T> {
 
 #[cfg(feature = "dataview")]
 unsafe impl<T: dataview::Pod> dataview::Pod for Quaternion<T> {}
+#[cfg(feature = "bytemuck")]
+unsafe impl<T: bytemuck::Zeroable> bytemuck::Zeroable for Quaternion<T> {}
+#[cfg(feature = "bytemuck")]
+unsafe impl<T: bytemuck::Pod> bytemuck::Pod for Quaternion<T> {}
 
 impl<T> Quaternion<T> {
 	/// Constructs a new quaternion from components.
@@ -58,6 +62,24 @@ impl<T> Into<[T; 4]> for Quaternion<T> {
 		[self.a, self.b, self.c, self.d]
 	}
 }
+#[cfg(feature = "mint")]
+impl<T> From<mint::Quaternion<T>> for Quaternion<T> {
+	#[inline]
+	fn from(q: mint::Quaternion<T>) -> Quaternion<T> {
+		Quaternion { a: q.s, b: q.v.x, c: q.v.y, d: q.v.z }
+	}
+}
+#[cfg(feature = "mint")]
+impl<T> From<Quaternion<T>> for mint::Quaternion<T> {
+	#[inline]
+	fn from(q: Quaternion<T>) -> mint::Quaternion<T> {
+		mint::Quaternion { v: mint::Vector3 { x: q.b, y: q.c, z: q.d }, s: q.a }
+	}
+}
+#[cfg(feature = "mint")]
+impl<T> mint::IntoMint for Quaternion<T> {
+	type MintType = mint::Quaternion<T>;
+}
 impl<T> AsRef<[T; 4]> for Quaternion<T> {
 	#[inline]
 	fn as_ref(&self) -> &[T; 4] {
@@ -110,6 +132,304 @@ impl<T: Float> Quaternion<T> {
 	pub fn inverse(self) -> Quaternion<T> {
 		self.conjugate().normalize()
 	}
+	/// Rotation around the +Z axis.
+	///
+	/// Embeds a 2D rotation in the XY plane, rotating a vector in the XY plane matches [`Mat2::rotate`].
+	///
+	/// ```
+	/// use cvmath::{Deg, Mat2, Quaternion, Vec2, Vec3};
+	///
+	/// let q = Quaternion::rotation_z(Deg(90.0));
+	/// assert!((q * Vec3(1.0, 0.0, 0.0)).distance(Vec3(0.0, 1.0, 0.0)) < 1e-12);
+	/// assert!((Mat2::rotate(Deg(90.0)) * Vec2(1.0, 0.0)).distance(Vec2(0.0, 1.0)) < 1e-12);
+	/// ```
+	#[inline]
+	pub fn rotation_z(angle: impl Angle<T = T>) -> Quaternion<T> {
+		let half = angle.to_rad() / (T::ONE + T::ONE);
+		let (sin, cos) = half.sin_cos();
+		Quaternion { a: cos, b: T::ZERO, c: T::ZERO, d: sin }
+	}
+	/// Angle of the twist around the +Z axis.
+	///
+	/// Decomposes the unit quaternion into `swing * twist` where the twist rotates around the +Z axis and the swing axis lies in the XY plane.
+	/// Returns the twist angle in the range `[-π, π]`, undefined rotations (swing of 180°) return zero.
+	/// This is the inverse of [rotation_z](#method.rotation_z) for rotations around the +Z axis.
+	///
+	/// ```
+	/// use cvmath::{Deg, Quaternion};
+	///
+	/// let q = Quaternion::rotation_z(Deg(-135.0f64));
+	/// assert!((q.twist_z().to_deg() - Deg(-135.0)).value.abs() < 1e-12);
+	/// ```
+	#[inline]
+	pub fn twist_z(self) -> Rad<T> {
+		let (a, d) = if self.a < T::ZERO { (-self.a, -self.d) } else { (self.a, self.d) };
+		Rad::atan2(d, a) * (T::ONE + T::ONE)
+	}
+	/// Decomposes the unit quaternion into a twist around the axis and a swing.
+	///
+	/// Returns `(twist, swing)` such that `swing * twist == self`, the twist rotates around the axis and the swing axis is perpendicular to it.
+	/// The axis does not need to be normalized.
+	/// When the rotation is 180° around an axis perpendicular to the twist axis the twist is undefined and the identity is returned, the swing is then the whole rotation.
+	///
+	/// ```
+	/// use cvmath::{Deg, Quaternion, Vec3};
+	///
+	/// let q = Quaternion(0.5f64, 0.5, 0.5, 0.5);
+	/// let (twist, swing) = q.twist_swing(Vec3(0.0, 0.0, 2.0));
+	/// assert!((swing * twist - q).len() < 1e-12);
+	/// assert!((twist - Quaternion::rotation_z(Deg(90.0))).len() < 1e-12);
+	/// // The swing axis lies in the XY plane
+	/// assert!(swing.d.abs() < 1e-12);
+	/// ```
+	#[inline]
+	pub fn twist_swing(self, axis: Vec3<T>) -> (Quaternion<T>, Quaternion<T>) {
+		let axis = axis.normalize();
+		// Project the rotation axis onto the twist axis
+		let p = axis * axis.dot(Vec3 { x: self.b, y: self.c, z: self.d });
+		let twist = Quaternion { a: self.a, b: p.x, c: p.y, d: p.z };
+		let len = twist.len();
+		let twist = if len > T::EPSILON { twist * (T::ONE / len) } else { Quaternion::IDENTITY };
+		(twist, self * twist.conjugate())
+	}
+	/// Rotation matrix of a unit quaternion.
+	///
+	/// Transforming a vector with the matrix matches rotating it with the quaternion.
+	///
+	/// ```
+	/// use cvmath::{Quaternion, Vec3};
+	///
+	/// let q = Quaternion(0.5, 0.5, 0.5, 0.5);
+	/// assert_eq!(q.rotation_matrix() * Vec3(1.0, 0.0, 0.0), Vec3(0.0, 1.0, 0.0));
+	/// assert_eq!(q * Vec3(1.0, 0.0, 0.0), Vec3(0.0, 1.0, 0.0));
+	/// ```
+	#[inline]
+	pub fn rotation_matrix(self) -> Mat3<T> {
+		let Quaternion { a: w, b: x, c: y, d: z } = self;
+		let two = T::ONE + T::ONE;
+		Mat3 {
+			a11: T::ONE - two * (y * y + z * z), a12: two * (x * y - w * z), a13: two * (x * z + w * y),
+			a21: two * (x * y + w * z), a22: T::ONE - two * (x * x + z * z), a23: two * (y * z - w * x),
+			a31: two * (x * z - w * y), a32: two * (y * z + w * x), a33: T::ONE - two * (x * x + y * y),
+		}
+	}
+	/// Unit quaternion from a rotation matrix.
+	///
+	/// The matrix must be orthonormal with a positive determinant.
+	///
+	/// ```
+	/// use cvmath::{Mat3, Quaternion};
+	///
+	/// let q = Quaternion(0.5, 0.5, 0.5, 0.5);
+	/// assert_eq!(Quaternion::from_rotation_matrix(q.rotation_matrix()), q);
+	/// ```
+	#[inline]
+	pub fn from_rotation_matrix(m: Mat3<T>) -> Quaternion<T> {
+		let half = T::cast_from(0.5);
+		let quarter = T::cast_from(0.25);
+		let trace = m.a11 + m.a22 + m.a33;
+		// Pick the largest component to divide by for numerical stability
+		if trace > T::ZERO {
+			let s = (trace + T::ONE).sqrt() * half;
+			let f = quarter / s;
+			Quaternion { a: s, b: (m.a32 - m.a23) * f, c: (m.a13 - m.a31) * f, d: (m.a21 - m.a12) * f }
+		}
+		else if m.a11 > m.a22 && m.a11 > m.a33 {
+			let s = (T::ONE + m.a11 - m.a22 - m.a33).sqrt() * half;
+			let f = quarter / s;
+			Quaternion { a: (m.a32 - m.a23) * f, b: s, c: (m.a12 + m.a21) * f, d: (m.a13 + m.a31) * f }
+		}
+		else if m.a22 > m.a33 {
+			let s = (T::ONE + m.a22 - m.a11 - m.a33).sqrt() * half;
+			let f = quarter / s;
+			Quaternion { a: (m.a13 - m.a31) * f, b: (m.a12 + m.a21) * f, c: s, d: (m.a23 + m.a32) * f }
+		}
+		else {
+			let s = (T::ONE + m.a33 - m.a11 - m.a22).sqrt() * half;
+			let f = quarter / s;
+			Quaternion { a: (m.a21 - m.a12) * f, b: (m.a13 + m.a31) * f, c: (m.a23 + m.a32) * f, d: s }
+		}
+	}
+	/// Rotation which orients the +Z axis along the forward direction.
+	///
+	/// The roll around the forward direction is chosen such that the +Y axis is rotated towards the up vector, the vectors do not need to be normalized.
+	/// If the forward direction is parallel to the up vector a fallback up vector is used, +Z or +X when looking along the Z axis.
+	///
+	/// This is the object space rotation matching the framing of [`Mat4::look_at`] with [LH](Hand::LH), whose rotation is the inverse.
+	///
+	/// ```
+	/// use cvmath::{Mat4, Quaternion, Vec3, LH};
+	///
+	/// let q = Quaternion::look_rotation(Vec3(1.0, 0.0, 0.0), Vec3(0.0, 1.0, 0.0));
+	/// assert!((q * Vec3(0.0, 0.0, 1.0)).distance(Vec3(1.0, 0.0, 0.0)) < 1e-12);
+	/// assert!((q * Vec3(0.0, 1.0, 0.0)).distance(Vec3(0.0, 1.0, 0.0)) < 1e-12);
+	///
+	/// let view = Mat4::look_at(Vec3(0.0, 0.0, 0.0), Vec3(1.0, 0.0, 0.0), Vec3(0.0, 1.0, 0.0), LH);
+	/// assert!(view.transform_vector3(q * Vec3(0.0, 0.0, 1.0)).distance(Vec3(0.0, 0.0, 1.0)) < 1e-12);
+	/// ```
+	#[inline]
+	pub fn look_rotation(forward: Vec3<T>, up: Vec3<T>) -> Quaternion<T> {
+		let forward = forward.normalize();
+		let mut side = up.cross(forward);
+		if side.len_sqr() <= T::EPSILON * up.len_sqr() {
+			let fallback = if forward.z.abs() < T::cast_from(0.9) { Vec3::Z } else { Vec3::X };
+			side = fallback.cross(forward);
+		}
+		let side = side.normalize();
+		let up = forward.cross(side);
+		Quaternion::from_rotation_matrix(Mat3::compose(side, up, forward))
+	}
+	/// Dot product.
+	#[inline]
+	pub fn dot(self, rhs: Quaternion<T>) -> T {
+		self.a * rhs.a + self.b * rhs.b + self.c * rhs.c + self.d * rhs.d
+	}
+	/// Spherical interpolation between unit quaternions with constant angular velocity.
+	///
+	/// Takes the shortest path by flipping the sign of `rhs` if the quaternions are more than 180° apart.
+	///
+	/// ```
+	/// use cvmath::Quaternion;
+	///
+	/// let half = f64::sqrt(0.5);
+	/// let q = Quaternion::IDENTITY.slerp(Quaternion(0.0, 1.0, 0.0, 0.0), 0.5);
+	/// assert!((q - Quaternion(half, half, 0.0, 0.0)).len() < 1e-12);
+	/// ```
+	#[inline]
+	pub fn slerp(self, rhs: Quaternion<T>, t: T) -> Quaternion<T> {
+		let mut rhs = rhs;
+		let mut cos_theta = self.dot(rhs);
+		if cos_theta < T::ZERO {
+			rhs = -rhs;
+			cos_theta = -cos_theta;
+		}
+		// Nearly parallel quaternions fall back to normalized linear interpolation
+		if cos_theta > T::cast_from(0.9995) {
+			return (self + (rhs - self) * t).normalize();
+		}
+		let theta = cos_theta.acos();
+		let sin_theta = theta.sin();
+		self * (((T::ONE - t) * theta).sin() / sin_theta) + rhs * ((t * theta).sin() / sin_theta)
+	}
+	/// Exponential decay smoothing.
+	///
+	/// Frame-rate independent rotation towards the target along the shortest path.
+	/// Useful decay values range from approx 1.0 to 25.0, slow to fast.
+	///
+	/// ```
+	/// use cvmath::Quaternion;
+	///
+	/// struct Camera {
+	///     orientation: Quaternion<f32>,
+	///     target: Quaternion<f32>,
+	/// }
+	/// impl Camera {
+	///     fn update(&mut self, dt: f32) {
+	///         // Smoothly rotate towards the target.
+	///         self.orientation = self.orientation.exp_decay(self.target, 5.0, dt);
+	///     }
+	/// }
+	/// ```
+	#[inline]
+	pub fn exp_decay(self, target: Quaternion<T>, decay: T, dt: T) -> Quaternion<T> {
+		target.slerp(self, (-decay * dt).exp())
+	}
+	/// Average of unit quaternions.
+	///
+	/// The quaternions are flipped into the hemisphere of the first one before they are summed and normalized.
+	/// This approximates the eigenvector method and is accurate for clustered rotations, such as noisy samples of the same orientation.
+	///
+	/// Returns `None` if there are no quaternions.
+	///
+	/// ```
+	/// use cvmath::{Deg, Quaternion};
+	///
+	/// let a = Quaternion::rotation_z(Deg(10.0f64));
+	/// let b = -Quaternion::rotation_z(Deg(30.0f64));
+	/// let avg = Quaternion::average(&[a, b]).unwrap();
+	/// assert!((avg - Quaternion::rotation_z(Deg(20.0))).len() < 1e-12);
+	/// assert_eq!(Quaternion::<f64>::average(&[]), None);
+	/// ```
+	pub fn average(quats: &[Quaternion<T>]) -> Option<Quaternion<T>> {
+		let first = *quats.first()?;
+		let mut sum = Quaternion::ZERO;
+		for &q in quats {
+			sum += if first.dot(q) < T::ZERO { -q } else { q };
+		}
+		Some(sum.normalize())
+	}
+	/// Returns `true` if all the components are finite.
+	///
+	/// ```
+	/// use cvmath::Quaternion;
+	///
+	/// assert!(Quaternion::<f32>::IDENTITY.all_finite());
+	/// assert!(!Quaternion(1.0, f32::INFINITY, 0.0, 0.0).all_finite());
+	/// ```
+	#[inline]
+	pub fn all_finite(self) -> bool {
+		self.a.is_finite() && self.b.is_finite() && self.c.is_finite() && self.d.is_finite()
+	}
+	/// Returns `true` if the length of the quaternion is within `epsilon` of `1.0`.
+	///
+	/// Only unit quaternions represent rotations.
+	///
+	/// ```
+	/// use cvmath::Quaternion;
+	///
+	/// assert!(Quaternion::<f64>::IDENTITY.is_normalized(1e-12));
+	/// assert!(!Quaternion(1.0, 1.0, 0.0, 0.0).is_normalized(1e-12));
+	/// assert!(!Quaternion(f64::NAN, 0.0, 0.0, 0.0).is_normalized(1e-12));
+	/// ```
+	#[inline]
+	pub fn is_normalized(self, epsilon: T) -> bool {
+		(self.len() - T::ONE).abs() <= epsilon
+	}
+	/// Squared length error of the quaternion.
+	///
+	/// Returns `|q|² - 1`, zero for unit quaternions.
+	/// Cheaper than [`is_normalized`](Self::is_normalized) as it avoids the square root.
+	///
+	/// ```
+	/// use cvmath::Quaternion;
+	///
+	/// assert_eq!(Quaternion::<f64>::IDENTITY.norm_sqr_error(), 0.0);
+	/// assert_eq!(Quaternion(1.0, 1.0, 0.0, 0.0).norm_sqr_error(), 1.0);
+	/// ```
+	#[inline]
+	pub fn norm_sqr_error(self) -> T {
+		self.dot(self) - T::ONE
+	}
+	/// Renormalizes a nearly unit quaternion without a square root.
+	///
+	/// Scales by the first-order approximation `0.5 * (3 - |q|²)` of `1 / |q|`.
+	/// If `|q|² = 1 + e` the length afterwards is `1 - 3/8 e² + O(e³)`, eg. an error of `1e-4` shrinks to below `4e-9`.
+	/// Meant for periodic correction of drift, use [`normalize`](Self::normalize) for quaternions far from unit length.
+	///
+	/// ```
+	/// use cvmath::Quaternion;
+	///
+	/// let q = Quaternion(0.6f64, 0.8, 0.0, 0.0) * 1.0001;
+	/// assert!(q.norm_sqr_error() > 1e-4);
+	/// assert!(q.renormalize_fast().norm_sqr_error().abs() < 1e-7);
+	/// ```
+	#[inline]
+	pub fn renormalize_fast(self) -> Quaternion<T> {
+		let half = T::ONE / (T::ONE + T::ONE);
+		self * (half * (T::cast_from(3.0) - self.dot(self)))
+	}
+	/// Returns `true` if any of the components are NaN.
+	///
+	/// ```
+	/// use cvmath::Quaternion;
+	///
+	/// assert!(!Quaternion::<f32>::IDENTITY.any_nan());
+	/// assert!(Quaternion(1.0, 0.0, f32::NAN, 0.0).any_nan());
+	/// ```
+	#[inline]
+	pub fn any_nan(self) -> bool {
+		self.a.is_nan() || self.b.is_nan() || self.c.is_nan() || self.d.is_nan()
+	}
 }
 
 // Addition
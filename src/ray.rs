@@ -3,6 +3,8 @@ use super::*;
 /// Ray structure.
 ///
 /// Rays are used to trace shapes in 3D space, see [`trace`](Ray::trace).
+///
+/// To trace instanced shapes transform the ray into object space instead of transforming the shape, see [`transformed`](Ray::transformed).
 #[derive(Copy, Clone, Debug, Eq, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[repr(C)]
@@ -20,6 +22,10 @@ pub fn Ray<T>(origin: Point3<T>, direction: Vec3<T>) -> Ray<T> {
 
 #[cfg(feature = "dataview")]
 unsafe impl<T: dataview::Pod> dataview::Pod for Ray<T> {}
+#[cfg(feature = "bytemuck")]
+unsafe impl<T: bytemuck::Zeroable> bytemuck::Zeroable for Ray<T> {}
+#[cfg(feature = "bytemuck")]
+unsafe impl<T: bytemuck::Pod> bytemuck::Pod for Ray<T> {}
 
 impl<T> Ray<T> {
 	/// Constructs a new ray.
@@ -29,13 +35,16 @@ impl<T> Ray<T> {
 	}
 }
 
+/// Transforms the origin as a point and the direction as a vector without translation.
+///
+/// The direction is not normalized, see [`Ray::transformed`](Ray::transformed).
 impl<T: Copy + ops::Add<Output = T> + ops::Mul<Output = T>> ops::Mul<Ray<T>> for Transform3<T> {
 	type Output = Ray<T>;
 	#[inline]
 	fn mul(self, ray: Ray<T>) -> Ray<T> {
 		Ray {
 			origin: self * ray.origin,
-			direction: self * ray.direction,
+			direction: self.mat3() * ray.direction,
 		}
 	}
 }
@@ -49,6 +58,25 @@ pub struct TraceHit<T> {
 	pub normal: Vec3<T>,
 }
 
+/// Ray bounce structure.
+///
+/// See [`Ray::bounce`](Ray::bounce) for more information.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Bounce<T> {
+	/// The reflected ray starting at the intersection point.
+	pub ray: Ray<T>,
+	/// The segment traveled from the ray origin to the intersection point.
+	pub segment: Line3<T>,
+	/// Angle between the incoming ray and the hit normal.
+	///
+	/// For rays leaving a shape from inside the normal is flipped to face the ray, the angle is never more than a quarter turn.
+	///
+	/// The angle of reflection between the hit normal and the reflected ray is equal.
+	pub incident: Rad<T>,
+	/// Length of the segment traveled.
+	pub distance: T,
+}
+
 /// Shapes that can be traced by a ray.
 pub trait TraceRay<T> {
 	/// Returns if the ray starts inside the shape.
@@ -60,13 +88,132 @@ pub trait TraceRay<T> {
 	fn trace(&self, ray: &Ray<T>, hits: &mut [TraceHit<T>]) -> usize;
 }
 
+// Writes the entry and exit hits of a convex shape given all the hits with its boundary surfaces.
+// Duplicate hits where surfaces meet, eg. at the edge of a cap, collapse into the entry and exit.
+pub(crate) fn convex_hits<T: Float>(candidates: &[TraceHit<T>], hits: &mut [TraceHit<T>]) -> usize {
+	let Some(&first) = candidates.first() else {
+		return 0;
+	};
+	let (mut entry, mut exit) = (first, first);
+	for &hit in &candidates[1..] {
+		if hit.distance < entry.distance {
+			entry = hit;
+		}
+		if hit.distance > exit.distance {
+			exit = hit;
+		}
+	}
+
+	let mut count = 0;
+	for hit in [entry, exit] {
+		if hit.distance >= T::ZERO {
+			if hits.len() > count {
+				hits[count] = hit;
+			}
+			count += 1;
+		}
+	}
+	count
+}
+
 impl<T: Float> Ray<T> {
+	/// Returns `true` if all the components are finite.
+	///
+	/// ```
+	/// use cvmath::{Point3, Ray, Vec3};
+	///
+	/// assert!(Ray(Point3(0.0, 0.0, 0.0), Vec3(0.0, 0.0, 1.0)).is_finite());
+	/// assert!(!Ray(Point3(0.0, 0.0, 0.0), Vec3(f64::NAN, 0.0, 1.0)).is_finite());
+	/// ```
+	#[inline]
+	pub fn is_finite(&self) -> bool {
+		self.origin.all_finite() && self.direction.all_finite()
+	}
+
+	/// Ray from one point towards another.
+	///
+	/// Returns the ray with normalized direction and the distance between the points.
+	/// Hits beyond this distance lie past the target, ideal for shadow rays towards a light.
+	/// Coincident points result in a zero direction and distance.
+	///
+	/// ```
+	/// use cvmath::{Point3, Ray, Vec3};
+	///
+	/// let (ray, distance) = Ray::between(Point3(1.0, 2.0, 3.0), Point3(1.0, 2.0, 8.0));
+	/// assert_eq!(ray, Ray(Point3(1.0, 2.0, 3.0), Vec3(0.0, 0.0, 1.0)));
+	/// assert_eq!(distance, 5.0);
+	/// assert_eq!(ray.at(distance), Point3(1.0, 2.0, 8.0));
+	/// ```
+	#[inline]
+	pub fn between(from: Point3<T>, to: Point3<T>) -> (Ray<T>, T) {
+		let (direction, distance) = (to - from).normalize_len();
+		(Ray { origin: from, direction }, distance)
+	}
+
+	/// Ray looking in a direction.
+	///
+	/// The direction is normalized, returns `None` if the direction is zero or not finite.
+	///
+	/// ```
+	/// use cvmath::{Point3, Ray, Vec3};
+	///
+	/// let ray = Ray::look(Point3(0.0, 0.0, 0.0), Vec3(3.0, 0.0, 4.0)).unwrap();
+	/// assert_eq!(ray.direction, Vec3(0.6, 0.0, 0.8));
+	/// assert_eq!(Ray::look(Point3(0.0, 0.0, 0.0), Vec3(0.0, 0.0, 0.0)), None);
+	/// ```
+	#[inline]
+	pub fn look(origin: Point3<T>, direction: Vec3<T>) -> Option<Ray<T>> {
+		let (direction, len) = direction.normalize_len();
+		if !(len > T::ZERO && len.is_finite()) {
+			return None;
+		}
+		Some(Ray { origin, direction })
+	}
+
 	/// Returns the point at a distance along the ray.
 	#[inline]
 	pub fn at(&self, distance: T) -> Point3<T> {
 		self.origin + self.direction * distance
 	}
 
+	/// Returns the segment from the origin to the point at a distance along the ray.
+	///
+	/// Useful to visualize rays clipped to a maximum distance or to the closest hit.
+	///
+	/// ```
+	/// use cvmath::{Line3, Point3, Ray};
+	///
+	/// let (ray, distance) = Ray::between(Point3(0.0, 0.0, 0.0), Point3(0.0, 4.0, 0.0));
+	/// assert_eq!(ray.segment(distance), Line3(Point3(0.0, 0.0, 0.0), Point3(0.0, 4.0, 0.0)));
+	/// ```
+	#[inline]
+	pub fn segment(&self, distance: T) -> Line3<T> {
+		Line3 { start: self.origin, end: self.at(distance) }
+	}
+
+	/// Transforms the ray and normalizes its direction.
+	///
+	/// Returns the transformed ray and the factor by which the transform scaled the direction.
+	/// Distances along the original ray multiplied by this factor give distances along the transformed ray.
+	/// To map hit distances in the transformed space back to the original space, divide them by the factor.
+	///
+	/// ```
+	/// use cvmath::{Point3, Ray, Transform3, Vec3};
+	///
+	/// let ray = Ray(Point3(1.0, 0.0, 0.0), Vec3(0.0, 1.0, 0.0));
+	/// let transform = Transform3::translate(Vec3(0.0, 0.0, 5.0)) * Transform3::scale(Vec3(1.0, 2.0, 1.0));
+	/// let (local, scale) = ray.transformed(&transform);
+	/// assert_eq!(local, Ray(Point3(1.0, 0.0, 5.0), Vec3(0.0, 1.0, 0.0)));
+	/// assert_eq!(scale, 2.0);
+	/// assert_eq!(ray.at(3.0), transform.inverse() * local.at(3.0 * scale));
+	/// ```
+	#[inline]
+	pub fn transformed(&self, m: &Transform3<T>) -> (Ray<T>, T) {
+		let ray = *m * *self;
+		let (direction, scale) = ray.direction.normalize_len();
+		(Ray { origin: ray.origin, direction }, scale)
+	}
+
 	/// Returns if the ray starts inside the shape.
 	#[inline]
 	pub fn inside<U: TraceRay<T>>(&self, shape: &U) -> bool {
@@ -83,4 +230,64 @@ impl<T: Float> Ray<T> {
 	pub fn trace<U: TraceRay<T>>(&self, shape: &U, hits: &mut [TraceHit<T>]) -> usize {
 		shape.trace(self, hits)
 	}
+
+	/// Reflects the ray at the hit.
+	///
+	/// The reflected ray starts at the intersection point and keeps the length of the direction.
+	/// The orientation of the hit normal does not matter, rays exiting a shape from inside reflect back into the shape.
+	///
+	/// ```
+	/// use cvmath::{Plane, Point3, Ray, TraceHit, Vec3};
+	///
+	/// let plane = Plane(Vec3(0.0, 0.0, 1.0), 0.0);
+	/// let ray = Ray(Point3(-2.0, 0.0, 2.0), Vec3(1.0, 0.0, -1.0));
+	///
+	/// let mut hits = [TraceHit::default(); 1];
+	/// assert_eq!(ray.trace(&plane, &mut hits), 1);
+	/// assert_eq!(ray.reflect(&hits[0]), Ray(Point3(0.0, 0.0, 0.0), Vec3(1.0, 0.0, 1.0)));
+	/// ```
+	#[inline]
+	pub fn reflect(&self, hit: &TraceHit<T>) -> Ray<T> {
+		Ray {
+			origin: self.at(hit.distance),
+			direction: -self.direction.reflect(hit.normal),
+		}
+	}
+
+	/// Reflects the ray at the hit and perturbs the reflected direction.
+	///
+	/// The callback receives the perfectly reflected direction and returns the direction of the new ray, eg. to jitter it for rough surfaces.
+	#[inline]
+	pub fn reflect_with<F: FnOnce(Vec3<T>) -> Vec3<T>>(&self, hit: &TraceHit<T>, perturb: F) -> Ray<T> {
+		let ray = self.reflect(hit);
+		Ray { origin: ray.origin, direction: perturb(ray.direction) }
+	}
+
+	/// Bounces the ray at the hit.
+	///
+	/// Returns the reflected ray along with the segment traveled to get there.
+	///
+	/// ```
+	/// use cvmath::{Plane, Point3, Ray, TraceHit, Vec3};
+	///
+	/// let plane = Plane(Vec3(0.0, 0.0, 1.0), 0.0);
+	/// let ray = Ray(Point3(-2.0, 0.0, 2.0), Vec3(1.0, 0.0, -1.0));
+	///
+	/// let mut hits = [TraceHit::default(); 1];
+	/// ray.trace(&plane, &mut hits);
+	/// let bounce = ray.bounce(&hits[0]);
+	///
+	/// assert_eq!(bounce.segment.end, Point3(0.0, 0.0, 0.0));
+	/// assert_eq!(bounce.distance, 8.0f64.sqrt());
+	/// assert!((bounce.incident.to_deg().value - 45.0).abs() < 1e-12);
+	/// ```
+	#[inline]
+	pub fn bounce(&self, hit: &TraceHit<T>) -> Bounce<T> {
+		let ray = self.reflect(hit);
+		let segment = Line3 { start: self.origin, end: ray.origin };
+		let normal = if self.direction.dot(hit.normal) > T::ZERO { -hit.normal } else { hit.normal };
+		let incident = (-self.direction).angle(normal);
+		let distance = hit.distance * self.direction.len();
+		Bounce { ray, segment, incident, distance }
+	}
 }
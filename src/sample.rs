@@ -0,0 +1,176 @@
+/*!
+Sampling warps.
+
+Maps uniformly distributed samples in the `[0, 1)` range to points distributed over various shapes.
+Pair these with a random number generator of your choice.
+*/
+
+use super::*;
+
+impl<T: Float> Vec2<T> {
+	/// Uniformly distributed unit vector.
+	///
+	/// ```
+	/// use cvmath::Vec2;
+	///
+	/// let v = Vec2::sample_unit_circle(0.25f64);
+	/// assert!(v.distance(Vec2(0.0, 1.0)) < 1e-12);
+	/// ```
+	#[inline]
+	pub fn sample_unit_circle(u: T) -> Vec2<T> {
+		let (sin, cos) = (T::cast_from(core::f64::consts::TAU) * u).sin_cos();
+		Vec2 { x: cos, y: sin }
+	}
+	/// Uniformly distributed point inside the unit disk.
+	///
+	/// ```
+	/// use cvmath::Vec2;
+	///
+	/// let v = Vec2::sample_unit_disk(Vec2(0.25f64, 0.5));
+	/// assert!(v.distance(Vec2(-0.5, 0.0)) < 1e-12);
+	/// ```
+	#[inline]
+	pub fn sample_unit_disk(u: Vec2<T>) -> Vec2<T> {
+		Vec2::sample_unit_circle(u.y) * u.x.sqrt()
+	}
+}
+
+impl<T: Float> Vec3<T> {
+	/// Uniformly distributed unit vector.
+	///
+	/// ```
+	/// use cvmath::{Vec2, Vec3};
+	///
+	/// let v = Vec3::sample_unit_sphere(Vec2(0.0f64, 0.0));
+	/// assert_eq!(v, Vec3(0.0, 0.0, 1.0));
+	/// ```
+	#[inline]
+	pub fn sample_unit_sphere(u: Vec2<T>) -> Vec3<T> {
+		let z = T::ONE - (u.x + u.x);
+		let r = (T::ONE - z * z).max(T::ZERO).sqrt();
+		let xy = Vec2::sample_unit_circle(u.y) * r;
+		Vec3 { x: xy.x, y: xy.y, z }
+	}
+	/// Uniformly distributed point inside the unit ball.
+	///
+	/// ```
+	/// use cvmath::Vec3;
+	///
+	/// let v = Vec3::sample_unit_ball(Vec3(0.5f64, 0.0, 0.125));
+	/// assert!(v.distance(Vec3(0.5, 0.0, 0.0)) < 1e-12);
+	/// ```
+	#[inline]
+	pub fn sample_unit_ball(u: Vec3<T>) -> Vec3<T> {
+		let third = T::ONE / T::cast_from(3.0);
+		Vec3::sample_unit_sphere(Vec2(u.x, u.y)) * u.z.powf(third)
+	}
+	/// Cosine-weighted unit vector in the hemisphere around the normal.
+	///
+	/// The normal must be normalized.
+	/// The probability density is `cos(θ) / π` where `θ` is the angle with the normal.
+	///
+	/// ```
+	/// use cvmath::{Vec2, Vec3};
+	///
+	/// let normal = Vec3(0.0f64, 1.0, 0.0);
+	/// let v = Vec3::sample_cosine_hemisphere(normal, Vec2(0.0, 0.0));
+	/// assert!(v.distance(normal) < 1e-12);
+	/// ```
+	#[inline]
+	pub fn sample_cosine_hemisphere(normal: Vec3<T>, u: Vec2<T>) -> Vec3<T> {
+		let disk = Vec2::sample_unit_disk(u);
+		let z = (T::ONE - disk.len_sqr()).max(T::ZERO).sqrt();
+
+		// Orthonormal basis around the normal (Duff et al. 2017)
+		let sign = if normal.z >= T::ZERO { T::ONE } else { -T::ONE };
+		let a = -T::ONE / (sign + normal.z);
+		let b = normal.x * normal.y * a;
+		let tangent = Vec3(T::ONE + sign * normal.x * normal.x * a, sign * b, -sign * normal.x);
+		let bitangent = Vec3(b, sign + normal.y * normal.y * a, -normal.y);
+
+		tangent * disk.x + bitangent * disk.y + normal * z
+	}
+}
+
+impl<T: Float> Triangle2<T> {
+	/// Uniformly distributed point inside the triangle.
+	///
+	/// ```
+	/// use cvmath::{Point2, Triangle2, Vec2};
+	///
+	/// let triangle = Triangle2(Point2(0.0f64, 0.0), Point2(2.0, 0.0), Point2(0.0, 2.0));
+	/// assert_eq!(triangle.sample(Vec2(0.0, 0.5)), triangle.p0);
+	/// assert_eq!(triangle.sample(Vec2(1.0, 0.0)), triangle.p1);
+	/// assert_eq!(triangle.sample(Vec2(1.0, 1.0)), triangle.p2);
+	/// assert_eq!(triangle.sample(Vec2(0.25, 0.5)), Point2(0.5, 0.5));
+	/// ```
+	#[inline]
+	pub fn sample(&self, u: Vec2<T>) -> Point2<T> {
+		let s = u.x.sqrt();
+		self.p0 * (T::ONE - s) + self.p1 * (s * (T::ONE - u.y)) + self.p2 * (s * u.y)
+	}
+}
+
+impl<T: Float> Triangle3<T> {
+	/// Uniformly distributed point inside the triangle.
+	///
+	/// ```
+	/// use cvmath::{Point3, Triangle3, Vec2};
+	///
+	/// let triangle = Triangle3(Point3(0.0f64, 0.0, 0.0), Point3(1.0, 0.0, 0.0), Point3(0.0, 1.0, 0.0));
+	/// assert_eq!(triangle.sample(Vec2(0.0, 0.5)), triangle.p0);
+	/// assert_eq!(triangle.sample(Vec2(1.0, 0.0)), triangle.p1);
+	/// assert_eq!(triangle.sample(Vec2(1.0, 1.0)), triangle.p2);
+	/// ```
+	#[inline]
+	pub fn sample(&self, u: Vec2<T>) -> Point3<T> {
+		let s = u.x.sqrt();
+		self.p0 * (T::ONE - s) + self.p1 * (s * (T::ONE - u.y)) + self.p2 * (s * u.y)
+	}
+}
+
+impl<T: Float> Sphere<T> {
+	/// Uniformly distributed point inside the sphere.
+	///
+	/// ```
+	/// use cvmath::{Point3, Sphere, Vec3};
+	///
+	/// let sphere = Sphere(Point3(1.0f64, 2.0, 3.0), 2.0);
+	/// let pt = sphere.sample(Vec3(0.5, 0.0, 0.125));
+	/// assert!(pt.distance(Point3(2.0, 2.0, 3.0)) < 1e-12);
+	/// ```
+	#[inline]
+	pub fn sample(&self, u: Vec3<T>) -> Point3<T> {
+		self.center + Vec3::sample_unit_ball(u) * self.radius
+	}
+}
+
+impl<T: Float> Rect<T> {
+	/// Uniformly distributed point inside the rectangle.
+	///
+	/// ```
+	/// use cvmath::{Point2, Rect, Vec2};
+	///
+	/// let rect = Rect(Point2(1.0, 2.0), Point2(3.0, 6.0));
+	/// assert_eq!(rect.sample(Vec2(0.5, 0.25)), Point2(2.0, 3.0));
+	/// ```
+	#[inline]
+	pub fn sample(&self, u: Vec2<T>) -> Point2<T> {
+		self.mins + (self.maxs - self.mins) * u
+	}
+}
+
+impl<T: Float> Cuboid<T> {
+	/// Uniformly distributed point inside the cuboid.
+	///
+	/// ```
+	/// use cvmath::{Cuboid, Point3, Vec3};
+	///
+	/// let cuboid = Cuboid(Point3(1.0, 2.0, 3.0), Point3(3.0, 6.0, 4.0));
+	/// assert_eq!(cuboid.sample(Vec3(0.5, 0.25, 1.0)), Point3(2.0, 3.0, 4.0));
+	/// ```
+	#[inline]
+	pub fn sample(&self, u: Vec3<T>) -> Point3<T> {
+		self.mins + (self.maxs - self.mins) * u
+	}
+}
@@ -0,0 +1,202 @@
+/*!
+Scalar utility functions.
+
+Interpolation, remapping and wrapping of single values.
+The vector types provide component-wise versions of several of these as methods, eg. [`Vec3::lerp`] and [`Vec3::smoothstep`].
+
+The argument order follows GLSL where it applies, the value being tested comes last.
+*/
+
+use super::*;
+
+/// Linear interpolation between `a` and `b`.
+///
+/// The parameter `t` is not clamped, values outside `[0, 1]` extrapolate.
+///
+/// ```
+/// use cvmath::scalar::lerp;
+///
+/// assert_eq!(lerp(10.0, 20.0, 0.25), 12.5);
+/// assert_eq!(lerp(10.0, 20.0, 1.5), 25.0);
+/// ```
+#[inline]
+pub fn lerp<T: Scalar>(a: T, b: T, t: T) -> T {
+	a + (b - a) * t
+}
+
+/// Inverse of [`lerp`], the parameter at which `x` lies between `a` and `b`.
+///
+/// The result is not clamped.
+/// Returns zero if `a` and `b` are equal.
+///
+/// ```
+/// use cvmath::scalar::inv_lerp;
+///
+/// assert_eq!(inv_lerp(10.0, 20.0, 12.5), 0.25);
+/// assert_eq!(inv_lerp(10.0, 20.0, 5.0), -0.5);
+/// assert_eq!(inv_lerp(10.0, 10.0, 12.5), 0.0);
+/// ```
+#[inline]
+pub fn inv_lerp<T: Float>(a: T, b: T, x: T) -> T {
+	let range = b - a;
+	if range == T::ZERO {
+		return T::ZERO;
+	}
+	(x - a) / range
+}
+
+/// Remaps `x` from the input range to the output range.
+///
+/// The result is not clamped.
+/// Returns `out_lo` if the input range is empty.
+///
+/// ```
+/// use cvmath::scalar::remap;
+///
+/// // Celsius to Fahrenheit
+/// assert_eq!(remap(0.0, 100.0, 32.0, 212.0, 37.0), 98.6);
+/// assert_eq!(remap(0.0, 0.0, 32.0, 212.0, 37.0), 32.0);
+/// ```
+#[inline]
+pub fn remap<T: Float>(in_lo: T, in_hi: T, out_lo: T, out_hi: T, x: T) -> T {
+	lerp(out_lo, out_hi, inv_lerp(in_lo, in_hi, x))
+}
+
+/// Step function.
+///
+/// Returns zero if `x` is less than the edge and one otherwise.
+///
+/// ```
+/// use cvmath::scalar::step;
+///
+/// assert_eq!(step(0.5, 0.2), 0.0);
+/// assert_eq!(step(0.5, 0.5), 1.0);
+/// ```
+#[inline]
+pub fn step<T: Scalar>(edge: T, x: T) -> T {
+	if x < edge { T::ZERO } else { T::ONE }
+}
+
+/// Clamps the value to the range `[0, 1]`.
+///
+/// ```
+/// use cvmath::scalar::clamp01;
+///
+/// assert_eq!(clamp01(-0.5), 0.0);
+/// assert_eq!(clamp01(0.5), 0.5);
+/// assert_eq!(clamp01(1.5), 1.0);
+/// ```
+#[inline]
+pub fn clamp01<T: Scalar>(x: T) -> T {
+	x.clamp(T::ZERO, T::ONE)
+}
+
+/// Smooth Hermite interpolation between zero and one.
+///
+/// Clamps `x` to the range between the edges and smoothly interpolates with `t * t * (3 - 2 * t)`.
+/// If the edges are equal the result is a [`step`] at the edge.
+///
+/// ```
+/// use cvmath::scalar::smoothstep;
+///
+/// assert_eq!(smoothstep(0.0, 1.0, -1.0), 0.0);
+/// assert_eq!(smoothstep(0.0, 1.0, 0.5), 0.5);
+/// assert_eq!(smoothstep(0.0, 1.0, 0.25), 0.15625);
+/// assert_eq!(smoothstep(1.0, 1.0, 1.0), 1.0);
+/// ```
+#[inline]
+pub fn smoothstep<T: Float>(edge0: T, edge1: T, x: T) -> T {
+	if edge0 == edge1 {
+		return step(edge0, x);
+	}
+	let t = clamp01((x - edge0) / (edge1 - edge0));
+	t * t * (T::cast_from(3.0) - T::cast_from(2.0) * t)
+}
+
+/// Smoother interpolation between zero and one.
+///
+/// Like [`smoothstep`] with zero first and second derivatives at the edges using `t * t * t * (t * (6 * t - 15) + 10)`.
+///
+/// ```
+/// use cvmath::scalar::smootherstep;
+///
+/// assert_eq!(smootherstep(0.0, 1.0, 0.5), 0.5);
+/// assert_eq!(smootherstep(1.0, 3.0, 4.0), 1.0);
+/// ```
+#[inline]
+pub fn smootherstep<T: Float>(edge0: T, edge1: T, x: T) -> T {
+	if edge0 == edge1 {
+		return step(edge0, x);
+	}
+	let t = clamp01((x - edge0) / (edge1 - edge0));
+	t * t * t * (t * (T::cast_from(6.0) * t - T::cast_from(15.0)) + T::cast_from(10.0))
+}
+
+/// Wraps the value into the range `[lo, hi)`.
+///
+/// Values equal to `hi` wrap around to `lo`, negative values wrap from the top.
+/// Returns `lo` if the range is empty.
+///
+/// ```
+/// use cvmath::scalar::wrap;
+///
+/// assert_eq!(wrap(370.0, 0.0, 360.0), 10.0);
+/// assert_eq!(wrap(-10.0, 0.0, 360.0), 350.0);
+/// assert_eq!(wrap(360.0, 0.0, 360.0), 0.0);
+/// assert_eq!(wrap(0.0, 0.0, 360.0), 0.0);
+/// assert_eq!(wrap(5.0, 1.0, 3.0), 1.0);
+/// ```
+#[inline]
+pub fn wrap<T: Float>(x: T, lo: T, hi: T) -> T {
+	let range = hi - lo;
+	if range == T::ZERO {
+		return lo;
+	}
+	let offset = x - lo;
+	let result = lo + (offset - (offset / range).floor() * range);
+	// Tiny negative offsets round up to hi
+	if result >= hi { lo } else { result }
+}
+
+/// Bounces the value back and forth between zero and `len`.
+///
+/// Increasing `x` moves the result up to `len` and back down to zero repeatedly.
+///
+/// ```
+/// use cvmath::scalar::ping_pong;
+///
+/// assert_eq!(ping_pong(0.5, 2.0), 0.5);
+/// assert_eq!(ping_pong(3.0, 2.0), 1.0);
+/// assert_eq!(ping_pong(4.0, 2.0), 0.0);
+/// assert_eq!(ping_pong(-0.5, 2.0), 0.5);
+/// ```
+#[inline]
+pub fn ping_pong<T: Float>(x: T, len: T) -> T {
+	let t = wrap(x, T::ZERO, len + len);
+	len - (t - len).abs()
+}
+
+/// Moves the current value towards the target by at most `max_delta`.
+///
+/// Never overshoots the target.
+///
+/// ```
+/// use cvmath::scalar::move_towards;
+///
+/// assert_eq!(move_towards(0.0, 10.0, 3.0), 3.0);
+/// assert_eq!(move_towards(0.0, -10.0, 3.0), -3.0);
+/// assert_eq!(move_towards(9.0, 10.0, 3.0), 10.0);
+/// ```
+#[inline]
+pub fn move_towards<T: Scalar>(current: T, target: T, max_delta: T) -> T {
+	let delta = target - current;
+	if delta.abs() <= max_delta {
+		target
+	}
+	else if delta > T::ZERO {
+		current + max_delta
+	}
+	else {
+		current - max_delta
+	}
+}
@@ -0,0 +1,154 @@
+/*!
+Screen space bounds.
+
+Projects 3D shapes with a view-projection matrix and returns their bounding rectangle in viewport pixels, eg. for selection rectangles or health bars above objects.
+
+The viewport coordinates have the y axis pointing down matching [`Mat4::screen`] and [`Mat4::unproject`].
+The rectangles are not clipped to the viewport, shapes partially outside the screen extend beyond its edges.
+
+Shapes are clipped against the `z = -w` clip space plane before projecting.
+This is the near plane of `Clip::NO` projections, for `Clip::ZO` projections the plane lies between the camera and the near plane making the bounds slightly conservative.
+Either way points behind the camera never wrap around the screen.
+*/
+
+use super::*;
+
+impl<T: Float> Cuboid<T> {
+	/// Screen space bounding rectangle of the cuboid.
+	///
+	/// Returns `None` if the cuboid is entirely behind the camera.
+	///
+	/// ```
+	/// use cvmath::{Cuboid, Deg, Mat4, Point2, Point3, Rect, LH, NO};
+	///
+	/// let viewport = Rect(Point2(0.0, 0.0), Point2(800.0, 600.0));
+	/// let view_proj = Mat4::perspective(Deg(90.0), 800.0 / 600.0, 0.1, 100.0, (LH, NO));
+	///
+	/// let cuboid = Cuboid(Point3(-1.0, -1.0, 10.0), Point3(1.0, 1.0, 12.0));
+	/// let rect = cuboid.project_bounds(&view_proj, viewport).unwrap();
+	/// assert!((rect.center().x - 400.0f64).abs() < 1e-9);
+	/// assert!((rect.center().y - 300.0f64).abs() < 1e-9);
+	///
+	/// let behind = Cuboid(Point3(-1.0, -1.0, -12.0), Point3(1.0, 1.0, -10.0));
+	/// assert_eq!(behind.project_bounds(&view_proj, viewport), None);
+	/// ```
+	#[inline]
+	pub fn project_bounds(&self, view_proj: &Mat4<T>, viewport: Rect<T>) -> Option<Rect<T>> {
+		project_clipped(view_proj, viewport, &self.corners(), &self.edges())
+	}
+}
+
+impl<T: Float> Triangle3<T> {
+	/// Screen space bounding rectangle of the triangle.
+	///
+	/// Returns `None` if the triangle is entirely behind the camera.
+	///
+	/// ```
+	/// use cvmath::{Deg, Mat4, Point2, Point3, Rect, Triangle3, LH, NO};
+	///
+	/// let viewport = Rect(Point2(0.0, 0.0), Point2(800.0, 800.0));
+	/// let view_proj = Mat4::perspective(Deg(90.0), 1.0, 0.1, 100.0, (LH, NO));
+	///
+	/// let triangle = Triangle3(Point3(0.0, 0.0, 10.0), Point3(10.0, 0.0, 10.0), Point3(0.0, 10.0, 10.0));
+	/// let rect = triangle.project_bounds(&view_proj, viewport).unwrap();
+	/// assert!(rect.mins.distance(Point2(400.0, 0.0)) < 1e-9);
+	/// assert!(rect.maxs.distance(Point2(800.0, 400.0)) < 1e-9);
+	/// ```
+	#[inline]
+	pub fn project_bounds(&self, view_proj: &Mat4<T>, viewport: Rect<T>) -> Option<Rect<T>> {
+		project_clipped(view_proj, viewport, &[self.p0, self.p1, self.p2], &[[0, 1], [1, 2], [2, 0]])
+	}
+}
+
+impl<T: Float> Sphere<T> {
+	/// Screen space bounding rectangle of the sphere.
+	///
+	/// Computes the exact bounds of the sphere's projected outline, which is a conic.
+	/// Spheres straddling the clip plane fall back to the bounds of their enclosing cuboid.
+	///
+	/// Returns `None` if the sphere is entirely behind the camera.
+	///
+	/// ```
+	/// use cvmath::{Deg, Mat4, Point2, Point3, Rect, Sphere, LH, NO};
+	///
+	/// let viewport = Rect(Point2(0.0, 0.0), Point2(800.0, 800.0));
+	/// let view_proj = Mat4::perspective(Deg(90.0), 1.0, 0.1, 100.0, (LH, NO));
+	///
+	/// // The tangent lines from the eye to the sphere are at 45°
+	/// let sphere = Sphere(Point3(0.0, 0.0, 2.0f64.sqrt()), 1.0);
+	/// let rect = sphere.project_bounds(&view_proj, viewport).unwrap();
+	/// assert!(rect.mins.distance(Point2(0.0, 0.0)) < 1e-9);
+	/// assert!(rect.maxs.distance(Point2(800.0, 800.0)) < 1e-9);
+	/// ```
+	pub fn project_bounds(&self, view_proj: &Mat4<T>, viewport: Rect<T>) -> Option<Rect<T>> {
+		let m = view_proj;
+		let r1 = Vec4(m.a11, m.a12, m.a13, m.a14);
+		let r2 = Vec4(m.a21, m.a22, m.a23, m.a24);
+		let r4 = Vec4(m.a41, m.a42, m.a43, m.a44);
+		let plane = Vec4(m.a31, m.a32, m.a33, m.a34) + r4;
+		let center = Vec4::from_point3(self.center);
+
+		let dist = plane.dot(center) / plane.xyz().len();
+		if dist < -self.radius {
+			return None;
+		}
+		if dist < self.radius {
+			let extent = Vec3::dup(self.radius);
+			return Cuboid(self.center - extent, self.center + extent).project_bounds(view_proj, viewport);
+		}
+
+		// Projected dual quadric of the sphere, the tangent planes x = X w satisfy c11 - 2 c14 X + c44 X² = 0
+		let radius_sqr = self.radius * self.radius;
+		let c = |a: Vec4<T>, b: Vec4<T>| a.dot(center) * b.dot(center) - radius_sqr * a.xyz().dot(b.xyz());
+		let c44 = c(r4, r4);
+		let roots = |row: Vec4<T>| {
+			let (cii, ci4) = (c(row, row), c(row, r4));
+			let sqrt = (ci4 * ci4 - cii * c44).max(T::ZERO).sqrt();
+			((ci4 - sqrt) / c44, (ci4 + sqrt) / c44)
+		};
+		let (x0, x1) = roots(r1);
+		let (y0, y1) = roots(r2);
+		Some(Rect(ndc_to_viewport(Vec2(x0, y1), viewport), ndc_to_viewport(Vec2(x1, y0), viewport)))
+	}
+}
+
+#[inline]
+fn ndc_to_viewport<T: Float>(ndc: Vec2<T>, viewport: Rect<T>) -> Point2<T> {
+	let half = T::ONE / (T::ONE + T::ONE);
+	Point2 {
+		x: viewport.left() + (ndc.x + T::ONE) * half * viewport.width(),
+		y: viewport.top() + (T::ONE - ndc.y) * half * viewport.height(),
+	}
+}
+
+// Projects the points and the crossings of the edges with the clip plane, skipping the clipped points
+fn project_clipped<T: Float>(view_proj: &Mat4<T>, viewport: Rect<T>, corners: &[Point3<T>], edges: &[[usize; 2]]) -> Option<Rect<T>> {
+	let clip = |pt: Point3<T>| *view_proj * Vec4::from_point3(pt);
+	let dist = |p: Vec4<T>| p.z + p.w;
+
+	let mut bounds: Option<Rect<T>> = None;
+	let mut include = |p: Vec4<T>| {
+		if p.w > T::ZERO {
+			let pt = ndc_to_viewport(Vec2(p.x / p.w, p.y / p.w), viewport);
+			bounds = Some(match bounds {
+				Some(bounds) => bounds.include(pt),
+				None => Rect(pt, pt),
+			});
+		}
+	};
+
+	for &pt in corners {
+		let p = clip(pt);
+		if dist(p) >= T::ZERO {
+			include(p);
+		}
+	}
+	for &[a, b] in edges {
+		let (a, b) = (clip(corners[a]), clip(corners[b]));
+		let (da, db) = (dist(a), dist(b));
+		if (da < T::ZERO) != (db < T::ZERO) {
+			include(a + (b - a) * (da / (da - db)));
+		}
+	}
+	bounds
+}
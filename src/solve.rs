@@ -0,0 +1,173 @@
+/*!
+Polynomial root solvers.
+
+Shared by the ray tracing of curved shapes, which reduces to finding the roots of a polynomial in the distance along the ray.
+*/
+
+use super::*;
+
+/// Real roots of the quadratic `a x² + b x + c = 0`.
+///
+/// Returns the roots in ascending order, a double root is returned twice.
+/// Returns `None` if there are no real roots.
+/// If `a` is zero the single root of the linear equation is returned twice.
+///
+/// Uses the numerically stable formulation `q = -(b + sign(b) √(b² - 4ac)) / 2` with roots `q / a` and `c / q`.
+/// The naive formula `(-b ± √(b² - 4ac)) / 2a` loses the precision of the smaller root to cancellation when `b² ≫ |ac|`.
+///
+/// ```
+/// use cvmath::solve_quadratic;
+///
+/// assert_eq!(solve_quadratic(1.0, -3.0, 2.0), Some((1.0, 2.0)));
+/// assert_eq!(solve_quadratic(1.0, 2.0, 1.0), Some((-1.0, -1.0)));
+/// assert_eq!(solve_quadratic(1.0, 0.0, 1.0), None);
+///
+/// // The naive formula returns zero for the root closest to zero
+/// let (_, small) = solve_quadratic(1.0f64, 1e9, 1.0).unwrap();
+/// assert_eq!(small, -1e-9);
+/// ```
+pub fn solve_quadratic<T: Float>(a: T, b: T, c: T) -> Option<(T, T)> {
+	if a == T::ZERO {
+		if b == T::ZERO {
+			return None;
+		}
+		let x = -c / b;
+		return Some((x, x));
+	}
+
+	let four = T::cast_from(4.0);
+	let disc = b * b - four * a * c;
+	if disc < T::ZERO || disc.is_nan() {
+		return None;
+	}
+
+	let half = T::ONE / (T::ONE + T::ONE);
+	let sqrt = disc.sqrt();
+	let q = if b < T::ZERO { (sqrt - b) * half } else { -(b + sqrt) * half };
+	// Both b and the discriminant are zero only if c is zero as well
+	if q == T::ZERO {
+		return Some((T::ZERO, T::ZERO));
+	}
+
+	let (x0, x1) = (q / a, c / q);
+	Some(if x0 <= x1 { (x0, x1) } else { (x1, x0) })
+}
+
+/// Real roots of the cubic `a x³ + b x² + c x + d = 0`.
+///
+/// Writes the roots in ascending order to `roots` and returns their number, multiple roots are written once.
+/// If `a` is zero the roots of the quadratic are returned instead, see [`solve_quadratic`].
+///
+/// The roots are found in closed form and refined with a Newton step.
+///
+/// ```
+/// use cvmath::solve_cubic;
+///
+/// // (x - 1)(x - 2)(x - 3)
+/// let mut roots = [0.0; 3];
+/// assert_eq!(solve_cubic(1.0, -6.0, 11.0, -6.0, &mut roots), 3);
+/// assert!((roots[0] - 1.0f64).abs() < 1e-12);
+/// assert!((roots[1] - 2.0f64).abs() < 1e-12);
+/// assert!((roots[2] - 3.0f64).abs() < 1e-12);
+///
+/// // x³ + x has a single real root
+/// assert_eq!(solve_cubic(1.0, 0.0, 1.0, 0.0, &mut roots), 1);
+/// assert_eq!(roots[0], 0.0);
+/// ```
+pub fn solve_cubic<T: Float>(a: T, b: T, c: T, d: T, roots: &mut [T; 3]) -> usize {
+	if a == T::ZERO {
+		return match solve_quadratic(b, c, d) {
+			Some((x0, x1)) if x0 == x1 => { roots[0] = x0; 1 },
+			Some((x0, x1)) => { roots[0] = x0; roots[1] = x1; 2 },
+			None => 0,
+		};
+	}
+
+	let two = T::ONE + T::ONE;
+	let three = two + T::ONE;
+	let (b, c, d) = (b / a, c / a, d / a);
+
+	// Substitute x = t - b / 3 for the depressed cubic t³ + p t + q = 0
+	let shift = b / three;
+	let p = c - b * shift;
+	let q = (two * shift * shift - c) * shift + d;
+
+	let half_q = q / two;
+	let third_p = p / three;
+	let disc = half_q * half_q + third_p * third_p * third_p;
+
+	let mut n;
+	if p == T::ZERO && q == T::ZERO {
+		roots[0] = T::ZERO;
+		n = 1;
+	}
+	else if disc > T::ZERO {
+		// One real root, avoid cancellation by choosing the sign of the square root
+		let s = disc.sqrt();
+		let w = if half_q < T::ZERO { s - half_q } else { -s - half_q };
+		let u = cbrt(w);
+		roots[0] = if u != T::ZERO { u - third_p / u } else { T::ZERO };
+		n = 1;
+	}
+	else if disc == T::ZERO {
+		// A single and a double root
+		let u = cbrt(-half_q);
+		roots[0] = u + u;
+		roots[1] = -u;
+		n = 2;
+	}
+	else {
+		// Three real roots in trigonometric form
+		let r = (-third_p).sqrt();
+		let cos = (-half_q / (r * r * r)).max(-T::ONE).min(T::ONE);
+		let phi = cos.acos() / three;
+		let turn = T::cast_from(core::f64::consts::TAU) / three;
+		roots[0] = two * r * phi.cos();
+		roots[1] = two * r * (phi - turn).cos();
+		roots[2] = two * r * (phi + turn).cos();
+		n = 3;
+	}
+
+	for root in &mut roots[..n] {
+		let x = *root - shift;
+		let f = ((x + b) * x + c) * x + d;
+		let df = (three * x + two * b) * x + c;
+		*root = if df != T::ZERO { x - f / df } else { x };
+	}
+
+	roots[..n].sort_unstable_by(|a, b| a.partial_cmp(b).unwrap_or(core::cmp::Ordering::Equal));
+	// Close roots may merge after refinement
+	if n == 2 && roots[0] == roots[1] {
+		n = 1;
+	}
+	n
+}
+
+/// Smallest root within the interval `[min, max]`.
+///
+/// Returns `None` if none of the roots lie within the interval.
+///
+/// ```
+/// use cvmath::{smallest_root_in, solve_quadratic};
+///
+/// // Closest hit in front of the ray origin
+/// let (t0, t1) = solve_quadratic(1.0, -2.0, -3.0).unwrap();
+/// assert_eq!(smallest_root_in(0.0, f64::INFINITY, &[t0, t1]), Some(3.0));
+/// assert_eq!(smallest_root_in(0.0, 2.0, &[t0, t1]), None);
+/// ```
+#[inline]
+pub fn smallest_root_in<T: Float>(min: T, max: T, roots: &[T]) -> Option<T> {
+	let mut result: Option<T> = None;
+	for &root in roots {
+		if root >= min && root <= max && result.is_none_or(|x| root < x) {
+			result = Some(root);
+		}
+	}
+	result
+}
+
+#[inline]
+fn cbrt<T: Float>(x: T) -> T {
+	let third = T::ONE / T::cast_from(3.0);
+	if x < T::ZERO { -(-x).powf(third) } else { x.powf(third) }
+}
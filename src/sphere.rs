@@ -20,6 +20,10 @@ pub fn Sphere<T>(center: Point3<T>, radius: T) -> Sphere<T> {
 
 #[cfg(feature = "dataview")]
 unsafe impl<T: dataview::Pod> dataview::Pod for Sphere<T> {}
+#[cfg(feature = "bytemuck")]
+unsafe impl<T: bytemuck::Zeroable> bytemuck::Zeroable for Sphere<T> {}
+#[cfg(feature = "bytemuck")]
+unsafe impl<T: bytemuck::Pod> bytemuck::Pod for Sphere<T> {}
 
 impl<T> Sphere<T> {
 	/// Constructs a new sphere.
@@ -29,6 +33,157 @@ impl<T> Sphere<T> {
 	}
 }
 
+impl<T: Float> Sphere<T> {
+	/// Returns `true` if all the components are finite.
+	#[inline]
+	pub fn is_finite(&self) -> bool {
+		self.center.all_finite() && self.radius.is_finite()
+	}
+
+	/// Returns `true` if the spheres overlap or touch.
+	///
+	/// ```
+	/// use cvmath::{Point3, Sphere};
+	///
+	/// let a = Sphere(Point3(0.0, 0.0, 0.0), 1.0);
+	/// assert!(a.intersects_sphere(&Sphere(Point3(1.5, 0.0, 0.0), 0.5)));
+	/// assert!(!a.intersects_sphere(&Sphere(Point3(1.5, 0.0, 0.0), 0.25)));
+	/// ```
+	#[inline]
+	pub fn intersects_sphere(&self, other: &Sphere<T>) -> bool {
+		let radius = self.radius + other.radius;
+		self.center.distance_sqr(other.center) <= radius * radius
+	}
+	/// Intersection circle of the spheres' surfaces.
+	///
+	/// Returns the center and radius of the circle, the circle lies in the plane perpendicular to the line between the sphere centers.
+	/// Returns none if the spheres are apart or one sphere lies inside the other, touching spheres intersect in a circle of radius zero.
+	///
+	/// ```
+	/// use cvmath::{Point3, Sphere};
+	///
+	/// let a = Sphere(Point3(0.0, 0.0, 0.0), 5.0);
+	/// let b = Sphere(Point3(8.0, 0.0, 0.0), 5.0);
+	/// assert_eq!(a.intersect_sphere(&b), Some((Point3(4.0, 0.0, 0.0), 3.0)));
+	/// ```
+	#[inline]
+	pub fn intersect_sphere(&self, other: &Sphere<T>) -> Option<(Point3<T>, T)> {
+		let delta = other.center - self.center;
+		let dist = delta.len();
+		if dist > self.radius + other.radius || dist < (self.radius - other.radius).abs() || dist == T::ZERO {
+			return None;
+		}
+		// Distance from this center to the plane of the circle
+		let h = (dist * dist + self.radius * self.radius - other.radius * other.radius) / (dist + dist);
+		let radius = (self.radius * self.radius - h * h).max(T::ZERO).sqrt();
+		Some((self.center + delta * (h / dist), radius))
+	}
+	/// Returns `true` if the sphere overlaps or touches the cuboid.
+	///
+	/// ```
+	/// use cvmath::{Cuboid, Point3, Sphere};
+	///
+	/// let cuboid = Cuboid(Point3(0.0, 0.0, 0.0), Point3(1.0, 1.0, 1.0));
+	/// assert!(Sphere(Point3(2.0, 0.5, 0.5), 1.0).intersects_bounds(&cuboid));
+	/// assert!(!Sphere(Point3(2.0, 2.0, 0.5), 1.0).intersects_bounds(&cuboid));
+	/// ```
+	#[inline]
+	pub fn intersects_bounds(&self, bounds: &Cuboid<T>) -> bool {
+		bounds.distance_sqr_to_point(self.center) <= self.radius * self.radius
+	}
+	/// Point of the cuboid closest to the sphere's center.
+	///
+	/// Returns none if the sphere does not overlap or touch the cuboid.
+	/// The point is the sphere's center if the center lies inside the cuboid.
+	///
+	/// ```
+	/// use cvmath::{Cuboid, Point3, Sphere};
+	///
+	/// let cuboid = Cuboid(Point3(0.0, 0.0, 0.0), Point3(1.0, 1.0, 1.0));
+	/// assert_eq!(Sphere(Point3(2.0, 0.5, 0.5), 1.5).intersect_bounds(&cuboid), Some(Point3(1.0, 0.5, 0.5)));
+	/// ```
+	#[inline]
+	pub fn intersect_bounds(&self, bounds: &Cuboid<T>) -> Option<Point3<T>> {
+		let pt = self.center.max(bounds.mins).min(bounds.maxs);
+		if pt.distance_sqr(self.center) <= self.radius * self.radius { Some(pt) } else { None }
+	}
+	/// Returns `true` if the sphere overlaps or touches the plane.
+	///
+	/// The plane's normal must be normalized.
+	///
+	/// ```
+	/// use cvmath::{Plane, Point3, Sphere, Vec3};
+	///
+	/// let ground = Plane(Vec3(0.0, 0.0, 1.0), 0.0);
+	/// assert!(Sphere(Point3(0.0, 0.0, -1.0), 1.0).intersects_plane(&ground));
+	/// assert!(!Sphere(Point3(0.0, 0.0, 2.0), 1.0).intersects_plane(&ground));
+	/// ```
+	#[inline]
+	pub fn intersects_plane(&self, plane: &Plane<T>) -> bool {
+		plane.dist_pt(self.center).abs() <= self.radius
+	}
+	/// Intersection circle of the sphere and the plane.
+	///
+	/// The plane's normal must be normalized.
+	/// Returns the center and radius of the circle which lies in the plane, a tangent plane intersects in a circle of radius zero.
+	///
+	/// ```
+	/// use cvmath::{Plane, Point3, Sphere, Vec3};
+	///
+	/// let ground = Plane(Vec3(0.0, 0.0, 1.0), 0.0);
+	/// let sphere = Sphere(Point3(1.0, 2.0, 3.0), 5.0);
+	/// assert_eq!(sphere.intersect_plane(&ground), Some((Point3(1.0, 2.0, 0.0), 4.0)));
+	/// ```
+	#[inline]
+	pub fn intersect_plane(&self, plane: &Plane<T>) -> Option<(Point3<T>, T)> {
+		let dist = plane.dist_pt(self.center);
+		if dist.abs() > self.radius {
+			return None;
+		}
+		let radius = (self.radius * self.radius - dist * dist).max(T::ZERO).sqrt();
+		Some((self.center - plane.normal * dist, radius))
+	}
+	/// Spherical texture coordinates of a point on the surface.
+	///
+	/// Follows the conventions of [`Spherical`](struct@Spherical) around the center:
+	/// `u` is the longitude mapped to `[0, 1]` and increases from -Z (`0`) over -X (`0.25`), +Z (`0.5`) and +X (`0.75`) back to -Z.
+	/// `v` is the latitude mapped to `[0, 1]` from the north pole at +Y to the south pole at -Y, the top row of the texture image.
+	/// Points off the surface are projected onto it along the direction from the center.
+	///
+	/// The seam lies at the back where `u` wraps from `1` to `0`, facing -Z.
+	/// Interpolating `u` across the seam, eg. between triangle vertices or for mipmap selection, sweeps the whole texture; wrap the texture horizontally and handle the seam by its derivatives.
+	/// At the poles the longitude is undefined and `u` is `0.5`, the texture converges into a single point there and texels are stretched near the poles.
+	///
+	/// ```
+	/// use cvmath::{Point3, Sphere, Vec2};
+	///
+	/// let sphere = Sphere(Point3(1.0, 2.0, 3.0), 2.0);
+	/// assert_eq!(sphere.uv(Point3(1.0, 2.0, 5.0)), Vec2(0.5, 0.5));
+	/// assert_eq!(sphere.uv(Point3(-1.0, 2.0, 3.0)), Vec2(0.25, 0.5));
+	/// assert_eq!(sphere.uv(Point3(3.0, 2.0, 3.0)), Vec2(0.75, 0.5));
+	/// assert_eq!(sphere.uv(Point3(1.0, 4.0, 3.0)), Vec2(0.5, 0.0));
+	/// assert_eq!(sphere.uv(Point3(1.0, 0.0, 3.0)), Vec2(0.5, 1.0));
+	/// ```
+	#[inline]
+	pub fn uv(&self, point: Point3<T>) -> Vec2<T> {
+		let Spherical { theta, phi, .. } = (point - self.center).spherical();
+		let pi = Rad::<T>::half().value;
+		let half = T::ONE / (T::ONE + T::ONE);
+		Vec2 { x: half + phi.value / (pi + pi), y: theta.value / pi }
+	}
+}
+
+impl<T: Float> Measure3<T> for Sphere<T> {
+	#[inline]
+	fn volume(&self) -> T {
+		T::cast_from(4.0 / 3.0 * core::f64::consts::PI) * self.radius * self.radius * self.radius
+	}
+	#[inline]
+	fn surface_area(&self) -> T {
+		T::cast_from(4.0 * core::f64::consts::PI) * self.radius * self.radius
+	}
+}
+
 //----------------------------------------------------------------
 
 impl<T: Float> TraceRay<T> for Sphere<T> {
@@ -37,19 +192,13 @@ impl<T: Float> TraceRay<T> for Sphere<T> {
 	}
 
 	fn trace(&self, ray: &Ray<T>, hits: &mut [TraceHit<T>]) -> usize {
-		let oc = self.center - ray.origin;
-		let tc = oc.dot(ray.direction);
-
-		let d2 = tc * tc - oc.dot(oc);
-		let r2 = self.radius * self.radius;
-		if d2 > r2 {
+		let oc = ray.origin - self.center;
+		let a = ray.direction.dot(ray.direction);
+		let b = (T::ONE + T::ONE) * oc.dot(ray.direction);
+		let c = oc.dot(oc) - self.radius * self.radius;
+		let Some((t1, t2)) = solve_quadratic(a, b, c) else {
 			return 0;
-		}
-
-		let t1c = (r2 - d2).sqrt();
-
-		let t1 = tc - t1c;
-		let t2 = tc + t1c;
+		};
 
 		let mut count = 0;
 		for t in [t1, t2] {
@@ -0,0 +1,144 @@
+/*!
+Spherical coordinates.
+*/
+
+use super::*;
+
+/// Spherical coordinates.
+///
+/// The polar angle `theta` is measured from the +Y axis and lies in the `[0, π]` range.
+/// The azimuth `phi` is measured around the Y axis starting at +Z towards +X.
+///
+/// ```
+/// use cvmath::{Deg, Spherical, Vec3};
+///
+/// // Orbit a camera around a target by incrementing the angles
+/// let target = Vec3(1.0, 0.0, -2.0);
+/// let mut orbit = Spherical::new(5.0, Deg(60.0).to_rad(), Deg(0.0).to_rad());
+/// orbit.phi += Deg(90.0).to_rad();
+/// orbit.theta -= Deg(30.0).to_rad();
+///
+/// let eye = target + orbit.vec3();
+/// assert!(eye.distance(Vec3(1.0 + 5.0 * 0.5, 5.0 * 0.75f64.sqrt(), -2.0)) < 1e-12);
+/// ```
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[repr(C)]
+pub struct Spherical<T> {
+	/// The distance from the origin.
+	pub radius: T,
+	/// The polar angle from the +Y axis.
+	pub theta: Rad<T>,
+	/// The azimuth around the Y axis.
+	pub phi: Rad<T>,
+}
+
+/// Spherical constructor.
+#[allow(non_snake_case)]
+#[inline]
+pub fn Spherical<T>(radius: T, theta: Rad<T>, phi: Rad<T>) -> Spherical<T> {
+	Spherical { radius, theta, phi }
+}
+
+#[cfg(feature = "dataview")]
+unsafe impl<T: dataview::Pod> dataview::Pod for Spherical<T> {}
+#[cfg(feature = "bytemuck")]
+unsafe impl<T: bytemuck::Zeroable> bytemuck::Zeroable for Spherical<T> {}
+#[cfg(feature = "bytemuck")]
+unsafe impl<T: bytemuck::Pod> bytemuck::Pod for Spherical<T> {}
+
+impl<T> Spherical<T> {
+	/// Constructs new spherical coordinates.
+	#[inline]
+	pub const fn new(radius: T, theta: Rad<T>, phi: Rad<T>) -> Spherical<T> {
+		Spherical { radius, theta, phi }
+	}
+}
+
+impl<T: Float> Spherical<T> {
+	/// Constructs spherical coordinates on the unit sphere.
+	///
+	/// ```
+	/// use cvmath::{Deg, Spherical, Vec3};
+	///
+	/// let pt = Spherical::unit(Deg(90.0), Deg(90.0)).vec3();
+	/// assert!(pt.distance(Vec3(1.0, 0.0, 0.0)) < 1e-12);
+	/// ```
+	#[inline]
+	pub fn unit(theta: impl Angle<T = T>, phi: impl Angle<T = T>) -> Spherical<T> {
+		Spherical { radius: T::ONE, theta: theta.to_rad(), phi: phi.to_rad() }
+	}
+
+	/// Converts to cartesian coordinates.
+	#[inline]
+	pub fn vec3(self) -> Vec3<T> {
+		let (sin_theta, cos_theta) = self.theta.sin_cos();
+		let (sin_phi, cos_phi) = self.phi.sin_cos();
+		Vec3 {
+			x: self.radius * sin_theta * sin_phi,
+			y: self.radius * cos_theta,
+			z: self.radius * sin_theta * cos_phi,
+		}
+	}
+
+	/// Linear interpolation between the coordinates.
+	///
+	/// The azimuth is interpolated along the shortest path around the Y axis.
+	///
+	/// ```
+	/// use cvmath::{Deg, Spherical};
+	///
+	/// let a = Spherical::unit(Deg(90.0f64), Deg(170.0));
+	/// let b = Spherical::unit(Deg(90.0), Deg(-170.0));
+	/// let mid = a.lerp(b, 0.5);
+	/// assert!((mid.phi.to_deg().value - 180.0).abs() < 1e-12);
+	/// ```
+	#[inline]
+	pub fn lerp(self, rhs: Spherical<T>, t: T) -> Spherical<T> {
+		let delta_phi = (rhs.phi - self.phi).normalize();
+		Spherical {
+			radius: self.radius + (rhs.radius - self.radius) * t,
+			theta: self.theta + (rhs.theta - self.theta) * t,
+			phi: self.phi + delta_phi * t,
+		}
+	}
+}
+
+impl<T: Float> Vec3<T> {
+	/// Converts to spherical coordinates.
+	///
+	/// See [`Spherical`] for the conventions used.
+	/// The angles of the zero vector are zero, on the Y axis the azimuth is zero.
+	///
+	/// ```
+	/// use cvmath::{Deg, Vec3};
+	///
+	/// let spherical = Vec3(0.0, 2.0, 2.0).spherical();
+	/// assert_eq!(spherical.radius, 8.0f64.sqrt());
+	/// assert!((spherical.theta.to_deg().value - 45.0).abs() < 1e-12);
+	/// assert_eq!(spherical.phi.value, 0.0);
+	/// ```
+	#[inline]
+	pub fn spherical(self) -> Spherical<T> {
+		let radius = self.len();
+		if radius == T::ZERO {
+			return Spherical { radius, theta: Rad(T::ZERO), phi: Rad(T::ZERO) };
+		}
+		let theta = Rad((self.y / radius).max(-T::ONE).min(T::ONE).acos());
+		let phi = Rad(self.x.atan2(self.z));
+		Spherical { radius, theta, phi }
+	}
+}
+
+impl<T: Float> From<Spherical<T>> for Vec3<T> {
+	#[inline]
+	fn from(spherical: Spherical<T>) -> Vec3<T> {
+		spherical.vec3()
+	}
+}
+impl<T: Float> From<Vec3<T>> for Spherical<T> {
+	#[inline]
+	fn from(vec: Vec3<T>) -> Spherical<T> {
+		vec.spherical()
+	}
+}
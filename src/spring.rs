@@ -0,0 +1,58 @@
+/*!
+Critically damped spring smoothing.
+*/
+
+use super::*;
+
+/// Critically damped spring towards the target.
+///
+/// Smoothly moves towards the target while tracking the velocity, unlike exponential decay the motion starts and stops smoothly.
+/// The smooth time is approximately the time it takes to reach the target.
+/// The optional max speed limits how fast the spring may move.
+///
+/// The velocity must be stored between updates and should start at zero.
+/// The result never overshoots the target and is stable for any time step.
+///
+/// ```
+/// let mut position = 0.0f64;
+/// let mut velocity = 0.0;
+/// for _ in 0..100 {
+///     position = cvmath::spring_damp(position, 10.0, &mut velocity, 0.25, 1.0 / 60.0, None);
+/// }
+/// assert!((position - 10.0).abs() < 1e-2);
+/// ```
+#[inline]
+pub fn spring_damp<T: Float>(current: T, target: T, velocity: &mut T, smooth_time: T, dt: T, max_speed: Option<T>) -> T {
+	damp(current, target, velocity, smooth_time, dt, max_speed, |change, max| change.clamp(-max, max), |a, b| a * b)
+}
+
+// Game Programming Gems 4, chapter 1.10.
+// The exponential is approximated by a rational function which stays well behaved for large time steps.
+#[allow(clippy::too_many_arguments)]
+#[inline]
+pub(crate) fn damp<T: Float, V>(
+	current: V, target: V, velocity: &mut V, smooth_time: T, dt: T, max_speed: Option<T>,
+	clamp: impl Fn(V, T) -> V, dot: impl Fn(V, V) -> T,
+) -> V where V: Copy + ops::Add<Output = V> + ops::Sub<Output = V> + ops::Mul<T, Output = V> {
+	let smooth_time = smooth_time.max(T::cast_from(0.0001));
+	let omega = T::cast_from(2.0) / smooth_time;
+	let x = omega * dt;
+	let exp = T::ONE / (T::ONE + x + T::cast_from(0.48) * x * x + T::cast_from(0.235) * x * x * x);
+
+	let change = match max_speed {
+		Some(max_speed) => clamp(current - target, max_speed * smooth_time),
+		None => current - target,
+	};
+	let clamped_target = current - change;
+
+	let temp = (*velocity + change * omega) * dt;
+	*velocity = (*velocity - temp * omega) * exp;
+	let output = clamped_target + (change + temp) * exp;
+
+	// Stop at the target instead of overshooting it
+	if dot(target - current, output - target) > T::ZERO {
+		*velocity = *velocity * T::ZERO;
+		return target;
+	}
+	output
+}
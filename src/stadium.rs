@@ -0,0 +1,200 @@
+/*!
+Stadium shape.
+*/
+
+use super::*;
+
+/// Stadium structure.
+///
+/// A line segment thickened by a radius, also known as a 2D capsule.
+/// The boundary consists of two half circle caps around the end points joined by two straight edges.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[repr(C)]
+pub struct Stadium2<T> {
+	/// The center line segment.
+	pub line: Line2<T>,
+	/// The radius around the line segment.
+	pub radius: T,
+}
+
+/// Stadium2 constructor.
+#[allow(non_snake_case)]
+#[inline]
+pub fn Stadium2<T>(line: Line2<T>, radius: T) -> Stadium2<T> {
+	Stadium2 { line, radius }
+}
+
+#[cfg(feature = "dataview")]
+unsafe impl<T: dataview::Pod> dataview::Pod for Stadium2<T> {}
+#[cfg(feature = "bytemuck")]
+unsafe impl<T: bytemuck::Zeroable> bytemuck::Zeroable for Stadium2<T> {}
+#[cfg(feature = "bytemuck")]
+unsafe impl<T: bytemuck::Pod> bytemuck::Pod for Stadium2<T> {}
+
+impl<T> Stadium2<T> {
+	/// Constructs a new stadium.
+	#[inline]
+	pub const fn new(line: Line2<T>, radius: T) -> Stadium2<T> {
+		Stadium2 { line, radius }
+	}
+}
+
+impl<T: Float> Stadium2<T> {
+	/// Returns `true` if the point lies inside the stadium.
+	///
+	/// Points on the boundary are inside.
+	///
+	/// ```
+	/// use cvmath::{Line2, Point2, Stadium2};
+	///
+	/// let stadium = Stadium2(Line2(Point2(0.0, 0.0), Point2(4.0, 0.0)), 1.0);
+	/// assert!(stadium.contains(Point2(2.0, 0.5)));
+	/// assert!(stadium.contains(Point2(-1.0, 0.0)));
+	/// assert!(!stadium.contains(Point2(-1.0, 1.0)));
+	/// ```
+	#[inline]
+	pub fn contains(&self, pt: Point2<T>) -> bool {
+		self.line.closest_point(pt).distance_sqr(pt) <= self.radius * self.radius
+	}
+	/// Closest point in the stadium.
+	///
+	/// Points inside the stadium are returned unchanged.
+	///
+	/// ```
+	/// use cvmath::{Line2, Point2, Stadium2};
+	///
+	/// let stadium = Stadium2(Line2(Point2(0.0, 0.0), Point2(4.0, 0.0)), 1.0);
+	/// assert_eq!(stadium.closest_point(Point2(2.0, 0.5)), Point2(2.0, 0.5));
+	/// assert_eq!(stadium.closest_point(Point2(2.0, 3.0)), Point2(2.0, 1.0));
+	/// assert_eq!(stadium.closest_point(Point2(7.0, 4.0)), Point2(4.6, 0.8));
+	/// ```
+	#[inline]
+	pub fn closest_point(&self, pt: Point2<T>) -> Point2<T> {
+		let center = self.line.closest_point(pt);
+		let offset = pt - center;
+		if offset.len_sqr() <= self.radius * self.radius {
+			pt
+		}
+		else {
+			center + offset.resize(self.radius)
+		}
+	}
+	/// Signed distance to the stadium's boundary.
+	///
+	/// Negative inside and positive outside.
+	///
+	/// ```
+	/// use cvmath::{Line2, Point2, Stadium2};
+	///
+	/// let stadium = Stadium2(Line2(Point2(0.0, 0.0), Point2(4.0, 0.0)), 1.0);
+	/// assert_eq!(stadium.sdf(Point2(2.0, 0.0)), -1.0);
+	/// assert_eq!(stadium.sdf(Point2(2.0, 3.0)), 2.0);
+	/// assert_eq!(stadium.sdf(Point2(7.0, 4.0)), 4.0);
+	/// ```
+	#[inline]
+	pub fn sdf(&self, pt: Point2<T>) -> T {
+		self.line.distance_to(pt) - self.radius
+	}
+	/// Intersects a ray with the stadium.
+	///
+	/// Returns the distance along the ray and the outward normal where the ray first crosses the boundary.
+	/// Rays starting inside the stadium cross the boundary where they exit.
+	/// The distance is in multiples of the direction, which does not need to be normalized.
+	///
+	/// Thickening walls with a radius prevents nearly parallel rays from slipping through them due to rounding errors.
+	///
+	/// ```
+	/// use cvmath::{Line2, Point2, Stadium2, Vec2};
+	///
+	/// let stadium = Stadium2(Line2(Point2(0.0, 0.0), Point2(4.0, 0.0)), 1.0);
+	/// assert_eq!(stadium.intersect_ray(Point2(2.0, 3.0), Vec2(0.0, -1.0)), Some((2.0, Vec2(0.0, 1.0))));
+	/// assert_eq!(stadium.intersect_ray(Point2(-3.0, 0.0), Vec2(1.0, 0.0)), Some((2.0, Vec2(-1.0, 0.0))));
+	/// assert_eq!(stadium.intersect_ray(Point2(2.0, 0.0), Vec2(0.0, 1.0)), Some((1.0, Vec2(0.0, 1.0))));
+	/// assert_eq!(stadium.intersect_ray(Point2(6.0, 3.0), Vec2(0.0, -1.0)), None);
+	/// ```
+	pub fn intersect_ray(&self, origin: Point2<T>, direction: Vec2<T>) -> Option<(T, Vec2<T>)> {
+		let Stadium2 { line, radius } = *self;
+
+		// The stadium is convex, the ray enters at the first entry into any of its parts and exits at the last exit
+		let mut entry = (T::INFINITY, Vec2::ZERO);
+		let mut exit = (T::NEG_INFINITY, Vec2::ZERO);
+
+		// The circles around the end points
+		for center in [line.start, line.end] {
+			let oc = origin - center;
+			let a = direction.dot(direction);
+			let b = (T::ONE + T::ONE) * oc.dot(direction);
+			let c = oc.dot(oc) - radius * radius;
+			if let Some((t0, t1)) = solve_quadratic(a, b, c) {
+				if t0 < entry.0 {
+					entry = (t0, (origin + direction * t0 - center) / radius);
+				}
+				if t1 > exit.0 {
+					exit = (t1, (origin + direction * t1 - center) / radius);
+				}
+			}
+		}
+
+		// The straight edges, rays parallel to them can only cross the boundary at the caps
+		let axis = line.end - line.start;
+		let len = axis.len();
+		if len > T::ZERO {
+			let axis = axis / len;
+			let perp = Vec2 { x: -axis.y, y: axis.x };
+			let offset = origin - line.start;
+			let (u, du) = (offset.dot(axis), direction.dot(axis));
+			let (v, dv) = (offset.dot(perp), direction.dot(perp));
+			if dv != T::ZERO {
+				let (t0, t1, normal) = if dv > T::ZERO {
+					((-radius - v) / dv, (radius - v) / dv, perp)
+				}
+				else {
+					((radius - v) / dv, (-radius - v) / dv, -perp)
+				};
+				// Only the part of the edge lines alongside the segment is on the boundary
+				let on_edge = |t: T| {
+					let s = u + du * t;
+					s >= T::ZERO && s <= len
+				};
+				if t0 < entry.0 && on_edge(t0) {
+					entry = (t0, -normal);
+				}
+				if t1 > exit.0 && on_edge(t1) {
+					exit = (t1, normal);
+				}
+			}
+		}
+
+		if entry.0 > exit.0 {
+			None
+		}
+		else if entry.0 >= T::ZERO {
+			Some(entry)
+		}
+		else if exit.0 >= T::ZERO {
+			Some(exit)
+		}
+		else {
+			None
+		}
+	}
+	/// Returns `true` if all the components are finite.
+	#[inline]
+	pub fn is_finite(&self) -> bool {
+		self.line.start.all_finite() && self.line.end.all_finite() && self.radius.is_finite()
+	}
+}
+
+impl<T: Float> Measure2<T> for Stadium2<T> {
+	#[inline]
+	fn area(&self) -> T {
+		let two = T::ONE + T::ONE;
+		two * self.radius * self.line.len() + T::cast_from(core::f64::consts::PI) * self.radius * self.radius
+	}
+	#[inline]
+	fn perimeter(&self) -> T {
+		let two = T::ONE + T::ONE;
+		two * self.line.len() + T::cast_from(2.0 * core::f64::consts::PI) * self.radius
+	}
+}
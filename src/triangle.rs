@@ -0,0 +1,255 @@
+/*!
+Triangle shape.
+*/
+
+use super::*;
+
+/// Triangle structure.
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[repr(C)]
+pub struct Triangle<T> {
+	pub p0: T,
+	pub p1: T,
+	pub p2: T,
+}
+
+/// Triangle constructor.
+#[allow(non_snake_case)]
+#[inline]
+pub fn Triangle<T>(p0: T, p1: T, p2: T) -> Triangle<T> {
+	Triangle { p0, p1, p2 }
+}
+
+#[cfg(feature = "dataview")]
+unsafe impl<T: dataview::Pod> dataview::Pod for Triangle<T> {}
+#[cfg(feature = "bytemuck")]
+unsafe impl<T: bytemuck::Zeroable> bytemuck::Zeroable for Triangle<T> {}
+#[cfg(feature = "bytemuck")]
+unsafe impl<T: bytemuck::Pod> bytemuck::Pod for Triangle<T> {}
+
+impl<T> Triangle<T> {
+	/// Constructs a new triangle.
+	#[inline]
+	pub const fn new(p0: T, p1: T, p2: T) -> Triangle<T> {
+		Triangle { p0, p1, p2 }
+	}
+}
+
+impl<T: Float> Triangle3<T> {
+	/// Returns `true` if all the components are finite.
+	#[inline]
+	pub fn is_finite(&self) -> bool {
+		self.p0.all_finite() && self.p1.all_finite() && self.p2.all_finite()
+	}
+	/// Normal of the triangle.
+	///
+	/// The normal points towards the side from which the points appear in counter-clockwise order.
+	///
+	/// ```
+	/// use cvmath::{Point3, Triangle3, Vec3};
+	///
+	/// let triangle = Triangle3(Point3(0.0, 0.0, 0.0), Point3(1.0, 0.0, 0.0), Point3(0.0, 1.0, 0.0));
+	/// assert_eq!(triangle.normal(), Vec3(0.0, 0.0, 1.0));
+	/// ```
+	#[inline]
+	pub fn normal(&self) -> Vec3<T> {
+		(self.p1 - self.p0).cross(self.p2 - self.p0).normalize()
+	}
+	/// Makes the winding order consistent with a reference normal.
+	///
+	/// If the [normal](Triangle3::normal) of the triangle opposes the reference normal, the points `p1` and `p2` are swapped to reverse the winding.
+	/// Otherwise the triangle is returned unchanged, including when it is degenerate or perpendicular to the reference.
+	///
+	/// ```
+	/// use cvmath::{Point3, Triangle3, Vec3};
+	///
+	/// let triangle = Triangle3(Point3(0.0, 0.0, 0.0), Point3(0.0, 1.0, 0.0), Point3(1.0, 0.0, 0.0));
+	/// assert_eq!(triangle.normal(), Vec3(0.0, 0.0, -1.0));
+	///
+	/// let fixed = triangle.norm_winding(Vec3(0.0, 0.0, 1.0));
+	/// assert_eq!(fixed, Triangle3(Point3(0.0, 0.0, 0.0), Point3(1.0, 0.0, 0.0), Point3(0.0, 1.0, 0.0)));
+	/// assert_eq!(fixed.norm_winding(Vec3(0.0, 0.0, 1.0)), fixed);
+	/// ```
+	#[inline]
+	pub fn norm_winding(self, reference_normal: Vec3<T>) -> Triangle3<T> {
+		let normal = (self.p1 - self.p0).cross(self.p2 - self.p0);
+		if normal.dot(reference_normal) < T::ZERO {
+			Triangle { p0: self.p0, p1: self.p2, p2: self.p1 }
+		}
+		else {
+			self
+		}
+	}
+	/// Subdivides the triangle into `n²` congruent triangles.
+	///
+	/// Each edge is split into `n` segments, the sub-triangles keep the winding order of the triangle.
+	///
+	/// ```
+	/// use cvmath::{Point3, Triangle3};
+	///
+	/// let triangle = Triangle3(Point3(0.0, 0.0, 0.0), Point3(2.0, 0.0, 0.0), Point3(0.0, 2.0, 0.0));
+	/// let parts: Vec<_> = triangle.tessellate(2).collect();
+	/// assert_eq!(parts.len(), 4);
+	/// assert!(parts.contains(&Triangle3(Point3(1.0, 0.0, 0.0), Point3(1.0, 1.0, 0.0), Point3(0.0, 1.0, 0.0))));
+	/// ```
+	#[inline]
+	pub fn tessellate(self, n: usize) -> impl Iterator<Item = Triangle3<T>> {
+		let e1 = (self.p1 - self.p0) / T::cast_from(n as f64);
+		let e2 = (self.p2 - self.p0) / T::cast_from(n as f64);
+		let p0 = self.p0;
+		let pt = move |i: usize, j: usize| p0 + e1 * T::cast_from(i as f64) + e2 * T::cast_from(j as f64);
+		(0..n).flat_map(move |j| (0..n - j).flat_map(move |i| {
+			// Every upright triangle is followed by an inverted one, except at the end of a row
+			let upright = Triangle { p0: pt(i, j), p1: pt(i + 1, j), p2: pt(i, j + 1) };
+			let inverted = Triangle { p0: pt(i + 1, j), p1: pt(i + 1, j + 1), p2: pt(i, j + 1) };
+			[upright, inverted].into_iter().take(if i + j + 1 < n { 2 } else { 1 })
+		}))
+	}
+}
+
+/// Triangle2 structure.
+pub type Triangle2<T> = Triangle<Point2<T>>;
+
+/// Triangle3 structure.
+pub type Triangle3<T> = Triangle<Point3<T>>;
+
+/// Triangle2 constructor.
+#[allow(non_snake_case)]
+#[inline]
+pub fn Triangle2<T>(p0: Point2<T>, p1: Point2<T>, p2: Point2<T>) -> Triangle2<T> {
+	Triangle { p0, p1, p2 }
+}
+
+/// Triangle3 constructor.
+#[allow(non_snake_case)]
+#[inline]
+pub fn Triangle3<T>(p0: Point3<T>, p1: Point3<T>, p2: Point3<T>) -> Triangle3<T> {
+	Triangle { p0, p1, p2 }
+}
+
+//----------------------------------------------------------------
+
+// Watertight ray/triangle intersection.
+// Sven Woop, Carsten Benthin, Ingo Wald, "Watertight Ray/Triangle Intersection", JCGT 2013.
+//
+// The vertices are transformed into a space where the ray starts at the origin and points along +Z.
+// The edge functions are then evaluated in 2D, consistently for shared edges of adjacent triangles so no rays slip through.
+impl<T: Float> TraceRay<T> for Triangle3<T> {
+	fn inside(&self, _ray: &Ray<T>) -> bool {
+		false
+	}
+
+	fn trace(&self, ray: &Ray<T>, hits: &mut [TraceHit<T>]) -> usize {
+		let Some(hit) = self.trace_culled(ray, CullMode::None) else {
+			return 0;
+		};
+		if let Some(first) = hits.first_mut() {
+			*first = hit;
+		}
+		1
+	}
+}
+
+/// Which faces of a triangle to skip when tracing.
+///
+/// The front face is the side the [normal](Triangle3::normal) points towards, where the points appear in counter-clockwise order.
+/// See [`Triangle3::trace_culled`].
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq, Hash)]
+pub enum CullMode {
+	/// Trace both faces.
+	#[default]
+	None,
+	/// Skip rays hitting the back face.
+	Back,
+	/// Skip rays hitting the front face.
+	Front,
+}
+
+impl<T: Float> Triangle3<T> {
+	/// Trace the ray against the triangle, skipping culled faces.
+	///
+	/// Culling back faces of closed meshes with consistent winding skips the inner surfaces, eg. when tracing from outside.
+	/// Tracing as a [shape](TraceRay) is two-sided, equivalent to [`CullMode::None`].
+	///
+	/// ```
+	/// use cvmath::{CullMode, Point3, Ray, Triangle3, Vec3};
+	///
+	/// let triangle = Triangle3(Point3(-1.0, -1.0, 0.0), Point3(1.0, -1.0, 0.0), Point3(0.0, 1.0, 0.0));
+	/// let down = Ray(Point3(0.0, 0.0, 2.0), Vec3(0.0, 0.0, -1.0));
+	/// let up = Ray(Point3(0.0, 0.0, -2.0), Vec3(0.0, 0.0, 1.0));
+	///
+	/// assert_eq!(triangle.trace_culled(&down, CullMode::Back).unwrap().distance, 2.0);
+	/// assert_eq!(triangle.trace_culled(&up, CullMode::Back), None);
+	/// assert_eq!(triangle.trace_culled(&down, CullMode::Front), None);
+	/// assert_eq!(triangle.trace_culled(&up, CullMode::Front).unwrap().distance, 2.0);
+	/// ```
+	pub fn trace_culled(&self, ray: &Ray<T>, cull: CullMode) -> Option<TraceHit<T>> {
+		let dir = ray.direction;
+
+		// Rays travelling against the normal hit the front face
+		let facing = dir.dot((self.p1 - self.p0).cross(self.p2 - self.p0));
+		match cull {
+			CullMode::None => {}
+			CullMode::Back => if facing > T::ZERO { return None; },
+			CullMode::Front => if facing < T::ZERO { return None; },
+		}
+
+		// Permute the axes so the dominant axis of the direction becomes Z, swapping X and Y to preserve the winding
+		let kz = dir.dominant_axis();
+		let mut kx = (kz + 1) % 3;
+		let mut ky = (kx + 1) % 3;
+		if dir[kz] < T::ZERO {
+			mem::swap(&mut kx, &mut ky);
+		}
+
+		// Shear constants
+		let sx = dir[kx] / dir[kz];
+		let sy = dir[ky] / dir[kz];
+		let sz = T::ONE / dir[kz];
+
+		let a = self.p0 - ray.origin;
+		let b = self.p1 - ray.origin;
+		let c = self.p2 - ray.origin;
+
+		let ax = a[kx] - sx * a[kz];
+		let ay = a[ky] - sy * a[kz];
+		let bx = b[kx] - sx * b[kz];
+		let by = b[ky] - sy * b[kz];
+		let cx = c[kx] - sx * c[kz];
+		let cy = c[ky] - sy * c[kz];
+
+		let mut u = cx * by - cy * bx;
+		let mut v = ax * cy - ay * cx;
+		let mut w = bx * ay - by * ax;
+
+		// Recompute the edge functions in double precision when they are too close to call
+		if u == T::ZERO || v == T::ZERO || w == T::ZERO {
+			let (ax, ay, bx, by, cx, cy): (f64, f64, f64, f64, f64, f64) = (ax.cast_to(), ay.cast_to(), bx.cast_to(), by.cast_to(), cx.cast_to(), cy.cast_to());
+			u = T::cast_from(cx * by - cy * bx);
+			v = T::cast_from(ax * cy - ay * cx);
+			w = T::cast_from(bx * ay - by * ax);
+		}
+
+		if (u < T::ZERO || v < T::ZERO || w < T::ZERO) && (u > T::ZERO || v > T::ZERO || w > T::ZERO) {
+			return None;
+		}
+
+		let det = u + v + w;
+		if det == T::ZERO {
+			return None;
+		}
+
+		let az = sz * a[kz];
+		let bz = sz * b[kz];
+		let cz = sz * c[kz];
+		let t = u * az + v * bz + w * cz;
+
+		// The hit must not lie behind the ray origin
+		if (det < T::ZERO && t > T::ZERO) || (det > T::ZERO && t < T::ZERO) {
+			return None;
+		}
+
+		Some(TraceHit { distance: t / det, normal: self.normal() })
+	}
+}
@@ -41,6 +41,8 @@ assert!(Vec2(1, 9) > Vec2(1, -2));
 
 `get(self, c)`: Gets a component generically.
 
+`component(self, i)`, `with_component(self, i, value)`: Gets or sets a component by runtime index.
+
 `shuffle(self, x, y, ..)`: Shuffles the components.
 
 ### Examples
@@ -58,6 +60,8 @@ assert_eq!(Vec3 { x: 1, y: 0, z: 0 }, Vec3::X);
 assert_eq!(Vec3 { x: -12, y: 0, z: 12 }, Vec3::default().with_x(-12).with_z(12));
 
 assert_eq!(2, Vec2 { x: 13, y: 2}.get(Y));
+assert_eq!(2, Vec2 { x: 13, y: 2}.component(1));
+assert_eq!(Vec3 { x: 1, y: 7, z: 3 }, Vec3(1, 2, 3).with_component(1, 7));
 
 assert_eq!(Vec3 { x: 5, y: -2, z: 5 }, Vec3(-2, 12, 5).shuffle(Z, X, Z));
 ```
@@ -145,6 +149,8 @@ assert_eq!(Vec2::<i32>::from((2, 3)), Vec2::from([2, 3]));
 
 `normalize(self)` where T: `Float`: Normalizes the vector. The vector with length zero stays zero.
 
+`normalize_fast(self)` where T: `Float`: Normalizes the vector using a fast approximation. The vector with length zero stays zero.
+
 `resize(self, len)` where T: `Float`: Scales the vector such that its length equals the given value. The vector with length zero remains zero.
 
 `project_scalar(self, v)` where T: `Float`: Scalar projection of `self` onto `v`.
@@ -171,10 +177,34 @@ assert_eq!(Vec2::<i32>::from((2, 3)), Vec2::from([2, 3]));
 
 `vmax(self)`: Horizontal maximum value.
 
+`clamp(self, min, max)`: Component-wise clamp between the bounds.
+
+`min_component_index(self)`: Index of the smallest component.
+
+`max_component_index(self)`: Index of the largest component.
+
+`dominant_axis(self)`: Index of the component with the largest absolute value.
+
 `mul_add(self, vec, scale)`: Adds the scaled value.
 
+`bezier3(p0, p1, p2, p3, t)`, `catmull_rom(p0, p1, p2, p3, t)`: Evaluates a cubic spline segment, see also their `_tangent` derivatives.
+
+`hermite(p0, m0, p1, m1, t)`: Evaluates a cubic Hermite curve.
+
+`average(iter)`, `centroid(points)` where T: `Float`: Averages the vectors, `None` if there are none.
+
+`smoothstep(self, edge0, edge1)`, `smootherstep(self, edge0, edge1)`: Component-wise smooth interpolation between the edges.
+
+`step(self, edge)`: Component-wise step function, zero below the edge and one otherwise.
+
+`mix(self, rhs, mask)`: Selects the components of `rhs` where the mask is `true`.
+
 Exclusive to `Vec2`:
 
+`from_angle(angle)`, `from_angle_length(angle, len)`: Constructs a vector from the polar angle.
+
+`rotate(self, angle)`: Rotates the vector around the origin.
+
 `polar_angle(self)`: Calculates the polar angle.
 
 `ccw(self)`: Rotates the vector counter-clockwise by 90°.
@@ -227,6 +257,37 @@ assert_eq!(12, Vec3::dot(Vec3(1, 2, 3), Vec3(4, -5, 6)));
 assert_eq!(Vec3 { x: -12, y: 1, z: 39 }, Vec3::cross(Vec3(3, -3, 1), Vec3(4, 9, 1)));
 ```
 
+## GLSL built-ins
+
+Shader code translates mechanically, note that the vector comes first as `self`:
+
+| GLSL | cvmath |
+|------|--------|
+| `mix(x, y, t)` with a scalar `t` | `x.lerp(y, t)` |
+| `mix(x, y, t)` with a vector `t` | `x + (y - x) * t` |
+| `mix(x, y, bvec)` | `x.mix(y, bvec)` |
+| `step(edge, x)` | `x.step(edge)` |
+| `smoothstep(e0, e1, x)` | `x.smoothstep(e0, e1)` |
+| `clamp(x, lo, hi)` | `x.clamp(lo, hi)` |
+| `fract(x)` | `x.fract()` |
+| `min(x, y)`, `max(x, y)` | `x.min(y)`, `x.max(y)` |
+| `dot(x, y)`, `cross(x, y)` | `x.dot(y)`, `x.cross(y)` |
+| `length(x)`, `distance(x, y)` | `x.len()`, `x.distance(y)` |
+| `normalize(x)` | `x.normalize()` |
+| `lessThan(x, y)`, `any(b)`, `all(b)` | `x.lt(y)`, `b.any()`, `b.all()` |
+
+### Examples
+
+```
+use cvmath::Vec3;
+
+// vec3 c = mix(a, b, step(0.5, t));
+let (a, b, t) = (Vec3(0.0, 0.0, 0.0), Vec3(1.0, 2.0, 3.0), Vec3(0.2, 0.5, 0.8));
+let c = a + (b - a) * t.step(Vec3::dup(0.5));
+assert_eq!(c, Vec3(0.0, 2.0, 3.0));
+assert_eq!(c, a.mix(b, t.ge(Vec3::dup(0.5))));
+```
+
 ## Operators
 
 `Add`: Adds the vectors component-wise.
@@ -518,6 +579,10 @@ macro_rules! vec {
 
 		#[cfg(feature = "dataview")]
 		unsafe impl<T> dataview::Pod for $vec<T> where T: dataview::Pod {}
+		#[cfg(feature = "bytemuck")]
+		unsafe impl<T> bytemuck::Zeroable for $vec<T> where T: bytemuck::Zeroable {}
+		#[cfg(feature = "bytemuck")]
+		unsafe impl<T> bytemuck::Pod for $vec<T> where T: bytemuck::Pod {}
 
 		//----------------------------------------------------------------
 		// Constructors
@@ -584,6 +649,38 @@ macro_rules! vec {
 			pub fn get<C>(self, _: C) -> T where Self: ComponentImpl<T, C> {
 				<Self as ComponentImpl<T, C>>::get(self)
 			}
+			/// Gets a component by index.
+			///
+			/// Panics if the index is out of bounds.
+			///
+			/// ```
+			/// use cvmath::Vec3;
+			///
+			/// let this = Vec3 { x: 5, y: 6, z: 7 };
+			/// assert_eq!(7, this.component(2));
+			/// ```
+			#[inline]
+			#[must_use]
+			pub fn component(self, index: usize) -> T {
+				self[index]
+			}
+			/// Sets a component by index.
+			///
+			/// Panics if the index is out of bounds.
+			///
+			/// ```
+			/// use cvmath::Vec3;
+			///
+			/// let this = Vec3 { x: 5, y: 6, z: 7 };
+			/// assert_eq!(Vec3(5, -1, 7), this.with_component(1, -1));
+			/// ```
+			#[inline]
+			#[must_use]
+			pub fn with_component(self, index: usize, value: T) -> $vec<T> {
+				let mut array: [T; $N] = self.into();
+				array[index] = value;
+				array.into()
+			}
 			/// Shuffles the components.
 			#[inline]
 			#[must_use]
@@ -600,6 +697,9 @@ macro_rules! vec {
 
 		impl<T> $vec<T> {
 			/// Casts to a vector of different type with the same dimensions.
+			///
+			/// Casting floats to integers truncates towards zero and saturates at the bounds of the integer type, NaN becomes zero.
+			/// See [cast_round](#method.cast_round), [cast_floor](#method.cast_floor) and [cast_ceil](#method.cast_ceil) to round differently.
 			#[inline]
 			#[must_use]
 			pub fn cast<U>(self) -> $vec<U> where T: CastTo<U> {
@@ -698,6 +798,27 @@ macro_rules! vec {
 			pub fn as_bytes(&self) -> &[u8] {
 				unsafe { slice::from_raw_parts(self as *const _ as *const u8, mem::size_of_val(self)) }
 			}
+			/// Reinterprets a slice of vectors as a slice of their components.
+			///
+			/// The vectors are `#[repr(C)]` without padding, useful for uploading vertex data.
+			///
+			/// ```
+			/// use cvmath::Vec3;
+			///
+			/// let vertices = [Vec3(1.0f32, 2.0, 3.0), Vec3(4.0, 5.0, 6.0)];
+			/// assert_eq!(Vec3::slice_as_scalars(&vertices), &[1.0, 2.0, 3.0, 4.0, 5.0, 6.0]);
+			/// ```
+			#[inline]
+			#[must_use]
+			pub fn slice_as_scalars(vectors: &[$vec<T>]) -> &[T] {
+				unsafe { slice::from_raw_parts(vectors.as_ptr() as *const T, vectors.len() * $N) }
+			}
+			/// Reinterprets a mutable slice of vectors as a mutable slice of their components.
+			#[inline]
+			#[must_use]
+			pub fn slice_as_scalars_mut(vectors: &mut [$vec<T>]) -> &mut [T] {
+				unsafe { slice::from_raw_parts_mut(vectors.as_mut_ptr() as *mut T, vectors.len() * $N) }
+			}
 		}
 
 		impl<T> AsMut<[T; $N]> for $vec<T> {
@@ -778,6 +899,14 @@ macro_rules! vec {
 			pub fn len(self) -> T where T: Float {
 				self.len_sqr().sqrt()
 			}
+			/// Calculates the length of the vector with fused multiply-add.
+			///
+			/// See [dot_fma](#method.dot_fma).
+			#[inline]
+			#[must_use]
+			pub fn len_fma(self) -> T where T: Float {
+				self.dot_fma(self).sqrt()
+			}
 			/// Calculates the manhattan length of the vector.
 			///
 			/// <!--LEN_HAT--><svg width="400" height="120" font-family="monospace" xmlns="http://www.w3.org/2000/svg"><path fill="none" d="M40 100 L360.5 20 M353.70688 25.818361 L360.5 20 L351.76944 18.056509" stroke="black" /><path fill="none" d="M40 100 L360.5 100 M352.5 104 L360.5 100 L352.5 96" stroke="grey" stroke-width="0.5" /><path fill="none" d="M360.5 100 L360.5 20 M364.5 28 L360.5 20 L356.5 28" stroke="grey" stroke-width="0.5" /><circle cx="40" cy="100" r="2" /><text x="365.5" y="20">this</text><text x="200.25" y="115" fill="grey">x</text><text x="365.5" y="60" fill="grey">y</text></svg>
@@ -796,6 +925,24 @@ macro_rules! vec {
 			pub fn len_hat(self) -> T {
 				infix!(+ $(self.$field.abs()),+)
 			}
+			/// Calculates the chebyshev length of the vector.
+			///
+			/// The largest absolute component, the number of king moves on a grid.
+			///
+			/// ```
+			/// use cvmath::{Vec2, Vec3};
+			///
+			/// let this = Vec2 { x: 3, y: -4 };
+			/// assert_eq!(4, this.len_cheby());
+			///
+			/// let this = Vec3 { x: 2, y: -3, z: -6 };
+			/// assert_eq!(6, this.len_cheby());
+			/// ```
+			#[inline]
+			#[must_use]
+			pub fn len_cheby(self) -> T {
+				fold!(T::max, $(self.$field.abs()),+)
+			}
 			/// Calculates the squared euclidean distance to another vector.
 			///
 			/// ```
@@ -824,6 +971,14 @@ macro_rules! vec {
 			pub fn distance(self, to: $vec<T>) -> T where T: Float {
 				self.distance_sqr(to).sqrt()
 			}
+			/// Calculates the euclidean distance to another vector with fused multiply-add.
+			///
+			/// See [dot_fma](#method.dot_fma).
+			#[inline]
+			#[must_use]
+			pub fn distance_fma(self, to: $vec<T>) -> T where T: Float {
+				(to - self).len_fma()
+			}
 			/// Calculates the manhattan distance to another vector.
 			///
 			/// <!--DISTANCE_HAT--><svg width="400" height="120" font-family="monospace" xmlns="http://www.w3.org/2000/svg"><line x1="40" y1="100" x2="360.5" y2="20" stroke="black" /><path fill="none" d="M40 100 L360.5 100 M352.5 104 L360.5 100 L352.5 96" stroke="grey" stroke-width="0.5" /><path fill="none" d="M360.5 100 L360.5 20 M364.5 28 L360.5 20 L356.5 28" stroke="grey" stroke-width="0.5" /><circle cx="40" cy="100" r="2" /><circle cx="360.5" cy="20" r="2" /><text x="20" y="90">this</text><text x="365.5" y="20">to</text><text x="200.25" y="115" fill="grey">x</text><text x="365.5" y="60" fill="grey">y</text></svg>
@@ -844,6 +999,26 @@ macro_rules! vec {
 			pub fn distance_hat(self, to: $vec<T>) -> T {
 				infix!(+ $((to.$field - self.$field).abs()),+)
 			}
+			/// Calculates the chebyshev distance to another vector.
+			///
+			/// The largest absolute difference between the components, the distance on a grid allowing diagonal moves.
+			///
+			/// ```
+			/// use cvmath::{Vec2, Vec3};
+			///
+			/// let this = Vec2 { x: 1.0, y: 5.0 };
+			/// let to = Vec2 { x: 5.0, y: 2.0 };
+			/// assert_eq!(4.0, this.distance_cheby(to));
+			///
+			/// let this = Vec3 { x: 1.0, y: 5.0, z: -1.0 };
+			/// let to = Vec3 { x: 2.0, y: 3.0, z: 1.0 };
+			/// assert_eq!(2.0, this.distance_cheby(to));
+			/// ```
+			#[inline]
+			#[must_use]
+			pub fn distance_cheby(self, to: $vec<T>) -> T {
+				fold!(T::max, $((to.$field - self.$field).abs()),+)
+			}
 			/// Normalizes the vector.
 			///
 			/// After normalizing the vector has the length `1.0` except the zero vector remains zero.
@@ -862,6 +1037,22 @@ macro_rules! vec {
 			pub fn normalize(self) -> $vec<T> where T: Float {
 				self.normalize_len().0
 			}
+			/// Returns `true` if the length of the vector is within `epsilon` of `1.0`.
+			///
+			/// Vectors with non-finite components are not normalized.
+			///
+			/// ```
+			/// use cvmath::Vec3;
+			///
+			/// assert!(Vec3(0.6, -0.8, 0.0).is_normalized(1e-12));
+			/// assert!(!Vec3(1.0, 1.0, 0.0).is_normalized(1e-12));
+			/// assert!(!Vec3(f64::NAN, 0.0, 0.0).is_normalized(1e-12));
+			/// ```
+			#[inline]
+			#[must_use]
+			pub fn is_normalized(self, epsilon: T) -> bool where T: Float {
+				(self.len() - T::ONE).abs() <= epsilon
+			}
 			/// Calculates the normalized vector and its length.
 			///
 			/// After normalizing the vector has the length `1.0` except the zero vector remains zero.
@@ -886,6 +1077,29 @@ macro_rules! vec {
 					(self, self_len)
 				}
 			}
+			/// Normalizes the vector using a fast reciprocal square root approximation.
+			///
+			/// The resulting length is within 0.2% of `1.0`, use [normalize](#method.normalize) when precision matters.
+			/// The zero vector remains zero.
+			///
+			/// ```
+			/// use cvmath::Vec3;
+			///
+			/// let this = Vec3 { x: 3.0f32, y: -4.0, z: 12.0 };
+			/// assert!((this.normalize_fast().len() - 1.0).abs() < 0.002);
+			/// assert!(this.normalize_fast().distance(this.normalize()) < 0.002);
+			/// ```
+			#[inline]
+			#[must_use]
+			pub fn normalize_fast(self) -> $vec<T> where T: Float {
+				let len_sqr = self.len_sqr();
+				if len_sqr > T::ZERO {
+					self * len_sqr.rsqrt_fast()
+				}
+				else {
+					self
+				}
+			}
 			/// Resizes the vector to the given length.
 			///
 			/// The zero vector remains zero.
@@ -1012,6 +1226,27 @@ macro_rules! vec {
 			pub fn dot(self, rhs: $vec<T>) -> T {
 				infix!(+ $(self.$field * rhs.$field),+)
 			}
+			/// Calculates the dot product with fused multiply-add.
+			///
+			/// Each product is accumulated with a single rounding, reducing the rounding error when the terms cancel.
+			/// Slower than [dot](#method.dot) on targets without hardware FMA support.
+			///
+			/// ```
+			/// use cvmath::Vec2;
+			///
+			/// let e = 1.0 / 8192.0;
+			/// let lhs = Vec2(1.0f32, 1.0 + e);
+			/// let rhs = Vec2(-1.0f32, 1.0 - e);
+			/// assert_eq!(lhs.dot(rhs), 0.0);
+			/// assert_eq!(lhs.dot_fma(rhs), -e * e);
+			/// ```
+			#[inline]
+			#[must_use]
+			pub fn dot_fma(self, rhs: $vec<T>) -> T {
+				let mut acc = T::ZERO;
+				$(acc = self.$field.mul_add(rhs.$field, acc);)+
+				acc
+			}
 			/// Calculates the cosine of the angle between two vectors.
 			///
 			/// <!--COS_ANGLE-->
@@ -1116,6 +1351,70 @@ macro_rules! vec {
 			pub fn vmax(self) -> T {
 				self.reduce(T::max)
 			}
+			/// Component-wise clamp between the bounds.
+			///
+			/// ```
+			/// use cvmath::Vec3;
+			///
+			/// let this = Vec3(-2, 5, 1).clamp(Vec3(0, 0, 0), Vec3(3, 3, 3));
+			/// assert_eq!(this, Vec3(0, 3, 1));
+			/// ```
+			#[inline]
+			#[must_use]
+			pub fn clamp(self, min: $vec<T>, max: $vec<T>) -> $vec<T> {
+				self.max(min).min(max)
+			}
+			/// Index of the smallest component.
+			///
+			/// Ties resolve to the lowest index.
+			///
+			/// ```
+			/// use cvmath::{Vec2, Vec3};
+			///
+			/// assert_eq!(2, Vec3(3, 1, -4).min_component_index());
+			/// assert_eq!(0, Vec2(2, 2).min_component_index());
+			/// assert_eq!(1, Vec3(5, 1, 1).min_component_index());
+			/// ```
+			#[inline]
+			#[must_use]
+			pub fn min_component_index(self) -> usize {
+				let array: [T; $N] = self.into();
+				array.iter().enumerate().skip(1).fold(0, |best, (i, &c)| if c < array[best] { i } else { best })
+			}
+			/// Index of the largest component.
+			///
+			/// Ties resolve to the lowest index.
+			///
+			/// ```
+			/// use cvmath::{Vec2, Vec3};
+			///
+			/// assert_eq!(0, Vec3(3, 1, -4).max_component_index());
+			/// assert_eq!(0, Vec2(2, 2).max_component_index());
+			/// assert_eq!(1, Vec3(-5, 1, 1).max_component_index());
+			/// ```
+			#[inline]
+			#[must_use]
+			pub fn max_component_index(self) -> usize {
+				let array: [T; $N] = self.into();
+				array.iter().enumerate().skip(1).fold(0, |best, (i, &c)| if c > array[best] { i } else { best })
+			}
+			/// Index of the component with the largest absolute value.
+			///
+			/// Useful to pick a projection plane or a split axis.
+			/// Ties resolve to the lowest index.
+			///
+			/// ```
+			/// use cvmath::Vec3;
+			///
+			/// assert_eq!(2, Vec3(3.0, 1.0, -4.0).dominant_axis());
+			/// assert_eq!(1, Vec3(0.5, -2.0, 2.0).dominant_axis());
+			/// assert_eq!(0, Vec3(1.0, 1.0, -1.0).dominant_axis());
+			/// ```
+			#[inline]
+			#[must_use]
+			pub fn dominant_axis(self) -> usize {
+				self.abs().max_component_index()
+			}
 			/// Adds the scaled vector.
 			///
 			/// Equivalent to `self + (vec * scale)` with less rounding errors.
@@ -1189,6 +1488,232 @@ macro_rules! vec {
 			pub fn exp_decay(self, rhs: $vec<T>, decay: T, dt: T) -> $vec<T> where T: Float {
 				rhs + (self - rhs) * (-decay * dt).exp()
 			}
+			/// Critically damped spring smoothing.
+			///
+			/// See [`spring_damp`](crate::spring_damp), the max speed limits the length of the velocity.
+			///
+			/// ```
+			/// use cvmath::Vec2;
+			///
+			/// let target = Vec2(3.0, 4.0);
+			/// let mut pos = Vec2(0.0f32, 0.0);
+			/// let mut velocity = Vec2(0.0, 0.0);
+			/// for _ in 0..100 {
+			///     pos = pos.spring_damp(target, &mut velocity, 0.25, 1.0 / 60.0, None);
+			/// }
+			/// assert!(pos.distance(target) < 1e-2);
+			/// ```
+			#[inline]
+			#[must_use]
+			pub fn spring_damp(self, target: $vec<T>, velocity: &mut $vec<T>, smooth_time: T, dt: T, max_speed: Option<T>) -> $vec<T> where T: Float {
+				let clamp = |change: $vec<T>, max: T| if change.len_sqr() > max * max { change.resize(max) } else { change };
+				crate::spring::damp(self, target, velocity, smooth_time, dt, max_speed, clamp, |a, b| a.dot(b))
+			}
+			/// Cubic Bézier curve through the control points.
+			///
+			/// The curve starts at `p0` for `t = 0` and ends at `p3` for `t = 1`.
+			///
+			/// ```
+			/// use cvmath::Vec2;
+			///
+			/// let (p0, p1, p2, p3) = (Vec2(0.0, 0.0), Vec2(0.0, 1.0), Vec2(1.0, 1.0), Vec2(1.0, 0.0));
+			/// assert_eq!(p0, Vec2::bezier3(p0, p1, p2, p3, 0.0));
+			/// assert_eq!(Vec2(0.5, 0.75), Vec2::bezier3(p0, p1, p2, p3, 0.5));
+			/// assert_eq!(p3, Vec2::bezier3(p0, p1, p2, p3, 1.0));
+			/// ```
+			#[inline]
+			#[must_use]
+			pub fn bezier3(p0: $vec<T>, p1: $vec<T>, p2: $vec<T>, p3: $vec<T>, t: T) -> $vec<T> where T: Float {
+				let s = T::ONE - t;
+				let three = T::cast_from(3.0);
+				p0 * (s * s * s) + p1 * (three * s * s * t) + p2 * (three * s * t * t) + p3 * (t * t * t)
+			}
+			/// Derivative of the cubic Bézier curve.
+			///
+			/// The tangent points along the curve, use it to orient objects following the path.
+			///
+			/// ```
+			/// use cvmath::Vec2;
+			///
+			/// let (p0, p1, p2, p3) = (Vec2(0.0, 0.0), Vec2(0.0, 1.0), Vec2(1.0, 1.0), Vec2(1.0, 0.0));
+			/// assert_eq!(Vec2(0.0, 3.0), Vec2::bezier3_tangent(p0, p1, p2, p3, 0.0));
+			/// assert_eq!(Vec2(1.5, 0.0), Vec2::bezier3_tangent(p0, p1, p2, p3, 0.5));
+			/// ```
+			#[inline]
+			#[must_use]
+			pub fn bezier3_tangent(p0: $vec<T>, p1: $vec<T>, p2: $vec<T>, p3: $vec<T>, t: T) -> $vec<T> where T: Float {
+				let s = T::ONE - t;
+				let three = T::cast_from(3.0);
+				(p1 - p0) * (three * s * s) + (p2 - p1) * (three * T::cast_from(2.0) * s * t) + (p3 - p2) * (three * t * t)
+			}
+			/// Uniform Catmull-Rom spline segment.
+			///
+			/// The curve passes through `p1` for `t = 0` and `p2` for `t = 1`, `p0` and `p3` shape the tangents at the ends.
+			///
+			/// ```
+			/// use cvmath::Vec3;
+			///
+			/// let (p0, p1, p2, p3) = (Vec3(0.0, 0.0, 0.0), Vec3(1.0, 1.0, 0.0), Vec3(2.0, 1.0, 1.0), Vec3(3.0, 0.0, 1.0));
+			/// assert_eq!(p1, Vec3::catmull_rom(p0, p1, p2, p3, 0.0));
+			/// assert_eq!(p2, Vec3::catmull_rom(p0, p1, p2, p3, 1.0));
+			/// ```
+			#[inline]
+			#[must_use]
+			pub fn catmull_rom(p0: $vec<T>, p1: $vec<T>, p2: $vec<T>, p3: $vec<T>, t: T) -> $vec<T> where T: Float {
+				let half = T::cast_from(0.5);
+				let t2 = t * t;
+				let t3 = t2 * t;
+				let w0 = half * (T::cast_from(2.0) * t2 - t3 - t);
+				let w1 = half * (T::cast_from(3.0) * t3 - T::cast_from(5.0) * t2 + T::cast_from(2.0));
+				let w2 = half * (T::cast_from(4.0) * t2 - T::cast_from(3.0) * t3 + t);
+				let w3 = half * (t3 - t2);
+				p0 * w0 + p1 * w1 + p2 * w2 + p3 * w3
+			}
+			/// Derivative of the uniform Catmull-Rom spline segment.
+			///
+			/// ```
+			/// use cvmath::Vec3;
+			///
+			/// let (p0, p1, p2, p3) = (Vec3(0.0, 0.0, 0.0), Vec3(1.0, 1.0, 0.0), Vec3(2.0, 1.0, 1.0), Vec3(3.0, 0.0, 1.0));
+			/// assert_eq!((p2 - p0) * 0.5, Vec3::catmull_rom_tangent(p0, p1, p2, p3, 0.0));
+			/// assert_eq!((p3 - p1) * 0.5, Vec3::catmull_rom_tangent(p0, p1, p2, p3, 1.0));
+			/// ```
+			#[inline]
+			#[must_use]
+			pub fn catmull_rom_tangent(p0: $vec<T>, p1: $vec<T>, p2: $vec<T>, p3: $vec<T>, t: T) -> $vec<T> where T: Float {
+				let half = T::cast_from(0.5);
+				let t2 = t * t;
+				let w0 = half * (T::cast_from(4.0) * t - T::cast_from(3.0) * t2 - T::ONE);
+				let w1 = half * (T::cast_from(9.0) * t2 - T::cast_from(10.0) * t);
+				let w2 = half * (T::cast_from(8.0) * t - T::cast_from(9.0) * t2 + T::ONE);
+				let w3 = half * (T::cast_from(3.0) * t2 - T::cast_from(2.0) * t);
+				p0 * w0 + p1 * w1 + p2 * w2 + p3 * w3
+			}
+			/// Cubic Hermite curve from `p0` with tangent `m0` to `p1` with tangent `m1`.
+			///
+			/// ```
+			/// use cvmath::Vec2;
+			///
+			/// let (p0, m0, p1, m1) = (Vec2(0.0, 0.0), Vec2(1.0, 0.0), Vec2(1.0, 1.0), Vec2(0.0, 1.0));
+			/// assert_eq!(p0, Vec2::hermite(p0, m0, p1, m1, 0.0));
+			/// assert_eq!(Vec2(0.625, 0.375), Vec2::hermite(p0, m0, p1, m1, 0.5));
+			/// assert_eq!(p1, Vec2::hermite(p0, m0, p1, m1, 1.0));
+			/// ```
+			#[inline]
+			#[must_use]
+			pub fn hermite(p0: $vec<T>, m0: $vec<T>, p1: $vec<T>, m1: $vec<T>, t: T) -> $vec<T> where T: Float {
+				let two = T::cast_from(2.0);
+				let three = T::cast_from(3.0);
+				let t2 = t * t;
+				let t3 = t2 * t;
+				let h00 = two * t3 - three * t2 + T::ONE;
+				let h10 = t3 - two * t2 + t;
+				let h01 = three * t2 - two * t3;
+				let h11 = t3 - t2;
+				p0 * h00 + m0 * h10 + p1 * h01 + m1 * h11
+			}
+			/// Component-wise smoothstep.
+			///
+			/// Each component is clamped independently to the range between the edges and smoothly interpolated with `t * t * (3 - 2 * t)`.
+			/// Components with equal edges are a [step](#method.step) at the edge.
+			///
+			/// ```
+			/// use cvmath::Vec3;
+			///
+			/// let this = Vec3(-1.0, 0.25, 2.0).smoothstep(Vec3(0.0, 0.0, 0.0), Vec3(1.0, 0.5, 1.0));
+			/// assert_eq!(this, Vec3(0.0, 0.5, 1.0));
+			///
+			/// let this = Vec3(0.5, 1.0, 1.5).smoothstep(Vec3(1.0, 1.0, 1.0), Vec3(1.0, 1.0, 1.0));
+			/// assert_eq!(this, Vec3(0.0, 1.0, 1.0));
+			/// ```
+			#[inline]
+			#[must_use]
+			pub fn smoothstep(self, edge0: $vec<T>, edge1: $vec<T>) -> $vec<T> where T: Float {
+				$vec { $($field: scalar::smoothstep(edge0.$field, edge1.$field, self.$field)),+ }
+			}
+			/// Component-wise smootherstep.
+			///
+			/// Like [smoothstep](#method.smoothstep) with zero first and second derivatives at the edges using `t * t * t * (t * (6 * t - 15) + 10)`.
+			///
+			/// ```
+			/// use cvmath::Vec2;
+			///
+			/// let this = Vec2(0.5, 4.0).smootherstep(Vec2(0.0, 1.0), Vec2(1.0, 3.0));
+			/// assert_eq!(this, Vec2(0.5, 1.0));
+			/// ```
+			#[inline]
+			#[must_use]
+			pub fn smootherstep(self, edge0: $vec<T>, edge1: $vec<T>) -> $vec<T> where T: Float {
+				$vec { $($field: scalar::smootherstep(edge0.$field, edge1.$field, self.$field)),+ }
+			}
+			/// Component-wise step function.
+			///
+			/// Each component is zero if it is less than the edge and one otherwise.
+			/// This is GLSL's `step(edge, x)` with the arguments swapped.
+			///
+			/// ```
+			/// use cvmath::Vec3;
+			///
+			/// let this = Vec3(-1.0, 0.5, 2.0).step(Vec3(0.0, 0.5, 3.0));
+			/// assert_eq!(this, Vec3(0.0, 1.0, 0.0));
+			/// ```
+			#[inline]
+			#[must_use]
+			pub fn step(self, edge: $vec<T>) -> $vec<T> {
+				$vec { $($field: if self.$field < edge.$field { T::ZERO } else { T::ONE }),+ }
+			}
+			/// Component-wise selection by mask.
+			///
+			/// Selects the components of `rhs` where the mask is `true` and the components of `self` otherwise.
+			/// This is GLSL's `mix(x, y, a)` with a boolean vector `a`, see [select](#method.select) for the reverse argument order.
+			///
+			/// ```
+			/// use cvmath::{Bool3, Vec3};
+			///
+			/// let this = Vec3(1, 2, 3).mix(Vec3(4, 5, 6), Bool3(true, false, true));
+			/// assert_eq!(this, Vec3(4, 2, 6));
+			/// ```
+			#[inline]
+			#[must_use]
+			pub fn mix(self, rhs: $vec<T>, mask: $vec<bool>) -> $vec<T> {
+				mask.select(rhs, self)
+			}
+			/// Average of the vectors.
+			///
+			/// Returns `None` if the iterator is empty.
+			///
+			/// ```
+			/// use cvmath::Vec3;
+			///
+			/// let colors = [Vec3(1.0, 0.0, 0.0), Vec3(0.0, 1.0, 0.0), Vec3(0.5, 0.5, 0.0)];
+			/// assert_eq!(Vec3::average(colors), Some(Vec3(0.5, 0.5, 0.0)));
+			/// assert_eq!(Vec3::<f32>::average([]), None);
+			/// ```
+			#[inline]
+			#[must_use]
+			pub fn average<I: IntoIterator<Item = $vec<T>>>(iter: I) -> Option<$vec<T>> where T: Float {
+				let (sum, count) = iter.into_iter().fold(($vec::<T>::ZERO, 0usize), |(sum, count), v| (sum + v, count + 1));
+				if count == 0 {
+					return None;
+				}
+				Some(sum / T::cast_from(count as f64))
+			}
+			/// Centroid of the points.
+			///
+			/// Returns `None` if there are no points.
+			///
+			/// ```
+			/// use cvmath::Vec2;
+			///
+			/// let points = [Vec2(0.0, 0.0), Vec2(4.0, 0.0), Vec2(4.0, 2.0), Vec2(0.0, 2.0)];
+			/// assert_eq!(Vec2::centroid(&points), Some(Vec2(2.0, 1.0)));
+			/// assert_eq!(Vec2::<f64>::centroid(&[]), None);
+			/// ```
+			#[inline]
+			#[must_use]
+			pub fn centroid(points: &[$vec<T>]) -> Option<$vec<T>> where T: Float {
+				$vec::average(points.iter().copied())
+			}
 		}
 
 		// Float ops
@@ -1217,6 +1742,88 @@ macro_rules! vec {
 			pub fn fract(self) -> $vec<T> {
 				$vec { $($field: self.$field.fract()),+ }
 			}
+			/// Rounds to the nearest integer then casts.
+			///
+			/// Halfway cases round away from zero, `0.5` becomes `1` and `-0.5` becomes `-1`.
+			/// Out of range values saturate and NaN becomes zero, see [cast](#method.cast).
+			///
+			/// ```
+			/// use cvmath::Vec2;
+			///
+			/// let this = Vec2(2.5f32, -1.7);
+			/// assert_eq!(this.cast::<i32>(), Vec2(2, -1));
+			/// assert_eq!(this.cast_round::<i32>(), Vec2(3, -2));
+			/// assert_eq!(Vec2(-2.5f32, 1e10).cast_round::<i32>(), Vec2(-3, i32::MAX));
+			/// ```
+			#[inline]
+			#[must_use]
+			pub fn cast_round<U>(self) -> $vec<U> where T: CastTo<U> {
+				$vec { $($field: self.$field.round().cast_to()),+ }
+			}
+			/// Rounds towards negative infinity then casts.
+			///
+			/// Out of range values saturate and NaN becomes zero, see [cast](#method.cast).
+			///
+			/// ```
+			/// use cvmath::Vec2;
+			///
+			/// assert_eq!(Vec2(2.5f32, -1.2).cast_floor::<i32>(), Vec2(2, -2));
+			/// ```
+			#[inline]
+			#[must_use]
+			pub fn cast_floor<U>(self) -> $vec<U> where T: CastTo<U> {
+				$vec { $($field: self.$field.floor().cast_to()),+ }
+			}
+			/// Rounds towards positive infinity then casts.
+			///
+			/// Out of range values saturate and NaN becomes zero, see [cast](#method.cast).
+			///
+			/// ```
+			/// use cvmath::Vec2;
+			///
+			/// assert_eq!(Vec2(2.5f32, -1.2).cast_ceil::<i32>(), Vec2(3, -1));
+			/// ```
+			#[inline]
+			#[must_use]
+			pub fn cast_ceil<U>(self) -> $vec<U> where T: CastTo<U> {
+				$vec { $($field: self.$field.ceil().cast_to()),+ }
+			}
+			/// Snaps to the nearest multiple of the grid size.
+			///
+			/// Each component is rounded to a multiple of the corresponding grid component.
+			/// Halfway cases round away from zero, a component exactly between two grid lines snaps to the one further from the origin.
+			/// Never returns negative zero.
+			///
+			/// ```
+			/// use cvmath::Vec2;
+			///
+			/// let grid = Vec2(1.0f64, 0.25);
+			/// assert_eq!(Vec2(-0.4, 0.6).snap(grid), Vec2(0.0, 0.5));
+			/// assert_eq!(Vec2(2.5, -0.125).snap(grid), Vec2(3.0, -0.25));
+			/// assert!(Vec2(-0.4, 0.0).snap(grid).x.is_sign_positive());
+			/// ```
+			#[inline]
+			#[must_use]
+			pub fn snap(self, grid: $vec<T>) -> $vec<T> {
+				$vec { $($field: (self.$field / grid.$field).round() * grid.$field + T::ZERO),+ }
+			}
+			/// Snaps down to the multiple of the grid size towards negative infinity.
+			///
+			/// Each component is the start of the grid cell containing it, useful to find the tile under a point.
+			/// Never returns negative zero.
+			///
+			/// ```
+			/// use cvmath::Vec2;
+			///
+			/// let grid = Vec2(16.0, 16.0);
+			/// assert_eq!(Vec2(-0.4, 31.9).snap_floor(grid), Vec2(-16.0, 16.0));
+			/// assert_eq!(Vec2(32.0, -32.0).snap_floor(grid), Vec2(32.0, -32.0));
+			/// ```
+			#[inline]
+			#[must_use]
+			pub fn snap_floor(self, grid: $vec<T>) -> $vec<T> {
+				$vec { $($field: (self.$field / grid.$field).floor() * grid.$field + T::ZERO),+ }
+			}
 		}
 
 		//----------------------------------------------------------------
@@ -1433,6 +2040,32 @@ macro_rules! vec {
 			}
 		}
 
+		// Iterator sum and product
+		impl<T: Zero + ops::Add<Output = T>> iter::Sum for $vec<T> {
+			#[inline]
+			fn sum<I: Iterator<Item = $vec<T>>>(iter: I) -> $vec<T> {
+				iter.fold($vec::ZERO, |acc, v| acc + v)
+			}
+		}
+		impl<'a, T: Copy + Zero + ops::Add<Output = T>> iter::Sum<&'a $vec<T>> for $vec<T> {
+			#[inline]
+			fn sum<I: Iterator<Item = &'a $vec<T>>>(iter: I) -> $vec<T> {
+				iter.fold($vec::ZERO, |acc, &v| acc + v)
+			}
+		}
+		impl<T: One + ops::Mul<Output = T>> iter::Product for $vec<T> {
+			#[inline]
+			fn product<I: Iterator<Item = $vec<T>>>(iter: I) -> $vec<T> {
+				iter.fold($vec::ONE, |acc, v| acc * v)
+			}
+		}
+		impl<'a, T: Copy + One + ops::Mul<Output = T>> iter::Product<&'a $vec<T>> for $vec<T> {
+			#[inline]
+			fn product<I: Iterator<Item = &'a $vec<T>>>(iter: I) -> $vec<T> {
+				iter.fold($vec::ONE, |acc, &v| acc * v)
+			}
+		}
+
 		//----------------------------------------------------------------
 		// Formatting
 
@@ -1462,6 +2095,76 @@ macro_rules! vec {
 
 // vec!(Vec1 1 { x 0 T U X });
 vec!(Vec2 2 { x 0 T U X, y 1 T U Y } {
+	/// Unit vector from the polar angle.
+	///
+	/// Returns `(cos, sin)` of the angle, the inverse of [polar_angle](#method.polar_angle).
+	///
+	/// ```
+	/// use cvmath::{Deg, Vec2};
+	///
+	/// let this = Vec2::from_angle(Deg(90.0));
+	/// assert!(this.distance(Vec2(0.0, 1.0)) < 1e-12);
+	/// let angle = Vec2::from_angle(Deg(30.0f64)).polar_angle();
+	/// assert!((angle - Deg(30.0).to_rad()).value.abs() < 1e-12);
+	/// ```
+	#[inline]
+	pub fn from_angle(angle: impl Angle<T = T>) -> Vec2<T> where T: Float {
+		let (sin, cos) = angle.sin_cos();
+		Vec2 { x: cos, y: sin }
+	}
+	/// Vector from the polar angle and length.
+	///
+	/// ```
+	/// use cvmath::{Deg, Vec2};
+	///
+	/// let this = Vec2::from_angle_length(Deg(180.0), 2.0);
+	/// assert!(this.distance(Vec2(-2.0, 0.0)) < 1e-12);
+	/// ```
+	#[inline]
+	pub fn from_angle_length(angle: impl Angle<T = T>, len: T) -> Vec2<T> where T: Float {
+		Vec2::from_angle(angle) * len
+	}
+	/// Rotates the vector around the origin.
+	///
+	/// Positive angles rotate from +X towards +Y, the same direction as [from_angle](#method.from_angle).
+	///
+	/// Equivalent to `Mat2::rotate(angle) * self` without constructing the matrix.
+	///
+	/// ```
+	/// use cvmath::{Deg, Mat2, Vec2};
+	///
+	/// let v = Vec2(3.0, 4.0);
+	/// assert_eq!(v.rotate(Deg(30.0)), Mat2::rotate(Deg(30.0)) * v);
+	/// assert!(v.rotate(Deg(90.0)).distance(Vec2(-4.0, 3.0)) < 1e-12);
+	/// ```
+	#[inline]
+	pub fn rotate(self, angle: impl Angle<T = T>) -> Vec2<T> where T: Float {
+		let (sin, cos) = angle.sin_cos();
+		Vec2 {
+			x: self.x * cos + self.y * -sin,
+			y: self.x * sin + self.y * cos,
+		}
+	}
+	/// Rotates the vector by a binary angle.
+	///
+	/// The sine and cosine come from [`BinAngle::sin_cos`], see there for the accuracy.
+	///
+	/// ```
+	/// use cvmath::{BinAngle, Vec2};
+	///
+	/// let this = Vec2(1.0f32, 0.0);
+	/// assert_eq!(this.rotate_bin(BinAngle::QUARTER), Vec2(0.0, 1.0));
+	/// assert_eq!(this.rotate_bin(BinAngle::HALF), Vec2(-1.0, 0.0));
+	/// ```
+	#[inline]
+	pub fn rotate_bin(self, angle: BinAngle) -> Vec2<T> where T: Float {
+		let (sin, cos) = angle.sin_cos();
+		let (sin, cos) = (T::cast_from(sin as f64), T::cast_from(cos as f64));
+		Vec2 {
+			x: self.x * cos + self.y * -sin,
+			y: self.x * sin + self.y * cos,
+		}
+	}
 	/// Calculates the polar angle.
 	///
 	/// <!--POLAR_ANGLE-->
@@ -1582,19 +2285,65 @@ vec!(Vec3 3 { x 0 T U X, y 1 T U Y, z 2 T U Z } {
 		}
 	}
 	/// Homogeneous divide.
+	///
+	/// ```
+	/// use cvmath::{Vec2, Vec3};
+	///
+	/// assert_eq!(Vec3(2.0, 4.0, 2.0).hdiv(), Vec2(1.0, 2.0));
+	/// assert_eq!(Vec3(2.0, 4.0, 0.0).hdiv(), Vec2(2.0, 4.0));
+	/// ```
 	#[inline]
 	pub fn hdiv(self) -> Vec2<T> {
 		if self.z != T::ZERO {
 			Vec2 {
 				x: self.x / self.z,
-				y: self.x / self.z,
+				y: self.y / self.z,
 			}
 		}
 		else { self.xy() }
 	}
 });
 vec!(Vec4 4 { x 0 T U X, y 1 T U Y, z 2 T U Z, w 3 T U W } {
+	/// Homogeneous point.
+	///
+	/// Points have `w = 1` and are affected by the translation of a transformation matrix.
+	///
+	/// ```
+	/// use cvmath::{Mat4, Vec3, Vec4};
+	///
+	/// let pt = Vec4::from_point3(Vec3(1.0, 2.0, 3.0));
+	/// assert_eq!(pt, Vec4(1.0, 2.0, 3.0, 1.0));
+	/// assert_eq!(Mat4::translate(Vec3(1.0, 0.0, 0.0)) * pt, Vec4(2.0, 2.0, 3.0, 1.0));
+	/// ```
+	#[inline]
+	pub fn from_point3(pt: Point3<T>) -> Vec4<T> where T: One {
+		Vec4 { x: pt.x, y: pt.y, z: pt.z, w: T::ONE }
+	}
+	/// Homogeneous direction.
+	///
+	/// Directions have `w = 0` and are not affected by the translation of a transformation matrix.
+	///
+	/// ```
+	/// use cvmath::{Mat4, Vec3, Vec4};
+	///
+	/// let dir = Vec4::from_vec3(Vec3(1.0, 2.0, 3.0));
+	/// assert_eq!(dir, Vec4(1.0, 2.0, 3.0, 0.0));
+	/// assert_eq!(Mat4::translate(Vec3(1.0, 0.0, 0.0)) * dir, dir);
+	/// ```
+	#[inline]
+	pub fn from_vec3(v: Vec3<T>) -> Vec4<T> where T: Zero {
+		Vec4 { x: v.x, y: v.y, z: v.z, w: T::ZERO }
+	}
 	/// Homogeneous divide.
+	///
+	/// Converts a homogeneous point back to a point, directions with `w = 0` are returned without dividing.
+	///
+	/// ```
+	/// use cvmath::{Vec3, Vec4};
+	///
+	/// assert_eq!(Vec4(2.0, 4.0, 6.0, 2.0).hdiv(), Vec3(1.0, 2.0, 3.0));
+	/// assert_eq!(Vec4(2.0, 4.0, 6.0, 0.0).hdiv(), Vec3(2.0, 4.0, 6.0));
+	/// ```
 	#[inline]
 	pub fn hdiv(self) -> Vec3<T> {
 		if self.w != T::ZERO {
@@ -1671,9 +2420,66 @@ impl<'de, T: serde::Deserialize<'de>> serde::Deserialize<'de> for Vec4<T> {
 	}
 }
 
+#[cfg(feature = "mint")]
+impl<T> From<mint::Vector2<T>> for Vec2<T> {
+	#[inline]
+	fn from(v: mint::Vector2<T>) -> Vec2<T> {
+		Vec2 { x: v.x, y: v.y }
+	}
+}
+#[cfg(feature = "mint")]
+impl<T> From<Vec2<T>> for mint::Vector2<T> {
+	#[inline]
+	fn from(v: Vec2<T>) -> mint::Vector2<T> {
+		mint::Vector2 { x: v.x, y: v.y }
+	}
+}
+#[cfg(feature = "mint")]
+impl<T> mint::IntoMint for Vec2<T> {
+	type MintType = mint::Vector2<T>;
+}
+
+#[cfg(feature = "mint")]
+impl<T> From<mint::Vector3<T>> for Vec3<T> {
+	#[inline]
+	fn from(v: mint::Vector3<T>) -> Vec3<T> {
+		Vec3 { x: v.x, y: v.y, z: v.z }
+	}
+}
+#[cfg(feature = "mint")]
+impl<T> From<Vec3<T>> for mint::Vector3<T> {
+	#[inline]
+	fn from(v: Vec3<T>) -> mint::Vector3<T> {
+		mint::Vector3 { x: v.x, y: v.y, z: v.z }
+	}
+}
+#[cfg(feature = "mint")]
+impl<T> mint::IntoMint for Vec3<T> {
+	type MintType = mint::Vector3<T>;
+}
+
+#[cfg(feature = "mint")]
+impl<T> From<mint::Vector4<T>> for Vec4<T> {
+	#[inline]
+	fn from(v: mint::Vector4<T>) -> Vec4<T> {
+		Vec4 { x: v.x, y: v.y, z: v.z, w: v.w }
+	}
+}
+#[cfg(feature = "mint")]
+impl<T> From<Vec4<T>> for mint::Vector4<T> {
+	#[inline]
+	fn from(v: Vec4<T>) -> mint::Vector4<T> {
+		mint::Vector4 { x: v.x, y: v.y, z: v.z, w: v.w }
+	}
+}
+#[cfg(feature = "mint")]
+impl<T> mint::IntoMint for Vec4<T> {
+	type MintType = mint::Vector4<T>;
+}
+
 //----------------------------------------------------------------
 
-use std::str::FromStr;
+#[cfg(feature = "std")]
 use std::error::Error;
 
 /// An error which can be returned when parsing a vec.
@@ -1692,12 +2498,16 @@ impl<E> From<E> for ParseVecError<E> {
 		ParseVecError::ParseValue(err)
 	}
 }
-impl<E: Error + 'static> fmt::Display for ParseVecError<E> {
+impl<E: fmt::Display> fmt::Display for ParseVecError<E> {
 	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-		#[allow(deprecated)]
-		self.description().fmt(f)
+		match *self {
+			ParseVecError::SyntaxError => f.write_str("syntax error"),
+			ParseVecError::DimMismatch => f.write_str("dim mismatch"),
+			ParseVecError::ParseValue(ref inner) => inner.fmt(f),
+		}
 	}
 }
+#[cfg(feature = "std")]
 impl<E: Error + 'static> Error for ParseVecError<E> {
 	fn description(&self) -> &str {
 		#[allow(deprecated)]
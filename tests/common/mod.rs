@@ -0,0 +1,71 @@
+//! Helpers shared by the integration tests.
+
+#![allow(dead_code)]
+
+use cvmath::*;
+
+/// Deterministic linear congruential generator for randomized tests.
+pub struct Rng(pub u64);
+
+impl Rng {
+	/// Uniform in the `[0, 1)` range.
+	pub fn unit(&mut self) -> f64 {
+		self.0 = self.0.wrapping_mul(6364136223846793005).wrapping_add(1442695040888963407);
+		(self.0 >> 11) as f64 / (1u64 << 53) as f64
+	}
+	/// Uniform in the `[-1, 1)` range.
+	pub fn next(&mut self) -> f64 {
+		self.unit() * 2.0 - 1.0
+	}
+	pub fn vec2(&mut self) -> Vec2<f64> {
+		Vec2(self.next(), self.next())
+	}
+	pub fn vec3(&mut self) -> Vec3<f64> {
+		Vec3(self.next(), self.next(), self.next())
+	}
+	pub fn vec4(&mut self) -> Vec4<f64> {
+		Vec4(self.next(), self.next(), self.next(), self.next())
+	}
+	/// Uniform in the `[0, 1)` square, the samplers take their coordinates in this range.
+	pub fn unit2(&mut self) -> Vec2<f64> {
+		Vec2(self.unit(), self.unit())
+	}
+	/// Uniform in the `[0, 1)` cube.
+	pub fn unit3(&mut self) -> Vec3<f64> {
+		Vec3(self.unit(), self.unit(), self.unit())
+	}
+	/// Uniform in the `[-scale, scale)` square.
+	pub fn point2(&mut self, scale: f64) -> Point2<f64> {
+		self.vec2() * scale
+	}
+	/// Uniform in the `[-scale, scale)` cube.
+	pub fn point3(&mut self, scale: f64) -> Point3<f64> {
+		self.vec3() * scale
+	}
+	/// Random unit quaternion.
+	pub fn quaternion(&mut self) -> Quaternion<f64> {
+		Quaternion(self.next(), self.next(), self.next(), self.next()).normalize()
+	}
+	pub fn mat2(&mut self) -> Mat2<f64> {
+		Mat2::compose(self.vec2(), self.vec2())
+	}
+	pub fn mat3(&mut self) -> Mat3<f64> {
+		Mat3::compose(self.vec3(), self.vec3(), self.vec3())
+	}
+	pub fn mat4(&mut self) -> Mat4<f64> {
+		Mat4::compose(self.vec4(), self.vec4(), self.vec4(), self.vec4())
+	}
+	pub fn transform2(&mut self) -> Transform2<f64> {
+		Transform2::compose(self.vec2(), self.vec2(), self.vec2())
+	}
+	pub fn transform3(&mut self) -> Transform3<f64> {
+		Transform3::compose(self.vec3(), self.vec3(), self.vec3(), self.vec3())
+	}
+}
+
+/// Asserts that two matrices in row-major order are equal up to rounding errors.
+pub fn assert_close<const R: usize, const C: usize>(a: [[f64; C]; R], b: [[f64; C]; R]) {
+	for (a, b) in a.iter().flatten().zip(b.iter().flatten()) {
+		assert!((a - b).abs() < 1e-9, "{a:?} != {b:?}");
+	}
+}
@@ -0,0 +1,55 @@
+use core::mem::{align_of, size_of};
+use cvmath::*;
+
+// The bytemuck Pod impls and the slice reinterpretations rely on these types being tightly packed arrays of scalars
+macro_rules! assert_layout {
+	($($ty:ty => $n:expr),* $(,)?) => {
+		$(
+			assert_eq!(size_of::<$ty>(), $n * size_of::<f32>(), stringify!($ty));
+			assert_eq!(align_of::<$ty>(), align_of::<f32>(), stringify!($ty));
+		)*
+	};
+}
+
+#[test]
+fn no_padding() {
+	assert_layout! {
+		Vec2<f32> => 2, Vec3<f32> => 3, Vec4<f32> => 4,
+		Point2<f32> => 2, Point3<f32> => 3,
+		Mat2<f32> => 4, Mat3<f32> => 9, Mat4<f32> => 16,
+		Transform2<f32> => 6, Transform3<f32> => 12,
+		Quaternion<f32> => 4, DualQuaternion<f32> => 8,
+		Rad<f32> => 1, Deg<f32> => 1,
+		Rect<f32> => 4, Cuboid<f32> => 6,
+		Line2<f32> => 4, Line3<f32> => 6,
+		Triangle2<f32> => 6, Triangle3<f32> => 9,
+		Plane<f32> => 4, Sphere<f32> => 4, Ray<f32> => 6,
+		Spherical<f32> => 3, Stadium2<f32> => 5,
+	}
+	assert_eq!(size_of::<Vec3<f64>>(), 24);
+	assert_eq!(size_of::<Vec3<u8>>(), 3);
+}
+
+#[test]
+fn slice_as_scalars() {
+	let mut vertices = [Vec3(1.0f32, 2.0, 3.0), Vec3(4.0, 5.0, 6.0)];
+	assert_eq!(Vec3::slice_as_scalars(&vertices), &[1.0, 2.0, 3.0, 4.0, 5.0, 6.0]);
+	Vec3::slice_as_scalars_mut(&mut vertices)[4] = 0.0;
+	assert_eq!(vertices[1], Vec3(4.0, 0.0, 6.0));
+	assert_eq!(Vec2::<i32>::slice_as_scalars(&[]), &[]);
+}
+
+#[cfg(feature = "bytemuck")]
+#[test]
+fn bytemuck_cast() {
+	let vertices = [Vec3(1.0f32, 2.0, 3.0), Vec3(4.0, 5.0, 6.0)];
+	let scalars: &[f32] = bytemuck::cast_slice(&vertices);
+	assert_eq!(scalars, Vec3::slice_as_scalars(&vertices));
+	let bytes: &[u8] = bytemuck::cast_slice(&vertices);
+	assert_eq!(bytes.len(), 24);
+
+	let mat: Mat4<f32> = bytemuck::cast(Mat4::<f32>::IDENTITY.into_row_major());
+	assert_eq!(mat, Mat4::IDENTITY);
+	let zero: Quaternion<f32> = bytemuck::Zeroable::zeroed();
+	assert_eq!(zero, Quaternion::ZERO);
+}
@@ -0,0 +1,568 @@
+use cvmath::*;
+
+mod common;
+use common::Rng;
+
+//----------------------------------------------------------------
+// Column-major arrays
+
+/// Multiplies a vector by a flat column-major matrix the way a shader consumes a `mat4` uniform.
+fn column_major_mul(m: &[f32; 16], v: [f32; 4]) -> [f32; 4] {
+	let mut out = [0.0; 4];
+	for row in 0..4 {
+		for col in 0..4 {
+			out[row] += m[col * 4 + row] * v[col];
+		}
+	}
+	out
+}
+
+#[test]
+fn agrees_with_into_column_major() {
+	let mat = Mat4::perspective(Deg(90.0f32), 1.5, 0.1, 100.0, (RH, NO));
+	let cols = mat.into_column_major();
+	let flat = mat.to_cols_array();
+	for col in 0..4 {
+		assert_eq!(cols[col][..], flat[col * 4..col * 4 + 4]);
+	}
+	assert_eq!(mat, Mat4::from_cols_array(flat));
+
+	let mat3 = Mat3::rotate(Deg(30.0f32), Vec3::Z);
+	let cols = mat3.into_column_major();
+	let flat = mat3.to_cols_array_std140();
+	for col in 0..3 {
+		assert_eq!(cols[col][..], flat[col * 4..col * 4 + 3]);
+		assert_eq!(0.0, flat[col * 4 + 3]);
+	}
+}
+
+#[test]
+fn perspective_upload() {
+	let view = Transform3::translate(Vec3(0.0f32, 0.0, -5.0));
+	let projection = Mat4::perspective(Deg(60.0f32), 16.0 / 9.0, 0.1, 100.0, (RH, NO));
+	let view_proj = projection * view;
+	let uniform = view_proj.to_cols_array();
+
+	let corners = [
+		Point3(-1.0f32, -1.0, -1.0),
+		Point3( 1.0, -1.0, -1.0),
+		Point3( 1.0,  1.0, -1.0),
+		Point3(-1.0,  1.0, -1.0),
+		Point3(-1.0, -1.0,  1.0),
+		Point3( 1.0, -1.0,  1.0),
+		Point3( 1.0,  1.0,  1.0),
+		Point3(-1.0,  1.0,  1.0),
+	];
+
+	for &pt in &corners {
+		let expected = view_proj * pt.vec4(1.0);
+		let clip = column_major_mul(&uniform, pt.vec4(1.0).into());
+		assert_eq!(expected, Vec4::from(clip));
+	}
+
+	let model = Transform3::rotate(Deg(45.0f32), Vec3::Y).to_mat4_cols_array();
+	assert_eq!(model, Transform3::rotate(Deg(45.0f32), Vec3::Y).mat4().to_cols_array());
+}
+
+//----------------------------------------------------------------
+// Normal matrix
+
+// Sample the normal field of the unit sphere and check that transformed normals stay perpendicular to transformed tangents.
+#[test]
+fn sphere_normals_under_non_uniform_scale() {
+	let transform = Transform3::translate((1.0f64, -2.0, 0.5)) * Transform3::rotate(Deg(30.0), Vec3(0.0, 1.0, 0.0)) * Transform3::scale((3.0, 0.5, 1.5));
+	let normal_matrix = transform.normal_matrix().unwrap();
+	let mat = transform.mat3();
+
+	for i in 0..8 {
+		for j in 1..8 {
+			let (sin_phi, cos_phi) = Deg(i as f64 * 45.0).sin_cos();
+			let (sin_theta, cos_theta) = Deg(j as f64 * 22.5).sin_cos();
+
+			let normal = Vec3(sin_theta * cos_phi, sin_theta * sin_phi, cos_theta);
+			let tangent_phi = Vec3(-sin_phi, cos_phi, 0.0);
+			let tangent_theta = Vec3(cos_theta * cos_phi, cos_theta * sin_phi, -sin_theta);
+
+			let n = (normal_matrix * normal).normalize();
+			assert!(n.dot(mat * tangent_phi).abs() < 1e-12);
+			assert!(n.dot(mat * tangent_theta).abs() < 1e-12);
+
+			// Transforming normals by the rotation matrix is wrong under non-uniform scale
+			if i == 1 && j == 2 {
+				assert!((mat * normal).dot(mat * tangent_theta).abs() > 0.1);
+			}
+		}
+	}
+}
+
+#[test]
+fn mirror_winding() {
+	let mirrors = [
+		Transform3::scale((-1.0f64, 1.0, 1.0)),
+		Transform3::scale((1.0, 1.0, -2.0)) * Transform3::rotate(Deg(60.0), Vec3(1.0, 0.0, 0.0)),
+		Transform3::compose(Vec3::Y, Vec3::X, Vec3::Z, Vec3(1.0, 2.0, 3.0)),
+	];
+	for m in &mirrors {
+		assert!(m.flips_winding());
+		assert!(!(*m * *m).flips_winding());
+	}
+
+	let a = Point3(0.0, 0.0, 0.0);
+	let b = Point3(1.0, 0.0, 0.0);
+	let c = Point3(0.0, 1.0, 0.0);
+	let normal = (b - a).cross(c - a);
+	for m in &mirrors {
+		let (a, b, c) = (*m * a, *m * b, *m * c);
+		let transformed = (m.normal_matrix().unwrap() * normal).normalize();
+		// The geometric normal of the mirrored triangle points the other way
+		assert!((b - a).cross(c - a).normalize().dot(transformed) < -0.999);
+	}
+}
+
+#[test]
+fn transform_plane() {
+	let plane = Plane::new_alt(Vec3(1.0f64, 1.0, 0.0).normalize(), Point3(1.0, 1.0, 0.0));
+	let transform = Transform3::translate((0.0, 0.0, 5.0)) * Transform3::scale((2.0, 1.0, -1.0));
+	let result = transform * plane;
+
+	assert!((result.normal.len() - 1.0).abs() < 1e-12);
+	for pt in [Point3(2.0, 0.0, 1.0), Point3(0.0, 2.0, -3.0), Point3(3.0, -1.0, 7.0)] {
+		assert!(result.dist_pt(transform * pt).abs() < 1e-12);
+	}
+	for pt in [Point3(2.0, 2.0, 0.0), Point3(-1.0, 0.0, 4.0)] {
+		assert_eq!(plane.dist_pt(pt) > 0.0, result.dist_pt(transform * pt) > 0.0);
+	}
+}
+
+// Triangle normals transformed with transform_normal match the normals of the transformed triangles.
+#[test]
+fn transform_normal_anisotropic() {
+	let transforms = [
+		Transform3::scale((10.0f64, 0.1, 2.0)),
+		Transform3::rotate(Deg(-50.0), Vec3(1.0, 2.0, -1.0).normalize()) * Transform3::scale((0.2, 5.0, 1.0)) * Transform3::rotate(Deg(20.0), Vec3::X),
+		Transform3::translate((3.0, 0.0, -1.0)) * Transform3::scale((-4.0, 1.0, 0.5)),
+	];
+	let triangles = [
+		Triangle3(Point3(0.0, 0.0, 0.0), Point3(1.0, 0.0, 0.0), Point3(0.0, 1.0, 0.0)),
+		Triangle3(Point3(1.0, 2.0, 3.0), Point3(-2.0, 0.5, 1.0), Point3(0.0, -1.0, 2.0)),
+		Triangle3(Point3(0.3, 0.3, 0.0), Point3(0.0, 0.7, 0.7), Point3(-0.5, 0.0, 0.5)),
+	];
+	for transform in transforms {
+		assert_eq!(transform.normal_matrix(), transform.mat3().normal_matrix());
+		for triangle in triangles {
+			let normal = transform.transform_normal(triangle.normal());
+			assert!(normal.is_normalized(1e-12));
+
+			let (a, b, c) = (transform * triangle.p0, transform * triangle.p1, transform * triangle.p2);
+			assert!(normal.dot(b - a).abs() < 1e-12);
+			assert!(normal.dot(c - a).abs() < 1e-12);
+
+			// Mirroring transforms flip the winding, the transformed normal keeps facing outward
+			let expected = if transform.flips_winding() { -Triangle3(a, b, c).normal() } else { Triangle3(a, b, c).normal() };
+			assert!(normal.distance(expected) < 1e-12);
+		}
+	}
+	assert_eq!(Transform3::scale((1.0, 0.0, 1.0)).transform_normal(Vec3(0.0, 1.0, 0.0)), Vec3::ZERO);
+}
+
+//----------------------------------------------------------------
+// Operators
+
+macro_rules! check_identities {
+	($rng:ident, $mat:ident, $new_mat:ident, $new_vec:expr) => {
+		for _ in 0..100 {
+			let a = $rng.$new_mat();
+			let b = $rng.$new_mat();
+			let v = $new_vec;
+			let s = $rng.next() + 2.0;
+
+			assert!(((a + b) * v).distance(a * v + b * v) < 1e-12);
+			assert!(((a - b) * v).distance(a * v - b * v) < 1e-12);
+			assert!(((a / s) * v).distance((a * v) / s) < 1e-12);
+			assert_eq!((-a) * v, -(a * v));
+			assert_eq!(-(-a), a);
+			assert_eq!(a + (-a), $mat::ZERO);
+			assert_eq!(a - a, $mat::ZERO);
+
+			let mut c = a;
+			c += b;
+			assert_eq!(c, a + b);
+			c -= b;
+			assert_eq!(c, a + b - b);
+			c /= s;
+			assert_eq!(c, (a + b - b) / s);
+
+			assert_eq!([a, b, a].into_iter().sum::<$mat<f64>>(), a + b + a);
+			assert_eq!([a, b].into_iter().product::<$mat<f64>>(), $mat::<f64>::IDENTITY * a * b);
+		}
+		assert_eq!(std::iter::empty().sum::<$mat<f64>>(), $mat::ZERO);
+		assert_eq!(std::iter::empty().product::<$mat<f64>>(), $mat::IDENTITY);
+	};
+}
+
+#[test]
+fn operator_identities() {
+	let mut rng = Rng(0x1234);
+	check_identities!(rng, Mat2, mat2, rng.vec4().xy());
+	check_identities!(rng, Mat3, mat3, rng.vec4().xyz());
+	check_identities!(rng, Mat4, mat4, rng.vec4());
+	check_identities!(rng, Transform2, transform2, rng.vec4().xy());
+	check_identities!(rng, Transform3, transform3, rng.vec4().xyz());
+}
+
+// Central differences estimate the Jacobian of a function using matrix arithmetic
+#[test]
+fn numerical_jacobian() {
+	let f = |v: Vec2<f64>| Vec2(v.x * v.x + v.y, v.x * v.y);
+	let jacobian = |v: Vec2<f64>| Mat2(2.0 * v.x, 1.0, v.y, v.x);
+
+	let h = 1e-5;
+	let v = Vec2(1.5, -0.5);
+	let dx = (f(v + Vec2(h, 0.0)) - f(v - Vec2(h, 0.0))) / (2.0 * h);
+	let dy = (f(v + Vec2(0.0, h)) - f(v - Vec2(0.0, h))) / (2.0 * h);
+	let estimate = Mat2::compose(dx, dy);
+
+	let error = estimate - jacobian(v);
+	assert!(error.x().len() < 1e-8 && error.y().len() < 1e-8);
+}
+
+#[test]
+fn transform_slices() {
+	let mat = Mat4::perspective(Deg(60.0), 1.5, 0.1, 100.0, (RH, NO)) * Mat4::translate(Vec3(1.0, -2.0, 3.0));
+	let points: Vec<_> = (0..10).map(|i| Vec4(i as f64, 1.0 - i as f64, 0.5 * i as f64, 1.0)).collect();
+	let mut out = vec![Vec4::ZERO; points.len()];
+	mat.transform_slice(&points, &mut out);
+	let mut in_place = points.clone();
+	mat.transform_slice_in_place(&mut in_place);
+	for i in 0..points.len() {
+		assert_eq!(out[i], mat * points[i]);
+		assert_eq!(in_place[i], out[i]);
+	}
+
+	let transform = Transform3::translate(Vec3(1.0, -2.0, 3.0)) * Transform3::rotate(Deg(30.0), Vec3(0.0, 1.0, 0.0));
+	let points: Vec<_> = points.iter().map(|v| v.xyz()).collect();
+	let mut out = vec![Vec3::ZERO; points.len()];
+	transform.transform_points(&points, &mut out);
+	let mut in_place = points.clone();
+	transform.transform_points_in_place(&mut in_place);
+	for i in 0..points.len() {
+		assert_eq!(out[i], transform * points[i]);
+		assert_eq!(in_place[i], out[i]);
+	}
+}
+
+#[test]
+#[should_panic]
+fn transform_slice_length_mismatch() {
+	let mut out = [Vec4::ZERO; 1];
+	Mat4::<f32>::IDENTITY.transform_slice(&[Vec4::ZERO; 2], &mut out);
+}
+
+//----------------------------------------------------------------
+// Inverse
+
+// Regression test for the adjugate being transposed, which made the inverse of non-symmetric matrices wrong
+#[test]
+fn mat4_inverse_non_symmetric() {
+	let mat = Mat4::translate(Vec3(1.0, -2.0, 3.0)) * Mat4::rotate(Deg(30.0), Vec3(1.0, 2.0, 3.0).normalize()) * Mat4::scale(Vec3(2.0, 0.5, 4.0));
+	let inv = mat.inverse();
+	for v in [Vec4(1.0, 0.0, 0.0, 0.0), Vec4(0.0, 1.0, 0.0, 0.0), Vec4(0.0, 0.0, 1.0, 0.0), Vec4(0.0, 0.0, 0.0, 1.0), Vec4(3.0, -1.0, 2.0, 1.0)] {
+		assert!((inv * (mat * v) - v).len() < 1e-12);
+		assert!((mat * (inv * v) - v).len() < 1e-12);
+	}
+
+	// The inverse of a translation negates the translation
+	let inv = Mat4::translate(Vec3(1.0, 2.0, 3.0)).inverse();
+	assert_eq!(inv * Vec4(1.0, 2.0, 3.0, 1.0), Vec4(0.0, 0.0, 0.0, 1.0));
+}
+
+//----------------------------------------------------------------
+// Fast paths
+
+#[test]
+fn normalize_fast_within_bounds() {
+	let mut rng = Rng(1);
+	for i in 0..1000 {
+		let scale = 10.0f64.powi(i % 13 - 6);
+		let v = rng.vec3() * scale;
+		let exact = v.normalize();
+
+		let fast = v.normalize_fast();
+		assert!(fast.distance(exact) < 0.002, "{:?} {:?}", fast, exact);
+
+		let v = v.cast::<f32>();
+		let fast = v.normalize_fast();
+		assert!(fast.cast::<f64>().distance(exact) < 0.002, "{:?} {:?}", fast, exact);
+	}
+	assert_eq!(Vec3::<f32>::ZERO.normalize_fast(), Vec3::ZERO);
+}
+
+#[test]
+fn inverse_affine_matches_inverse() {
+	let mut rng = Rng(2);
+	for _ in 0..100 {
+		let mat = Mat4::translate(rng.vec3() * 10.0) * Mat4::rotate(Rad(rng.next() * 3.0), rng.vec3().normalize()) * Mat4::scale(rng.vec3() + Vec3(2.0, 2.0, 2.0));
+		let exact = mat.inverse();
+		let affine = mat.inverse_affine();
+		let diff = exact - affine;
+		for x in diff.into_row_major().iter().flatten() {
+			assert!(x.abs() < 1e-12, "{:?}", diff);
+		}
+		let diff = mat * exact - Mat4::IDENTITY;
+		for x in diff.into_row_major().iter().flatten() {
+			assert!(x.abs() < 1e-12, "{:?}", diff);
+		}
+	}
+}
+
+#[test]
+fn inverse_rigid_matches_inverse() {
+	let mut rng = Rng(3);
+	for _ in 0..100 {
+		let transform = Transform3::translate(rng.vec3() * 10.0) * Transform3::rotate(Rad(rng.next() * 3.0), rng.vec3().normalize());
+		let exact = transform.inverse();
+		let rigid = transform.inverse_rigid();
+		let diff = exact - rigid;
+		for x in diff.into_row_major().iter().flatten() {
+			assert!(x.abs() < 1e-12, "{:?}", diff);
+		}
+	}
+}
+
+#[test]
+#[should_panic]
+#[cfg(debug_assertions)]
+fn inverse_rigid_rejects_scale() {
+	let _ = Transform3::scale(Vec3(2.0f64, 1.0, 1.0)).inverse_rigid();
+}
+
+#[test]
+#[should_panic]
+#[cfg(debug_assertions)]
+fn inverse_affine_rejects_projection() {
+	let mut mat = Mat4::<f64>::IDENTITY;
+	mat.a43 = 1.0;
+	let _ = mat.inverse_affine();
+}
+
+#[test]
+fn view_matrix_fast_inverses() {
+	let mut rng = Rng(4);
+	for _ in 0..100 {
+		let eye = rng.vec3() * 10.0;
+		let target = rng.vec3();
+		let view = Mat4::look_at(eye, target, Vec3(0.0, 1.0, 0.0), LH);
+		let exact = view.inverse();
+		for fast in [view.inverse_affine(), view.inverse_rigid()] {
+			let diff = exact - fast;
+			for x in diff.into_row_major().iter().flatten() {
+				assert!(x.abs() < 1e-9, "{:?}", diff);
+			}
+		}
+		// The inverse view matrix maps the origin back to the eye
+		assert!((view.inverse_rigid() * Vec4(0.0, 0.0, 0.0, 1.0)).xyz().distance(eye) < 1e-9);
+	}
+}
+
+//----------------------------------------------------------------
+// Decomposition
+
+fn max_diff(a: Mat4<f64>, b: Mat4<f64>) -> f64 {
+	(a - b).into_row_major().iter().flatten().fold(0.0, |acc, &x| f64::max(acc, x.abs()))
+}
+
+#[test]
+fn rotation_matrix_matches_quaternion() {
+	let mut rng = Rng(7);
+	for _ in 0..100 {
+		let q = rng.quaternion();
+		let v = rng.vec3();
+		assert!((q.rotation_matrix() * v).distance(q * v) < 1e-12);
+
+		// Round trip up to the sign of the quaternion
+		let r = Quaternion::from_rotation_matrix(q.rotation_matrix());
+		assert!(f64::min((r - q).len(), (r + q).len()) < 1e-12, "{:?} {:?}", q, r);
+	}
+}
+
+#[test]
+fn decompose_round_trip() {
+	let mut rng = Rng(8);
+	for i in 0..100 {
+		let translation = rng.vec3() * 10.0;
+		let rotation = rng.quaternion();
+		let mut scale = rng.vec3().map(|x| x.abs() + 0.1) * 3.0;
+		// Mirror some of the matrices
+		if i % 3 == 0 {
+			scale.y = -scale.y;
+		}
+		let mat = Mat4::compose_trs(translation, rotation, scale);
+
+		let r = rotation.rotation_matrix();
+		let r = Mat4::compose(r.x().vec4(0.0), r.y().vec4(0.0), r.z().vec4(0.0), Vec4(0.0, 0.0, 0.0, 1.0));
+		let trs = Mat4::translate(translation) * r * Mat4::scale(scale);
+		assert!(max_diff(mat, trs) < 1e-5);
+
+		let (t, r, s) = mat.decompose();
+		assert!(t.distance(translation) < 1e-5);
+		assert!(s.abs().distance(scale.abs()) < 1e-5);
+		assert_eq!(s.x < 0.0, i % 3 == 0);
+		assert!((r.len() - 1.0).abs() < 1e-5);
+		assert!(max_diff(Mat4::compose_trs(t, r, s), mat) < 1e-5);
+	}
+}
+
+#[test]
+fn transform_compose_trs_order() {
+	let mut rng = Rng(10);
+	for _ in 0..100 {
+		let (translation, rotation, scale) = (rng.vec3(), rng.quaternion(), rng.vec3());
+		let transform = Transform3::compose_trs(translation, rotation, scale);
+		let expected = Mat4::compose_trs(translation, rotation, scale);
+		assert_eq!(Mat4::from(transform), expected);
+
+		let pt = rng.vec3();
+		let manual = rotation * (pt * scale) + translation;
+		assert!((transform * pt).distance(manual) < 1e-12);
+	}
+}
+
+//----------------------------------------------------------------
+// Finite checks
+
+const BAD: [f64; 3] = [f64::NAN, f64::INFINITY, f64::NEG_INFINITY];
+
+#[test]
+fn finite_vectors_and_rotations() {
+	for &bad in &BAD {
+		assert!(!Vec2(bad, 0.0).is_finite().all());
+		assert!(!Vec3(0.0, 0.0, bad).is_finite().all());
+		assert!(!Vec4(0.0, bad, 0.0, 1.0).is_finite().all());
+		assert!(!Vec3(bad, 0.0, 0.0).is_normalized(1e-6));
+		assert!(!Rad(bad).is_finite());
+		assert!(!Deg(bad).is_finite());
+		assert!(!Quaternion(1.0, 0.0, bad, 0.0).all_finite());
+		assert!(!Quaternion(bad, 0.0, 0.0, 0.0).is_normalized(1e-6));
+	}
+	assert!(Vec3(0.0, 1.0, 0.0).is_normalized(0.0));
+	assert!(Vec3(1.0, 1.0, 1.0).normalize().is_normalized(1e-12));
+	assert!(!Vec3(1.0, 1.0, 1.0).is_normalized(1e-6));
+	assert!(Rad(1.0).is_finite() && Deg(-90.0).is_finite());
+	assert!(Quaternion(1.0, 2.0, 3.0, 4.0).normalize().is_normalized(1e-12));
+}
+
+#[test]
+fn finite_matrices() {
+	for &bad in &BAD {
+		let mut m2 = Mat2::<f64>::IDENTITY;
+		m2.a21 = bad;
+		assert!(!m2.all_finite());
+		let mut m3 = Mat3::<f64>::IDENTITY;
+		m3.a32 = bad;
+		assert!(!m3.all_finite());
+		let mut m4 = Mat4::<f64>::IDENTITY;
+		m4.a44 = bad;
+		assert!(!m4.all_finite());
+		let mut t2 = Transform2::<f64>::IDENTITY;
+		t2.a13 = bad;
+		assert!(!t2.all_finite());
+		let mut t3 = Transform3::<f64>::IDENTITY;
+		t3.a34 = bad;
+		assert!(!t3.all_finite());
+	}
+}
+
+#[test]
+fn finite_shapes() {
+	let rect = Rect(Point2(0.0, 0.0), Point2(1.0, 1.0));
+	let cuboid = Cuboid(Point3(0.0, 0.0, 0.0), Point3(1.0, 1.0, 1.0));
+	let ray = Ray(Point3(0.0, 0.0, 0.0), Vec3(0.0, 0.0, 1.0));
+	let plane = Plane(Vec3(0.0, 1.0, 0.0), 0.0);
+	let sphere = Sphere(Point3(0.0, 0.0, 0.0), 1.0);
+	let triangle = Triangle3(Point3(0.0, 0.0, 0.0), Point3(1.0, 0.0, 0.0), Point3(0.0, 1.0, 0.0));
+	assert!(rect.is_finite() && rect.is_valid());
+	assert!(cuboid.is_finite() && cuboid.is_valid());
+	assert!(ray.is_finite() && plane.is_finite() && sphere.is_finite() && triangle.is_finite());
+
+	for &bad in &BAD {
+		assert!(!Rect(Point2(0.0, bad), Point2(1.0, 1.0)).is_finite());
+		assert!(!Cuboid(Point3(0.0, 0.0, 0.0), Point3(1.0, bad, 1.0)).is_finite());
+		assert!(!Ray(Point3(bad, 0.0, 0.0), ray.direction).is_finite());
+		assert!(!Ray(ray.origin, Vec3(0.0, bad, 1.0)).is_finite());
+		assert!(!Plane(plane.normal, bad).is_finite());
+		assert!(!Sphere(sphere.center, bad).is_finite());
+		assert!(!Triangle3(triangle.p0, triangle.p1, Point3(0.0, 0.0, bad)).is_finite());
+	}
+
+	// Inverted or NaN bounds are invalid
+	assert!(!Rect(Point2(2.0, 0.0), Point2(1.0, 1.0)).is_valid());
+	assert!(!Cuboid(Point3(0.0, 0.0, f64::NAN), Point3(1.0, 1.0, 1.0)).is_valid());
+	assert!(Cuboid(Point3(0.0, 0.0, 0.0), Point3(0.0, 0.0, 0.0)).is_valid());
+}
+
+//----------------------------------------------------------------
+// Formatting and parsing
+
+#[test]
+fn display_single_line() {
+	let m = Mat2::from_row_major([[1, 2], [3, 4]]);
+	assert_eq!(format!("{}", m), "[(1,2),(3,4)]");
+	assert_eq!(format!("{}", Transform2::from_row_major([[1, 2, 3], [4, 5, 6]])), "[(1,2,3),(4,5,6)]");
+	assert_eq!(format!("{:.1}", Mat2::from_row_major([[1.0, 0.25], [-2.0, 10.0]])), "[(1.0,0.2),(-2.0,10.0)]");
+}
+
+#[test]
+fn display_alternate_snapshot() {
+	let m = Mat3::from_row_major([[1.0, -2.5, 30.0], [0.0, 1.0, 0.0], [-10.0, 0.125, 1.0]]);
+	assert_eq!(format!("{:#}", m), "\
+[(  1,  -2.5, 30),
+ (  0,     1,  0),
+ (-10, 0.125,  1)]");
+}
+
+#[test]
+fn display_alternate_precision_and_width() {
+	let m = Mat3::from_row_major([[1.0, -2.5, 30.0], [0.0, 1.0, 0.0], [-10.0, 0.125, 1.0]]);
+	assert_eq!(format!("{:#.2}", m), "\
+[(  1.00, -2.50, 30.00),
+ (  0.00,  1.00,  0.00),
+ (-10.00,  0.12,  1.00)]");
+	assert_eq!(format!("{:#4}", Transform2::from_row_major([[1, -2, 30], [4, 5, 6]])), "\
+[(   1,   -2,   30),
+ (   4,    5,    6)]");
+}
+
+#[test]
+fn parse_round_trip() {
+	let mut rng = Rng(42);
+	for _ in 0..100 {
+		let m = Mat4::from_row_major([[rng.next(), rng.next(), rng.next(), rng.next()], [rng.next(), rng.next(), rng.next(), rng.next()], [rng.next(), rng.next(), rng.next(), rng.next()], [rng.next(), rng.next(), rng.next(), rng.next()]]);
+		assert_eq!(m.to_string().parse::<Mat4<f64>>(), Ok(m));
+		// The alternate format parses as well
+		assert_eq!(format!("{:#}", m).parse::<Mat4<f64>>(), Ok(m));
+
+		let m = Transform3::from_row_major([[rng.next(), rng.next(), rng.next(), rng.next()], [rng.next(), rng.next(), rng.next(), rng.next()], [rng.next(), rng.next(), rng.next(), rng.next()]]);
+		assert_eq!(m.to_string().parse::<Transform3<f64>>(), Ok(m));
+
+		let m = Mat3::from_row_major([[rng.next(), rng.next(), rng.next()], [rng.next(), rng.next(), rng.next()], [rng.next(), rng.next(), rng.next()]]);
+		assert_eq!(m.to_string().parse::<Mat3<f64>>(), Ok(m));
+
+		let m = Transform2::from_row_major([[rng.next(), rng.next(), rng.next()], [rng.next(), rng.next(), rng.next()]]);
+		assert_eq!(m.to_string().parse::<Transform2<f64>>(), Ok(m));
+
+		let m = Mat2::from_row_major([[rng.next(), rng.next()], [rng.next(), rng.next()]]);
+		assert_eq!(m.to_string().parse::<Mat2<f64>>(), Ok(m));
+	}
+}
+
+#[test]
+fn parse_errors() {
+	assert!("[(1,2),(3,4)]".parse::<Mat2<i32>>().is_ok());
+	assert!(" [ (1, 2) , (3, 4) ] ".parse::<Mat2<i32>>().is_ok());
+	assert!("(1,2),(3,4)".parse::<Mat2<i32>>().is_err());
+	assert!("[(1,2)]".parse::<Mat2<i32>>().is_err());
+	assert!("[(1,2),(3,4),(5,6)]".parse::<Mat2<i32>>().is_err());
+	assert!("[(1,2,3),(3,4,5)]".parse::<Mat2<i32>>().is_err());
+	assert!("[(1,2),(3,x)]".parse::<Mat2<i32>>().is_err());
+	assert!("[(1,2)(3,4)]".parse::<Mat2<i32>>().is_err());
+}
@@ -0,0 +1,65 @@
+#![cfg(feature = "mint")]
+
+use cvmath::*;
+
+#[test]
+fn vectors() {
+	let v = Vec2(1.0, 2.0);
+	let m: mint::Vector2<f64> = v.into();
+	assert_eq!((m.x, m.y), (1.0, 2.0));
+	assert_eq!(Vec2::from(m), v);
+
+	let v = Vec3(1.0, 2.0, 3.0);
+	let m: mint::Vector3<f64> = v.into();
+	assert_eq!((m.x, m.y, m.z), (1.0, 2.0, 3.0));
+	assert_eq!(Vec3::from(m), v);
+
+	let v = Vec4(1.0, 2.0, 3.0, 4.0);
+	let m: mint::Vector4<f64> = v.into();
+	assert_eq!((m.x, m.y, m.z, m.w), (1.0, 2.0, 3.0, 4.0));
+	assert_eq!(Vec4::from(m), v);
+}
+
+#[test]
+fn points() {
+	let pt = Point2(1.0, 2.0);
+	let m: mint::Point2<f64> = pt.into();
+	assert_eq!((m.x, m.y), (1.0, 2.0));
+	assert_eq!(Point2::from(m), pt);
+
+	let pt = Point3(1.0, 2.0, 3.0);
+	let m: mint::Point3<f64> = pt.into();
+	assert_eq!((m.x, m.y, m.z), (1.0, 2.0, 3.0));
+	assert_eq!(Point3::from(m), pt);
+}
+
+#[test]
+fn quaternion() {
+	let q = Quaternion(1.0, 2.0, 3.0, 4.0);
+	let m: mint::Quaternion<f64> = q.into();
+	assert_eq!(m.s, 1.0);
+	assert_eq!((m.v.x, m.v.y, m.v.z), (2.0, 3.0, 4.0));
+	assert_eq!(Quaternion::from(m), q);
+}
+
+#[test]
+fn matrices() {
+	let mat = Mat3::from_row_major([[1, 2, 3], [4, 5, 6], [7, 8, 9]]);
+	let m: mint::ColumnMatrix3<i32> = mat.into();
+	let cols: [[i32; 3]; 3] = m.into();
+	assert_eq!(cols, mat.into_column_major());
+	assert_eq!(Mat3::from(m), mat);
+
+	let mat = Mat4::from_row_major([[1, 2, 3, 4], [5, 6, 7, 8], [9, 10, 11, 12], [13, 14, 15, 16]]);
+	let m: mint::ColumnMatrix4<i32> = mat.into();
+	let cols: [[i32; 4]; 4] = m.into();
+	assert_eq!(cols, mat.into_column_major());
+	assert_eq!(Mat4::from(m), mat);
+
+	let mat = Transform3::from_row_major([[1, 2, 3, 4], [5, 6, 7, 8], [9, 10, 11, 12]]);
+	let m: mint::ColumnMatrix3x4<i32> = mat.into();
+	assert_eq!((m.w.x, m.w.y, m.w.z), (4, 8, 12));
+	let cols: [[i32; 3]; 4] = m.into();
+	assert_eq!(cols, mat.into_column_major());
+	assert_eq!(Transform3::from(m), mat);
+}
@@ -0,0 +1,33 @@
+//! Representative usage without the standard library.
+//!
+//! Run with `cargo test --no-default-features --features libm --test no_std`.
+
+#![no_std]
+
+// Only the test harness needs std
+extern crate std;
+
+use cvmath::*;
+
+fn trace_transformed_sphere() -> Option<TraceHit<f32>> {
+	let model = Transform3::translate(Vec3(0.0, 0.0, 5.0)) * Transform3::rotate(Deg(30.0), Vec3(0.0, 1.0, 0.0));
+	let sphere = Sphere(model * Point3(0.0, 0.0, 0.0), 1.0);
+	let ray = Ray(Point3(0.0, 0.0, 0.0), Vec3(0.0, 0.0, 1.0));
+
+	let mut hits = [TraceHit::default(); 2];
+	let count = ray.trace(&sphere, &mut hits);
+	hits[..count].iter().copied().reduce(|a, b| if a.distance < b.distance { a } else { b })
+}
+
+#[test]
+fn representative_usage() {
+	let hit = trace_transformed_sphere().unwrap();
+	assert!((hit.distance - 4.0).abs() < 1e-5);
+	assert!(hit.normal.distance(Vec3(0.0, 0.0, -1.0)) < 1e-5);
+
+	let q = Quaternion::rotation_z(Deg(90.0f32));
+	assert!((q * Vec3(1.0, 0.0, 0.0)).distance(Vec3(0.0, 1.0, 0.0)) < 1e-6);
+
+	let m = Mat4::perspective(Deg(60.0f32), 1.5, 0.1, 100.0, (RH, NO));
+	assert!((m.inverse() * m).into_row_major().iter().flatten().all(|x| x.is_finite()));
+}
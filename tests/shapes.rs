@@ -0,0 +1,1724 @@
+use std::cmp::Ordering;
+use std::f64::consts::PI;
+use cvmath::*;
+
+mod common;
+use common::Rng;
+
+//----------------------------------------------------------------
+// Polygon clipping
+
+// Clip each face of the [-1, 1] cube against a diagonal plane through its center.
+#[cfg(feature = "std")]
+#[test]
+fn cube_faces_diagonal_plane() {
+	let corners = Cuboid(Point3(-1.0, -1.0, -1.0), Point3(1.0, 1.0, 1.0)).corners();
+	let faces: [[usize; 4]; 6] = [
+		[0, 2, 6, 4], // -X
+		[1, 5, 7, 3], // +X
+		[0, 4, 5, 1], // -Y
+		[2, 3, 7, 6], // +Y
+		[0, 1, 3, 2], // -Z
+		[4, 6, 7, 5], // +Z
+	];
+
+	let plane = Plane::new_alt(Vec3(1.0, 1.0, 1.0).normalize(), Point3(0.0, 0.0, 0.0));
+
+	let mut out = Vec::new();
+	let mut counts = Vec::new();
+	for face in &faces {
+		let poly = face.map(|i| corners[i]);
+		plane.clip_polygon(&poly, &mut out);
+		counts.push(out.len());
+		for &pt in &out {
+			assert!(plane.dist_pt(pt) >= -1e-12, "{pt} is behind the plane");
+		}
+	}
+	assert_eq!(counts, [3, 5, 3, 5, 3, 5]);
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn polygon_behind_plane() {
+	let plane = Plane(Vec3(0.0, 0.0, 1.0), -10.0);
+	let poly = [Point3(0.0, 0.0, 0.0), Point3(1.0, 0.0, 0.0), Point3(0.0, 1.0, 0.0)];
+	let mut out = vec![Point3(1.0, 2.0, 3.0)];
+	plane.clip_polygon(&poly, &mut out);
+	assert!(out.is_empty());
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn polygon_touching_plane() {
+	// Only an edge lies on the plane, the rest is behind it
+	let plane = Plane(Vec3(0.0, 0.0, 1.0), 0.0);
+	let poly = [Point3(0.0, 0.0, 0.0), Point3(1.0, 0.0, 0.0), Point3(0.0, 0.0, -1.0)];
+	let mut out = Vec::new();
+	plane.clip_polygon(&poly, &mut out);
+	assert!(out.is_empty());
+
+	// Points on the plane are kept without introducing duplicates
+	let poly = [Point3(0.0, 0.0, 0.0), Point3(1.0, 0.0, 0.0), Point3(0.0, 0.0, 1.0)];
+	plane.clip_polygon(&poly, &mut out);
+	assert_eq!(out, poly);
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn half_plane_triangle() {
+	let line = Line2(Point2(0.0, 1.0), Point2(1.0, 1.0));
+	let triangle = [Point2(0.0, 0.0), Point2(4.0, 0.0), Point2(0.0, 4.0)];
+	let mut out = Vec::new();
+	line.clip_polygon(&triangle, &mut out);
+	assert_eq!(out, [Point2(0.0, 1.0), Point2(3.0, 1.0), Point2(0.0, 4.0)]);
+
+	let flipped = Line2(line.end, line.start);
+	flipped.clip_polygon(&triangle, &mut out);
+	assert_eq!(out, [Point2(0.0, 1.0), Point2(0.0, 0.0), Point2(4.0, 0.0), Point2(3.0, 1.0)]);
+}
+
+//----------------------------------------------------------------
+// Bounds
+
+#[test]
+fn transform_encloses_corners() {
+	let cuboid = Cuboid(Point3(-1.0, 0.5, 2.0), Point3(3.0, 1.5, 7.0));
+	let transforms = [
+		Transform3::rotate(Deg(30.0), Vec3(1.0, 2.0, 3.0).normalize()),
+		Transform3::translate((1.0, -2.0, 3.0)) * Transform3::scale((2.0, -1.0, 0.5)),
+		Transform3::rotate(Deg(-75.0), Vec3(0.0, 1.0, 0.0)) * Transform3::translate((-4.0, 0.0, 1.0)),
+		Transform3::compose(Vec3(1.0, 0.2, 0.0), Vec3(0.3, 1.0, -0.4), Vec3(0.0, 0.5, 1.0), Vec3(1.0, 1.0, 1.0)),
+	];
+
+	for m in &transforms {
+		let fitted = cuboid.transform(m);
+		let corners = cuboid.corners().map(|pt| *m * pt);
+		for pt in corners {
+			assert!(fitted.contains(pt), "{pt} not in {fitted:?}");
+		}
+		// The fit is tight: refitting the transformed corners gives the same bounds
+		let tight = corners.iter().fold(Bounds::point(corners[0]), |acc, &pt| acc.include(pt));
+		assert!(fitted.mins.distance(tight.mins) < 1e-12);
+		assert!(fitted.maxs.distance(tight.maxs) < 1e-12);
+	}
+}
+
+#[test]
+fn transform_rect_encloses_corners() {
+	let rect = Rect(Point2(-3.0, 1.0), Point2(2.0, 4.0));
+	let m = Transform2::translate((5.0, -1.0)) * Transform2::rotate(Deg(60.0)) * Transform2::skew((0.5, 0.0));
+	let fitted = rect.transform(&m);
+	for pt in rect.corners() {
+		assert!(fitted.contains(m * pt));
+	}
+}
+
+#[test]
+fn subdivide_octants_contain_corners() {
+	let cuboid = Cuboid(Point3(-1.0, 2.0, 0.5), Point3(3.0, 3.0, 4.5));
+	let corners = cuboid.corners();
+	let octants = cuboid.subdivide();
+	for (i, octant) in octants.iter().enumerate() {
+		assert!(octant.contains(corners[i]));
+		assert!(octant.contains(cuboid.center()));
+		assert_eq!(octant.size(), cuboid.size() * 0.5);
+		assert!(cuboid.encloses(*octant));
+	}
+	// Octants only share faces
+	for i in 0..8 {
+		for j in 0..8 {
+			assert_eq!(i == j, octants[i].strictly_overlaps(octants[j]));
+		}
+	}
+}
+
+#[test]
+fn subdivide_quadrants() {
+	let rect = Rect(Point2(-2.0, -2.0), Point2(2.0, 6.0));
+	let quadrants = rect.subdivide();
+	let area: f64 = quadrants.iter().map(|q| q.area()).sum();
+	assert_eq!(area, rect.area());
+	assert_eq!(quadrants[0].mins, rect.mins);
+	assert_eq!(quadrants[3].maxs, rect.maxs);
+	assert!(quadrants[1].contains(Point2(1.0, 0.0)));
+	assert!(quadrants[2].contains(Point2(-1.0, 4.0)));
+}
+
+#[test]
+fn cuboid_ray_interval() {
+	let cuboid = Cuboid(Point3(-1.0, -2.0, -3.0), Point3(4.0, 5.0, 6.0));
+	let mut rng = Rng(7);
+	for _ in 0..1000 {
+		let origin = Point3(rng.next(), rng.next(), rng.next()) * 10.0;
+		let target = Point3(rng.next(), rng.next(), rng.next()) * 5.0;
+		let ray = Ray(origin, (target - origin).normalize());
+
+		let mut hits = [TraceHit::default(); 2];
+		let count = ray.trace(&cuboid, &mut hits);
+		match cuboid.ray_intersect_t(&ray) {
+			Some((t0, t1)) => {
+				if t0 >= 0.0 {
+					assert_eq!(count, 2);
+					assert_eq!(hits[0].distance, t0);
+				}
+				else {
+					assert_eq!(count, 1);
+					assert!(cuboid.contains(origin));
+				}
+				assert_eq!(hits[count - 1].distance, t1);
+			},
+			None => assert_eq!(count, 0),
+		}
+	}
+}
+
+#[test]
+fn cuboid_ray_interval_parallel() {
+	let cuboid = Cuboid(Point3(-1.0, -1.0, -1.0), Point3(1.0, 1.0, 1.0));
+	let mut hits = [TraceHit::default(); 2];
+
+	// Parallel to the X and Z axes, lying in the plane of the -X face
+	let ray = Ray(Point3(-1.0, -5.0, 0.0), Vec3(0.0, 1.0, 0.0));
+	assert_eq!(cuboid.ray_intersect_t(&ray), Some((4.0, 6.0)));
+	assert_eq!(ray.trace(&cuboid, &mut hits), 2);
+	assert_eq!((hits[0].distance, hits[1].distance), (4.0, 6.0));
+
+	// Lying in the plane of the +X face
+	let ray = Ray(Point3(1.0, -5.0, 0.0), Vec3(0.0, 1.0, 0.0));
+	assert_eq!(cuboid.ray_intersect_t(&ray), Some((4.0, 6.0)));
+
+	// Parallel but outside the slab
+	let ray = Ray(Point3(-2.0, -5.0, 0.0), Vec3(0.0, 1.0, 0.0));
+	assert_eq!(cuboid.ray_intersect_t(&ray), None);
+	assert_eq!(ray.trace(&cuboid, &mut hits), 0);
+}
+
+#[test]
+fn cuboid_surface_area() {
+	let cuboid = Cuboid(Point3(0.0, 0.0, 0.0), Point3(1.0, 1.0, 1.0));
+	assert_eq!(cuboid.surface_area(), 6.0);
+	assert_eq!(Measure3::surface_area(&cuboid), 6.0);
+
+	let cuboid = Cuboid(Point3(-1.0, 2.0, 0.5), Point3(3.0, 2.5, 4.0));
+	assert_eq!(cuboid.surface_area(), 2.0 * (4.0 * 0.5 + 0.5 * 3.5 + 3.5 * 4.0));
+}
+
+#[test]
+fn cuboid_distance_sqr_to_point() {
+	let cuboid = Cuboid(Point3(-1.0, -2.0, -3.0), Point3(4.0, 5.0, 6.0));
+	let mut rng = Rng(11);
+	for _ in 0..1000 {
+		let pt = Point3(rng.next(), rng.next(), rng.next()) * 10.0;
+
+		// Brute force the closest point by clamping each coordinate
+		let clamped = Point3(
+			pt.x.clamp(cuboid.mins.x, cuboid.maxs.x),
+			pt.y.clamp(cuboid.mins.y, cuboid.maxs.y),
+			pt.z.clamp(cuboid.mins.z, cuboid.maxs.z),
+		);
+		let expected = (pt.x - clamped.x).powi(2) + (pt.y - clamped.y).powi(2) + (pt.z - clamped.z).powi(2);
+		assert!((cuboid.distance_sqr_to_point(pt) - expected).abs() < 1e-12);
+		assert_eq!(cuboid.distance_sqr_to_point(pt) == 0.0, cuboid.contains(pt));
+	}
+}
+
+#[test]
+fn rect_layout_helpers() {
+	let mut rng = Rng(21);
+	let anchors = [
+		Anchor::TopLeft, Anchor::Top, Anchor::TopRight,
+		Anchor::Left, Anchor::Center, Anchor::Right,
+		Anchor::BottomLeft, Anchor::Bottom, Anchor::BottomRight,
+	];
+	for _ in 0..100 {
+		let mins = Point2(rng.next(), rng.next()) * 100.0;
+		let rect = Rect(mins, mins + Point2(rng.next().abs(), rng.next().abs()) * 50.0);
+		let size = Vec2(rng.next().abs(), rng.next().abs()) * 50.0;
+
+		// Center centers and every anchor keeps the size
+		assert!(rect.anchored(size, Anchor::Center).center().distance(rect.center()) < 1e-12);
+		for anchor in anchors {
+			let child = rect.anchored(size, anchor);
+			assert!((child.size() - size).len() < 1e-12);
+		}
+		assert_eq!(rect.anchored(size, Anchor::TopLeft).top_left(), rect.top_left());
+		assert_eq!(rect.anchored(size, Anchor::BottomRight).bottom_right(), rect.bottom_right());
+
+		// Splits partition the rectangle without overlap or gap
+		let at = rng.next().abs() * rect.width();
+		let (left, right) = rect.split_h(at);
+		assert_eq!(left.right(), right.left());
+		assert_eq!(left.union(right), rect);
+		assert!(!left.strictly_overlaps(right));
+		assert!((left.area() + right.area() - rect.area()).abs() < 1e-9);
+		let at = rng.next().abs() * rect.height();
+		let (top, bottom) = rect.split_v(at);
+		assert_eq!(top.bottom(), bottom.top());
+		assert_eq!(top.union(bottom), rect);
+		assert!(!top.strictly_overlaps(bottom));
+
+		// Scaling keeps the anchor point fixed
+		let anchor = rect.mins + rect.size() * Vec2(rng.next().abs(), rng.next().abs());
+		let factor = Vec2(rng.next().abs(), rng.next().abs()) * 3.0 + Vec2::dup(0.1);
+		let scaled = rect.scaled_about(factor, anchor);
+		let rel = (anchor - rect.mins) / rect.size();
+		assert!((scaled.mins + scaled.size() * rel).distance(anchor) < 1e-9);
+		assert!((scaled.size() - rect.size() * factor).len() < 1e-9);
+
+		// Insets shrink both sides equally
+		let inset = rect.inset(Vec2::dup(1.0));
+		assert!(rect.encloses(inset));
+		assert_eq!(inset.center(), rect.center());
+	}
+
+	// Exact for representable centers
+	let rect = Rect(Point2(3, 5), Point2(103, 55));
+	assert_eq!(rect.anchored(Vec2(20, 10), Anchor::Center).center(), rect.center());
+	let rect = Rect(Point2(0.5, 0.25), Point2(8.0, 4.0));
+	assert_eq!(rect.anchored(Vec2(1.5, 0.75), Anchor::Center).center(), rect.center());
+
+	let cuboid = Cuboid(Point3(1.0, 2.0, 3.0), Point3(5.0, 4.0, 9.0));
+	let anchor = Point3(2.0, 3.0, 4.0);
+	let scaled = cuboid.scaled_about(Vec3(0.5, 2.0, 3.0), anchor);
+	assert_eq!(scaled, Cuboid(Point3(1.5, 1.0, 1.0), Point3(3.5, 5.0, 19.0)));
+	assert_eq!(Cuboid::point(anchor).scaled_about(Vec3::dup(7.0), anchor), Cuboid::point(anchor));
+}
+
+//----------------------------------------------------------------
+// Measure
+
+fn close(a: f64, b: f64) -> bool {
+	(a - b).abs() <= 1e-12 * b.abs().max(1.0)
+}
+
+#[test]
+fn measure2() {
+	let rect = Rect(Point2(-1.0, 2.0), Point2(4.0, 5.5));
+	assert_eq!(Measure2::area(&rect), 5.0 * 3.5);
+	assert_eq!(rect.perimeter(), 2.0 * (5.0 + 3.5));
+
+	let line = Line2(Point2(1.0, 1.0), Point2(-2.0, 5.0));
+	assert_eq!(line.area(), 0.0);
+	assert_eq!(line.perimeter(), 5.0);
+
+	let ints = Rect(Point2(0, 0), Point2(3, 7));
+	assert_eq!(Measure2::area(&ints), 21);
+	assert_eq!(ints.perimeter(), 20);
+}
+
+#[test]
+fn measure3() {
+	let sphere = Sphere(Point3(1.0, -2.0, 3.0), 1.5);
+	assert!(close(sphere.volume(), 4.0 / 3.0 * PI * 1.5 * 1.5 * 1.5));
+	assert!(close(sphere.surface_area(), 4.0 * PI * 1.5 * 1.5));
+
+	let cuboid = Cuboid(Point3(0.0, 0.0, 0.0), Point3(1.0, 2.0, 3.0));
+	assert_eq!(Measure3::volume(&cuboid), 6.0);
+	assert_eq!(cuboid.surface_area(), 2.0 * (2.0 + 6.0 + 3.0));
+
+	let plane = Plane(Vec3(0.0, 1.0, 0.0), 2.0);
+	assert_eq!(plane.volume(), f64::INFINITY);
+	assert_eq!(plane.surface_area(), f64::INFINITY);
+}
+
+// Shapes can be weighted by their size through the traits
+#[test]
+fn weights() {
+	fn total<S: Measure3<f64>>(shapes: &[S]) -> f64 {
+		shapes.iter().map(|shape| shape.volume()).sum()
+	}
+	let spheres = [Sphere(Point3(0.0, 0.0, 0.0), 1.0), Sphere(Point3(5.0, 0.0, 0.0), 2.0)];
+	assert!(close(total(&spheres), 4.0 / 3.0 * PI * 9.0));
+	assert!(!total(&[Plane(Vec3::X, 0.0)]).is_finite());
+}
+
+//----------------------------------------------------------------
+// Lines
+
+#[test]
+fn segment_intersection_parameters() {
+	let a = Line2(Point2(-1.0, -3.0), Point2(5.0, 3.0));
+	let b = Line2(Point2(4.0, 0.0), Point2(0.0, 4.0));
+	let (pt, t, u) = a.intersect(&b).unwrap();
+	assert!(pt.distance(Point2(3.0, 1.0)) < 1e-12);
+	assert!(a.start.lerp(a.end, t).distance(pt) < 1e-12);
+	assert!(b.start.lerp(b.end, u).distance(pt) < 1e-12);
+
+	// Swapping the segments swaps the parameters
+	assert_eq!(b.intersect(&a), Some((pt, u, t)));
+}
+
+#[test]
+fn segment_intersection_endpoints() {
+	// Segments touching at their endpoints intersect
+	let a = Line2(Point2(0.0, 0.0), Point2(2.0, 0.0));
+	let b = Line2(Point2(2.0, 0.0), Point2(2.0, 5.0));
+	assert_eq!(a.intersect(&b), Some((Point2(2.0, 0.0), 1.0, 0.0)));
+
+	// T-junction
+	let c = Line2(Point2(1.0, 3.0), Point2(1.0, 0.0));
+	assert_eq!(a.intersect(&c), Some((Point2(1.0, 0.0), 0.5, 1.0)));
+
+	// The infinite lines cross but the segments do not
+	let d = Line2(Point2(3.0, 1.0), Point2(3.0, -1.0));
+	assert_eq!(a.intersect(&d), None);
+	assert_eq!(d.intersect(&a), None);
+}
+
+#[test]
+fn segment_intersection_parallel() {
+	let a = Line2(Point2(0.0, 0.0), Point2(2.0, 2.0));
+	assert_eq!(a.intersect(&Line2(Point2(1.0, 0.0), Point2(3.0, 2.0))), None);
+	// Collinear and overlapping
+	assert_eq!(a.intersect(&Line2(Point2(1.0, 1.0), Point2(3.0, 3.0))), None);
+	// Degenerate segments
+	assert_eq!(a.intersect(&Line2(Point2(1.0, 1.0), Point2(1.0, 1.0))), None);
+}
+
+#[test]
+fn segment_closest_point() {
+	let line = Line2(Point2(1.0f64, 1.0), Point2(4.0, 5.0));
+
+	// Points beyond the end points snap to them
+	assert_eq!(line.closest_point(Point2(-2.0, -3.0)), line.start);
+	assert_eq!(line.closest_point(Point2(-3.0, 4.0)), line.start);
+	assert_eq!(line.closest_point(Point2(8.0, 8.0)), line.end);
+	assert_eq!(line.distance_to(Point2(8.0, 8.0)), 5.0);
+	assert_eq!(line.distance_to(line.end), 0.0);
+
+	// Points alongside the segment project onto it
+	let pt = Point2(6.5, 0.0);
+	let closest = line.closest_point(pt);
+	assert!(closest.distance(Point2(2.5, 3.0)) < 1e-12);
+	assert!((line.distance_to(pt) - 5.0).abs() < 1e-12);
+	assert!((line.dist_pt(pt) - 5.0).abs() < 1e-12);
+
+	// Degenerate segments measure the distance to the point
+	let point = Line2(Point2(1.0, 2.0), Point2(1.0, 2.0));
+	assert_eq!(point.closest_point(Point2(4.0, 6.0)), Point2(1.0, 2.0));
+	assert_eq!(point.distance_to(Point2(4.0, 6.0)), 5.0);
+}
+
+#[test]
+fn parameter_endpoints() {
+	let lines = [
+		Line3(Point3(0.1f32, -7.3, 2.9), Point3(1.7, 0.3, -4.1)),
+		Line3(Point3(1e3, 1e-3, 0.0), Point3(-3.3, 2.2, 1e4)),
+	];
+	for line in lines {
+		assert_eq!(line.at(0.0), line.start);
+		assert_eq!(line.at(1.0), line.end);
+
+		let points: Vec<_> = line.subdivide(49).collect();
+		assert_eq!(points.len(), 50);
+		assert_eq!(points[0], line.start);
+		assert_eq!(points[49], line.end);
+
+		let (a, b) = line.split(0.3);
+		assert_eq!(a.start, line.start);
+		assert_eq!(a.end, b.start);
+		assert_eq!(b.end, line.end);
+		assert_eq!(line.reversed().reversed(), line);
+	}
+
+	let line = Line2(Point2(1.0f64, 2.0), Point2(1.0, 2.0));
+	assert_eq!(line.subdivide(0).collect::<Vec<_>>(), [line.start]);
+	assert_eq!(line.unit_direction(), Point2(0.0, 0.0));
+}
+
+#[test]
+fn transform_lines() {
+	let line = Line2(Point2(1.0f64, 2.0), Point2(-3.0, 0.5));
+	for deg in [0.0, 30.0, 90.0, 135.0, -60.0] {
+		let rotation = Mat2::rotate(Deg(deg));
+		let rotated = rotation * line;
+		assert_eq!(rotated, Line2(rotation * line.start, rotation * line.end));
+		assert!((rotated.direction().len() - line.direction().len()).abs() < 1e-12);
+
+		let transform = Transform2::translate(Vec2(5.0, -1.0)) * Transform2::rotate(Deg(deg));
+		let moved = transform * line;
+		assert!(moved.start.distance(rotated.start + Vec2(5.0, -1.0)) < 1e-12);
+		assert!(moved.end.distance(rotated.end + Vec2(5.0, -1.0)) < 1e-12);
+		assert!(moved.start.distance(line.start.rotate(Deg(deg)) + Vec2(5.0, -1.0)) < 1e-12);
+	}
+
+	let line = Line3(Point3(1.0f64, 2.0, 3.0), Point3(-1.0, 0.0, 4.0));
+	let transform = Transform3::translate(Vec3(0.0, 0.0, 1.0)) * Transform3::rotate(Deg(90.0), Vec3::Z);
+	let moved = transform * line;
+	assert!(moved.start.distance(transform * line.start) < 1e-12);
+	assert!(moved.end.distance(transform * line.end) < 1e-12);
+}
+
+//----------------------------------------------------------------
+// Polylines
+
+#[cfg(feature = "std")]
+#[test]
+fn resample_spacing() {
+	let path = [Point2(0.0, 0.0), Point2(10.0, 0.0), Point2(10.0, 5.0), Point2(0.0, 5.0)];
+	assert_eq!(polyline_length(&path), 25.0);
+
+	let points = resample_uniform(&path, 11);
+	assert_eq!(points.len(), 11);
+	assert_eq!(points[0], path[0]);
+	assert_eq!(points[10], path[3]);
+	for w in points.windows(2) {
+		// Spacing is measured along the path, corners are cut short
+		assert!(w[0].distance(w[1]) <= 2.5 + 1e-12);
+	}
+	assert_eq!(points[4], Point2(10.0, 0.0));
+	assert_eq!(points[5], Point2(10.0, 2.5));
+	assert!(polyline_length(&points) > 24.0);
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn resample_degenerate() {
+	// Zero length segments are skipped
+	let path = [Point2(0.0, 0.0), Point2(0.0, 0.0), Point2(4.0, 0.0), Point2(4.0, 0.0), Point2(4.0, 0.0), Point2(8.0, 0.0)];
+	let points = resample_uniform(&path, 5);
+	assert_eq!(points, [Point2(0.0, 0.0), Point2(2.0, 0.0), Point2(4.0, 0.0), Point2(6.0, 0.0), Point2(8.0, 0.0)]);
+
+	// All points coincide
+	let path = [Point2(1.0, 2.0); 3];
+	assert_eq!(polyline_length(&path), 0.0);
+	assert_eq!(resample_uniform(&path, 4), [Point2(1.0, 2.0); 4]);
+
+	// Trivial inputs
+	assert_eq!(resample_uniform::<f64>(&[], 3), []);
+	assert_eq!(resample_uniform(&[Point2(1.0, 1.0)], 2), [Point2(1.0, 1.0); 2]);
+	assert_eq!(resample_uniform(&path, 1), [Point2(1.0, 2.0)]);
+	assert_eq!(resample_uniform(&path, 0), []);
+}
+
+//----------------------------------------------------------------
+// Signed distance
+
+fn gradient(f: impl Fn(Point2<f64>) -> f64, pt: Point2<f64>) -> Vec2<f64> {
+	let h = 1e-6;
+	let dx = (f(pt + Vec2(h, 0.0)) - f(pt - Vec2(h, 0.0))) / (2.0 * h);
+	let dy = (f(pt + Vec2(0.0, h)) - f(pt - Vec2(0.0, h))) / (2.0 * h);
+	Vec2(dx, dy)
+}
+
+#[test]
+fn rect_sdf_sign_matches_contains() {
+	let rect = Rect(Point2(-1.5f64, -0.5), Point2(2.0, 3.0));
+	for i in -20..20 {
+		for j in -20..20 {
+			let pt = Point2(i as f64 * 0.23 + 0.01, j as f64 * 0.21 + 0.01);
+			let d = rect.sdf(pt);
+			assert_eq!(d < 0.0, rect.contains(pt), "{:?} {}", pt, d);
+			assert!((d.abs() - rect.closest_point(pt).distance(pt)).abs() < 1e-12 || d < 0.0);
+		}
+	}
+}
+
+#[test]
+fn rect_sdf_gradient_is_outward_normal() {
+	let rect = Rect(Point2(-1.0f64, -2.0), Point2(3.0, 1.0));
+	let sdf = |pt| rect.sdf(pt);
+	let cases = [
+		(Point2(1.0, 1.05), Vec2(0.0, 1.0)),
+		(Point2(1.0, 0.95), Vec2(0.0, 1.0)),
+		(Point2(-1.05, 0.0), Vec2(-1.0, 0.0)),
+		(Point2(2.95, -1.0), Vec2(1.0, 0.0)),
+		(Point2(0.0, -2.05), Vec2(0.0, -1.0)),
+	];
+	for (pt, normal) in cases {
+		assert!(gradient(sdf, pt).distance(normal) < 1e-6, "{:?}", pt);
+	}
+
+	// Past a corner the gradient points away from the corner
+	let pt = Point2(3.3, 1.4);
+	let normal = (pt - Point2(3.0, 1.0)).normalize();
+	assert!(gradient(sdf, pt).distance(normal) < 1e-6);
+}
+
+#[test]
+fn rect_sdf_corners_are_exact() {
+	let rect = Rect(Point2(0.0f64, 0.0), Point2(2.0, 1.0));
+	for corner in rect.corners() {
+		let dir = (corner - rect.center()).map(f64::signum);
+		assert_eq!(rect.sdf(corner + dir * Vec2(3.0, 4.0)), 5.0);
+		assert_eq!(rect.sdf(corner), 0.0);
+	}
+	assert_eq!(rect.sdf(rect.center()), -0.5);
+}
+
+#[test]
+fn line_sdf_is_unsigned() {
+	let line = Line2(Point2(0.0f64, 0.0), Point2(4.0, 0.0));
+	assert_eq!(line.sdf(Point2(2.0, 3.0)), 3.0);
+	assert_eq!(line.sdf(Point2(2.0, -3.0)), 3.0);
+	assert_eq!(line.sdf(Point2(7.0, 4.0)), 5.0);
+	assert_eq!(line.sdf(Point2(1.0, 0.0)), 0.0);
+}
+
+//----------------------------------------------------------------
+// Triangles
+
+#[test]
+fn trace_triangle_hit_and_miss() {
+	let triangle = Triangle3(Point3(0.0f64, 0.0, 0.0), Point3(2.0, 0.0, 0.0), Point3(0.0, 2.0, 0.0));
+	let mut hits = [TraceHit::default(); 1];
+
+	let ray = Ray(Point3(0.5, 0.5, 3.0), Vec3(0.0, 0.0, -1.0));
+	assert_eq!(ray.trace(&triangle, &mut hits), 1);
+	assert_eq!(hits[0].distance, 3.0);
+	assert_eq!(hits[0].normal, Vec3(0.0, 0.0, 1.0));
+
+	// Hit from behind
+	let ray = Ray(Point3(0.5, 0.5, -3.0), Vec3(0.0, 0.0, 1.0));
+	assert_eq!(ray.trace(&triangle, &mut hits), 1);
+	assert_eq!(hits[0].distance, 3.0);
+
+	// Outside the triangle
+	let ray = Ray(Point3(1.5, 1.5, 3.0), Vec3(0.0, 0.0, -1.0));
+	assert_eq!(ray.trace(&triangle, &mut hits), 0);
+
+	// Pointing away from the triangle
+	let ray = Ray(Point3(0.5, 0.5, 3.0), Vec3(0.0, 0.0, 1.0));
+	assert_eq!(ray.trace(&triangle, &mut hits), 0);
+}
+
+#[test]
+fn trace_shared_edge_is_watertight() {
+	let mut rng = Rng(11);
+	// Two triangles of a tilted quad sharing the diagonal edge from p1 to p2
+	let tilt = Transform3::rotate(Deg(23.0), Vec3(1.0, 2.0, 0.5).normalize());
+	let p0 = (tilt * Point3(-1.3, -0.7, 0.0)).cast::<f32>();
+	let p1 = (tilt * Point3(1.1, -0.9, 0.0)).cast::<f32>();
+	let p2 = (tilt * Point3(-0.8, 1.2, 0.0)).cast::<f32>();
+	let p3 = (tilt * Point3(1.4, 1.0, 0.0)).cast::<f32>();
+	let a = Triangle3(p0, p1, p2);
+	let b = Triangle3(p2, p1, p3);
+
+	let mut double = 0;
+	for _ in 0..10000 {
+		// Aim at a point on the shared edge from a random origin
+		let t = (rng.next() * 0.45 + 0.5) as f32;
+		let target = p1.lerp(p2, t);
+		let origin = (tilt * (rng.vec3() * 5.0 + Vec3(0.0, 0.0, 6.0))).cast::<f32>();
+		let ray = Ray(origin, (target - origin).normalize());
+
+		let mut hits = [TraceHit::default(); 1];
+		let na = ray.trace(&a, &mut hits);
+		let nb = ray.trace(&b, &mut hits);
+		assert!(na + nb >= 1, "ray slipped through the shared edge: {:?}", ray);
+		if na + nb == 2 {
+			double += 1;
+		}
+	}
+	// Both triangles only report a hit when the ray lands exactly on the shared edge, none of these rays do
+	assert_eq!(double, 0);
+}
+
+#[test]
+fn tessellate_area_and_congruence() {
+	let mut rng = Rng(3);
+	let area = |t: &Triangle3<f64>| (t.p1 - t.p0).cross(t.p2 - t.p0).len() * 0.5;
+	let sides = |t: &Triangle3<f64>| {
+		let mut sides = [t.p0.distance(t.p1), t.p1.distance(t.p2), t.p2.distance(t.p0)];
+		sides.sort_by(f64::total_cmp);
+		sides
+	};
+
+	for n in [1, 2, 3, 7] {
+		let triangle = Triangle3(rng.vec3(), rng.vec3(), rng.vec3());
+		let parts: Vec<_> = triangle.tessellate(n).collect();
+		assert_eq!(parts.len(), n * n);
+
+		let total: f64 = parts.iter().map(area).sum();
+		assert!((total - area(&triangle)).abs() < 1e-12);
+
+		let expected = sides(&triangle).map(|s| s / n as f64);
+		for part in &parts {
+			assert!(part.normal().distance(triangle.normal()) < 1e-9);
+			let sides = sides(part);
+			for k in 0..3 {
+				assert!((sides[k] - expected[k]).abs() < 1e-12);
+			}
+		}
+	}
+
+	assert_eq!(Triangle3(Point3(0.0, 0.0, 0.0), Point3(1.0, 0.0, 0.0), Point3(0.0, 1.0, 0.0)).tessellate(0).count(), 0);
+}
+
+#[test]
+fn trace_culled_octahedron() {
+	// Counter-clockwise seen from outside
+	let v = [Point3(1.0, 0.0, 0.0), Point3(0.0, 1.0, 0.0), Point3(-1.0, 0.0, 0.0), Point3(0.0, -1.0, 0.0)];
+	let (top, bottom) = (Point3(0.0, 0.0, 1.0), Point3(0.0, 0.0, -1.0));
+	let faces: Vec<Triangle3<f64>> = (0..4).flat_map(|i| {
+		let (a, b) = (v[i], v[(i + 1) % 4]);
+		[Triangle3(a, b, top), Triangle3(b, a, bottom)]
+	}).collect();
+
+	let mut rng = Rng(13);
+	for _ in 0..1000 {
+		let origin = Point3::<f64>::ZERO + rng.vec3().normalize() * 5.0;
+		let ray = Ray(origin, ((Point3::<f64>::ZERO + rng.vec3() * 0.4) - origin).normalize());
+
+		let trace = |cull| faces.iter().filter_map(|face| face.trace_culled(&ray, cull)).collect::<Vec<_>>();
+		let all = trace(CullMode::None);
+		let front = trace(CullMode::Back);
+		let back = trace(CullMode::Front);
+		assert_eq!(all.len(), front.len() + back.len());
+		assert!(front.iter().all(|hit| hit.normal.dot(ray.direction) < 0.0));
+		assert!(back.iter().all(|hit| hit.normal.dot(ray.direction) > 0.0));
+
+		// Entering through the front faces and leaving through the back faces
+		let nearest = all.iter().map(|hit| hit.distance).fold(f64::INFINITY, f64::min);
+		let farthest = all.iter().map(|hit| hit.distance).fold(0.0, f64::max);
+		assert_eq!(front.iter().map(|hit| hit.distance).fold(f64::INFINITY, f64::min), nearest);
+		assert_eq!(back.iter().map(|hit| hit.distance).fold(0.0, f64::max), farthest);
+	}
+}
+
+#[test]
+fn norm_winding_faces_reference() {
+	let mut rng = Rng(17);
+	for _ in 0..100 {
+		let triangle = Triangle3(rng.vec3(), rng.vec3(), rng.vec3());
+		let reference = rng.vec3();
+		let fixed = triangle.norm_winding(reference);
+		assert!(fixed.normal().dot(reference) >= 0.0);
+		assert_eq!(fixed.p0, triangle.p0);
+		assert!(fixed == triangle || (fixed.p1 == triangle.p2 && fixed.p2 == triangle.p1));
+		assert_eq!(fixed.norm_winding(reference), fixed);
+		assert_eq!(fixed.norm_winding(-reference).normal(), -fixed.normal());
+	}
+}
+
+//----------------------------------------------------------------
+// Planes
+
+#[test]
+fn from_points_matches_triangle() {
+	let mut rng = Rng(7);
+	for _ in 0..100 {
+		let triangle = Triangle3(rng.vec3() * 10.0, rng.vec3() * 10.0, rng.vec3() * 10.0);
+		let plane = Plane::try_from_points(triangle.p0, triangle.p1, triangle.p2).unwrap();
+		assert!(plane.normal.distance(triangle.normal()) < 1e-9);
+		for &pt in &[triangle.p0, triangle.p1, triangle.p2] {
+			assert!(plane.dist_pt(pt).abs() < 1e-9);
+		}
+
+		let other = Plane::from_point_normal(triangle.p1, triangle.normal() * 3.0);
+		assert!(other.normal.distance(plane.normal) < 1e-9);
+		assert!((other.distance - plane.distance).abs() < 1e-9);
+	}
+}
+
+#[test]
+fn try_from_points_degenerate() {
+	let a = Point3(1.0f64, 2.0, 3.0);
+	let b = Point3(4.0, -1.0, 0.5);
+	assert_eq!(Plane::try_from_points(a, a, a), None);
+	assert_eq!(Plane::try_from_points(a, a, b), None);
+	assert_eq!(Plane::try_from_points(a, b, b), None);
+	assert_eq!(Plane::try_from_points(a, b, a.lerp(b, 0.25)), None);
+	assert_eq!(Plane::try_from_points(a, b, a.lerp(b, 3.0)), None);
+	assert!(Plane::try_from_points(a, b, Point3(0.0, 0.0, 0.0)).is_some());
+
+	// The test is scale invariant
+	let s = 1e-6;
+	assert!(Plane::try_from_points(Point3(0.0, 0.0, 0.0), Point3(s, 0.0, 0.0), Point3(0.0, s, 0.0)).is_some());
+}
+
+#[test]
+fn project_reflect() {
+	let mut rng = Rng(8);
+	for _ in 0..100 {
+		let plane = Plane::from_point_normal(rng.vec3() * 10.0, rng.vec3());
+		let pt = rng.vec3() * 10.0;
+		let d = plane.dist_pt(pt);
+
+		let projected = plane.project_pt(pt);
+		assert!(plane.dist_pt(projected).abs() < 1e-12);
+		assert!(projected.distance(pt) - d.abs() < 1e-12);
+		assert!(plane.project_pt(projected).distance(projected) < 1e-12);
+
+		let reflected = plane.reflect_pt(pt);
+		assert!((plane.dist_pt(reflected) + d).abs() < 1e-12);
+		assert!(plane.reflect_pt(reflected).distance(pt) < 1e-12);
+		assert!(reflected.lerp(pt, 0.5).distance(projected) < 1e-12);
+	}
+
+	// Points exactly on the plane are unchanged
+	let plane = Plane(Vec3(0.0f64, 1.0, 0.0), -2.0);
+	let pt = Point3(3.5, 2.0, -7.25);
+	assert_eq!(plane.dist_pt(pt), 0.0);
+	assert_eq!(plane.project_pt(pt), pt);
+	assert_eq!(plane.reflect_pt(pt), pt);
+	// In front of the plane the distance is positive
+	assert_eq!(plane.dist_pt(Point3(0.0, 5.0, 0.0)), 3.0);
+	assert_eq!((-plane).dist_pt(Point3(0.0, 5.0, 0.0)), -3.0);
+}
+
+#[test]
+fn reflect_plane_matrix() {
+	let mut rng = Rng(11);
+	for _ in 0..100 {
+		let plane = Plane::from_point_normal(rng.vec3() * 10.0, rng.vec3());
+		let mirror = Mat4::reflect_plane(plane);
+		let triangle = Triangle3(rng.vec3() * 10.0, rng.vec3() * 10.0, rng.vec3() * 10.0);
+
+		for &pt in &[triangle.p0, triangle.p1, triangle.p2] {
+			let reflected = mirror.transform_point3(pt);
+			assert!(reflected.distance(plane.reflect_pt(pt)) < 1e-9);
+			assert!((plane.dist_pt(reflected) + plane.dist_pt(pt)).abs() < 1e-9);
+			assert!(mirror.transform_point3(reflected).distance(pt) < 1e-9);
+		}
+		assert!((mirror * mirror).into_row_major().iter().flatten().zip(Mat4::IDENTITY.into_row_major().iter().flatten()).all(|(a, b)| (a - b).abs() < 1e-12));
+
+		// The reflected triangle has the opposite winding
+		let reflected = Triangle3(mirror.transform_point3(triangle.p0), mirror.transform_point3(triangle.p1), mirror.transform_point3(triangle.p2));
+		assert!(reflected.normal().distance(-mirror.transform_vector3(triangle.normal())) < 1e-9);
+		let [r1, r2, r3, _] = mirror.into_row_major();
+		assert!(Transform3::from_row_major([r1, r2, r3]).flips_winding());
+	}
+}
+
+#[test]
+fn shadow_matrix() {
+	let mut rng = Rng(13);
+	for _ in 0..100 {
+		let plane = Plane::from_point_normal(rng.vec3() * 10.0, rng.vec3());
+		// Scaling the plane does not change the projection
+		let scaled = Plane(plane.normal * 3.0, plane.distance * 3.0);
+
+		let point_light = (plane.project_pt(rng.vec3() * 10.0) + plane.normal * 20.0).vec4(1.0);
+		let directional = (plane.normal + rng.vec3() * 0.5).vec4(0.0);
+		for light in [point_light, directional] {
+			let shadow = Mat4::shadow(scaled, light);
+			for _ in 0..10 {
+				let pt = plane.project_pt(rng.vec3() * 10.0) + plane.normal * (rng.next() + 1.0) * 5.0;
+				let clip = shadow * pt.vec4(1.0);
+				assert!(clip.w > 0.0);
+				let projected = clip.hdiv();
+				assert!(plane.dist_pt(projected).abs() < 1e-9);
+
+				// The projected point lies on the line through the light
+				let to_light = if light.w == 0.0 { light.xyz() } else { light.xyz() - pt };
+				assert!((projected - pt).cross(to_light).len() < 1e-9 * to_light.len() * (projected - pt).len().max(1.0));
+			}
+		}
+	}
+}
+
+//----------------------------------------------------------------
+// Constructive solid geometry
+
+fn trace_sorted<S: TraceRay<f64>>(shape: &S, ray: &Ray<f64>) -> Vec<TraceHit<f64>> {
+	let mut hits = [TraceHit::default(); 8];
+	let count = ray.trace(shape, &mut hits);
+	assert!(count <= hits.len());
+	let mut hits = hits[..count].to_vec();
+	hits.sort_by(|a, b| a.distance.total_cmp(&b.distance));
+	hits
+}
+
+#[test]
+fn box_with_spherical_hole() {
+	let cuboid = Cuboid(Point3(-2.0, -2.0, -2.0), Point3(2.0, 2.0, 2.0));
+	let sphere = Sphere(Point3(0.0, 0.0, 0.0), 1.0);
+	let shape = Difference3(cuboid, sphere);
+
+	// Straight through the box and the cavity
+	let ray = Ray(Point3(-5.0, 0.0, 0.0), Vec3(1.0, 0.0, 0.0));
+	let hits = trace_sorted(&shape, &ray);
+	assert_eq!(hits, [
+		TraceHit { distance: 3.0, normal: Vec3(-1.0, 0.0, 0.0) },
+		TraceHit { distance: 4.0, normal: Vec3(1.0, 0.0, 0.0) },
+		TraceHit { distance: 6.0, normal: Vec3(-1.0, 0.0, 0.0) },
+		TraceHit { distance: 7.0, normal: Vec3(1.0, 0.0, 0.0) },
+	]);
+
+	// Passing beside the cavity only hits the box
+	let ray = Ray(Point3(-5.0, 1.5, 0.0), Vec3(1.0, 0.0, 0.0));
+	let hits = trace_sorted(&shape, &ray);
+	assert_eq!(hits, [
+		TraceHit { distance: 3.0, normal: Vec3(-1.0, 0.0, 0.0) },
+		TraceHit { distance: 7.0, normal: Vec3(1.0, 0.0, 0.0) },
+	]);
+
+	// Starting inside the cavity
+	let ray = Ray(Point3(0.0, 0.0, 0.0), Vec3(0.0, 0.0, 1.0));
+	assert!(!shape.inside(&ray));
+	let hits = trace_sorted(&shape, &ray);
+	assert_eq!(hits, [
+		TraceHit { distance: 1.0, normal: Vec3(0.0, 0.0, -1.0) },
+		TraceHit { distance: 2.0, normal: Vec3(0.0, 0.0, 1.0) },
+	]);
+
+	// Starting inside the solid part
+	let ray = Ray(Point3(1.5, 0.0, 0.0), Vec3(0.0, 0.0, 1.0));
+	assert!(shape.inside(&ray));
+}
+
+#[test]
+fn rounded_box() {
+	let cuboid = Cuboid(Point3(-1.0, -1.0, -1.0), Point3(1.0, 1.0, 1.0));
+	let sphere = Sphere(Point3(0.0, 0.0, 0.0), 1.5);
+	let shape = Intersection3(cuboid, sphere);
+
+	// Through the flat faces of the box
+	let ray = Ray(Point3(-5.0, 0.0, 0.0), Vec3(1.0, 0.0, 0.0));
+	let hits = trace_sorted(&shape, &ray);
+	assert_eq!(hits, [
+		TraceHit { distance: 4.0, normal: Vec3(-1.0, 0.0, 0.0) },
+		TraceHit { distance: 6.0, normal: Vec3(1.0, 0.0, 0.0) },
+	]);
+
+	// Through the rounded corners
+	let ray = Ray(Point3(-5.0, 0.9, 0.9), Vec3(1.0, 0.0, 0.0));
+	let hits = trace_sorted(&shape, &ray);
+	assert_eq!(hits.len(), 2);
+	for hit in &hits {
+		let pt = ray.at(hit.distance);
+		assert!((pt.len() - 1.5).abs() < 1e-12);
+		assert!(hit.normal.distance(pt / 1.5) < 1e-12);
+	}
+
+	assert!(shape.inside(&Ray(Point3(0.9, 0.0, 0.0), Vec3(1.0, 0.0, 0.0))));
+	assert!(!shape.inside(&Ray(Point3(0.9, 0.9, 0.9), Vec3(1.0, 0.0, 0.0))));
+}
+
+#[test]
+fn complement_flips_normals() {
+	let sphere = Sphere(Point3(0.0, 0.0, 0.0), 1.0);
+	let shape = Complement3(sphere);
+
+	let ray = Ray(Point3(0.0, 0.0, 0.0), Vec3(1.0, 0.0, 0.0));
+	assert!(!shape.inside(&ray));
+	let hits = trace_sorted(&shape, &ray);
+	assert_eq!(hits, [TraceHit { distance: 1.0, normal: Vec3(-1.0, 0.0, 0.0) }]);
+
+	// The complement of the complement is the original shape
+	let shape = Complement3(shape);
+	assert!(shape.inside(&ray));
+	assert_eq!(trace_sorted(&shape, &ray), trace_sorted(&sphere, &ray));
+}
+
+//----------------------------------------------------------------
+// Stadium
+
+#[test]
+fn contains_agrees_with_segment_distance() {
+	let mut rng = Rng(42);
+	for _ in 0..1000 {
+		let stadium = Stadium2(Line2(rng.point2(5.0), rng.point2(5.0)), rng.next().abs() * 2.0);
+		let pt = rng.point2(5.0);
+		let inside = stadium.line.distance_to(pt) <= stadium.radius;
+		assert_eq!(stadium.contains(pt), inside, "{:?} {:?}", stadium, pt);
+		assert_eq!(stadium.sdf(pt) <= 0.0, inside, "{:?} {:?}", stadium, pt);
+	}
+}
+
+#[test]
+fn closest_point_lies_on_boundary() {
+	let mut rng = Rng(7);
+	for _ in 0..1000 {
+		let stadium = Stadium2(Line2(rng.point2(5.0), rng.point2(5.0)), rng.next().abs() * 2.0);
+		let pt = rng.point2(5.0);
+		let closest = stadium.closest_point(pt);
+		if stadium.contains(pt) {
+			assert_eq!(closest, pt);
+		}
+		else {
+			assert!(stadium.sdf(closest).abs() < 1e-9, "{:?} {:?}", stadium, pt);
+			assert!((closest.distance(pt) - stadium.sdf(pt)).abs() < 1e-9, "{:?} {:?}", stadium, pt);
+		}
+	}
+}
+
+#[test]
+fn sides_and_caps() {
+	let stadium = Stadium2(Line2(Point2(0.0f64, 0.0), Point2(4.0, 0.0)), 0.5);
+
+	// Parallel to the segment within the radius touches the side
+	for x in [0.0, 1.0, 2.0, 3.0, 4.0] {
+		assert!(stadium.contains(Point2(x, 0.49)));
+		assert!(stadium.contains(Point2(x, -0.49)));
+		assert!(!stadium.contains(Point2(x, 0.51)));
+	}
+
+	// Beyond the caps is outside
+	assert!(stadium.contains(Point2(-0.49, 0.0)));
+	assert!(stadium.contains(Point2(4.49, 0.0)));
+	assert!(!stadium.contains(Point2(-0.51, 0.0)));
+	assert!(!stadium.contains(Point2(4.51, 0.0)));
+	assert!(!stadium.contains(Point2(4.4, 0.4)));
+}
+
+#[test]
+fn degenerate_segment_is_a_circle() {
+	let stadium = Stadium2(Line2(Point2(1.0f64, 2.0), Point2(1.0, 2.0)), 2.0);
+	assert_eq!(stadium.sdf(Point2(4.0, 6.0)), 3.0);
+	assert_eq!(stadium.area(), 4.0 * std::f64::consts::PI);
+	assert_eq!(stadium.perimeter(), 4.0 * std::f64::consts::PI);
+}
+
+#[test]
+fn intersect_ray_grazing_hits_side() {
+	let stadium = Stadium2(Line2(Point2(0.0f64, 0.0), Point2(4.0, 0.0)), 0.5);
+
+	// Nearly parallel rays hit the flat sides instead of tunneling through
+	for slope in [1e-3, 1e-6, 1e-9] {
+		let (distance, normal) = stadium.intersect_ray(Point2(1.0, 0.5 + slope), Vec2(1.0, -slope)).unwrap();
+		assert!((distance - 1.0).abs() < 1e-6, "{}", distance);
+		assert_eq!(normal, Vec2(0.0, 1.0));
+
+		let (distance, normal) = stadium.intersect_ray(Point2(3.0, -0.5 - slope), Vec2(-1.0, slope)).unwrap();
+		assert!((distance - 1.0).abs() < 1e-6, "{}", distance);
+		assert_eq!(normal, Vec2(0.0, -1.0));
+	}
+
+	// Parallel rays within the radius enter through the cap
+	let (distance, normal) = stadium.intersect_ray(Point2(-2.0, 0.3), Vec2(1.0, 0.0)).unwrap();
+	assert!((distance - 1.6).abs() < 1e-12);
+	assert!(normal.distance(Vec2(-0.8, 0.6)) < 1e-12);
+}
+
+#[test]
+fn intersect_ray_misses_past_caps() {
+	let stadium = Stadium2(Line2(Point2(0.0f64, 0.0), Point2(4.0, 0.0)), 0.5);
+	for x in [-0.51, 4.51, -2.0, 6.0] {
+		assert_eq!(stadium.intersect_ray(Point2(x, 3.0), Vec2(0.0, -1.0)), None);
+	}
+	// Parallel rays beyond the radius miss
+	assert_eq!(stadium.intersect_ray(Point2(-2.0, 0.51), Vec2(1.0, 0.0)), None);
+	// Pointing away
+	assert_eq!(stadium.intersect_ray(Point2(-2.0, 0.0), Vec2(-1.0, 0.0)), None);
+}
+
+#[test]
+fn intersect_ray_agrees_with_sdf() {
+	let mut rng = Rng(13);
+	for _ in 0..1000 {
+		let stadium = Stadium2(Line2(rng.point2(5.0), rng.point2(5.0)), rng.next().abs() * 2.0);
+		let origin = rng.point2(5.0) * 2.0;
+		let direction = rng.point2(5.0).normalize();
+		match stadium.intersect_ray(origin, direction) {
+			Some((distance, normal)) => {
+				assert!(distance >= 0.0);
+				let pt = origin + direction * distance;
+				assert!(stadium.sdf(pt).abs() < 1e-9, "{:?} {:?} {:?}", stadium, origin, direction);
+				assert!((normal.len() - 1.0).abs() < 1e-9);
+				// The normal points away from the center line
+				assert!(stadium.sdf(pt + normal * 1e-3) > 0.0);
+			},
+			None => {
+				// March along the ray to make sure it stays outside
+				assert!(!stadium.contains(origin));
+				for i in 0..1000 {
+					let pt = origin + direction * (i as f64 * 0.05);
+					assert!(!stadium.contains(pt), "{:?} {:?} {:?}", stadium, origin, direction);
+				}
+			},
+		}
+	}
+}
+
+//----------------------------------------------------------------
+// Overlap
+
+#[test]
+fn sphere_sphere() {
+	let a = Sphere(Point3(0.0f64, 0.0, 0.0), 2.0);
+
+	// Externally tangent
+	let b = Sphere(Point3(3.0, 0.0, 0.0), 1.0);
+	assert!(a.intersects_sphere(&b));
+	assert_eq!(a.intersect_sphere(&b), Some((Point3(2.0, 0.0, 0.0), 0.0)));
+
+	// Apart
+	let b = Sphere(Point3(0.0, 3.5, 0.0), 1.0);
+	assert!(!a.intersects_sphere(&b));
+	assert_eq!(a.intersect_sphere(&b), None);
+
+	// Overlapping, the 3-4-5 triangle puts the circle at x = 3 with radius 4
+	let a = Sphere(Point3(1.0f64, 1.0, 1.0), 5.0);
+	let b = Sphere(Point3(1.0, 1.0, 7.0), 5.0);
+	let (center, radius) = a.intersect_sphere(&b).unwrap();
+	assert!(center.distance(Point3(1.0, 1.0, 4.0)) < 1e-12);
+	assert!((radius - 4.0).abs() < 1e-12);
+	assert!(a.intersects_sphere(&b) && b.intersects_sphere(&a));
+
+	// Contained spheres overlap but their surfaces don't intersect
+	let inner = Sphere(Point3(1.5, 1.0, 1.0), 1.0);
+	assert!(a.intersects_sphere(&inner));
+	assert_eq!(a.intersect_sphere(&inner), None);
+	assert_eq!(a.intersect_sphere(&a), None);
+
+	// Internally tangent
+	let inner = Sphere(Point3(4.0, 1.0, 1.0), 2.0);
+	assert_eq!(a.intersect_sphere(&inner), Some((Point3(6.0, 1.0, 1.0), 0.0)));
+}
+
+#[test]
+fn sphere_bounds() {
+	let cuboid = Cuboid(Point3(0.0f64, 0.0, 0.0), Point3(2.0, 2.0, 2.0));
+
+	// Touching a face, an edge and a corner
+	let cases = [
+		(Sphere(Point3(3.0, 1.0, 1.0), 1.0), Point3(2.0, 1.0, 1.0)),
+		(Sphere(Point3(5.0, 6.0, 1.0), 5.0), Point3(2.0, 2.0, 1.0)),
+		(Sphere(Point3(-2.0, -2.0, 3.0), 3.0), Point3(0.0, 0.0, 2.0)),
+	];
+	for (sphere, pt) in cases {
+		assert!(sphere.intersects_bounds(&cuboid), "{:?}", sphere);
+		assert_eq!(sphere.intersect_bounds(&cuboid), Some(pt));
+
+		let smaller = Sphere(sphere.center, sphere.radius - 1e-9);
+		assert!(!smaller.intersects_bounds(&cuboid), "{:?}", sphere);
+		assert_eq!(smaller.intersect_bounds(&cuboid), None);
+	}
+
+	// Center inside
+	let sphere = Sphere(Point3(1.0, 0.5, 1.5), 0.1);
+	assert_eq!(sphere.intersect_bounds(&cuboid), Some(sphere.center));
+
+	// Cuboid inside the sphere
+	assert!(Sphere(Point3(1.0, 1.0, 1.0), 10.0).intersects_bounds(&cuboid));
+}
+
+#[test]
+fn sphere_plane() {
+	let plane = Plane::from_point_normal(Point3(0.0f64, 0.0, 1.0), Vec3(0.0, 0.0, 1.0));
+
+	// Tangent from either side
+	for z in [3.0, -1.0] {
+		let sphere = Sphere(Point3(1.0, 2.0, z), 2.0);
+		assert!(sphere.intersects_plane(&plane));
+		assert_eq!(sphere.intersect_plane(&plane), Some((Point3(1.0, 2.0, 1.0), 0.0)));
+	}
+
+	// Apart
+	let sphere = Sphere(Point3(1.0, 2.0, -1.5), 2.0);
+	assert!(!sphere.intersects_plane(&plane));
+	assert_eq!(sphere.intersect_plane(&plane), None);
+
+	// Great circle
+	let sphere = Sphere(Point3(1.0, 2.0, 1.0), 2.0);
+	assert_eq!(sphere.intersect_plane(&plane), Some((Point3(1.0, 2.0, 1.0), 2.0)));
+
+	// Tilted plane, every point on the circle lies on both the plane and the sphere
+	let plane = Plane::from_point_normal(Point3(1.0, 0.0, 0.0), Vec3(1.0, 1.0, 1.0));
+	let sphere = Sphere(Point3(0.5, -0.5, 2.0), 1.5);
+	let (center, radius) = sphere.intersect_plane(&plane).unwrap();
+	let u = plane.normal.cross(Vec3::X).normalize();
+	let v = plane.normal.cross(u);
+	for i in 0..8 {
+		let (s, c) = Deg(i as f64 * 45.0).sin_cos();
+		let pt = center + (u * c + v * s) * radius;
+		assert!(plane.dist_pt(pt).abs() < 1e-12);
+		assert!((pt.distance(sphere.center) - sphere.radius).abs() < 1e-12);
+	}
+}
+
+#[test]
+fn bounds_plane() {
+	let cuboid = Cuboid(Point3(-1.0f64, -1.0, -1.0), Point3(1.0, 1.0, 1.0));
+
+	let plane = Plane(Vec3(1.0, 0.0, 0.0), -2.0);
+	assert_eq!(cuboid.plane_side(&plane), PlaneSide::Back);
+	assert_eq!(cuboid.plane_side(&-plane), PlaneSide::Front);
+	assert!(!cuboid.intersects_plane(&plane));
+
+	// Touching a face
+	let plane = Plane(Vec3(0.0, 1.0, 0.0), -1.0);
+	assert_eq!(cuboid.plane_side(&plane), PlaneSide::Straddle);
+	assert!(cuboid.intersects_plane(&plane));
+
+	// Touching the corner, just past the corner
+	let normal = Vec3(1.0, 1.0, 1.0).normalize();
+	let plane = Plane::from_point_normal(Point3(1.0, 1.0, 1.0), normal);
+	assert_eq!(cuboid.plane_side(&plane), PlaneSide::Straddle);
+	let plane = Plane::from_point_normal(Point3(1.01, 1.0, 1.0), normal);
+	assert_eq!(cuboid.plane_side(&plane), PlaneSide::Back);
+
+	// Through the center
+	let plane = Plane::from_point_normal(Point3(0.0, 0.0, 0.0), Vec3(0.3, -0.4, 0.5));
+	assert_eq!(cuboid.plane_side(&plane), PlaneSide::Straddle);
+}
+
+#[test]
+fn plane_plane() {
+	let a = Plane::from_point_normal(Point3(0.0f64, 0.0, 1.0), Vec3(0.0, 0.0, 1.0));
+
+	// Parallel and anti-parallel planes
+	for b in [Plane(Vec3(0.0, 0.0, 1.0), 5.0), Plane(Vec3(0.0, 0.0, -1.0), 1.0)] {
+		assert!(!a.intersects_plane(&b));
+		assert_eq!(a.intersect_plane(&b), None);
+	}
+
+	// The line lies in both planes
+	let b = Plane::from_point_normal(Point3(2.0, 3.0, 4.0), Vec3(1.0, -2.0, 0.5));
+	assert!(a.intersects_plane(&b));
+	let line = a.intersect_plane(&b).unwrap();
+	for t in [-2.0, 0.0, 0.5, 3.0] {
+		let pt = line.at(t);
+		assert!(a.dist_pt(pt).abs() < 1e-12);
+		assert!(b.dist_pt(pt).abs() < 1e-12);
+	}
+	assert!((line.len() - 1.0).abs() < 1e-12);
+	assert!(line.direction().cross(a.normal.cross(b.normal)).len() < 1e-12);
+
+	// The start is the point closest to the origin
+	assert!(line.start.dot(line.direction()).abs() < 1e-12);
+}
+
+//----------------------------------------------------------------
+// Robust predicates
+
+//----------------------------------------------------------------
+// Big integer reference
+
+#[derive(Clone, Debug)]
+struct Big {
+	neg: bool,
+	mag: Vec<u32>,
+}
+
+impl Big {
+	fn normalize(mut self) -> Big {
+		while self.mag.last() == Some(&0) {
+			self.mag.pop();
+		}
+		if self.mag.is_empty() {
+			self.neg = false;
+		}
+		self
+	}
+	fn cmp_mag(a: &[u32], b: &[u32]) -> Ordering {
+		a.len().cmp(&b.len()).then_with(|| a.iter().rev().cmp(b.iter().rev()))
+	}
+	fn add_mag(a: &[u32], b: &[u32]) -> Vec<u32> {
+		let mut out = Vec::new();
+		let mut carry = 0u64;
+		for i in 0..a.len().max(b.len()) {
+			let sum = *a.get(i).unwrap_or(&0) as u64 + *b.get(i).unwrap_or(&0) as u64 + carry;
+			out.push(sum as u32);
+			carry = sum >> 32;
+		}
+		out.push(carry as u32);
+		out
+	}
+	// Requires a >= b
+	fn sub_mag(a: &[u32], b: &[u32]) -> Vec<u32> {
+		let mut out = Vec::new();
+		let mut borrow = 0i64;
+		for i in 0..a.len() {
+			let mut diff = a[i] as i64 - *b.get(i).unwrap_or(&0) as i64 - borrow;
+			borrow = 0;
+			if diff < 0 {
+				diff += 1 << 32;
+				borrow = 1;
+			}
+			out.push(diff as u32);
+		}
+		out
+	}
+	fn add(&self, rhs: &Big) -> Big {
+		if self.neg == rhs.neg {
+			return Big { neg: self.neg, mag: Big::add_mag(&self.mag, &rhs.mag) }.normalize();
+		}
+		match Big::cmp_mag(&self.mag, &rhs.mag) {
+			Ordering::Less => Big { neg: rhs.neg, mag: Big::sub_mag(&rhs.mag, &self.mag) }.normalize(),
+			_ => Big { neg: self.neg, mag: Big::sub_mag(&self.mag, &rhs.mag) }.normalize(),
+		}
+	}
+	fn sub(&self, rhs: &Big) -> Big {
+		self.add(&Big { neg: !rhs.neg, mag: rhs.mag.clone() }.normalize())
+	}
+	fn mul(&self, rhs: &Big) -> Big {
+		let mut out = vec![0u64; self.mag.len() + rhs.mag.len() + 1];
+		for (i, &a) in self.mag.iter().enumerate() {
+			let mut carry = 0u64;
+			for (j, &b) in rhs.mag.iter().enumerate() {
+				let t = out[i + j] + a as u64 * b as u64 + carry;
+				out[i + j] = t & 0xffff_ffff;
+				carry = t >> 32;
+			}
+			out[i + rhs.mag.len()] += carry;
+		}
+		Big { neg: self.neg != rhs.neg, mag: out.into_iter().map(|x| x as u32).collect() }.normalize()
+	}
+	fn sign(&self) -> Ordering {
+		if self.mag.is_empty() { Ordering::Equal } else if self.neg { Ordering::Less } else { Ordering::Greater }
+	}
+}
+
+// Decomposes a finite float into mantissa and exponent
+fn decompose(x: f64) -> (bool, u64, i32) {
+	let bits = x.to_bits();
+	let neg = bits >> 63 != 0;
+	let exp = ((bits >> 52) & 0x7ff) as i32;
+	let frac = bits & ((1 << 52) - 1);
+	if exp == 0 { (neg, frac, -1074) } else { (neg, frac | (1 << 52), exp - 1075) }
+}
+
+// Exact integers for the coordinates scaled by a common power of two
+fn to_big(values: &[f64]) -> Vec<Big> {
+	let emin = values.iter().map(|&x| decompose(x).2).min().unwrap();
+	values.iter().map(|&x| {
+		let (neg, m, e) = decompose(x);
+		let shift = (e - emin) as usize;
+		let mut mag = vec![0u32; shift / 32 + 3];
+		let m = (m as u128) << (shift % 32);
+		for k in 0..3 {
+			mag[shift / 32 + k] = (m >> (32 * k)) as u32;
+		}
+		Big { neg, mag }.normalize()
+	}).collect()
+}
+
+fn orient2d_ref(a: Point2<f64>, b: Point2<f64>, c: Point2<f64>) -> Ordering {
+	let v = to_big(&[a.x, a.y, b.x, b.y, c.x, c.y]);
+	let (ax, ay, bx, by, cx, cy) = (&v[0], &v[1], &v[2], &v[3], &v[4], &v[5]);
+	ax.sub(cx).mul(&by.sub(cy)).sub(&ay.sub(cy).mul(&bx.sub(cx))).sign()
+}
+
+fn incircle_ref(a: Point2<f64>, b: Point2<f64>, c: Point2<f64>, d: Point2<f64>) -> Ordering {
+	let v = to_big(&[a.x, a.y, b.x, b.y, c.x, c.y, d.x, d.y]);
+	let (adx, ady) = (v[0].sub(&v[6]), v[1].sub(&v[7]));
+	let (bdx, bdy) = (v[2].sub(&v[6]), v[3].sub(&v[7]));
+	let (cdx, cdy) = (v[4].sub(&v[6]), v[5].sub(&v[7]));
+	let alift = adx.mul(&adx).add(&ady.mul(&ady));
+	let blift = bdx.mul(&bdx).add(&bdy.mul(&bdy));
+	let clift = cdx.mul(&cdx).add(&cdy.mul(&cdy));
+	let a = alift.mul(&bdx.mul(&cdy).sub(&cdx.mul(&bdy)));
+	let b = blift.mul(&cdx.mul(&ady).sub(&adx.mul(&cdy)));
+	let c = clift.mul(&adx.mul(&bdy).sub(&bdx.mul(&ady)));
+	a.add(&b).add(&c).sign()
+}
+
+fn orient2d_naive(a: Point2<f64>, b: Point2<f64>, c: Point2<f64>) -> Ordering {
+	((a.x - c.x) * (b.y - c.y) - (a.y - c.y) * (b.x - c.x)).partial_cmp(&0.0).unwrap()
+}
+
+fn incircle_naive(a: Point2<f64>, b: Point2<f64>, c: Point2<f64>, d: Point2<f64>) -> Ordering {
+	let (ad, bd, cd) = (a - d, b - d, c - d);
+	let det = ad.len_sqr() * bd.cross(cd) + bd.len_sqr() * cd.cross(ad) + cd.len_sqr() * ad.cross(bd);
+	det.partial_cmp(&0.0).unwrap()
+}
+
+fn ulp_offset(x: f64, n: i64) -> f64 {
+	f64::from_bits((x.to_bits() as i64 + n) as u64)
+}
+
+//----------------------------------------------------------------
+
+#[test]
+fn reference_sanity() {
+	assert_eq!(orient2d_ref(Point2(0.0, 0.0), Point2(1.0, 0.0), Point2(0.0, 1.0)), Ordering::Greater);
+	assert_eq!(orient2d_ref(Point2(0.0, 0.0), Point2(1.0, 0.0), Point2(0.0, -1e-300)), Ordering::Less);
+	assert_eq!(incircle_ref(Point2(5.0, 0.0), Point2(0.0, 5.0), Point2(-5.0, 0.0), Point2(3.0, -4.0)), Ordering::Equal);
+	assert_eq!(incircle_ref(Point2(5.0, 0.0), Point2(0.0, 5.0), Point2(-5.0, 0.0), Point2(0.0, 0.0)), Ordering::Greater);
+}
+
+#[test]
+fn orient2d_random() {
+	let mut rng = Rng(42);
+	for _ in 0..10000 {
+		let (a, b, c) = (rng.vec2(), rng.vec2(), rng.vec2());
+		assert_eq!(orient2d(a, b, c), orient2d_ref(a, b, c));
+	}
+}
+
+#[test]
+fn orient2d_nearly_collinear() {
+	// The classic grid of points next to the line y = x
+	let b = Point2(12.0, 12.0);
+	let c = Point2(24.0, 24.0);
+	let mut naive_wrong = 0;
+	for i in 0..64 {
+		for j in 0..64 {
+			let a = Point2(ulp_offset(0.5, i), ulp_offset(0.5, j));
+			let expected = orient2d_ref(a, b, c);
+			assert_eq!(orient2d(a, b, c), expected, "{:?}", a);
+			assert_eq!(orient2d(b, c, a), expected, "{:?}", a);
+			assert_eq!(orient2d(c, b, a), expected.reverse(), "{:?}", a);
+			if orient2d_naive(a, b, c) != expected {
+				naive_wrong += 1;
+			}
+		}
+	}
+	assert!(naive_wrong > 0);
+}
+
+#[test]
+fn orient2d_collinear() {
+	let mut rng = Rng(7);
+	for _ in 0..1000 {
+		// Small integers scaled by a power of two are exact
+		let scale = 2f64.powi((rng.next() * 60.0) as i32);
+		let a = Point2((rng.next() * 1000.0).round(), (rng.next() * 1000.0).round()) * scale;
+		let d = Vec2((rng.next() * 1000.0).round(), (rng.next() * 1000.0).round()) * scale;
+		let k = (rng.next() * 10.0).round();
+		assert_eq!(orient2d(a, a + d, a + d * k), Ordering::Equal);
+
+		// Perturb by a single ulp
+		let c = a + d * 3.0;
+		let c = Point2(ulp_offset(c.x, 1), c.y);
+		assert_eq!(orient2d(a, a + d, c), orient2d_ref(a, a + d, c));
+	}
+}
+
+#[test]
+fn orient2d_f32() {
+	let mut rng = Rng(3);
+	for _ in 0..1000 {
+		let b = Point2(rng.next() as f32, rng.next() as f32);
+		let a = Point2(rng.next() as f32, rng.next() as f32);
+		// Nearly on the line through a and b
+		let c = a + (b - a) * 3.0;
+		let c = Point2(f32::from_bits((c.x.to_bits() as i32 + (rng.next() * 4.0) as i32) as u32), c.y);
+		assert_eq!(orient2d(a, b, c), orient2d_ref(a.cast(), b.cast(), c.cast()));
+	}
+}
+
+#[test]
+fn incircle_random() {
+	let mut rng = Rng(42);
+	for _ in 0..10000 {
+		let (a, b, c, d) = (rng.vec2(), rng.vec2(), rng.vec2(), rng.vec2());
+		assert_eq!(incircle(a, b, c, d), incircle_ref(a, b, c, d));
+	}
+}
+
+#[test]
+fn incircle_cocircular() {
+	// Pythagorean triples lie exactly on the circle of radius 5
+	let pts = [Point2(5.0, 0.0), Point2(3.0, 4.0), Point2(0.0, 5.0), Point2(-4.0, 3.0), Point2(-5.0, 0.0), Point2(-3.0, -4.0), Point2(0.0, -5.0), Point2(4.0, -3.0)];
+	let offset = Vec2(0.125, -1024.0 + 0.25);
+	for i in 0..pts.len() {
+		for j in 0..pts.len() {
+			for k in 0..pts.len() {
+				for l in 0..pts.len() {
+					let (a, b, c, d) = (pts[i] + offset, pts[j] + offset, pts[k] + offset, pts[l] + offset);
+					assert_eq!(incircle(a, b, c, d), Ordering::Equal);
+				}
+			}
+		}
+	}
+}
+
+#[test]
+fn incircle_nearly_cocircular() {
+	let a = Point2(5.0, 0.0);
+	let b = Point2(0.0, 5.0);
+	let c = Point2(-5.0, 0.0);
+	let mut naive_wrong = 0;
+	for i in -16..16 {
+		for j in -16..16 {
+			let d = Point2(ulp_offset(3.0, i), ulp_offset(-4.0, j));
+			let expected = incircle_ref(a, b, c, d);
+			assert_eq!(incircle(a, b, c, d), expected, "{:?}", d);
+			assert_eq!(incircle(b, a, c, d), expected.reverse(), "{:?}", d);
+			if incircle_naive(a, b, c, d) != expected {
+				naive_wrong += 1;
+			}
+		}
+	}
+	assert!(naive_wrong > 0);
+
+	// Tiny circle far from the origin
+	let mut rng = Rng(11);
+	let center = Vec2(1e6, -3e5);
+	for _ in 0..1000 {
+		let (a, b, c) = (rng.vec2() + center, rng.vec2() + center, rng.vec2() + center);
+		let d = Point2(ulp_offset(a.x, (rng.next() * 8.0) as i64), ulp_offset(a.y, (rng.next() * 8.0) as i64));
+		assert_eq!(incircle(a, b, c, d), incircle_ref(a, b, c, d));
+	}
+}
+
+//----------------------------------------------------------------
+// Cylinders and cones
+
+fn trace<S: TraceRay<f64>>(shape: &S, origin: Point3<f64>, direction: Vec3<f64>) -> Vec<TraceHit<f64>> {
+	let mut hits = [TraceHit::default(); 4];
+	let n = Ray(origin, direction).trace(shape, &mut hits);
+	hits[..n].to_vec()
+}
+
+#[test]
+fn cylinder_axis_aligned() {
+	let cylinder = Cylinder3(Point3(0.0, 0.0, 0.0), Vec3::<f64>::Z, 2.0, 1.0);
+
+	// Lateral surface
+	let hits = trace(&cylinder, Point3(-5.0, 0.0, 1.0), Vec3::<f64>::X);
+	assert_eq!(hits, [TraceHit { distance: 4.0, normal: -Vec3::<f64>::X }, TraceHit { distance: 6.0, normal: Vec3::<f64>::X }]);
+
+	// Parallel to the axis through the caps, also exactly along the lateral surface
+	let hits = trace(&cylinder, Point3(0.5, 0.0, -5.0), Vec3::<f64>::Z);
+	assert_eq!(hits, [TraceHit { distance: 5.0, normal: -Vec3::<f64>::Z }, TraceHit { distance: 7.0, normal: Vec3::<f64>::Z }]);
+	let hits = trace(&cylinder, Point3(1.0, 0.0, 5.0), -Vec3::<f64>::Z);
+	assert_eq!(hits, [TraceHit { distance: 3.0, normal: Vec3::<f64>::Z }, TraceHit { distance: 5.0, normal: -Vec3::<f64>::Z }]);
+
+	// Diagonally through the rims of both caps
+	let hits = trace(&cylinder, Point3(-3.0, 0.0, -2.0), Vec3(1.0, 0.0, 1.0));
+	assert_eq!(hits.len(), 2);
+	assert_eq!((hits[0].distance, hits[1].distance), (2.0, 4.0));
+
+	// Starting inside only exits
+	let hits = trace(&cylinder, Point3(0.0, 0.0, 1.0), Vec3(0.0, 2.0, 0.0));
+	assert_eq!(hits, [TraceHit { distance: 0.5, normal: Vec3::<f64>::Y }]);
+	let hits = trace(&cylinder, Point3(0.0, 0.0, 1.0), -Vec3::<f64>::Z);
+	assert_eq!(hits, [TraceHit { distance: 1.0, normal: -Vec3::<f64>::Z }]);
+
+	// Misses beside, above and behind
+	assert!(trace(&cylinder, Point3(-5.0, 1.5, 1.0), Vec3::<f64>::X).is_empty());
+	assert!(trace(&cylinder, Point3(-5.0, 0.0, 3.0), Vec3::<f64>::X).is_empty());
+	assert!(trace(&cylinder, Point3(5.0, 0.0, 1.0), Vec3::<f64>::X).is_empty());
+	assert!(trace(&cylinder, Point3(2.0, 0.0, -5.0), Vec3::<f64>::Z).is_empty());
+}
+
+#[test]
+fn cone_axis_aligned() {
+	// Apex at the top, base disk on the ground
+	let cone = Cone3(Point3(0.0, 0.0, 2.0), -Vec3::<f64>::Z, 2.0, 1.0);
+
+	// Lateral surface at half the height where the radius is 0.5
+	let hits = trace(&cone, Point3(-5.0, 0.0, 1.0), Vec3::<f64>::X);
+	assert_eq!(hits.len(), 2);
+	assert_eq!((hits[0].distance, hits[1].distance), (4.5, 5.5));
+	assert!(hits[0].normal.distance(Vec3(-2.0, 0.0, 1.0).normalize()) < 1e-12);
+	assert!(hits[1].normal.distance(Vec3(2.0, 0.0, 1.0).normalize()) < 1e-12);
+
+	// Parallel to the axis through the base
+	let hits = trace(&cone, Point3(0.25, 0.0, -5.0), Vec3::<f64>::Z);
+	assert_eq!(hits.len(), 2);
+	assert_eq!(hits[0], TraceHit { distance: 5.0, normal: -Vec3::<f64>::Z });
+	assert_eq!(hits[1].distance, 6.5);
+
+	// Through the rim of the base
+	let hits = trace(&cone, Point3(-2.0, 0.0, -1.0), Vec3(1.0, 0.0, 1.0));
+	assert_eq!(hits.len(), 2);
+	assert_eq!(hits[0].distance, 1.0);
+
+	// Starting inside only exits
+	let hits = trace(&cone, Point3(0.0, 0.0, 0.5), Vec3::<f64>::X);
+	assert_eq!(hits.len(), 1);
+	assert_eq!(hits[0].distance, 0.75);
+
+	// The mirrored nappe above the apex is not part of the cone
+	assert!(trace(&cone, Point3(-5.0, 0.0, 3.0), Vec3::<f64>::X).is_empty());
+	assert!(trace(&cone, Point3(-5.0, 0.0, -1.0), Vec3::<f64>::X).is_empty());
+
+	// Along the axis through the apex, where the normal points away from the base
+	let hits = trace(&cone, Point3(0.0, 0.0, 5.0), -Vec3::<f64>::Z);
+	assert_eq!(hits.len(), 2);
+	assert_eq!(hits[0], TraceHit { distance: 3.0, normal: Vec3::<f64>::Z });
+	assert_eq!(hits[1], TraceHit { distance: 5.0, normal: -Vec3::<f64>::Z });
+}
+
+// Hits lie on the boundary between the inside and outside and their normals face against the entering ray.
+fn check_boundary<S: TraceRay<f64>>(shape: &S, contains: impl Fn(Point3<f64>) -> bool, bounds: Cuboid<f64>, rng: &mut Rng, tangent: impl Fn(Point3<f64>, Vec3<f64>) -> bool) {
+	let center = bounds.center();
+	let size = bounds.size();
+	for _ in 0..1000 {
+		let origin = center + rng.vec3() * 10.0;
+		let target = center + rng.vec3() * size * 0.5;
+		let direction = (target - origin).normalize();
+		let ray = Ray(origin, direction);
+		assert_eq!(ray.inside(shape), contains(origin));
+
+		let hits = trace(shape, origin, direction);
+		let eps = 1e-6;
+		for (i, hit) in hits.iter().enumerate() {
+			let pt = ray.at(hit.distance);
+			assert!(bounds.inflate(1e-9).contains(pt));
+			assert!(hit.normal.is_normalized(1e-9));
+			assert!(tangent(pt, hit.normal));
+
+			let exiting = hits.len() == 1 || i == 1;
+			if (hits.len() == 2 && hits[1].distance - hits[0].distance < 1e-3) || hit.normal.dot(direction).abs() < 1e-3 {
+				continue;
+			}
+			assert_eq!(hit.normal.dot(direction) > 0.0, exiting);
+			assert_eq!(contains(ray.at(hit.distance - eps)), exiting);
+			assert_eq!(contains(ray.at(hit.distance + eps)), !exiting);
+		}
+	}
+}
+
+#[test]
+fn cylinder_boundary() {
+	let mut rng = Rng(5);
+	for _ in 0..10 {
+		let axis = rng.vec3().normalize();
+		let cylinder = Cylinder3(Point3::<f64>::ZERO + rng.vec3(), axis, 1.0 + rng.next().abs() * 3.0, 0.5 + rng.next().abs());
+		let bounds = cylinder.bounds();
+		// The normal is perpendicular to the axis on the lateral surface and parallel to it on the caps
+		check_boundary(&cylinder, |pt| cylinder.contains(pt), bounds, &mut rng, |_, normal| {
+			normal.dot(axis).abs() < 1e-9 || (normal.dot(axis).abs() - 1.0).abs() < 1e-9
+		});
+	}
+}
+
+#[test]
+fn cone_boundary() {
+	let mut rng = Rng(6);
+	for _ in 0..10 {
+		let axis = rng.vec3().normalize();
+		let cone = Cone3(Point3::<f64>::ZERO + rng.vec3(), axis, 1.0 + rng.next().abs() * 3.0, 0.5 + rng.next().abs());
+		let bounds = cone.bounds();
+		// The normal is perpendicular to the line from the apex on the lateral surface and parallel to the axis on the base
+		check_boundary(&cone, |pt| cone.contains(pt), bounds, &mut rng, |pt, normal| {
+			normal.dot((pt - cone.apex).normalize()).abs() < 1e-9 || (normal.dot(axis) - 1.0).abs() < 1e-9
+		});
+	}
+}
+
+#[test]
+fn tight_bounds() {
+	let mut rng = Rng(7);
+	let cylinder = Cylinder3(Point3(1.0, -2.0, 0.5), Vec3(1.0, 2.0, -0.5).normalize(), 3.0, 0.75);
+	let cone = Cone3::from_angle(Point3(-1.0, 0.0, 2.0), Vec3(0.3, -1.0, 0.2).normalize(), 4.0, Deg(30.0));
+	let mut cylinder_hull = Cuboid(cylinder.base, cylinder.base);
+	let mut cone_hull = Cuboid(cone.apex, cone.apex);
+	for _ in 0..10000 {
+		let (sin, cos) = Rad(rng.next() * 4.0).sin_cos();
+		let u = cylinder.axis.cross(Vec3(0.6, 0.0, 0.8)).normalize();
+		let v = cylinder.axis.cross(u);
+		for h in [0.0, cylinder.height] {
+			let pt = cylinder.base + cylinder.axis * h + (u * cos + v * sin) * cylinder.radius;
+			assert!(cylinder.bounds().inflate(1e-9).contains(pt));
+			cylinder_hull = cylinder_hull.include(pt);
+		}
+		let u = cone.axis.cross(Vec3(0.6, 0.0, 0.8)).normalize();
+		let v = cone.axis.cross(u);
+		let pt = cone.base() + (u * cos + v * sin) * cone.radius;
+		assert!(cone.bounds().inflate(1e-9).contains(pt));
+		cone_hull = cone_hull.include(pt);
+	}
+	assert!(cylinder_hull.mins.distance(cylinder.bounds().mins) < 1e-3);
+	assert!(cylinder_hull.maxs.distance(cylinder.bounds().maxs) < 1e-3);
+	assert!(cone_hull.mins.distance(cone.bounds().mins) < 1e-3);
+	assert!(cone_hull.maxs.distance(cone.bounds().maxs) < 1e-3);
+}
+
+//----------------------------------------------------------------
+// Polygonize
+
+// Every turn of a convex outline winds counter-clockwise, allowing duplicated points
+fn is_convex_ccw(points: &[Point2<f64>]) -> bool {
+	let n = points.len();
+	(0..n).all(|i| {
+		let (a, b, c) = (points[i], points[(i + 1) % n], points[(i + 2) % n]);
+		(b - a).cross(c - b) >= -1e-9
+	})
+}
+
+fn signed_area(points: &[Point2<f64>]) -> f64 {
+	let n = points.len();
+	(0..n).map(|i| points[i].cross(points[(i + 1) % n])).sum::<f64>() * 0.5
+}
+
+#[test]
+fn polygonize_rect_and_triangle() {
+	let mut rng = Rng(31);
+	for _ in 0..100 {
+		let rect = Rect(rng.vec2(), rng.vec2()).normalize();
+		let points: Vec<_> = rect.polygonize().collect();
+		assert_eq!(points.len(), 4);
+		assert!(is_convex_ccw(&points));
+		assert!((signed_area(&points) - rect.area()).abs() < 1e-12);
+
+		let triangle = Triangle2(rng.vec2(), rng.vec2(), rng.vec2());
+		let points: Vec<_> = triangle.polygonize().collect();
+		assert_eq!(points.len(), 3);
+		assert_eq!(points[0], triangle.p0);
+		assert!(signed_area(&points) >= 0.0);
+		assert_ne!(orient2d(points[0], points[1], points[2]), core::cmp::Ordering::Less);
+	}
+}
+
+#[test]
+fn polygonize_stadium_and_circle() {
+	let mut rng = Rng(32);
+	for n in 1..20 {
+		let stadium = Stadium2(Line2(rng.vec2() * 10.0, rng.vec2() * 10.0), 0.5 + rng.next().abs());
+		let points: Vec<_> = stadium.polygonize(n).collect();
+		assert_eq!(points.len(), 2 * (n + 1));
+		assert!(is_convex_ccw(&points));
+		assert!(signed_area(&points) > 0.0);
+		for &pt in &points {
+			assert!(stadium.sdf(pt).abs() < 1e-9);
+		}
+
+		// A zero length stadium is a circle
+		let center = rng.vec2() * 10.0;
+		let radius = 0.5 + rng.next().abs();
+		let circle = Stadium2(Line2(center, center), radius);
+		let points: Vec<_> = circle.polygonize(n).collect();
+		assert_eq!(points.len(), 2 * (n + 1));
+		assert!(is_convex_ccw(&points));
+		for &pt in &points {
+			assert!((pt.distance(center) - radius).abs() < 1e-12);
+		}
+	}
+}
+
+#[test]
+fn polygonize_stadium_no_segments() {
+	let stadium = Stadium2(Line2(Point2(0.0f64, 0.0), Point2(4.0, 0.0)), 1.0);
+	let points: Vec<_> = stadium.polygonize(0).collect();
+	assert_eq!(points, stadium.polygonize(1).collect::<Vec<_>>());
+	assert_eq!(points.len(), 4);
+	let rect = Rect(Point2(0.0, -1.0), Point2(4.0, 1.0));
+	for (pt, corner) in points.iter().zip(rect.corners().iter().cycle().skip(1)) {
+		assert!(pt.distance(*corner) < 1e-12, "{:?}", points);
+	}
+}
+
+#[test]
+fn cuboid_edge_lines_connect_corners() {
+	let cuboid = Cuboid(Point3(-1.0, 2.0, 0.5), Point3(3.0, 3.0, 4.5));
+	let corners = cuboid.corners();
+	let lines = cuboid.edge_lines();
+	for (line, [a, b]) in lines.iter().zip(cuboid.edges()) {
+		assert_eq!(*line, Line3(corners[a], corners[b]));
+		// Edges are axis aligned along the sides of the cuboid
+		let size = cuboid.size();
+		let d = line.end - line.start;
+		assert!(d == Vec3(size.x, 0.0, 0.0) || d == Vec3(0.0, size.y, 0.0) || d == Vec3(0.0, 0.0, size.z));
+	}
+	// Every corner joins three edges
+	for corner in corners {
+		assert_eq!(lines.iter().filter(|line| line.start == corner || line.end == corner).count(), 3);
+	}
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn sphere_wire() {
+	let sphere = Sphere(Point3(1.0f64, -2.0, 3.0), 1.5);
+	for (rings, segments) in [(0, 3), (1, 4), (5, 12)] {
+		let lines = sphere.polygonize_wire(rings, segments);
+		assert_eq!(lines.len(), rings * segments + segments * (rings + 1));
+		for line in &lines {
+			assert!((line.start.distance(sphere.center) - sphere.radius).abs() < 1e-12);
+			assert!((line.end.distance(sphere.center) - sphere.radius).abs() < 1e-12);
+		}
+		// Circles of latitude wind counter-clockwise around the Y axis
+		for line in &lines[..rings * segments] {
+			let (a, b) = (line.start - sphere.center, line.end - sphere.center);
+			assert!((a.y - b.y).abs() < 1e-12);
+			assert!(a.cross(b).y > 0.0);
+		}
+		// Meridians run from the north to the south pole
+		for line in &lines[rings * segments..] {
+			assert!(line.start.y > line.end.y);
+		}
+		// The first meridian lies towards +Z
+		if rings > 0 {
+			let first = lines[rings * segments].end - sphere.center;
+			assert!(first.x.abs() < 1e-12 && first.z > 0.0);
+		}
+	}
+}
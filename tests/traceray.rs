@@ -1,5 +1,8 @@
 use cvmath::*;
 
+mod common;
+use common::Rng;
+
 #[test]
 fn trace_plane() {
 	let plane = Plane(Vec3(0.0, 0.0, 1.0), 0.0);
@@ -85,3 +88,337 @@ fn trace_sphere_inside() {
 	assert_eq!(count, 1);
 	assert_eq!(hits[0].distance, 15.0);
 }
+
+#[test]
+fn bounce_angles() {
+	let planes = [
+		Plane(Vec3(0.0f64, 0.0, 1.0), 0.0),
+		Plane(Vec3(1.0, 2.0, 3.0).normalize(), 0.0),
+		Plane(Vec3(-0.5, 1.0, 0.3).normalize(), 0.0),
+	];
+	let ray = Ray(Point3(4.0, 7.0, 10.0), Vec3(-0.5, -1.0, -1.5));
+	for plane in &planes {
+		let mut hits = [TraceHit::default(); 2];
+		assert_eq!(ray.trace(plane, &mut hits), 1);
+		let hit = &hits[0];
+
+		let bounce = ray.bounce(hit);
+		let reflected = bounce.ray.direction.angle(hit.normal);
+		assert!((bounce.incident.value - reflected.value).abs() < 1e-12);
+		assert!(bounce.incident < Rad::quarter());
+
+		// The incoming, outgoing and normal vectors lie in the same plane
+		assert!(ray.direction.cross(bounce.ray.direction).dot(hit.normal).abs() < 1e-12);
+
+		assert_eq!(bounce.segment.start, ray.origin);
+		assert!((bounce.distance - bounce.segment.direction().len()).abs() < 1e-12);
+		assert!(plane.dist_pt(bounce.ray.origin).abs() < 1e-12);
+	}
+}
+
+#[test]
+fn bounce_from_inside() {
+	// The ray leaves the sphere at 30° from the outward normal
+	let sphere = Sphere(Point3(0.0f64, 0.0, 0.0), 2.0);
+	let ray = Ray(Point3(0.0, 1.0, 0.0), Vec3(1.0, 0.0, 0.0));
+	let mut hits = [TraceHit::default(); 2];
+	let n = ray.trace(&sphere, &mut hits);
+	let hit = hits[..n].iter().find(|hit| hit.distance > 0.0).unwrap();
+	assert!(ray.direction.dot(hit.normal) > 0.0);
+
+	let bounce = ray.bounce(hit);
+	assert!((bounce.incident.to_deg().value - 30.0).abs() < 1e-12);
+	let reflected = bounce.ray.direction.angle(-hit.normal);
+	assert!((bounce.incident.value - reflected.value).abs() < 1e-12);
+}
+
+#[test]
+fn reflect_with_perturbation() {
+	let plane = Plane(Vec3::Z, 0.0);
+	let ray = Ray(Point3(0.0, 0.0, 1.0), Vec3(0.0, 0.0, -1.0));
+	let mut hits = [TraceHit::default(); 1];
+	assert_eq!(ray.trace(&plane, &mut hits), 1);
+
+	let jitter = Vec3(0.1f64, -0.2, 0.0);
+	let reflected = ray.reflect_with(&hits[0], |dir| (dir + jitter).normalize());
+	assert_eq!(reflected.origin, Point3(0.0, 0.0, 0.0));
+	assert_eq!(reflected.direction, (Vec3::<f64>::Z + jitter).normalize());
+}
+
+// Rays bouncing around inside a closed shape never escape it.
+// The hit normals point outward even when exiting the shape, reflecting does not depend on their orientation.
+fn bounce_inside<S: TraceRay<f64>>(shape: &S, seed: u64, on_surface: impl Fn(Point3<f64>) -> bool) {
+	let mut rng = Rng(seed);
+	let mut ray = Ray(Point3(rng.next(), rng.next(), rng.next()) * 0.5, Vec3(rng.next(), rng.next(), rng.next()).normalize());
+	assert!(ray.inside(shape));
+	for _ in 0..100 {
+		// Skip the hit at the origin of the previously reflected ray
+		let mut hits = [TraceHit::default(); 8];
+		let n = ray.trace(shape, &mut hits);
+		let hit = *hits[..n].iter().filter(|hit| hit.distance > 1e-9).min_by(|a, b| a.distance.total_cmp(&b.distance)).unwrap();
+		assert!(ray.direction.dot(hit.normal) > 0.0);
+
+		ray = ray.reflect(&hit);
+		assert!(on_surface(ray.origin));
+		assert!(ray.direction.dot(hit.normal) < 0.0);
+		assert!(Ray(ray.at(1e-6), ray.direction).inside(shape));
+	}
+}
+
+#[test]
+fn bounce_inside_sphere() {
+	let sphere = Sphere(Point3(0.0, 0.0, 0.0), 2.0);
+	for seed in [0x583d44f0, 0x1c9e7a21] {
+		bounce_inside(&sphere, seed, |pt| (pt.len() - 2.0).abs() < 1e-9);
+	}
+}
+
+#[test]
+fn bounce_inside_cuboid() {
+	let cuboid = Cuboid(Point3(-1.0, -2.0, -1.5), Point3(1.0, 2.0, 1.5));
+	for seed in [0x583d44f0, 0x1c9e7a21] {
+		bounce_inside(&cuboid, seed, |pt| {
+			let d = (pt.abs() - cuboid.maxs).vmax();
+			d.abs() < 1e-9
+		});
+	}
+}
+
+#[test]
+fn trace_plane_offset() {
+	// Regression: the plane distance was ignored when tracing
+	let plane = Plane::new_alt(Vec3(0.0, 1.0, 0.0), Point3(0.0, -1.0, 0.0));
+	for (origin, direction, distance) in [(5.0, -1.0, 6.0), (-4.0, 1.0, 3.0), (1.0, -0.5, 4.0)] {
+		let ray = Ray(Point3(3.0, origin, 2.0), Vec3(0.0, direction, 0.0));
+		let mut hits = [TraceHit::default(); 1];
+		assert_eq!(ray.trace(&plane, &mut hits), 1);
+		assert_eq!(hits[0].distance, distance);
+		assert_eq!(ray.at(hits[0].distance).y, -1.0);
+	}
+}
+
+#[test]
+fn trace_plane_two_sided() {
+	let plane = Plane(Vec3(0.0, 0.0, 1.0), -2.0f64);
+
+	// From the front the normal already opposes the ray
+	let front = Ray(Point3(1.0, 1.0, 5.0), Vec3(0.0, 0.6, -0.8));
+	let hit = plane.trace_ray(&front, true).unwrap();
+	assert_eq!(hit, plane.trace_ray(&front, false).unwrap());
+	assert_eq!(hit.normal, Vec3::Z);
+	assert!((hit.distance - 3.75).abs() < 1e-12);
+
+	// From behind the normal is flipped when requested
+	let behind = Ray(Point3(1.0, 1.0, -1.0), Vec3(0.0, 0.0, 1.0));
+	assert_eq!(plane.trace_ray(&behind, false), Some(TraceHit { distance: 3.0, normal: Vec3::Z }));
+	assert_eq!(plane.trace_ray(&behind, true), Some(TraceHit { distance: 3.0, normal: Vec3(0.0, 0.0, -1.0) }));
+
+	// Pointing away from the plane
+	let away = Ray(Point3(1.0, 1.0, -1.0), Vec3(0.0, 0.0, -1.0));
+	assert_eq!(plane.trace_ray(&away, true), None);
+
+	// Parallel rays, including those lying in the plane, never produce NaN
+	for z in [-1.0, 2.0, 5.0] {
+		let parallel = Ray(Point3(0.0, 0.0, z), Vec3(1.0, 0.0, 0.0));
+		assert_eq!(plane.trace_ray(&parallel, true), None);
+		assert_eq!(plane.trace_ray(&parallel, false), None);
+		assert_eq!(parallel.trace(&plane, &mut [TraceHit::default(); 1]), 0);
+	}
+}
+
+#[test]
+fn trace_cuboid_faces() {
+	let cuboid = Cuboid(Point3(-1.0f64, -2.0, -3.0), Point3(1.0, 2.0, 3.0));
+	let cases = [
+		(Point3(-5.0, 0.5, 0.5), Vec3(1.0, 0.0, 0.0), 4.0, Vec3(-1.0, 0.0, 0.0)),
+		(Point3(5.0, 0.5, 0.5), Vec3(-1.0, 0.0, 0.0), 4.0, Vec3(1.0, 0.0, 0.0)),
+		(Point3(0.5, -5.0, 0.5), Vec3(0.0, 1.0, 0.0), 3.0, Vec3(0.0, -1.0, 0.0)),
+		(Point3(0.5, 5.0, 0.5), Vec3(0.0, -1.0, 0.0), 3.0, Vec3(0.0, 1.0, 0.0)),
+		(Point3(0.5, 0.5, -5.0), Vec3(0.0, 0.0, 1.0), 2.0, Vec3(0.0, 0.0, -1.0)),
+		(Point3(0.5, 0.5, 5.0), Vec3(0.0, 0.0, -1.0), 2.0, Vec3(0.0, 0.0, 1.0)),
+		(Point3(-2.0, 4.0, 0.0), Vec3(0.6, -0.8, 0.0), 2.5, Vec3(0.0, 1.0, 0.0)),
+	];
+	for (origin, direction, distance, normal) in cases {
+		let ray = Ray { origin, direction };
+		let mut hits = [TraceHit::default(); 2];
+		assert_eq!(ray.trace(&cuboid, &mut hits), 2);
+		assert!((hits[0].distance - distance).abs() < 1e-12, "{:?}", hits[0]);
+		assert_eq!(hits[0].normal, normal);
+
+		// The exit hit faces along the ray
+		assert!(hits[1].distance > hits[0].distance);
+		assert!(hits[1].normal.dot(direction) > 0.0);
+		assert!(cuboid.contains(ray.at(hits[1].distance).lerp(ray.at(hits[0].distance), 0.5)));
+	}
+
+	// Starting inside only the exit is hit
+	let ray = Ray { origin: Point3(0.0, 0.0, 0.0), direction: Vec3(0.0, 0.0, 1.0) };
+	let mut hits = [TraceHit::default(); 2];
+	assert_eq!(ray.trace(&cuboid, &mut hits), 1);
+	assert_eq!(hits[0], TraceHit { distance: 3.0, normal: Vec3(0.0, 0.0, 1.0) });
+
+	// Misses the cuboid
+	let ray = Ray { origin: Point3(-5.0, 3.0, 0.0), direction: Vec3(1.0, 0.0, 0.0) };
+	let mut hits = [TraceHit::default(); 2];
+	assert_eq!(ray.trace(&cuboid, &mut hits), 0);
+}
+
+#[test]
+fn trace_sphere_off_center() {
+	let sphere = Sphere(Point3(0.0f64, 0.0, 0.0), 5.0);
+	let ray = Ray(Point3(-10.0, 3.0, 0.0), Vec3(1.0, 0.0, 0.0));
+	let mut hits = [TraceHit::default(); 2];
+	assert_eq!(ray.trace(&sphere, &mut hits), 2);
+	assert_eq!(hits[0].distance, 6.0);
+	assert_eq!(hits[1].distance, 14.0);
+	assert_eq!(hits[0].normal, Vec3(-0.8, 0.6, 0.0));
+
+	let ray = Ray(Point3(-10.0, 6.0, 0.0), Vec3(1.0, 0.0, 0.0));
+	assert_eq!(ray.trace(&sphere, &mut hits), 0);
+}
+
+#[test]
+fn trace_transformed_sphere() {
+	let unit = Sphere(Point3(0.0f64, 0.0, 0.0), 1.0);
+	let rays = [
+		Ray(Point3(-10.0, 0.3, 0.2), Vec3(1.0, 0.0, 0.0)),
+		Ray(Point3(4.0, 5.0, -3.0), Vec3(-1.0, -1.2, 0.9).normalize()),
+		Ray(Point3(0.5, -8.0, 1.5), Vec3(0.1, 1.0, -0.1).normalize()),
+	];
+	let objects = [
+		(Transform3::translate(Vec3(1.0, 2.0, 1.0)), None),
+		(Transform3::rotate(Deg(40.0), Vec3(1.0, 1.0, 0.0).normalize()), None),
+		(Transform3::translate(Vec3(0.5, 0.0, 1.0)) * Transform3::scale(Vec3(3.0, 3.0, 3.0)), Some(3.0)),
+		(Transform3::rotate(Deg(-30.0), Vec3::Z) * Transform3::scale(Vec3(3.0, 1.5, 2.0)), Some(0.0)),
+	];
+	for ray in rays {
+		for (object, uniform_scale) in objects {
+			let (local, scale) = ray.transformed(&object.inverse());
+			let mut local_hits = [TraceHit::default(); 2];
+			let n = local.trace(&unit, &mut local_hits);
+
+			// Non-uniform scale: the world hit points lie on the ellipsoid
+			for hit in &local_hits[..n] {
+				let world_pt = ray.at(hit.distance / scale);
+				assert!(((object.inverse() * world_pt).len() - 1.0).abs() < 1e-9);
+				assert!(world_pt.distance(object * local.at(hit.distance)) < 1e-9);
+			}
+
+			// Rigid and uniform scale: compare against the transformed sphere
+			if uniform_scale != Some(0.0) {
+				let world = Sphere(object * unit.center, uniform_scale.unwrap_or(1.0));
+				let mut world_hits = [TraceHit::default(); 2];
+				assert_eq!(ray.trace(&world, &mut world_hits), n);
+				for (w, l) in world_hits[..n].iter().zip(&local_hits[..n]) {
+					assert!((w.distance - l.distance / scale).abs() < 1e-9);
+					assert!(w.normal.distance((object.mat3() * l.normal).normalize()) < 1e-9);
+				}
+			}
+		}
+	}
+}
+
+#[test]
+fn trace_instance() {
+	let unit = Sphere(Point3(0.0f64, 0.0, 0.0), 1.0);
+	let origins = [Point3(-10.0, 0.3, 0.2), Point3(4.0, 5.0, -3.0), Point3(0.5, -8.0, 1.5)];
+	let transforms = [
+		Transform3::translate(Vec3(1.0, 2.0, 1.0)),
+		Transform3::translate(Vec3(0.5, 0.0, 1.0)) * Transform3::scale(Vec3(3.0, 3.0, 3.0)),
+		Transform3::rotate(Deg(-30.0), Vec3::Z) * Transform3::scale(Vec3(3.0, 1.5, 2.0)),
+	];
+	for origin in origins {
+		for transform in transforms {
+			let instance = Instance3(unit, transform);
+			// Aim slightly off center with a direction that is not normalized
+			let ray = Ray(origin, (transform * Point3(0.2, -0.3, 0.1) - origin) * 0.5);
+			let mut hits = [TraceHit::default(); 2];
+			assert_eq!(ray.trace(&instance, &mut hits), 2);
+
+			// Compare against the implicit surface of the transformed sphere
+			let f = |pt: Point3<f64>| (transform.inverse() * pt).len_sqr() - 1.0;
+			for hit in &hits {
+				let pt = ray.at(hit.distance);
+				assert!(f(pt).abs() < 1e-9);
+				assert!(hit.normal.is_normalized(1e-12));
+				let eps = 1e-6;
+				let grad = Vec3(
+					f(pt + Vec3(eps, 0.0, 0.0)) - f(pt - Vec3(eps, 0.0, 0.0)),
+					f(pt + Vec3(0.0, eps, 0.0)) - f(pt - Vec3(0.0, eps, 0.0)),
+					f(pt + Vec3(0.0, 0.0, eps)) - f(pt - Vec3(0.0, 0.0, eps)),
+				).normalize();
+				assert!(hit.normal.distance(grad) < 1e-6);
+			}
+
+			assert!(!ray.inside(&instance));
+			assert!(Ray(transform * unit.center, ray.direction).inside(&instance));
+		}
+	}
+}
+
+#[test]
+fn between_shadow_ray() {
+	let from = Point3(1.0, -2.0, 3.0);
+	let to = Point3(-3.0, 6.0, 11.0);
+	let (ray, max_distance) = Ray::between(from, to);
+	assert!(ray.direction.is_normalized(1e-12));
+	assert!((max_distance - 12.0f64).abs() < 1e-12);
+	assert!(ray.at(max_distance).distance(to) < 1e-12);
+
+	// A sphere halfway between the points blocks the ray exactly in the middle
+	let mid = from.lerp(to, 0.5);
+	let blocker = Sphere(mid, 1.0);
+	let mut hits = [TraceHit::default(); 2];
+	let count = ray.trace(&blocker, &mut hits);
+	assert_eq!(count, 2);
+	let nearest = hits[..count].iter().map(|hit| hit.distance).fold(f64::INFINITY, f64::min);
+	assert!((nearest - (max_distance * 0.5 - 1.0)).abs() < 1e-12);
+
+	// Geometry beyond the target does not shadow it
+	let beyond = Sphere(to + ray.direction * 3.0, 1.0);
+	let count = ray.trace(&beyond, &mut hits);
+	assert_eq!(count, 2);
+	assert!(hits[..count].iter().all(|hit| hit.distance > max_distance));
+
+	// The visualized segment ends at the target
+	let segment = ray.segment(max_distance);
+	assert_eq!(segment.start, from);
+	assert!(segment.end.distance(to) < 1e-12);
+}
+
+#[test]
+fn look_degenerate() {
+	let origin = Point3(1.0, 2.0, 3.0);
+	assert_eq!(Ray::look(origin, Vec3::ZERO), None);
+	assert_eq!(Ray::look(origin, Vec3(f64::NAN, 0.0, 0.0)), None);
+	assert_eq!(Ray::look(origin, Vec3(f64::INFINITY, 0.0, 0.0)), None);
+	assert_eq!(Ray::look(origin, Vec3(0.0, -2.0, 0.0)), Some(Ray(origin, Vec3(0.0, -1.0, 0.0))));
+
+	// Coincident points have no direction
+	let (ray, distance) = Ray::between(origin, origin);
+	assert_eq!((ray.direction, distance), (Vec3::ZERO, 0.0));
+}
+
+#[test]
+fn sphere_uv_of_hits() {
+	let sphere = Sphere(Point3(1.0, -2.0, 0.5), 3.0);
+	let mut rng = Rng(0x2f6b19c3);
+	for _ in 0..1000 {
+		let origin = sphere.center + Vec3(rng.next(), rng.next(), rng.next()).normalize() * 10.0;
+		let target = sphere.center + Vec3(rng.next(), rng.next(), rng.next()) * 1.5;
+		let ray = Ray(origin, (target - origin).normalize());
+		let mut hits = [TraceHit::default(); 2];
+		let n = ray.trace(&sphere, &mut hits);
+		for hit in &hits[..n] {
+			let uv = sphere.uv(ray.at(hit.distance));
+			assert!(uv.x >= 0.0 && uv.x <= 1.0 && uv.y >= 0.0 && uv.y <= 1.0);
+
+			// The texture coordinates map back onto the hit point
+			let theta = Rad(uv.y * core::f64::consts::PI);
+			let phi = Rad((uv.x - 0.5) * core::f64::consts::TAU);
+			let pt = sphere.center + Spherical(sphere.radius, theta, phi).vec3();
+			assert!(pt.distance(ray.at(hit.distance)) < 1e-9);
+			assert!(Vec3::from(Spherical(1.0, theta, phi)).distance(hit.normal) < 1e-9);
+		}
+	}
+}
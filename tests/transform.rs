@@ -0,0 +1,940 @@
+use cvmath::*;
+
+mod common;
+use common::{assert_close, Rng};
+
+//----------------------------------------------------------------
+// Quaternions
+
+fn axis_angle(axis: Vec3<f64>, angle: Deg<f64>) -> Quaternion<f64> {
+	let (s, c) = (angle * 0.5).sin_cos();
+	Quaternion(c, axis.x * s, axis.y * s, axis.z * s)
+}
+
+#[test]
+fn exp_decay_frame_rate_independent() {
+	let start = axis_angle(Vec3::Y, Deg(10.0));
+	let target = axis_angle(Vec3::Y, Deg(100.0));
+
+	// One large step equals many small steps
+	let once = start.exp_decay(target, 3.0, 0.5);
+	let mut many = start;
+	for _ in 0..50 {
+		many = many.exp_decay(target, 3.0, 0.01);
+	}
+	assert!((once - many).len() < 1e-9, "{:?} {:?}", once, many);
+
+	// Converges to the target
+	let mut q = start;
+	for _ in 0..100 {
+		q = q.exp_decay(target, 10.0, 0.1);
+	}
+	assert!((q - target).len() < 1e-9);
+}
+
+#[test]
+fn exp_decay_shortest_path() {
+	let start = axis_angle(Vec3::Z, Deg(10.0));
+	// Same rotation as -20° around Z but with the opposite sign
+	let target = -axis_angle(Vec3::Z, Deg(-20.0));
+
+	let q = start.exp_decay(target, 1.0, 0.1);
+	let v = q * Vec3(1.0, 0.0, 0.0);
+	let angle = v.y.atan2(v.x).to_degrees();
+	assert!(angle < 10.0 && angle > -20.0, "{}", angle);
+}
+
+#[test]
+fn rotation_z_matches_2d() {
+	for i in -12..=12 {
+		let angle = Deg(i as f64 * 15.0);
+		let q = Quaternion::rotation_z(angle);
+		let rot = Mat2::rotate(angle);
+		for &v in &[Vec2(1.0, 0.0), Vec2(0.0, 1.0), Vec2(3.0, -2.0)] {
+			let expected = (rot * v).vec3(0.0);
+			assert!((q * v.vec3(0.0)).distance(expected) < 1e-12);
+			// The Z component is unaffected
+			assert!((q * v.vec3(5.0)).distance((rot * v).vec3(5.0)) < 1e-12);
+		}
+	}
+}
+
+#[test]
+fn twist_z_round_trip() {
+	for i in -11..=11 {
+		let angle = Deg(i as f64 * 15.0);
+		let twist = Quaternion::rotation_z(angle);
+		assert!((twist.twist_z().to_deg() - angle).value.abs() < 1e-9);
+		assert!(((-twist).twist_z().to_deg() - angle).value.abs() < 1e-9);
+
+		// Swing around an axis in the XY plane does not change the twist
+		let swing = axis_angle(Vec3(1.0, 2.0, 0.0).normalize(), Deg(70.0));
+		assert!(((swing * twist).twist_z().to_deg() - angle).value.abs() < 1e-9);
+	}
+}
+
+#[test]
+fn twist_swing_recomposes() {
+	let mut rng = Rng(42u64);
+	for _ in 0..1000 {
+		let q = Quaternion(rng.next(), rng.next(), rng.next(), rng.next()).normalize();
+		let axis = Vec3(rng.next(), rng.next(), rng.next());
+		let (twist, swing) = q.twist_swing(axis);
+		assert!((swing * twist - q).len() < 1e-12, "{:?} {:?}", q, axis);
+		assert!((twist.len() - 1.0).abs() < 1e-12);
+		assert!((swing.len() - 1.0).abs() < 1e-12);
+
+		// The twist rotates around the axis and the swing around a perpendicular axis
+		let n = axis.normalize();
+		assert!(Vec3(twist.b, twist.c, twist.d).cross(n).len() < 1e-12);
+		assert!(Vec3(swing.b, swing.c, swing.d).dot(n).abs() < 1e-12);
+	}
+}
+
+#[test]
+fn twist_swing_matches_twist_z() {
+	let q = axis_angle(Vec3(1.0, 2.0, 0.0).normalize(), Deg(70.0)) * Quaternion::rotation_z(Deg(-40.0));
+	let (twist, _) = q.twist_swing(Vec3::Z);
+	assert!((twist.twist_z().to_deg() - Deg(-40.0)).value.abs() < 1e-9);
+	assert!((twist.twist_z() - q.twist_z()).value.abs() < 1e-12);
+}
+
+#[test]
+fn twist_swing_singularity() {
+	// Half turn around an axis perpendicular to the twist axis has no twist
+	let q = axis_angle(Vec3(1.0, -1.0, 0.0).normalize(), Deg(180.0));
+	let (twist, swing) = q.twist_swing(Vec3::Z);
+	assert!(twist.all_finite() && swing.all_finite());
+	assert!((swing * twist - q).len() < 1e-12);
+	assert_eq!(twist, Quaternion::IDENTITY);
+
+	// Pure twist has an identity swing
+	let q = axis_angle(Vec3::Y, Deg(180.0));
+	let (twist, swing) = q.twist_swing(Vec3::Y);
+	assert!((twist - q).len() < 1e-12);
+	assert!((swing - Quaternion::IDENTITY).len() < 1e-12);
+}
+
+#[test]
+fn look_rotation_matches_look_at() {
+	let mut rng = Rng(7u64);
+	for _ in 0..1000 {
+		let eye = Vec3(rng.next(), rng.next(), rng.next()) * 10.0;
+		let target = Vec3(rng.next(), rng.next(), rng.next()) * 10.0;
+		let up = Vec3(rng.next(), rng.next(), rng.next());
+		let q = Quaternion::look_rotation(target - eye, up);
+		assert!((q.len() - 1.0).abs() < 1e-12);
+
+		// The view rotation is the inverse of the object rotation
+		let view = Mat4::look_at(eye, target, up, LH);
+		let m = q.rotation_matrix() * Mat3::compose(view.x().xyz(), view.y().xyz(), view.z().xyz());
+		let m = m.into_row_major();
+		let identity = Mat3::<f64>::IDENTITY.into_row_major();
+		for (a, b) in m.iter().flatten().zip(identity.iter().flatten()) {
+			assert!((a - b).abs() < 1e-9, "{:?}", m);
+		}
+
+		// The up vector stays on the same side
+		assert!((q * Vec3::Y).dot(up) >= 0.0);
+	}
+}
+
+#[test]
+fn look_rotation_degenerate_up() {
+	for forward in [Vec3(0.0f64, 1.0, 0.0), Vec3(0.0, -3.0, 0.0), Vec3(0.0, 0.0, 1.0), Vec3(0.0, 0.0, -1.0), Vec3(1.0, 1.0, 1.0)] {
+		for up in [forward, -forward, Vec3::ZERO] {
+			let q = Quaternion::look_rotation(forward, up);
+			assert!(q.all_finite(), "{:?} {:?}", forward, up);
+			assert!((q.len() - 1.0).abs() < 1e-12);
+			assert!((q * Vec3::Z).distance(forward.normalize()) < 1e-12, "{:?} {:?}", forward, up);
+		}
+	}
+}
+
+#[test]
+fn average_cluster() {
+	let mut rng = Rng(7u64);
+	let center = axis_angle(Vec3(1.0, 2.0, 2.0) / 3.0, Deg(70.0));
+	for _ in 0..100 {
+		// Jitter the center by a few degrees and randomly flip the signs
+		let quats: Vec<_> = (0..20).map(|_| {
+			let axis = Vec3(rng.next(), rng.next(), rng.next()).normalize();
+			let q = center * axis_angle(axis, Deg(rng.next() * 3.0));
+			if rng.next() < 0.0 { -q } else { q }
+		}).collect();
+
+		let avg = Quaternion::average(&quats).unwrap();
+		assert!(avg.is_normalized(1e-12));
+		// Within the cluster: no further than 3° from the center
+		let angle = Rad(avg.dot(center).abs().min(1.0).acos() * 2.0);
+		assert!(angle < Deg(3.0).to_rad(), "{:?}", angle);
+		// Nearer the center than the worst sample
+		let worst = quats.iter().map(|q| q.dot(center).abs()).fold(1.0, f64::min);
+		assert!(avg.dot(center).abs() >= worst);
+	}
+}
+
+#[test]
+fn average_single() {
+	let q = axis_angle(Vec3(0.0, 1.0, 0.0), Deg(45.0));
+	assert!((Quaternion::average(&[q]).unwrap() - q).len() < 1e-15);
+	assert!((Quaternion::average(&[-q, q, q]).unwrap() + q).len() < 1e-15);
+}
+
+//----------------------------------------------------------------
+// Transform interpolation
+
+fn look_at(eye: Vec3<f64>, target: Vec3<f64>) -> Transform3<f64> {
+	let m = Mat4::look_at(eye, target, Vec3(0.0, 1.0, 0.0), Hand::LH);
+	Transform3::compose(m.x().xyz(), m.y().xyz(), m.z().xyz(), m.w().xyz())
+}
+
+#[test]
+fn interpolate_look_at_stays_rigid() {
+	let a = look_at(Vec3(0.0, 2.0, -5.0), Vec3(0.0, 0.0, 0.0));
+	let b = look_at(Vec3(4.0, 1.0, 3.0), Vec3(1.0, 0.5, 0.0));
+	let qa = Quaternion::from_rotation_matrix(a.mat3());
+	let qb = Quaternion::from_rotation_matrix(b.mat3());
+
+	for i in 0..=16 {
+		let t = i as f64 / 16.0;
+		let m = a.interpolate_rigid(b, t);
+
+		// The basis remains orthonormal
+		let (x, y, z) = (m.x(), m.y(), m.z());
+		assert!((x.len() - 1.0).abs() < 1e-12 && (y.len() - 1.0).abs() < 1e-12 && (z.len() - 1.0).abs() < 1e-12);
+		assert!(x.dot(y).abs() < 1e-12 && x.dot(z).abs() < 1e-12 && y.dot(z).abs() < 1e-12);
+		assert!((m.determinant() - 1.0).abs() < 1e-12);
+
+		// The rotation matches slerp of the quaternions
+		let q = qa.slerp(qb, t);
+		let diff = q.rotation_matrix() - m.mat3();
+		assert!(diff.into_row_major().iter().flatten().all(|x| x.abs() < 1e-12), "{:?}", diff);
+		assert!(m.t().distance(a.t().lerp(b.t(), t)) < 1e-12);
+	}
+
+	// Element-wise interpolation shears the basis
+	let lerp = (a + b) / 2.0;
+	assert!(lerp.x().dot(lerp.y()).abs() > 1e-3 || (lerp.x().len() - 1.0).abs() > 1e-3);
+}
+
+#[test]
+fn interpolate_rigid_discards_scale() {
+	let scale = Transform3::scale(Vec3(3.0, 0.5, 2.0));
+	let a = Transform3::translate(Vec3(1.0, 0.0, 0.0)) * scale;
+	let b = Transform3::translate(Vec3(1.0, 4.0, 0.0)) * Transform3::rotate(Deg(90.0), Vec3::Z) * scale;
+	let mid = a.interpolate_rigid(b, 0.5);
+	let expected = Transform3::translate(Vec3(1.0, 2.0, 0.0)) * Transform3::rotate(Deg(45.0), Vec3::Z);
+	let diff = mid - expected;
+	assert!(diff.into_row_major().iter().flatten().all(|x: &f64| x.abs() < 1e-12), "{:?}", mid);
+
+	// Shear is discarded as well
+	let shear = Transform3::compose(Vec3(2.0, 0.0, 0.0), Vec3(1.0, 1.0, 0.0), Vec3(0.0, 0.0, 1.0), Vec3::ZERO);
+	let m = shear.interpolate_rigid(shear, 0.25);
+	assert!(m.mat3().into_row_major().iter().flatten().zip(Mat3::<f64>::IDENTITY.into_row_major().iter().flatten()).all(|(a, b)| (a - b).abs() < 1e-12));
+}
+
+#[test]
+fn relative_to_matches_inverse() {
+	let a = look_at(Vec3(1.0, 2.0, 3.0), Vec3(-1.0, 0.0, 0.5));
+	let b = look_at(Vec3(-4.0, 0.5, 2.0), Vec3(0.0, 1.0, 0.0));
+	let diff = a.relative_to(b) - b.inverse() * a;
+	assert!(diff.into_row_major().iter().flatten().all(|x| x.abs() < 1e-12));
+	let diff = b * a.relative_to(b) - a;
+	assert!(diff.into_row_major().iter().flatten().all(|x| x.abs() < 1e-12));
+}
+
+//----------------------------------------------------------------
+// Projection and unprojection
+
+#[test]
+fn unproject_round_trip() {
+	let viewport = Rect(Point2(10.0f64, 20.0), Point2(810.0, 620.0));
+	for &flags in &[(LH, ZO), (LH, NO), (RH, ZO), (RH, NO)] {
+		let (hand, clip) = flags;
+		let view = Mat4::look_at(Vec3(3.0, 2.0, -6.0), Vec3(0.0, 0.5, 0.0), Vec3(0.0, 1.0, 0.0), hand);
+		let proj = Mat4::perspective(Deg(60.0), 800.0 / 600.0, 0.5, 50.0, flags);
+		let view_proj = proj * view;
+		let inv = view_proj.inverse();
+
+		let eye = Vec3(3.0, 2.0, -6.0);
+		for &pt in &[Vec3(0.0, 0.0, 0.0), Vec3(1.0, -0.5, 0.25), Vec3(-0.5, 1.5, 2.0)] {
+			let ndc = (view_proj * pt.vec4(1.0)).hdiv();
+			let screen = (Mat4::screen(viewport) * ndc.vec4(1.0)).xyz();
+			let world = inv.unproject(screen, viewport);
+			assert!(world.distance(pt) < 1e-9, "{:?} {:?}", world, pt);
+
+			// The ray through the pixel passes through the point
+			let ray = inv.screen_ray(screen.xy(), viewport, clip);
+			assert!((ray.direction.len() - 1.0).abs() < 1e-12);
+			let to_pt = pt - ray.origin;
+			assert!(to_pt.cross(ray.direction).len() < 1e-9);
+			assert!(to_pt.dot(ray.direction) > 0.0);
+			assert!((eye - ray.origin).cross(ray.direction).len() < 1e-9);
+		}
+	}
+}
+
+#[test]
+fn screen_2d_matches_3d() {
+	let viewport = Rect(Point2(10.0f64, 20.0), Point2(810.0, 620.0));
+	let screen2 = Transform2::screen(viewport);
+	let screen3 = Mat4::screen(viewport);
+	for &ndc in &[Point2(-1.0, -1.0), Point2(1.0, 1.0), Point2(0.25, -0.75), Point2(0.0, 0.0)] {
+		let expected = (screen3 * Vec4(ndc.x, ndc.y, 0.0, 1.0)).xy();
+		assert!((screen2 * ndc).distance(expected) < 1e-9);
+	}
+}
+
+#[test]
+fn projection_params_match_mat4() {
+	let viewport = Rect(Point2(0.0f64, 0.0), Point2(1280.0, 720.0));
+	for &flags in &[(LH, ZO), (LH, NO), (RH, ZO), (RH, NO)] {
+		let persp = Perspective::new(Deg(70.0).to_rad(), 1.0, 0.1, 100.0, flags).with_aspect(1280.0, 720.0);
+		assert_eq!(persp.matrix(), Mat4::perspective_fov(Deg(70.0), 1280.0, 720.0, 0.1, 100.0, flags));
+		let pixel = Point2(100.0, 600.0);
+		assert_eq!(persp.ray(pixel, viewport), persp.matrix().inverse().screen_ray(pixel, viewport, flags.1));
+
+		let ortho = Orthographic::new(-2.0, 2.0, -1.0, 1.0, 0.1, 100.0, flags).with_aspect(1280.0, 720.0);
+		assert_eq!(ortho.matrix(), Mat4::ortho_3d(-16.0 / 9.0, 16.0 / 9.0, -1.0, 1.0, 0.1, 100.0, flags));
+		let ray = ortho.ray(pixel, viewport);
+		assert!(ray.direction.cross(Vec3(0.0, 0.0, 1.0)).len() < 1e-12);
+	}
+}
+
+#[test]
+fn transform_point3_matches_manual_hdiv() {
+	for &flags in &[(LH, ZO), (LH, NO), (RH, ZO), (RH, NO)] {
+		let view = Mat4::look_at(Vec3(3.0f64, 2.0, -6.0), Vec3(0.0, 0.5, 0.0), Vec3(0.0, 1.0, 0.0), flags.0);
+		let proj = Mat4::perspective(Deg(60.0), 800.0 / 600.0, 0.5, 50.0, flags);
+		let view_proj = proj * view;
+		for &pt in &[Vec3(0.0, 0.0, 0.0), Vec3(1.0, -0.5, 0.25), Vec3(-0.5, 1.5, 2.0)] {
+			let clip = view_proj * Vec4(pt.x, pt.y, pt.z, 1.0);
+			let ndc = Vec3(clip.x / clip.w, clip.y / clip.w, clip.z / clip.w);
+			assert!(view_proj.transform_point3(pt).distance(ndc) < 1e-12);
+			assert_eq!(view_proj.transform_point3(pt), (view_proj * Vec4::from_point3(pt)).hdiv());
+		}
+
+		// Directions ignore the translation and are not divided
+		let dir = Vec3(1.0, -2.0, 0.5);
+		assert_eq!(view.transform_vector3(dir), (view * Vec4(dir.x, dir.y, dir.z, 0.0)).xyz());
+		assert!((view.transform_vector3(dir).len() - dir.len()).abs() < 1e-12);
+	}
+}
+
+#[test]
+fn jitter_shifts_pixels() {
+	let viewport = Rect(Point2(0.0f64, 0.0), Point2(800.0, 600.0));
+	let size = Vec2(viewport.width(), viewport.height());
+	for &flags in &[(LH, ZO), (LH, NO), (RH, ZO), (RH, NO)] {
+		let view = Mat4::look_at(Vec3(3.0, 2.0, -6.0), Vec3(0.0, 0.5, 0.0), Vec3(0.0, 1.0, 0.0), flags.0);
+		let proj = Mat4::perspective(Deg(60.0), 800.0 / 600.0, 0.5, 50.0, flags);
+		let ortho = Mat4::ortho_3d(-4.0, 4.0, -3.0, 3.0, 0.5, 50.0, flags);
+		for proj in [proj, ortho] {
+			assert_eq!(proj.with_jitter(Vec2::ZERO, size), proj);
+
+			for offset in [Vec2(0.5, -0.5), Vec2(-0.25, 0.125)] {
+				let jittered = proj.with_jitter(offset, size) * view;
+				let view_proj = proj * view;
+				for &pt in &[Vec3(0.0, 0.0, 0.0), Vec3(1.0, -0.5, 0.25), Vec3(-0.5, 1.5, 2.0)] {
+					let a = Mat4::screen(viewport).transform_point3(view_proj.transform_point3(pt));
+					let b = Mat4::screen(viewport).transform_point3(jittered.transform_point3(pt));
+					assert!((b.xy() - a.xy()).distance(offset) < 1e-9, "{:?} {:?}", a, b);
+					assert!((b.z - a.z).abs() < 1e-12);
+				}
+			}
+		}
+	}
+}
+
+//----------------------------------------------------------------
+// Fitting rectangles
+
+#[test]
+fn ortho_corners() {
+	let bounds = Rect(Point2(-10.0f64, 5.0), Point2(30.0, 25.0));
+	let ortho = Transform2::ortho(bounds);
+	assert_eq!(ortho * bounds.top_left(), Point2(-1.0, -1.0));
+	assert_eq!(ortho * bounds.bottom_right(), Point2(1.0, 1.0));
+	assert_eq!(ortho * bounds.center(), Point2(0.0, 0.0));
+
+	// World to pixels with y flipped
+	let viewport = Rect(Point2(0.0, 0.0), Point2(640.0, 480.0));
+	let world_to_screen = Transform2::screen(viewport) * ortho;
+	assert_eq!(world_to_screen * Point2(-10.0, 25.0), Point2(0.0, 0.0));
+	assert_eq!(world_to_screen * Point2(30.0, 5.0), Point2(640.0, 480.0));
+}
+
+#[test]
+fn fit_stretch() {
+	let src = Rect(Point2(1.0f64, 2.0), Point2(5.0, 4.0));
+	let dst = Rect(Point2(0.0, 0.0), Point2(100.0, 50.0));
+	let fit = Transform2::fit(src, dst, FitPolicy::Stretch);
+	assert_eq!(fit * src.mins, dst.mins);
+	assert_eq!(fit * src.maxs, dst.maxs);
+}
+
+#[test]
+fn fit_contain() {
+	let dst = Rect(Point2(10.0f64, 20.0), Point2(330.0, 260.0));
+	for &src in &[Rect(Point2(0.0, 0.0), Point2(100.0, 25.0)), Rect(Point2(-5.0, -5.0), Point2(5.0, 45.0))] {
+		let fit = Transform2::fit(src, dst, FitPolicy::Contain);
+		let mins = fit * src.mins;
+		let maxs = fit * src.maxs;
+		let out = Rect(mins, maxs);
+
+		// Aspect ratio preserved and centered
+		assert!((out.width() / out.height() - src.width() / src.height()).abs() < 1e-12);
+		assert!(out.center().distance(dst.center()) < 1e-12);
+
+		// Inside the destination and touching it along one axis
+		assert!(out.mins.x >= dst.mins.x - 1e-12 && out.mins.y >= dst.mins.y - 1e-12);
+		assert!(out.maxs.x <= dst.maxs.x + 1e-12 && out.maxs.y <= dst.maxs.y + 1e-12);
+		assert!((out.width() - dst.width()).abs() < 1e-12 || (out.height() - dst.height()).abs() < 1e-12);
+	}
+}
+
+#[test]
+fn fit_cover() {
+	let dst = Rect(Point2(10.0f64, 20.0), Point2(330.0, 260.0));
+	for &src in &[Rect(Point2(0.0, 0.0), Point2(100.0, 25.0)), Rect(Point2(-5.0, -5.0), Point2(5.0, 45.0))] {
+		let fit = Transform2::fit(src, dst, FitPolicy::Cover);
+		let out = Rect(fit * src.mins, fit * src.maxs);
+
+		// Aspect ratio preserved
+		assert!((out.width() / out.height() - src.width() / src.height()).abs() < 1e-12);
+
+		// Covers the destination and crops symmetrically
+		assert!(out.mins.x <= dst.mins.x + 1e-12 && out.mins.y <= dst.mins.y + 1e-12);
+		assert!(out.maxs.x >= dst.maxs.x - 1e-12 && out.maxs.y >= dst.maxs.y - 1e-12);
+		assert!(((dst.mins.x - out.mins.x) - (out.maxs.x - dst.maxs.x)).abs() < 1e-12);
+		assert!(((dst.mins.y - out.mins.y) - (out.maxs.y - dst.maxs.y)).abs() < 1e-12);
+	}
+}
+
+//----------------------------------------------------------------
+// Homography
+
+const SQUARE: [Point2<f64>; 4] = [Point2 { x: 0.0, y: 0.0 }, Point2 { x: 1.0, y: 0.0 }, Point2 { x: 1.0, y: 1.0 }, Point2 { x: 0.0, y: 1.0 }];
+
+#[test]
+fn hdiv_uses_each_component() {
+	assert_eq!(Vec3(3.0, 6.0, 3.0).hdiv(), Vec2(1.0, 2.0));
+	assert_eq!(Vec4(3.0, 6.0, 9.0, 3.0).hdiv(), Vec3(1.0, 2.0, 3.0));
+}
+
+#[test]
+fn unit_square_to_quad_corners() {
+	let quads = [
+		SQUARE,
+		[Point2(0.0, 0.0), Point2(4.0, 0.0), Point2(3.0, 2.0), Point2(1.0, 2.0)],
+		[Point2(-1.0, 2.0), Point2(3.0, 1.5), Point2(5.0, 6.0), Point2(0.5, 4.0)],
+		// Parallelogram, the homography is affine
+		[Point2(1.0, 1.0), Point2(3.0, 2.0), Point2(4.0, 5.0), Point2(2.0, 4.0)],
+	];
+	for quad in quads {
+		let mat = Mat3::from_unit_square_to_quad(quad).unwrap();
+		for i in 0..4 {
+			assert!(mat.transform_point2(SQUARE[i]).distance(quad[i]) < 1e-12, "{:?} {:?}", mat.transform_point2(SQUARE[i]), quad[i]);
+		}
+	}
+	assert_eq!(Mat3::from_unit_square_to_quad(SQUARE), Some(Mat3::IDENTITY));
+	let affine = Mat3::from_unit_square_to_quad(quads[3]).unwrap();
+	assert_eq!((affine.a31, affine.a32), (0.0, 0.0));
+}
+
+#[test]
+fn quad_to_quad_corners() {
+	let src = [Point2(10.0, 20.0), Point2(110.0, 25.0), Point2(105.0, 90.0), Point2(5.0, 80.0)];
+	let dst = [Point2(-1.0, 2.0), Point2(3.0, 1.5), Point2(5.0, 6.0), Point2(0.5, 4.0)];
+	let mat = Mat3::from_quad_to_quad(src, dst).unwrap();
+	for i in 0..4 {
+		assert!(mat.transform_point2(src[i]).distance(dst[i]) < 1e-9);
+	}
+	// Straight lines stay straight: the midpoint of an edge maps onto the destination edge
+	let mid = mat.transform_point2(src[0].lerp(src[1], 0.5));
+	let cross: f64 = (mid - dst[0]).cross(dst[1] - dst[0]);
+	assert!(cross.abs() < 1e-9);
+
+	// The inverse mapping
+	let inv = Mat3::from_quad_to_quad(dst, src).unwrap();
+	for i in 0..4 {
+		assert!(inv.transform_point2(dst[i]).distance(src[i]) < 1e-9);
+	}
+}
+
+#[test]
+fn degenerate_quads() {
+	let collapsed = [Point2(0.0, 0.0), Point2(1.0, 1.0), Point2(2.0, 2.0), Point2(3.0, 3.0)];
+	assert_eq!(Mat3::from_unit_square_to_quad(collapsed), None);
+	assert_eq!(Mat3::from_quad_to_quad(SQUARE, collapsed), None);
+	assert_eq!(Mat3::from_quad_to_quad(collapsed, SQUARE), None);
+	let point = [Point2(1.0, 1.0); 4];
+	assert_eq!(Mat3::from_unit_square_to_quad(point), None);
+}
+
+//----------------------------------------------------------------
+// Field of view
+
+#[test]
+fn fov_known_values() {
+	let fov_y = fov_y_from_fov_x(Deg(90.0f64), 16.0 / 9.0);
+	assert!((fov_y.value - 58.715507).abs() < 1e-4, "{}", fov_y);
+	let fov_y = fov_y_from_fov_x(Deg(90.0f64), 4.0 / 3.0);
+	assert!((fov_y.value - 73.739795).abs() < 1e-4, "{}", fov_y);
+	// 35mm full frame: 36x24mm sensor, 50mm lens
+	let fov_x: Deg<f64> = fov_from_focal_length(50.0, 36.0);
+	assert!((fov_x.value - 39.597752).abs() < 1e-4, "{}", fov_x);
+	let fov_d: Deg<f64> = fov_from_focal_length(50.0, (36.0f64 * 36.0 + 24.0 * 24.0).sqrt());
+	assert!((fov_d.value - 46.793003).abs() < 1e-4, "{}", fov_d);
+	let fov_y: Deg<f64> = fov_from_focal_length(50.0, 24.0);
+	assert!((fov_y_from_diagonal(fov_d, 1.5).value - fov_y.value).abs() < 1e-9);
+	assert!((fov_y_from_fov_x(fov_x, 1.5).value - fov_y.value).abs() < 1e-9);
+}
+
+#[test]
+fn fov_round_trips() {
+	for &aspect in &[0.5, 1.0, 16.0 / 9.0, 21.0 / 9.0] {
+		for i in 1..18 {
+			let fov = Deg(i as f64 * 10.0);
+			assert!((fov_x_from_fov_y(fov_y_from_fov_x(fov, aspect), aspect).value - fov.value).abs() < 1e-9);
+			let focal_length = focal_length_from_fov(fov, 24.0);
+			let back: Deg<f64> = fov_from_focal_length(focal_length, 24.0);
+			assert!((back.value - fov.value).abs() < 1e-9);
+		}
+	}
+}
+
+#[test]
+fn perspective_fov_x() {
+	let a = Mat4::perspective_fov_x(Deg(90.0f64), 16.0 / 9.0, 0.1, 100.0, (RH, NO));
+	let b = Mat4::perspective(fov_y_from_fov_x(Deg(90.0), 16.0 / 9.0), 16.0 / 9.0, 0.1, 100.0, (RH, NO));
+	let diff = a - b;
+	for x in diff.into_row_major().iter().flatten() {
+		assert!(x.abs() < 1e-12);
+	}
+	// A horizontal FOV of 90° maps x = z to the edge of the screen
+	assert!((a.a11 - 1.0).abs() < 1e-12);
+}
+
+#[test]
+fn perspective_offcenter() {
+	for flags in [(LH, ZO), (LH, NO), (RH, ZO), (RH, NO)] {
+		let centered = Mat4::perspective(Deg(60.0f64), 16.0 / 9.0, 0.1, 100.0, flags);
+		assert_eq!(Mat4::perspective_offcenter(Deg(60.0f64), 16.0 / 9.0, 0.1, 100.0, Vec2::ZERO, flags), centered);
+
+		// The shift only changes the off-center terms of the projection
+		let shifted = Mat4::perspective_offcenter(Deg(60.0f64), 16.0 / 9.0, 0.1, 100.0, Vec2(0.25, -0.5), flags);
+		let diff = shifted - centered;
+		assert!((diff.a13 - 0.5).abs() < 1e-12);
+		assert!((diff.a23 + 1.0).abs() < 1e-12);
+		assert_eq!(Mat4 { a13: 0.0, a23: 0.0, ..diff }, Mat4::ZERO);
+	}
+}
+
+//----------------------------------------------------------------
+// Cameras
+
+fn assert_orthonormal(view: &Transform3<f64>, hand: Hand) {
+	let (r, u, f) = (view.right(), view.up(), view.forward(hand));
+	for v in [r, u, f] {
+		assert!((v.len() - 1.0).abs() < 1e-12, "{v}");
+	}
+	assert!(r.dot(u).abs() < 1e-12);
+	assert!(u.dot(f).abs() < 1e-12);
+	assert!(f.dot(r).abs() < 1e-12);
+}
+
+#[test]
+fn orbit_matches_look_at() {
+	let target = Point3(1.0, -2.0, 0.5);
+	for hand in [LH, RH] {
+		let view = Transform3::orbit(target, 4.0, Deg(0.0), Deg(0.0), hand);
+		let expected = Mat4::look_at(target - Vec3(0.0, 0.0, 4.0), target, Vec3(0.0, 1.0, 0.0), hand);
+		assert_eq!(view.mat4(), expected);
+	}
+}
+
+#[test]
+fn orbit_distance_and_direction() {
+	let target = Point3(1.0, -2.0, 0.5);
+	for hand in [LH, RH] {
+		for (yaw, pitch) in [(0.0, 0.0), (45.0, 30.0), (-120.0, -60.0), (200.0, 89.0)] {
+			let view = Transform3::orbit(target, 4.0, Deg(yaw), Deg(pitch), hand);
+			assert_orthonormal(&view, hand);
+
+			// The camera is at the distance from the target, looking at it
+			let eye = view.inverse_rigid().t();
+			assert!((eye.distance(target) - 4.0).abs() < 1e-12);
+			assert!(view.forward(hand).distance((target - eye).normalize()) < 1e-12);
+
+			// The target is straight ahead in view space
+			let pt = view * target;
+			assert!(pt.x.abs() < 1e-12 && pt.y.abs() < 1e-12);
+		}
+	}
+}
+
+#[test]
+fn fps_basis() {
+	let position = Point3(3.0, 1.0, -2.0);
+	for hand in [LH, RH] {
+		for (yaw, pitch) in [(0.0, 0.0), (90.0, 0.0), (-30.0, 45.0), (150.0, -80.0)] {
+			let view = Transform3::fps(position, Deg(yaw), Deg(pitch), hand);
+			assert_orthonormal(&view, hand);
+			assert!(view.inverse_rigid().t().distance(position) < 1e-12);
+
+			// Pitch tilts the view up, yaw turns it around the Y axis
+			let forward = view.forward(hand);
+			assert!((forward.y - f64::sin(pitch.to_radians())).abs() < 1e-12);
+			assert!((Vec2(forward.z, forward.x).polar_angle().to_deg().value - yaw).abs() < 1e-9);
+		}
+	}
+}
+
+#[test]
+fn pitch_clamp() {
+	let limit = Transform3::clamp_pitch(Deg(90.0f64));
+	assert!(limit < Deg(90.0).to_rad());
+	assert!(limit > Deg(89.0).to_rad());
+	assert_eq!(Transform3::clamp_pitch(Deg(-90.0f64)), -limit);
+	assert_eq!(Transform3::clamp_pitch(Deg(45.0f64)), Deg(45.0).to_rad());
+
+	// Looking straight up or down is clamped and still produces a valid view
+	for hand in [LH, RH] {
+		for pitch in [90.0f64, -90.0, 180.0] {
+			let view = Transform3::fps(Point3(0.0, 0.0, 0.0), Deg(30.0), Deg(pitch), hand);
+			assert!(view.mat3().determinant().is_finite());
+			assert_orthonormal(&view, hand);
+			assert_eq!(view, Transform3::fps(Point3(0.0, 0.0, 0.0), Deg(30.0), Transform3::clamp_pitch(Deg(pitch)), hand));
+		}
+	}
+}
+
+//----------------------------------------------------------------
+// Similarity fit
+
+fn similarity(rng: &mut Rng) -> Transform2<f64> {
+	let angle = Rad(rng.next() * 3.0);
+	let scale = 0.1 + (rng.next() + 1.0) * 2.0;
+	Transform2::translate(rng.point2(10.0)) * Transform2::rotate(angle) * Transform2::scale(Vec2(scale, scale))
+}
+
+#[test]
+fn from_point_pairs() {
+	let mut rng = Rng(42);
+	for _ in 0..100 {
+		let expected = similarity(&mut rng);
+		let src = (rng.point2(10.0), rng.point2(10.0));
+		let dst = (expected * src.0, expected * src.1);
+		let transform = Transform2::from_point_pairs(src, dst).unwrap();
+		assert_close(transform.into_row_major(), expected.into_row_major());
+	}
+}
+
+#[test]
+fn fit_similarity() {
+	let mut rng = Rng(7);
+	for n in 2..50 {
+		let expected = similarity(&mut rng);
+		let src: Vec<_> = (0..n).map(|_| rng.point2(10.0)).collect();
+		let dst: Vec<_> = src.iter().map(|&pt| expected * pt).collect();
+		let transform = Transform2::fit_similarity(&src, &dst).unwrap();
+		assert_close(transform.into_row_major(), expected.into_row_major());
+	}
+}
+
+#[test]
+fn fit_similarity_noise() {
+	// Symmetric noise around a square cancels out exactly
+	let expected = Transform2::translate(Vec2(1.0, 2.0)) * Transform2::rotate(Deg(90.0)) * Transform2::scale(Vec2(2.0, 2.0));
+	let src = [Point2(-1.0, -1.0), Point2(1.0, -1.0), Point2(1.0, 1.0), Point2(-1.0, 1.0)];
+	let noise = [Vec2(0.1, 0.0), Vec2(-0.1, 0.0), Vec2(0.1, 0.0), Vec2(-0.1, 0.0)];
+	let dst: Vec<_> = src.iter().zip(&noise).map(|(&pt, &n)| expected * pt + n).collect();
+	let transform = Transform2::fit_similarity(&src, &dst).unwrap();
+	assert_close(transform.into_row_major(), expected.into_row_major());
+}
+
+#[test]
+fn similarity_degenerate() {
+	let pt = Point2(1.0f64, 2.0);
+	assert_eq!(Transform2::from_point_pairs((pt, pt), (Point2(0.0, 0.0), Point2(1.0, 0.0))), None);
+	assert_eq!(Transform2::fit_similarity(&[], &[] as &[Point2<f64>]), None);
+	assert_eq!(Transform2::fit_similarity(&[pt], &[pt]), None);
+	assert_eq!(Transform2::fit_similarity(&[pt, pt, pt], &[Point2(0.0, 0.0), Point2(1.0, 0.0), Point2(2.0, 0.0)]), None);
+}
+
+//----------------------------------------------------------------
+// Renormalization
+
+// Small random rotation of at most 1°
+fn rotation(rng: &mut Rng) -> Quaternion<f32> {
+	let axis = rng.vec3().cast::<f32>().normalize();
+	let (s, c) = (Deg(rng.next() as f32) * 0.5).sin_cos();
+	Quaternion(c, axis.x * s, axis.y * s, axis.z * s)
+}
+
+// Rounding errors accumulate when chaining a million small rotations in f32
+const STEPS: usize = 1_000_000;
+const EVERY: usize = 100;
+
+#[test]
+fn quaternion_drift() {
+	let mut rng = Rng(1);
+	let mut drifting = Quaternion::<f32>::IDENTITY;
+	let mut corrected = Quaternion::<f32>::IDENTITY;
+	let mut max_error = 0.0f32;
+	for i in 0..STEPS {
+		let step = rotation(&mut rng);
+		drifting = step * drifting;
+		corrected = step * corrected;
+		if i % EVERY == 0 {
+			corrected = corrected.renormalize_fast();
+		}
+		max_error = max_error.max(corrected.norm_sqr_error().abs());
+	}
+	assert!(drifting.norm_sqr_error().abs() > 1e-4, "{}", drifting.norm_sqr_error());
+	assert!(max_error < 1e-5, "{}", max_error);
+}
+
+#[test]
+fn mat3_drift() {
+	let mut rng = Rng(2);
+	let mut drifting = Mat3::<f32>::IDENTITY;
+	let mut corrected = Mat3::<f32>::IDENTITY;
+	let mut max_error = 0.0f32;
+	for i in 0..STEPS {
+		let step = rotation(&mut rng).rotation_matrix();
+		drifting = step * drifting;
+		corrected = step * corrected;
+		if i % EVERY == 0 {
+			corrected = corrected.renormalize();
+		}
+		max_error = max_error.max(corrected.orthogonality_error());
+	}
+	assert!(drifting.orthogonality_error() > 1e-4, "{}", drifting.orthogonality_error());
+	assert!(max_error < 1e-5, "{}", max_error);
+}
+
+//----------------------------------------------------------------
+// Dual quaternions
+
+fn rigid(rng: &mut Rng) -> Transform3<f64> {
+	let rotation = rng.quaternion();
+	Transform3::compose_trs(rng.point3(10.0), rotation, Vec3::ONE)
+}
+
+#[test]
+fn dual_quaternion_round_trip() {
+	let mut rng = Rng(1);
+	for _ in 0..1000 {
+		let transform = rigid(&mut rng);
+		let dq = DualQuaternion::from_transform3(transform);
+		assert_close(dq.to_transform3().into_row_major(), transform.into_row_major());
+		assert_close(Transform3::from(DualQuaternion::from(transform)).into_row_major(), transform.into_row_major());
+
+		let pt = rng.point3(10.0);
+		assert!((dq * pt).distance(transform * pt) < 1e-9);
+	}
+}
+
+#[test]
+fn dual_quaternion_composition() {
+	let mut rng = Rng(2);
+	for _ in 0..1000 {
+		let (a, b) = (rigid(&mut rng), rigid(&mut rng));
+		let dq = DualQuaternion::from_transform3(a) * DualQuaternion::from_transform3(b);
+		assert_close(dq.to_transform3().into_row_major(), (a * b).into_row_major());
+		assert_close(DualQuaternion::from_transform3(a).conjugate().to_transform3().into_row_major(), a.inverse_rigid().into_row_major());
+	}
+}
+
+#[test]
+fn sclerp_endpoints() {
+	let mut rng = Rng(3);
+	for _ in 0..1000 {
+		let (a, b) = (rigid(&mut rng), rigid(&mut rng));
+		let (qa, qb) = (DualQuaternion::from_transform3(a), DualQuaternion::from_transform3(b));
+		assert_close(qa.sclerp(qb, 0.0).to_transform3().into_row_major(), a.into_row_major());
+		assert_close(qa.sclerp(qb, 1.0).to_transform3().into_row_major(), b.into_row_major());
+		// Unaffected by the sign of the quaternions
+		assert_close(qa.sclerp(-qb, 1.0).to_transform3().into_row_major(), b.into_row_major());
+	}
+}
+
+#[test]
+fn sclerp_screw_motion() {
+	// A screw motion around the Z axis through (1, 0, 0) interpolates to partial turns and advances
+	let screw = |angle: f64, advance: f64| {
+		Transform3::translate(Vec3(1.0, 0.0, advance)) * Transform3::rotate(Deg(angle), Vec3::Z) * Transform3::translate(Vec3(-1.0, 0.0, 0.0))
+	};
+	let a = DualQuaternion::IDENTITY;
+	let b = DualQuaternion::from_transform3(screw(120.0, 3.0));
+	for i in 0..=10 {
+		let t = i as f64 / 10.0;
+		let dq = a.sclerp(b, t);
+		assert!(dq.real.is_normalized(1e-12));
+		assert!(dq.real.dot(dq.dual).abs() < 1e-12);
+		assert_close(dq.to_transform3().into_row_major(), screw(120.0 * t, 3.0 * t).into_row_major());
+	}
+
+	// Pure translation interpolates linearly
+	let b = DualQuaternion::from_rotation_translation(Quaternion::IDENTITY, Vec3(2.0, 4.0, 6.0));
+	assert!(a.sclerp(b, 0.25).translation().distance(Vec3(0.5, 1.0, 1.5)) < 1e-12);
+}
+
+//----------------------------------------------------------------
+// Screen bounds
+
+const VIEWPORT: Rect<f64> = Rect(Point2(0.0, 0.0), Point2(800.0, 600.0));
+
+fn view_proj(flags: (Hand, Clip)) -> Mat4<f64> {
+	let proj = Mat4::perspective(Deg(60.0), 800.0 / 600.0, 0.5, 100.0, flags);
+	let view = Mat4::look_at(Vec3(1.0, 2.0, 3.0), Vec3(4.0, 1.0, 9.0), Vec3(0.0, 1.0, 0.0), flags.0);
+	proj * view
+}
+
+fn project(view_proj: &Mat4<f64>, pt: Point3<f64>) -> Point2<f64> {
+	let ndc = (*view_proj * Vec4::from_point3(pt)).hdiv();
+	Point2(400.0 + ndc.x * 400.0, 300.0 - ndc.y * 300.0)
+}
+
+#[test]
+fn sphere_on_view_axis() {
+	let view_proj = Mat4::perspective(Deg(60.0), 800.0 / 600.0, 0.5, 100.0, (RH, ZO));
+	let rect = Sphere(Point3(0.0, 0.0, -20.0), 2.0).project_bounds(&view_proj, VIEWPORT).unwrap();
+	assert!(rect.center().distance(Point2(400.0, 300.0)) < 1e-9);
+	// Square in pixels as the aspect ratio matches the viewport
+	assert!((rect.width() - rect.height()).abs() < 1e-9);
+	// The outline's half angle is asin(r / d), the vertical field of view spans 600 pixels
+	let expected = (2.0f64 / 20.0).asin().tan() / Deg(30.0f64).tan() * 600.0;
+	assert!((rect.height() - expected).abs() < 1e-9, "{} {}", rect.height(), expected);
+}
+
+#[test]
+fn sphere_exact() {
+	let mut rng = Rng(1);
+	for flags in [(LH, ZO), (LH, NO), (RH, ZO), (RH, NO)] {
+		let view_proj = view_proj(flags);
+		let sphere = Sphere(Point3(4.0, 1.0, 9.0) + Vec3(rng.next(), rng.next(), rng.next()), 1.5);
+		let rect = sphere.project_bounds(&view_proj, VIEWPORT).unwrap();
+
+		// The projected surface points fill the rectangle
+		let mut fit: Option<Rect<f64>> = None;
+		for _ in 0..20000 {
+			let dir = Vec3(rng.next(), rng.next(), rng.next()).normalize();
+			let pt = project(&view_proj, sphere.center + dir * sphere.radius);
+			assert!(rect.inflate(1e-9).contains(pt), "{:?} {:?}", rect, pt);
+			fit = Some(fit.map_or(Rect(pt, pt), |fit| fit.include(pt)));
+		}
+		let fit = fit.unwrap();
+		assert!(fit.mins.distance(rect.mins) < 1.0 && fit.maxs.distance(rect.maxs) < 1.0, "{:?} {:?}", fit, rect);
+	}
+}
+
+#[test]
+fn cuboid_and_triangle() {
+	for flags in [(LH, ZO), (LH, NO), (RH, ZO), (RH, NO)] {
+		let view_proj = view_proj(flags);
+		let cuboid = Cuboid(Point3(3.0, 0.0, 8.0), Point3(5.0, 2.0, 11.0));
+		let expected = Rect::from_points(cuboid.corners().map(|pt| project(&view_proj, pt))).unwrap();
+		let rect = cuboid.project_bounds(&view_proj, VIEWPORT).unwrap();
+		assert!(rect.mins.distance(expected.mins) < 1e-9 && rect.maxs.distance(expected.maxs) < 1e-9);
+
+		let triangle = Triangle3(Point3(3.0, 0.0, 8.0), Point3(5.0, 2.0, 11.0), Point3(3.0, 2.0, 9.0));
+		let expected = Rect::from_points([triangle.p0, triangle.p1, triangle.p2].map(|pt| project(&view_proj, pt))).unwrap();
+		let rect = triangle.project_bounds(&view_proj, VIEWPORT).unwrap();
+		assert!(rect.mins.distance(expected.mins) < 1e-9 && rect.maxs.distance(expected.maxs) < 1e-9);
+	}
+}
+
+#[test]
+fn behind_camera() {
+	for flags in [(LH, ZO), (LH, NO), (RH, ZO), (RH, NO)] {
+		let view_proj = view_proj(flags);
+		// Mirror the target through the eye
+		let behind = Point3(1.0, 2.0, 3.0) * 2.0 - Point3(4.0, 1.0, 9.0);
+		assert_eq!(Sphere(behind, 1.0).project_bounds(&view_proj, VIEWPORT), None);
+		assert_eq!(Cuboid(behind - Vec3::dup(1.0), behind + Vec3::dup(1.0)).project_bounds(&view_proj, VIEWPORT), None);
+		let triangle = Triangle3(behind, behind + Vec3(1.0, 0.0, 0.0), behind + Vec3(0.0, 1.0, 0.0));
+		assert_eq!(triangle.project_bounds(&view_proj, VIEWPORT), None);
+	}
+}
+
+#[test]
+fn straddling_near_plane() {
+	for flags in [(LH, ZO), (LH, NO), (RH, ZO), (RH, NO)] {
+		let view_proj = view_proj(flags);
+		let eye = Point3(1.0, 2.0, 3.0);
+
+		// Shapes around the camera cover the whole screen without blowing up
+		let rect = Sphere(eye, 1.0).project_bounds(&view_proj, VIEWPORT).unwrap();
+		assert!(rect.is_finite() && rect.encloses(VIEWPORT), "{:?}", rect);
+		let rect = Cuboid(eye - Vec3::dup(1.0), eye + Vec3::dup(1.0)).project_bounds(&view_proj, VIEWPORT).unwrap();
+		assert!(rect.is_finite() && rect.encloses(VIEWPORT), "{:?}", rect);
+
+		// A long triangle from behind the camera to far in front
+		let target = Point3(4.0, 1.0, 9.0);
+		let triangle = Triangle3(eye * 2.0 - target, target, target + Vec3(0.0, 1.0, 0.0));
+		let rect = triangle.project_bounds(&view_proj, VIEWPORT).unwrap();
+		assert!(rect.is_finite());
+		assert!(rect.inflate(1e-9).contains(project(&view_proj, target)));
+		assert!(rect.inflate(1e-9).contains(project(&view_proj, eye.lerp(target, 0.5))));
+	}
+}
+
+//----------------------------------------------------------------
+// Oblique projection
+
+const FLAGS: [(Hand, Clip); 4] = [(LH, ZO), (LH, NO), (RH, ZO), (RH, NO)];
+
+// Forward is +Z for LH and -Z for RH view space
+fn forward(hand: Hand) -> f64 {
+	match hand { LH => 1.0, RH => -1.0 }
+}
+
+#[test]
+fn regular_near_plane() {
+	for (hand, clip) in FLAGS {
+		let proj = Mat4::perspective(Deg(60.0), 1.5, 0.5, 50.0, (hand, clip));
+		let near = Vec4(0.0, 0.0, forward(hand), -0.5);
+		let same = proj.with_oblique_near(near, clip);
+		for x in (same - proj).into_row_major().iter().flatten() {
+			assert!(x.abs() < 1e-9, "{:?}", (hand, clip));
+		}
+	}
+}
+
+#[test]
+fn oblique_plane() {
+	for (hand, clip) in FLAGS {
+		let proj = Mat4::perspective(Deg(60.0), 1.5, 0.5, 50.0, (hand, clip));
+		let f = forward(hand);
+		// Mirror plane tilted towards the camera, 4 units ahead
+		let normal = Vec3(0.2, -0.3, f).normalize();
+		let origin = Vec3(0.0, 0.0, 4.0 * f);
+		let plane = normal.vec4(-normal.dot(origin));
+		let oblique = proj.with_oblique_near(plane, clip);
+
+		// Only the depth row changes
+		let (a, b) = (oblique.into_row_major(), proj.into_row_major());
+		assert_eq!((a[0], a[1], a[3]), (b[0], b[1], b[3]));
+
+		let near_z = match clip { ZO => 0.0, NO => -1.0 };
+		let u = normal.cross(Vec3(0.0, 1.0, 0.0)).normalize();
+		let v = normal.cross(u);
+		for i in -5..=5 {
+			for j in -5..=5 {
+				let pt = origin + u * (i as f64 * 0.3) + v * (j as f64 * 0.3);
+				// On the plane maps to the near depth
+				let ndc = (oblique * pt.vec4(1.0)).hdiv();
+				assert!((ndc.z - near_z).abs() < 1e-9, "{:?} {:?}", (hand, clip), ndc);
+				// Behind the mirror is visible, in front is clipped
+				let behind = (oblique * (pt + normal).vec4(1.0)).hdiv();
+				assert!(behind.z > near_z && behind.z < 1.0, "{:?} {:?}", (hand, clip), behind);
+				let front = (oblique * (pt - normal * 0.5).vec4(1.0)).hdiv();
+				assert!(front.z < near_z, "{:?} {:?}", (hand, clip), front);
+			}
+		}
+	}
+}
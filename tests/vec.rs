@@ -0,0 +1,1038 @@
+use cvmath::*;
+
+mod common;
+use common::Rng;
+
+//----------------------------------------------------------------
+// Color spaces
+
+fn grid() -> impl Iterator<Item = Vec3<f64>> {
+	let steps = [0.0, 0.001, 0.01, 0.04, 0.18, 0.25, 0.5, 0.73, 0.99, 1.0];
+	steps.into_iter().flat_map(move |r| steps.into_iter().flat_map(move |g| steps.into_iter().map(move |b| Vec3(r, g, b))))
+}
+
+#[test]
+fn srgb_round_trip() {
+	for color in grid() {
+		assert!(color.srgb_to_linear().linear_to_srgb().distance(color) < 1e-12, "{color}");
+		assert!(color.linear_to_srgb().srgb_to_linear().distance(color) < 1e-12, "{color}");
+	}
+	// Single precision round trip
+	let color = Vec3(0.2f32, 0.5, 0.9);
+	assert!(color.srgb_to_linear().linear_to_srgb().distance(color) < 1e-6);
+}
+
+#[test]
+fn srgb_known_values() {
+	// 18% gray
+	let gray = Vec3::dup(0.18f64).linear_to_srgb();
+	assert!((gray.x - 0.461356).abs() < 1e-6);
+	// The linear segment of the curve
+	assert_eq!(Vec3::dup(0.002f64).linear_to_srgb(), Vec3::dup(0.002 * 12.92));
+	// sRGB 128 is about 21.6% linear
+	assert!((Vec3::dup(128.0f64 / 255.0).srgb_to_linear().x - 0.215861).abs() < 1e-6);
+}
+
+#[test]
+fn hsv_round_trip() {
+	for color in grid() {
+		let hsv = color.rgb_to_hsv();
+		assert!(hsv.x >= 0.0 && hsv.x < 1.0, "{color} {hsv}");
+		assert!(hsv.hsv_to_rgb().distance(color) < 1e-12, "{color}");
+	}
+}
+
+#[test]
+fn hsv_known_values() {
+	let red = Vec3(1.0f64, 0.0, 0.0);
+	assert_eq!(red.rgb_to_hsv(), Vec3(0.0, 1.0, 1.0));
+	assert_eq!(Vec3(1.0f64, 1.0, 0.0).rgb_to_hsv(), Vec3(1.0 / 6.0, 1.0, 1.0));
+	assert_eq!(Vec3(0.0f64, 0.0, 1.0).rgb_to_hsv(), Vec3(2.0 / 3.0, 1.0, 1.0));
+	assert_eq!(Vec3(0.18f64, 0.18, 0.18).rgb_to_hsv(), Vec3(0.0, 0.0, 0.18));
+	assert_eq!(Vec3(5.0 / 6.0, 1.0, 1.0).hsv_to_rgb(), Vec3(1.0, 0.0, 1.0));
+}
+
+#[test]
+fn luminance() {
+	assert_eq!(Vec3(1.0f64, 0.0, 0.0).luminance(), 0.2126);
+	assert!((Vec3::dup(0.18f64).luminance() - 0.18).abs() < 1e-15);
+}
+
+#[test]
+fn reinhard() {
+	let mut prev = -1.0;
+	for i in 0..100 {
+		let c = Vec3::dup(i as f64 * 0.5).tonemap_reinhard();
+		assert!(c.x > prev && c.x < 1.0);
+		prev = c.x;
+	}
+}
+
+#[test]
+fn aces() {
+	assert_eq!(Vec3::<f64>::ZERO.tonemap_aces(), Vec3::ZERO);
+
+	let mut prev = 0.0;
+	for i in 1..1000 {
+		let x = i as f64 * 0.05;
+		let c = Vec3(x, x * 0.5, x * 2.0).tonemap_aces();
+		assert!(c.x >= prev && c.x <= 1.0);
+		assert!(c.y <= c.x && c.x <= c.z);
+		// Strictly increasing over the typical exposure range
+		if x < 2.0 {
+			assert!(c.x > prev);
+		}
+		prev = c.x;
+	}
+	assert_eq!(prev, 1.0);
+}
+
+#[test]
+fn xyz_matrices() {
+	// The matrices are inverses of each other up to rounding errors
+	for identity in [Mat3d::XYZ_TO_RGB * Mat3d::RGB_TO_XYZ, Mat3d::RGB_TO_XYZ * Mat3d::XYZ_TO_RGB] {
+		for (a, b) in identity.into_row_major().iter().flatten().zip(Mat3d::IDENTITY.into_row_major().iter().flatten()) {
+			assert!((a - b).abs() < 1e-15);
+		}
+	}
+
+	// D65 white
+	let white = Vec3(1.0f64, 1.0, 1.0).rgb_to_xyz();
+	assert!(white.distance(Vec3(0.9505, 1.0, 1.089)) < 1e-4);
+
+	for color in grid() {
+		assert!((color.rgb_to_xyz().y - color.luminance()).abs() < 1e-12);
+		assert!(color.rgb_to_xyz().xyz_to_rgb().distance(color) < 1e-14, "{color}");
+		assert!(color.xyz_to_rgb().rgb_to_xyz().distance(color) < 1e-14, "{color}");
+	}
+
+	// Single precision constants chain with other transforms
+	let m: Mat3f = Mat3f::XYZ_TO_RGB * Mat3f::scale(Vec3(2.0, 2.0, 2.0));
+	assert!((m.a11 - 6.481251).abs() < 1e-5);
+}
+
+#[test]
+fn hsv_hue_below_full_turn() {
+	// Reds just below 360° round to a hue of exactly one turn before wrapping
+	let hsv = Vec3(1.0f32, 0.0, 1e-10).rgb_to_hsv();
+	assert!(hsv.x >= 0.0 && hsv.x < 1.0, "{:?}", hsv);
+	let hsv = Vec3(1.0f64, 0.0, 1e-20).rgb_to_hsv();
+	assert_eq!(hsv, Vec3(0.0, 1.0, 1.0));
+}
+
+//----------------------------------------------------------------
+// Spherical coordinates
+
+#[test]
+fn round_trip_unit_vectors() {
+	let mut rng = Rng(42);
+	let mut count = 0;
+	while count < 1000 {
+		let v = Vec3(rng.next(), rng.next(), rng.next());
+		let len = v.len();
+		// Rejection sample the unit ball and stay away from the poles
+		if len > 1.0 || len < 0.1 || v.x.hypot(v.z) < 1e-3 * len {
+			continue;
+		}
+		count += 1;
+		let v = v / len;
+
+		let s = v.spherical();
+		assert!((s.radius - 1.0).abs() < 1e-12);
+		assert!(s.theta.value >= 0.0 && s.theta.value <= std::f64::consts::PI);
+		assert!(s.phi.value >= -std::f64::consts::PI && s.phi.value <= std::f64::consts::PI);
+		assert!(s.vec3().distance(v) < 1e-12);
+
+		let u = Spherical::unit(s.theta, s.phi);
+		assert!(u.vec3().distance(v) < 1e-12);
+	}
+}
+
+#[test]
+fn spherical_poles() {
+	let north = Vec3(0.0, 3.0, 0.0).spherical();
+	assert_eq!(north, Spherical(3.0, Rad(0.0), Rad(0.0)));
+	assert_eq!(north.vec3(), Vec3(0.0, 3.0, 0.0));
+
+	let south = Vec3(0.0, -2.0, 0.0).spherical();
+	assert_eq!(south.radius, 2.0);
+	assert_eq!(south.theta, Rad::half());
+	assert!(south.vec3().distance(Vec3(0.0, -2.0, 0.0)) < 1e-12);
+
+	let zero = Vec3(0.0, 0.0, 0.0).spherical();
+	assert_eq!(zero, Spherical(0.0, Rad(0.0), Rad(0.0)));
+	assert_eq!(zero.vec3(), Vec3(0.0, 0.0, 0.0));
+}
+
+#[test]
+fn spherical_axes() {
+	let close = |a: Vec3<f64>, b: Vec3<f64>| a.distance(b) < 1e-12;
+	assert!(close(Spherical::unit(Deg(90.0), Deg(0.0)).vec3(), Vec3::Z));
+	assert!(close(Spherical::unit(Deg(90.0), Deg(90.0)).vec3(), Vec3::X));
+	assert!(close(Spherical::unit(Deg(90.0), Deg(180.0)).vec3(), Vec3(0.0, 0.0, -1.0)));
+	assert!(close(Spherical::unit(Deg(90.0), Deg(-90.0)).vec3(), Vec3(-1.0, 0.0, 0.0)));
+}
+
+#[test]
+fn lerp_shortest_path() {
+	let a = Spherical(1.0f64, Deg(30.0).to_rad(), Deg(-170.0).to_rad());
+	let b = Spherical(3.0, Deg(90.0).to_rad(), Deg(160.0).to_rad());
+
+	assert_eq!(a.lerp(b, 0.0), a);
+	let end = a.lerp(b, 1.0);
+	assert!(end.vec3().distance(b.vec3()) < 1e-12);
+
+	// Goes through the back, crossing ±180°
+	let mid = a.lerp(b, 0.5);
+	assert_eq!(mid.radius, 2.0);
+	assert!((mid.theta.to_deg().value - 60.0).abs() < 1e-12);
+	assert!((mid.phi.to_deg().normalize().value.abs() - 175.0).abs() < 1e-12);
+}
+
+//----------------------------------------------------------------
+// Splines
+
+#[test]
+fn endpoints_are_exact() {
+	let p0 = Vec3(0.1, -2.3, 4.7);
+	let p1 = Vec3(1.9, 0.3, -0.7);
+	let p2 = Vec3(-3.3, 5.1, 2.2);
+	let p3 = Vec3(0.7, 0.9, -1.3);
+
+	assert_eq!(Vec3::bezier3(p0, p1, p2, p3, 0.0), p0);
+	assert_eq!(Vec3::bezier3(p0, p1, p2, p3, 1.0), p3);
+	assert_eq!(Vec3::catmull_rom(p0, p1, p2, p3, 0.0), p1);
+	assert_eq!(Vec3::catmull_rom(p0, p1, p2, p3, 1.0), p2);
+
+	let q = [Vec2(0.3f32, 0.7), Vec2(1.1, -0.2), Vec2(2.9, 0.4), Vec2(3.3, 1.7)];
+	assert_eq!(Vec2::bezier3(q[0], q[1], q[2], q[3], 1.0), q[3]);
+	assert_eq!(Vec2::catmull_rom(q[0], q[1], q[2], q[3], 1.0), q[2]);
+}
+
+#[test]
+fn tangents_match_finite_differences() {
+	let p0 = Vec3(0.0, 0.0, 0.0);
+	let p1 = Vec3(1.0, 2.0, 0.0);
+	let p2 = Vec3(3.0, 2.0, -1.0);
+	let p3 = Vec3(4.0, 0.0, 1.0);
+
+	let h = 1e-6;
+	for i in 1..10 {
+		let t = i as f64 / 10.0;
+		let fd = (Vec3::bezier3(p0, p1, p2, p3, t + h) - Vec3::bezier3(p0, p1, p2, p3, t - h)) / (2.0 * h);
+		assert!(fd.distance(Vec3::bezier3_tangent(p0, p1, p2, p3, t)) < 1e-8);
+
+		let fd = (Vec3::catmull_rom(p0, p1, p2, p3, t + h) - Vec3::catmull_rom(p0, p1, p2, p3, t - h)) / (2.0 * h);
+		assert!(fd.distance(Vec3::catmull_rom_tangent(p0, p1, p2, p3, t)) < 1e-8);
+	}
+}
+
+// Consecutive Catmull-Rom segments join with matching positions and tangents
+#[test]
+fn catmull_rom_continuity() {
+	let pts = [Vec2(0.0, 0.0), Vec2(1.0, 3.0), Vec2(4.0, 3.0), Vec2(5.0, -1.0), Vec2(8.0, 0.0)];
+	let end = Vec2::catmull_rom(pts[0], pts[1], pts[2], pts[3], 1.0);
+	let start = Vec2::catmull_rom(pts[1], pts[2], pts[3], pts[4], 0.0);
+	assert_eq!(end, start);
+
+	let end = Vec2::catmull_rom_tangent(pts[0], pts[1], pts[2], pts[3], 1.0);
+	let start = Vec2::catmull_rom_tangent(pts[1], pts[2], pts[3], pts[4], 0.0);
+	assert_eq!(end, start);
+}
+
+// A straight line of evenly spaced points is reproduced by both splines
+#[test]
+fn linear_precision() {
+	let p = |x: f64| Vec2(x, 2.0 * x + 1.0);
+	for i in 0..=8 {
+		let t = i as f64 / 8.0;
+		assert!(Vec2::bezier3(p(0.0), p(1.0), p(2.0), p(3.0), t).distance(p(3.0 * t)) < 1e-12);
+		assert!(Vec2::catmull_rom(p(0.0), p(1.0), p(2.0), p(3.0), t).distance(p(1.0 + t)) < 1e-12);
+	}
+}
+
+//----------------------------------------------------------------
+// Smoothstep and Hermite interpolation
+
+fn smoothstep(edge0: f64, edge1: f64, x: f64) -> f64 {
+	let t = ((x - edge0) / (edge1 - edge0)).clamp(0.0, 1.0);
+	t * t * (3.0 - 2.0 * t)
+}
+
+#[test]
+fn smoothstep_componentwise() {
+	let edge0 = Vec4(0.0, -1.0, 2.0, 1.0);
+	let edge1 = Vec4(1.0, 1.0, 4.0, 0.0);
+	for i in -10..20 {
+		let x = i as f64 * 0.25;
+		let v = Vec4(x, x * 0.5, x + 1.0, -x);
+		let expected = Vec4(
+			smoothstep(edge0.x, edge1.x, v.x),
+			smoothstep(edge0.y, edge1.y, v.y),
+			smoothstep(edge0.z, edge1.z, v.z),
+			smoothstep(edge0.w, edge1.w, v.w),
+		);
+		assert_eq!(v.smoothstep(edge0, edge1), expected);
+	}
+}
+
+#[test]
+fn hermite_matches_endpoints_and_tangents() {
+	let (p0, m0, p1, m1) = (Vec3(1.0, 2.0, 3.0), Vec3(-1.0, 0.5, 2.0), Vec3(4.0, -2.0, 0.0), Vec3(0.0, 3.0, -1.0));
+	assert_eq!(Vec3::hermite(p0, m0, p1, m1, 0.0), p0);
+	assert_eq!(Vec3::hermite(p0, m0, p1, m1, 1.0), p1);
+
+	// Finite difference tangents at the ends
+	let h = 1e-6;
+	let d0 = (Vec3::hermite(p0, m0, p1, m1, h) - p0) / h;
+	let d1 = (p1 - Vec3::hermite(p0, m0, p1, m1, 1.0 - h)) / h;
+	assert!(d0.distance(m0) < 1e-4);
+	assert!(d1.distance(m1) < 1e-4);
+}
+
+// Scalar clamp used to apply min and max in the wrong order, mapping every in-range value to the upper bound
+#[test]
+fn smoothstep_clamps_in_range() {
+	let v = Vec2(0.5, 0.25).smoothstep(Vec2(0.0, 0.0), Vec2(1.0, 1.0));
+	assert_eq!(v, Vec2(0.5, 0.15625));
+	assert_eq!(Vec2(-1.0, 5.0).smoothstep(Vec2(0.0, 0.0), Vec2(1.0, 1.0)), Vec2(0.0, 1.0));
+}
+
+#[test]
+fn smoothstep_equal_edges() {
+	// Equal edges are a step which is one at the edge
+	let edge = Vec3(1.0, 1.0, 1.0);
+	let v = Vec3(0.5, 1.0, 1.5);
+	assert_eq!(v.smoothstep(edge, edge), Vec3(0.0, 1.0, 1.0));
+	assert_eq!(v.smootherstep(edge, edge), Vec3(0.0, 1.0, 1.0));
+	assert_eq!(v.smoothstep(edge, edge), v.step(edge));
+
+	// Only the components with equal edges step
+	assert_eq!(Vec2(0.5, 0.5).smoothstep(Vec2(0.0, 0.5), Vec2(1.0, 0.5)), Vec2(0.5, 1.0));
+}
+
+//----------------------------------------------------------------
+// Sums
+
+#[test]
+fn sum_sample_colors() {
+	// Accumulate the samples of a pixel and average them
+	let samples = 16;
+	let color: Vec3<f32> = (0..samples).map(|i| Vec3(i as f32, 1.0, 0.5)).sum::<Vec3<f32>>() / samples as f32;
+	assert_eq!(color, Vec3(7.5, 1.0, 0.5));
+
+	let colors = [Vec3(0.25f32, 0.5, 1.0), Vec3(0.75, 0.5, 0.0)];
+	assert_eq!(colors.iter().sum::<Vec3<f32>>(), Vec3(1.0, 1.0, 1.0));
+	assert_eq!(colors.iter().product::<Vec3<f32>>(), Vec3(0.1875, 0.25, 0.0));
+	assert_eq!(colors.into_iter().product::<Vec3<f32>>(), Vec3(0.1875, 0.25, 0.0));
+	assert_eq!(Vec3::average(colors), Some(Vec3(0.5, 0.5, 0.5)));
+}
+
+#[test]
+fn bounds_from_points_matches_include() {
+	let mut rng = Rng(9);
+	for n in 1..50 {
+		let points: Vec<Point3<f64>> = (0..n).map(|_| rng.vec3() * 10.0).collect();
+		let expected = points[1..].iter().fold(Cuboid::point(points[0]), |bounds, &pt| bounds.include(pt));
+		assert_eq!(Cuboid::from_points(points.iter().copied()), Some(expected));
+		let centroid = Point3::centroid(&points).unwrap();
+		assert!(expected.contains(centroid));
+	}
+	assert_eq!(Cuboid::<f64>::from_points(std::iter::empty()), None);
+}
+
+//----------------------------------------------------------------
+// Sampling
+
+const N: usize = 100000;
+
+fn mean_var3(samples: &[Vec3<f64>]) -> (Vec3<f64>, Vec3<f64>) {
+	let n = samples.len() as f64;
+	let mean = samples.iter().sum::<Vec3<f64>>() / n;
+	let var = samples.iter().map(|&v| (v - mean) * (v - mean)).sum::<Vec3<f64>>() / n;
+	(mean, var)
+}
+
+fn assert_close3(a: Vec3<f64>, b: Vec3<f64>, tol: f64) {
+	assert!(a.distance(b) < tol, "{:?} {:?}", a, b);
+}
+
+#[test]
+fn sample_unit_circle() {
+	let mut rng = Rng(1);
+	let mut sum = Vec2::<f64>::ZERO;
+	let mut sqr = Vec2::<f64>::ZERO;
+	for _ in 0..N {
+		let v = Vec2::sample_unit_circle(rng.unit());
+		assert!((v.len() - 1.0).abs() < 1e-12);
+		sum += v;
+		sqr += v * v;
+	}
+	let mean = sum / N as f64;
+	let var = sqr / N as f64;
+	assert!(mean.len() < 0.01, "{:?}", mean);
+	assert!(var.distance(Vec2(0.5, 0.5)) < 0.01, "{:?}", var);
+}
+
+#[test]
+fn sample_unit_sphere() {
+	let mut rng = Rng(2);
+	let samples: Vec<_> = (0..N).map(|_| Vec3::sample_unit_sphere(rng.unit2())).collect();
+	for v in &samples {
+		assert!((v.len() - 1.0).abs() < 1e-12);
+	}
+	let (mean, var) = mean_var3(&samples);
+	assert_close3(mean, Vec3::ZERO, 0.01);
+	assert_close3(var, Vec3::dup(1.0 / 3.0), 0.01);
+}
+
+#[test]
+fn sample_unit_ball() {
+	let mut rng = Rng(3);
+	let samples: Vec<_> = (0..N).map(|_| Vec3::sample_unit_ball(rng.unit3())).collect();
+	for v in &samples {
+		assert!(v.len() <= 1.0 + 1e-12);
+	}
+	let (mean, var) = mean_var3(&samples);
+	assert_close3(mean, Vec3::ZERO, 0.01);
+	// Each component has variance 1/5 in the unit ball
+	assert_close3(var, Vec3::dup(0.2), 0.01);
+	// Half the volume lies within radius 0.5^(1/3)
+	let inner = samples.iter().filter(|v| v.len() < 0.5f64.powf(1.0 / 3.0)).count() as f64 / N as f64;
+	assert!((inner - 0.5).abs() < 0.01, "{}", inner);
+
+	let sphere = Sphere(Point3(1.0, 2.0, 3.0), 2.0);
+	let pt = sphere.sample(rng.unit3());
+	assert!(pt.distance(sphere.center) <= sphere.radius);
+}
+
+#[test]
+fn sample_cosine_hemisphere() {
+	let mut rng = Rng(4);
+	for &normal in &[Vec3(0.0, 0.0, 1.0), Vec3(0.0, 0.0, -1.0), Vec3(1.0, 2.0, -3.0).normalize(), Vec3(-0.3, 0.1, 0.9).normalize()] {
+		let samples: Vec<_> = (0..N).map(|_| Vec3::sample_cosine_hemisphere(normal, rng.unit2())).collect();
+		let mut cos_sum = 0.0;
+		let mut cos_sqr = 0.0;
+		for v in &samples {
+			assert!((v.len() - 1.0).abs() < 1e-9);
+			let cos = v.dot(normal);
+			assert!(cos >= -1e-12);
+			cos_sum += cos;
+			cos_sqr += cos * cos;
+		}
+		// E[cos θ] = 2/3 and E[cos² θ] = 1/2 for the cosine-weighted hemisphere
+		assert!((cos_sum / N as f64 - 2.0 / 3.0).abs() < 0.01);
+		assert!((cos_sqr / N as f64 - 0.5).abs() < 0.01);
+		// Mean direction lies along the normal
+		let (mean, _) = mean_var3(&samples);
+		assert_close3(mean, normal * (2.0 / 3.0), 0.01);
+	}
+}
+
+#[test]
+fn sample_triangle() {
+	let mut rng = Rng(5);
+	let triangle = Triangle3(Point3(1.0, 0.0, 0.0), Point3(4.0, 1.0, 0.0), Point3(0.0, 3.0, 2.0));
+	let samples: Vec<_> = (0..N).map(|_| triangle.sample(rng.unit2())).collect();
+	let plane = Plane::from_points(triangle.p0, triangle.p1, triangle.p2);
+	for &pt in &samples {
+		assert!(plane.dist_pt(pt).abs() < 1e-12);
+	}
+	let (mean, _) = mean_var3(&samples);
+	assert_close3(mean, (triangle.p0 + triangle.p1 + triangle.p2) / 3.0, 0.02);
+
+	// Uniform: the sub-triangle at the first corner with half the edges holds a quarter of the samples
+	let tri2 = Triangle2(Point2(0.0, 0.0), Point2(1.0, 0.0), Point2(0.0, 1.0));
+	let count = (0..N).map(|_| tri2.sample(rng.unit2())).filter(|pt| pt.x + pt.y < 0.5).count() as f64 / N as f64;
+	assert!((count - 0.25).abs() < 0.01, "{}", count);
+}
+
+#[test]
+fn sample_bounds() {
+	let mut rng = Rng(6);
+	let cuboid = Cuboid(Point3(-1.0, 0.0, 2.0), Point3(3.0, 1.0, 8.0));
+	let samples: Vec<_> = (0..N).map(|_| cuboid.sample(rng.unit3())).collect();
+	for &pt in &samples {
+		assert!(cuboid.contains(pt));
+	}
+	let (mean, var) = mean_var3(&samples);
+	assert_close3(mean, cuboid.center(), 0.02);
+	let size = cuboid.size();
+	assert_close3(var, size * size / 12.0, 0.05);
+
+	let rect = Rect(Point2(-1.0, 0.0), Point2(3.0, 1.0));
+	for _ in 0..1000 {
+		assert!(rect.contains(rect.sample(rng.unit2())));
+	}
+}
+
+//----------------------------------------------------------------
+// Fused multiply-add
+
+#[test]
+fn dot_fma_cancellation() {
+	// The products cancel, the plain dot product rounds the residual away
+	for k in 13..20 {
+		let e = 1.0 / (1u32 << k) as f32;
+		let lhs = Vec3(3.0f32, 1.0, 1.0 + e);
+		let rhs = Vec3(0.0f32, -1.0, 1.0 - e);
+		let exact = lhs.cast::<f64>().dot(rhs.cast::<f64>());
+		assert_eq!(lhs.dot_fma(rhs) as f64, exact);
+		assert_ne!(lhs.dot(rhs) as f64, exact);
+	}
+}
+
+#[test]
+fn len_fma_accuracy() {
+	// Compare against a double precision reference
+	let mut rng = Rng(1);
+	let mut plain_err = 0.0;
+	let mut fma_err = 0.0;
+	for _ in 0..10000 {
+		let v = (Vec4(rng.next(), rng.next(), rng.next(), rng.next()) * 1e6).cast::<f32>();
+		let exact = v.cast::<f64>().len();
+		plain_err += (v.len() as f64 - exact).abs();
+		fma_err += (v.len_fma() as f64 - exact).abs();
+		assert_eq!(v.distance_fma(Vec4::ZERO), v.len_fma());
+	}
+	assert!(fma_err <= plain_err, "{} {}", fma_err, plain_err);
+}
+
+//----------------------------------------------------------------
+// Half precision floats
+
+// Reference values from the IEEE 754 binary16 encoding, these are exact in both directions
+const EXACT: [(f32, u16); 14] = [
+	(0.0, 0x0000),
+	(-0.0, 0x8000),
+	(1.0, 0x3C00),
+	(-2.0, 0xC000),
+	(0.5, 0x3800),
+	(0.333251953125, 0x3555),
+	(65504.0, 0x7BFF),
+	(-65504.0, 0xFBFF),
+	(f32::INFINITY, 0x7C00),
+	(f32::NEG_INFINITY, 0xFC00),
+	// Smallest normal
+	(6.103515625e-5, 0x0400),
+	// Largest subnormal
+	(6.097555160522461e-5, 0x03FF),
+	// Smallest subnormal
+	(5.960464477539063e-8, 0x0001),
+	(-5.960464477539063e-8, 0x8001),
+];
+
+// Values which need rounding
+const ROUNDED: [(f32, u16); 6] = [
+	// Ties to even
+	(1.00048828125, 0x3C00),
+	(1.00146484375, 0x3C02),
+	(2.9802322387695312e-8, 0x0000),
+	(8.940696716308594e-8, 0x0002),
+	// Overflow after rounding
+	(65520.0, 0x7C00),
+	(1e10, 0x7C00),
+];
+
+#[test]
+fn half_reference_table() {
+	for &(value, half) in &EXACT {
+		assert_eq!(Vec3h::from_f32(Vec3::dup(value)), Vec3h(Vec3::dup(half)), "{} should convert to {:#06x}", value, half);
+		assert_eq!(Vec4h(Vec4::dup(half)).to_f32().x.to_bits(), value.to_bits(), "{:#06x} should convert to {}", half, value);
+	}
+	for &(value, half) in &ROUNDED {
+		assert_eq!(Vec4h::from_f32(Vec4::dup(value)), Vec4h(Vec4::dup(half)), "{} should round to {:#06x}", value, half);
+	}
+}
+
+#[test]
+fn half_round_trip_all() {
+	for half in 0..=u16::MAX {
+		let value = Vec3h(Vec3::dup(half)).to_f32();
+		if value.x.is_nan() {
+			assert_eq!(half & 0x7C00, 0x7C00);
+			assert!(Vec3h::from_f32(value).0.x & 0x03FF != 0);
+			continue;
+		}
+		assert_eq!(Vec3h::from_f32(value), Vec3h(Vec3::dup(half)), "{:#06x} {}", half, value.x);
+	}
+}
+
+#[test]
+fn half_nan() {
+	let v = Vec4h::from_f32(Vec4(f32::NAN, -f32::NAN, 0.0, 1.0));
+	assert!(v.to_f32().x.is_nan());
+	assert!(v.to_f32().y.is_nan());
+	assert_eq!(v.0.y & 0x8000, 0x8000);
+}
+
+//----------------------------------------------------------------
+// Normalized integers
+
+#[test]
+fn unorm8_round_trip() {
+	for i in 0..=1000 {
+		let c = i as f64 / 1000.0;
+		let v = Vec3(c, 1.0 - c, c * c);
+		let back = Vec3::<f64>::unpack_unorm8(v.pack_unorm8());
+		assert!((back - v).abs().vmax() <= 0.5 / 255.0 + 1e-12, "{v} {back}");
+	}
+
+	// Every byte survives the round trip exactly
+	for b in 0..=255u8 {
+		assert_eq!(Vec3::<f32>::unpack_unorm8([b; 3]).pack_unorm8(), [b; 3]);
+	}
+
+	assert_eq!(Vec3(-1.0f32, 2.0, f32::INFINITY).pack_unorm8(), [0, 255, 255]);
+}
+
+#[test]
+fn snorm8_round_trip() {
+	for i in -1000..=1000 {
+		let c = i as f64 / 1000.0;
+		let v = Vec3(c, -c, c * c);
+		let back = Vec3::<f64>::unpack_snorm8(v.pack_snorm8());
+		assert!((back - v).abs().vmax() <= 0.5 / 127.0 + 1e-12, "{v} {back}");
+	}
+
+	// Every byte except -128 survives the round trip exactly
+	for b in -127..=127i8 {
+		assert_eq!(Vec3::<f32>::unpack_snorm8([b; 3]).pack_snorm8(), [b; 3]);
+	}
+
+	// Unit normals stay close to unit length
+	let n = Vec3(1.0, -2.0, 3.0).normalize();
+	let back = Vec3::<f64>::unpack_snorm8(n.pack_snorm8());
+	assert!((back.len() - 1.0).abs() < 1.0 / 127.0);
+}
+
+//----------------------------------------------------------------
+// Octahedral encoding
+
+#[test]
+fn oct_round_trip() {
+	let mut rng = Rng(5);
+	for _ in 0..10000 {
+		let v = Vec3::sample_unit_sphere(Vec2(rng.unit(), rng.unit()));
+		let oct = v.to_oct();
+		assert!(oct.x.abs() <= 1.0 && oct.y.abs() <= 1.0, "{oct}");
+		assert!(Vec3::from_oct(oct).distance(v) < 1e-12, "{v}");
+
+		// Quantized to 8 bits per component
+		let oct = Vec2((oct.x * 127.0).round() / 127.0, (oct.y * 127.0).round() / 127.0);
+		assert!(Vec3::from_oct(oct).distance(v) < 2e-2, "{v}");
+	}
+}
+
+#[test]
+fn oct_poles() {
+	let axes = [
+		Vec3(1.0, 0.0, 0.0), Vec3(-1.0, 0.0, 0.0),
+		Vec3(0.0, 1.0, 0.0), Vec3(0.0, -1.0, 0.0),
+		Vec3(0.0, 0.0, 1.0), Vec3(0.0, 0.0, -1.0),
+	];
+	for v in axes {
+		assert_eq!(Vec3::from_oct(v.to_oct()), v);
+	}
+
+	// All four corners decode to the -Z pole
+	for corner in [Vec2(1.0, 1.0), Vec2(-1.0, 1.0), Vec2(1.0, -1.0), Vec2(-1.0, -1.0)] {
+		assert_eq!(Vec3::from_oct(corner), Vec3(0.0, 0.0, -1.0));
+	}
+}
+
+//----------------------------------------------------------------
+// Springs
+
+#[test]
+fn critically_damped_no_overshoot() {
+	let dt = 1.0 / 60.0;
+	for smooth_time in [0.05, 0.25, 1.0] {
+		let mut pos = 0.0f64;
+		let mut velocity = 0.0;
+		for _ in 0..(smooth_time * 10.0 / dt) as usize {
+			let next = spring_damp(pos, 10.0, &mut velocity, smooth_time, dt, None);
+			assert!(next >= pos && next <= 10.0, "{pos} {next}");
+			pos = next;
+		}
+		assert!((pos - 10.0).abs() < 1e-3, "{smooth_time} {pos}");
+	}
+}
+
+#[test]
+fn moving_away_turns_around() {
+	// Starting with velocity away from the target
+	let mut pos = 0.0f64;
+	let mut velocity = -20.0;
+	for _ in 0..600 {
+		pos = spring_damp(pos, 1.0, &mut velocity, 0.2, 1.0 / 60.0, None);
+		assert!(pos <= 1.0);
+	}
+	assert!((pos - 1.0).abs() < 1e-6);
+}
+
+#[test]
+fn spring_max_speed() {
+	let dt = 1.0 / 60.0;
+	let mut pos = Vec3(0.0f64, 0.0, 0.0);
+	let target = Vec3(100.0, -50.0, 20.0);
+	let mut velocity = Vec3::ZERO;
+	for _ in 0..600 {
+		let next = pos.spring_damp(target, &mut velocity, 0.5, dt, Some(2.0));
+		assert!(velocity.len() <= 2.0 + 1e-9, "{velocity}");
+		assert!(next.distance(pos) <= 2.0 * dt + 1e-9);
+		pos = next;
+	}
+	// Ten seconds at two units per second
+	assert!((pos.len() - 20.0).abs() < 1.0, "{pos}");
+}
+
+#[test]
+fn spring_large_time_steps() {
+	for dt in [1.0, 10.0, 1e6] {
+		let mut pos = Vec2(5.0f64, -5.0);
+		let mut velocity = Vec2(100.0, 100.0);
+		for _ in 0..10 {
+			pos = pos.spring_damp(Vec2::ZERO, &mut velocity, 0.1, dt, None);
+			assert!(pos.all_finite() && velocity.all_finite());
+			assert!(pos.len() <= 5.0 * 2f64.sqrt() + 1e-9, "{dt} {pos}");
+		}
+		assert!(pos.len() < 1e-3, "{dt} {pos}");
+	}
+}
+
+#[test]
+fn spring_angle_shortest_path() {
+	let mut angle = Deg(170.0f64);
+	let mut velocity = Deg(0.0);
+	for _ in 0..600 {
+		let next = angle.spring_damp(Deg(-170.0), &mut velocity, 0.2, 1.0 / 60.0, None);
+		// Goes up through 180° instead of down through 0°
+		assert!(next >= angle);
+		angle = next;
+	}
+	assert!((angle.value - 190.0).abs() < 1e-6, "{angle}");
+}
+
+//----------------------------------------------------------------
+// Boolean masks
+
+#[test]
+fn select_mixed_masks() {
+	let a = Vec4(1.0, 2.0, 3.0, 4.0);
+	let b = Vec4(4.0, 2.0, 1.0, 5.0);
+
+	// Every mask selects from the left-hand side where true
+	for bits in 0..16 {
+		let mask = Bool4::from_bitmask(bits);
+		let v: [f64; 4] = mask.select(a, b).into();
+		let (lhs, rhs): ([f64; 4], [f64; 4]) = (a.into(), b.into());
+		for i in 0..4 {
+			assert_eq!(v[i], if bits & (1 << i) != 0 { lhs[i] } else { rhs[i] });
+		}
+		assert_eq!((!mask).select(a, b), mask.select(b, a));
+	}
+
+	// Componentwise min and max
+	assert_eq!(a.lt(b).select(a, b), Vec4(1.0, 2.0, 1.0, 4.0));
+	assert_eq!(a.gt(b).select(a, b), Vec4(4.0, 2.0, 3.0, 5.0));
+
+	// The comparisons partition the components
+	assert_eq!(a.lt(b) | a.eq(b) | a.gt(b), Bool4(true, true, true, true));
+	assert_eq!(a.le(b), a.lt(b) | a.eq(b));
+	assert_eq!(a.ge(b), a.gt(b) | a.eq(b));
+	assert_eq!(a.ne(b), !a.eq(b));
+	assert_eq!(a.lt(b) & a.ge(b), Bool4(false, false, false, false));
+
+	// Combining masks
+	let mask = a.ge(Vec4::dup(2.0)) & a.le(Vec4::dup(3.0));
+	assert_eq!(mask, Bool4(false, true, true, false));
+	assert_eq!(mask.select_scalar(1, 0), Vec4(0, 1, 1, 0));
+	assert!(mask.any() && !mask.all() && !mask.none());
+}
+
+#[test]
+fn nan_masks() {
+	let v = Vec3(1.0, f64::NAN, f64::INFINITY);
+
+	// NaN compares unequal to everything, including itself
+	assert_eq!(v.eq(v), Bool3(true, false, true));
+	assert_eq!(v.ne(v), Bool3(false, true, false));
+	assert_eq!(v.lt(Vec3::dup(f64::INFINITY)) | v.ge(Vec3::dup(f64::INFINITY)), Bool3(true, false, true));
+
+	// Replace non-finite components
+	assert_eq!(v.is_finite().select(v, Vec3::ZERO), Vec3(1.0, 0.0, 0.0));
+	assert_eq!(v.is_nan(), Bool3(false, true, false));
+	assert_eq!(v.is_infinite(), Bool3(false, false, true));
+	assert!(v.any_nan() && !v.all_finite());
+}
+
+//----------------------------------------------------------------
+// GLSL style functions
+
+#[test]
+fn step_at_the_edge_is_one() {
+	let edge = Vec4(0.0, 1.0, -1.0, 0.5);
+	assert_eq!(edge.step(edge), Vec4::dup(1.0));
+	assert_eq!(Vec4(-0.1, 0.9, -1.1, 0.4).step(edge), Vec4::dup(0.0));
+	assert_eq!(Vec2(f64::NAN, 2.0).step(Vec2(0.0, 1.0)), Vec2(1.0, 1.0));
+}
+
+#[test]
+fn mix_selects_rhs_where_true() {
+	let x = Vec4(1, 2, 3, 4);
+	let y = Vec4(5, 6, 7, 8);
+	for bits in 0..16 {
+		let mask = Bool4::from_bitmask(bits);
+		let mixed = x.mix(y, mask);
+		assert_eq!(mixed, mask.select(y, x));
+		assert_eq!(mixed.eq(y), mask | x.eq(y));
+	}
+}
+
+#[test]
+fn step_and_mix_agree() {
+	let a = Vec3(-1.0, 2.0, 4.0);
+	let b = Vec3(3.0, -5.0, 0.5);
+	let t = Vec3(0.1, 0.7, 0.5);
+	let edge = Vec3::dup(0.5);
+	assert_eq!(a + (b - a) * t.step(edge), a.mix(b, t.ge(edge)));
+}
+
+#[test]
+fn clamp_matches_scalar_clamp() {
+	let min = Vec3(-1.0, 0.0, 2.0);
+	let max = Vec3(1.0, 0.5, 3.0);
+	for x in [-3.0f64, -0.5, 0.25, 1.5, 2.5, 4.0] {
+		let v = Vec3::dup(x).clamp(min, max);
+		assert_eq!(v, Vec3(x.clamp(-1.0, 1.0), x.clamp(0.0, 0.5), x.clamp(2.0, 3.0)));
+	}
+}
+
+//----------------------------------------------------------------
+// Polar angles
+
+#[test]
+fn from_angle_inverts_polar_angle() {
+	for i in -179..180 {
+		let angle = Deg(i as f64).to_rad();
+		let v = Vec2::from_angle(angle);
+		assert!((v.len() - 1.0).abs() < 1e-12);
+		assert!((v.polar_angle().value - angle.value).abs() < 1e-12, "{}", i);
+	}
+}
+
+#[test]
+fn polar_angle_inverts_from_angle() {
+	for i in 0..360 {
+		let v = Vec2::from_angle(Deg(i as f64 + 0.5));
+		assert!(Vec2::from_angle(v.polar_angle()).distance(v) < 1e-12, "{}", i);
+	}
+}
+
+#[test]
+fn from_angle_length_scales() {
+	let v = Vec2::from_angle_length(Rad(0.75f64), 3.0);
+	assert!((v.len() - 3.0).abs() < 1e-12);
+	assert!(v.distance(Vec2::from_angle(Rad(0.75)) * 3.0) < 1e-12);
+	assert_eq!(Vec2::from_angle_length(Rad(1.0f64), 0.0), Vec2(0.0, 0.0));
+}
+
+#[test]
+fn rotate_matches_matrix() {
+	let mut rng = Rng(42u64);
+	for _ in 0..1000 {
+		let v = Vec2(rng.next() * 100.0, rng.next() * 100.0);
+		let angle = Rad(rng.next() * 10.0);
+		assert_eq!(v.rotate(angle), Mat2::rotate(angle) * v);
+		let v = v.cast::<f32>();
+		let angle = Deg(angle.value as f32 * 57.0);
+		assert_eq!(v.rotate(angle), Mat2::rotate(angle) * v);
+	}
+}
+
+#[test]
+fn rotate_preserves_length_and_composes() {
+	let v = Vec2(3.0f64, -4.0);
+	assert!((v.rotate(Deg(123.0)).len() - 5.0).abs() < 1e-12);
+	assert!(v.rotate(Deg(40.0)).rotate(Deg(50.0)).distance(v.rotate(Deg(90.0))) < 1e-12);
+	// Positive angles rotate from +X towards +Y, clockwise in screen coordinates
+	assert!(v.rotate(Deg(90.0)).distance(v.cw()) < 1e-12);
+	assert_eq!(v.rotate(Rad(0.0)), v);
+}
+
+//----------------------------------------------------------------
+// Binary angles
+
+#[test]
+fn binangle_wrapping() {
+	assert_eq!(BinAngle(0xffff) + BinAngle(1), BinAngle::ZERO);
+	assert_eq!(BinAngle::ZERO - BinAngle(1), BinAngle(0xffff));
+	assert_eq!(-BinAngle::ZERO, BinAngle::ZERO);
+	assert_eq!(-BinAngle::HALF, BinAngle::HALF);
+	assert_eq!(-BinAngle::QUARTER, BinAngle(0xc000));
+	assert_eq!(BinAngle::HALF * 2, BinAngle::ZERO);
+	assert_eq!(BinAngle::QUARTER * 5, BinAngle::QUARTER);
+	assert_eq!(BinAngle::QUARTER * -1, BinAngle(0xc000));
+	assert_eq!(BinAngle(3) * -70000, -BinAngle((3 * 70000 % 65536) as u16));
+
+	let mut a = BinAngle(0xfff0);
+	a += BinAngle(0x20);
+	assert_eq!(a, BinAngle(0x10));
+	a -= BinAngle(0x20);
+	assert_eq!(a, BinAngle(0xfff0));
+	a *= 16;
+	assert_eq!(a, BinAngle(0xff00));
+}
+
+#[test]
+fn binangle_conversions() {
+	for i in 0..=0xffffu16 {
+		let a = BinAngle(i);
+		assert_eq!(BinAngle::from_angle(a.to_rad::<f64>()), a);
+		assert_eq!(BinAngle::from(Deg::<f64>::from(a)), a);
+		assert_eq!(BinAngle::from(a.to_rad::<f64>() - Rad::turn()), a);
+	}
+	assert_eq!(BinAngle::from(Deg(-180.0f32)), BinAngle::HALF);
+	assert_eq!(BinAngle::from(Deg(359.999f64)), BinAngle::ZERO);
+	assert_eq!(BinAngle::QUARTER.to_deg::<f32>(), Deg(90.0));
+}
+
+#[test]
+fn binangle_sin_cos_error() {
+	let mut max_error = 0.0f64;
+	for i in 0..=0xffffu16 {
+		let (sin, cos) = BinAngle(i).sin_cos();
+		let (exact_sin, exact_cos) = BinAngle(i).to_rad::<f64>().value.sin_cos();
+		max_error = max_error.max((sin as f64 - exact_sin).abs()).max((cos as f64 - exact_cos).abs());
+		assert_eq!(BinAngle(i).sin(), sin);
+		assert_eq!(BinAngle(i).cos(), cos);
+	}
+	assert!(max_error <= BinAngle::MAX_ERROR as f64, "max error: {max_error}");
+}
+
+#[test]
+fn binangle_bit_exact() {
+	// Simulates a spinning object in lockstep, the results must not depend on the platform
+	let mut angle = BinAngle::ZERO;
+	let mut pos = Vec2(0.0f32, 0.0);
+	let mut checksum = 0u32;
+	for i in 0..10000 {
+		angle += BinAngle(0x123) * (i % 7 - 3);
+		angle = -angle + BinAngle(i as u16);
+		pos += Vec2(1.0f32, 0.5).rotate_bin(angle);
+		let (sin, cos) = angle.sin_cos();
+		checksum = checksum.rotate_left(5) ^ sin.to_bits() ^ cos.to_bits().rotate_left(16);
+	}
+	assert_eq!(angle, BinAngle(0x1142));
+	assert_eq!((pos.x.to_bits(), pos.y.to_bits()), (0x4603c227, 0x45df770a));
+	assert_eq!(checksum, 0x7f366170);
+}
+
+//----------------------------------------------------------------
+// Polynomial roots
+
+#[test]
+fn quadratic_cancellation() {
+	// Roots -1e-k and -1e+k where b ≫ ac, the naive formula loses the root close to zero
+	for k in [4, 6, 8, 10, 12] {
+		let r = 10.0f64.powi(k);
+		let (a, b, c) = (1.0, r + 1.0 / r, 1.0);
+		let (x0, x1) = solve_quadratic(a, b, c).unwrap();
+		assert!((x0 + r).abs() <= r * 1e-15);
+		assert!((x1 * r + 1.0).abs() < 1e-15);
+
+		let naive = (-b + (b * b - 4.0 * a * c).sqrt()) / (2.0 * a);
+		if k >= 8 {
+			assert!((naive * r + 1.0).abs() > 1e-3);
+		}
+	}
+
+	let (x0, x1) = solve_quadratic(1.0f32, -1e5, 1.0).unwrap();
+	assert_eq!((x0, x1), (1e-5, 1e5));
+}
+
+#[test]
+fn quadratic_special_cases() {
+	// Double roots
+	assert_eq!(solve_quadratic(1.0, -6.0, 9.0), Some((3.0, 3.0)));
+	assert_eq!(solve_quadratic(4.0, 4.0, 1.0), Some((-0.5, -0.5)));
+	assert_eq!(solve_quadratic(2.0, 0.0, 0.0), Some((0.0, 0.0)));
+
+	// No real roots
+	assert_eq!(solve_quadratic(1.0, 1.0, 1.0), None);
+	assert_eq!(solve_quadratic(-1.0, 0.0, -4.0), None);
+	assert_eq!(solve_quadratic(0.0, 0.0, 1.0), None);
+	assert_eq!(solve_quadratic(f64::NAN, 1.0, 1.0), None);
+
+	// Linear and negative leading coefficient
+	assert_eq!(solve_quadratic(0.0, 2.0, -4.0), Some((2.0, 2.0)));
+	assert_eq!(solve_quadratic(-1.0, 0.0, 4.0), Some((-2.0, 2.0)));
+}
+
+#[test]
+fn cubic_random_roots() {
+	let mut rng = Rng(3);
+	let mut roots = [0.0f64; 3];
+	for _ in 0..1000 {
+		let mut expected = [rng.next() * 10.0, rng.next() * 10.0, rng.next() * 10.0];
+		expected.sort_by(|a, b| a.total_cmp(b));
+		let [r0, r1, r2] = expected;
+		let scale = rng.next() + 2.0;
+		let (a, b, c, d) = (scale, -scale * (r0 + r1 + r2), scale * (r0 * r1 + r1 * r2 + r2 * r0), -scale * r0 * r1 * r2);
+		let n = solve_cubic(a, b, c, d, &mut roots);
+		if r1 - r0 < 1e-3 || r2 - r1 < 1e-3 {
+			continue;
+		}
+		assert_eq!(n, 3);
+		for (root, expected) in roots.iter().zip(&expected) {
+			assert!((root - expected).abs() < 1e-8, "{roots:?} {expected:?}");
+		}
+	}
+}
+
+#[test]
+fn cubic_special_cases() {
+	let mut roots = [0.0f64; 3];
+
+	// Single real root
+	assert_eq!(solve_cubic(1.0, 0.0, 0.0, -8.0, &mut roots), 1);
+	assert_eq!(roots[0], 2.0);
+	assert_eq!(solve_cubic(2.0, 0.0, 2.0, 4.0, &mut roots), 1);
+	assert!((roots[0] + 1.0).abs() < 1e-12);
+
+	// Double and triple roots: (x - 1)²(x + 2) and (x - 1)³
+	assert_eq!(solve_cubic(1.0, 0.0, -3.0, 2.0, &mut roots), 2);
+	assert_eq!(&roots[..2], &[-2.0, 1.0]);
+	assert_eq!(solve_cubic(1.0, -3.0, 3.0, -1.0, &mut roots), 1);
+	assert_eq!(roots[0], 1.0);
+
+	// Degenerates to a quadratic
+	assert_eq!(solve_cubic(0.0, 1.0, -3.0, 2.0, &mut roots), 2);
+	assert_eq!(&roots[..2], &[1.0, 2.0]);
+	assert_eq!(solve_cubic(0.0, 1.0, 0.0, 1.0, &mut roots), 0);
+}
+
+#[test]
+fn smallest_root() {
+	assert_eq!(smallest_root_in(0.0, 10.0, &[5.0, -1.0, 3.0, 12.0]), Some(3.0));
+	assert_eq!(smallest_root_in(0.0, 10.0, &[0.0, 10.0]), Some(0.0));
+	assert_eq!(smallest_root_in(0.0, 10.0, &[-1.0, f64::NAN, 11.0]), None);
+	assert_eq!(smallest_root_in(0.0, 10.0, &[]), None);
+}
+
+#[test]
+fn sphere_far_away() {
+	// A small sphere far away from the ray origin, the hits must remain accurate
+	let sphere = Sphere(Point3(0.0, 0.0, 1e6), 1.0);
+	let ray = Ray(Point3(0.0f64, 0.0, 0.0), Vec3(0.0, 0.0, 1.0));
+	let mut hits = [TraceHit::default(); 2];
+	assert_eq!(ray.trace(&sphere, &mut hits), 2);
+	assert_eq!(hits[0].distance, 1e6 - 1.0);
+	assert_eq!(hits[1].distance, 1e6 + 1.0);
+
+	// The direction does not need to be normalized
+	let ray = Ray(Point3(0.0f64, 0.0, 0.0), Vec3(0.0, 0.0, 4.0));
+	assert_eq!(ray.trace(&sphere, &mut hits), 2);
+	assert_eq!(hits[0].distance, (1e6 - 1.0) / 4.0);
+}